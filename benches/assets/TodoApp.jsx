@@ -0,0 +1,174 @@
+import React, { Fragment, useCallback, useEffect, useMemo, useReducer, useRef } from "react";
+import * as Icons from "./icons";
+import { Button, Checkbox, TextField, Tooltip } from "./ui";
+
+const FILTERS = {
+  all: () => true,
+  active: (todo) => !todo.done,
+  done: (todo) => todo.done,
+};
+
+function reducer(state, action) {
+  switch (action.type) {
+    case "add":
+      return {
+        ...state,
+        todos: [...state.todos, { id: state.nextId, title: action.title, done: false }],
+        nextId: state.nextId + 1,
+      };
+    case "toggle":
+      return {
+        ...state,
+        todos: state.todos.map((todo) =>
+          todo.id === action.id ? { ...todo, done: !todo.done } : todo
+        ),
+      };
+    case "remove":
+      return { ...state, todos: state.todos.filter((todo) => todo.id !== action.id) };
+    case "rename":
+      return {
+        ...state,
+        todos: state.todos.map((todo) =>
+          todo.id === action.id ? { ...todo, title: action.title } : todo
+        ),
+      };
+    case "filter":
+      return { ...state, filter: action.filter };
+    case "clear":
+      return { ...state, todos: state.todos.filter((todo) => !todo.done) };
+    default:
+      throw new Error(`Unknown action: ${action.type}`);
+  }
+}
+
+function TodoItem({ todo, onToggle, onRemove, onRename }) {
+  const [editing, setEditing] = React.useState(false);
+  const input = useRef(null);
+
+  useEffect(() => {
+    if (editing && input.current) {
+      input.current.focus();
+    }
+  }, [editing]);
+
+  const submit = (e) => {
+    e.preventDefault();
+    onRename(todo.id, input.current.value);
+    setEditing(false);
+  };
+
+  return (
+    <li className={todo.done ? "todo todo--done" : "todo"} data-id={todo.id}>
+      {editing ? (
+        <form onSubmit={submit}>
+          <TextField ref={input} defaultValue={todo.title} onBlur={submit} />
+        </form>
+      ) : (
+        <Fragment>
+          <Checkbox checked={todo.done} onChange={() => onToggle(todo.id)} />
+          <span className="todo__title" onDoubleClick={() => setEditing(true)}>
+            {todo.title}
+          </span>
+          <Tooltip title="Edit">
+            <Button variant="icon" onClick={() => setEditing(true)}>
+              <Icons.Edit size={16} />
+            </Button>
+          </Tooltip>
+          <Tooltip title="Remove">
+            <Button variant="icon" onClick={() => onRemove(todo.id)}>
+              <Icons.Close size={16} />
+            </Button>
+          </Tooltip>
+        </Fragment>
+      )}
+    </li>
+  );
+}
+
+function Footer({ count, filter, onFilter, onClear }) {
+  return (
+    <footer className="footer">
+      <span className="footer__count">
+        <strong>{count}</strong> {count === 1 ? "item" : "items"} left
+      </span>
+      <ul className="footer__filters">
+        {Object.keys(FILTERS).map((name) => (
+          <li key={name}>
+            <a
+              href={`#/${name}`}
+              className={name === filter ? "selected" : undefined}
+              onClick={() => onFilter(name)}
+            >
+              {name[0].toUpperCase() + name.slice(1)}
+            </a>
+          </li>
+        ))}
+      </ul>
+      <Button variant="text" onClick={onClear}>
+        <Icons.Trash size={14} /> Clear completed
+      </Button>
+    </footer>
+  );
+}
+
+export default function TodoApp({ initialTodos = [] }) {
+  const [state, dispatch] = useReducer(reducer, {
+    todos: initialTodos,
+    nextId: initialTodos.length + 1,
+    filter: "all",
+  });
+  const input = useRef(null);
+
+  const visible = useMemo(() => state.todos.filter(FILTERS[state.filter]), [
+    state.todos,
+    state.filter,
+  ]);
+  const remaining = state.todos.filter(FILTERS.active).length;
+
+  const onToggle = useCallback((id) => dispatch({ type: "toggle", id }), []);
+  const onRemove = useCallback((id) => dispatch({ type: "remove", id }), []);
+  const onRename = useCallback((id, title) => dispatch({ type: "rename", id, title }), []);
+
+  const onSubmit = (e) => {
+    e.preventDefault();
+    const title = input.current.value.trim();
+    if (title) {
+      dispatch({ type: "add", title });
+      input.current.value = "";
+    }
+  };
+
+  return (
+    <section className="todoapp">
+      <header className="header">
+        <h1>
+          <Icons.Check size={32} /> todos
+        </h1>
+        <form onSubmit={onSubmit}>
+          <TextField ref={input} placeholder="What needs to be done?" autoFocus />
+        </form>
+      </header>
+      {state.todos.length > 0 && (
+        <Fragment>
+          <ul className="todo-list">
+            {visible.map((todo) => (
+              <TodoItem
+                key={todo.id}
+                todo={todo}
+                onToggle={onToggle}
+                onRemove={onRemove}
+                onRename={onRename}
+              />
+            ))}
+          </ul>
+          <Footer
+            count={remaining}
+            filter={state.filter}
+            onFilter={(filter) => dispatch({ type: "filter", filter })}
+            onClear={() => dispatch({ type: "clear" })}
+          />
+        </Fragment>
+      )}
+    </section>
+  );
+}
@@ -0,0 +1,188 @@
+//! Throughput of parsing, transforming and emitting representative files.
+//!
+//! Run with `cargo bench --features bench --bench throughput`.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use std::sync::Arc;
+use swc::{
+    config::{Config, JscConfig, JscTarget, Options, SourceMapsConfig},
+    Compiler,
+};
+use swc_common::{
+    errors::{ColorConfig, Handler},
+    FileName, FilePathMapping, SourceMap,
+};
+use swc_ecma_ast::Program;
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+
+struct Fixture {
+    name: &'static str,
+    src: String,
+    syntax: Syntax,
+    is_module: bool,
+}
+
+impl Fixture {
+    fn options(&self) -> Options {
+        Options {
+            config: Some(Config {
+                jsc: JscConfig {
+                    syntax: Some(self.syntax),
+                    target: JscTarget::Es5,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            swcrc: false,
+            is_module: self.is_module,
+            ..Default::default()
+        }
+    }
+
+    fn parse(&self, c: &Compiler) -> Program {
+        let fm =
+            c.cm.new_source_file(FileName::Custom(self.name.into()), self.src.clone());
+
+        c.parse_js(fm, JscTarget::Es2020, self.syntax, self.is_module, false)
+            .unwrap()
+    }
+}
+
+fn mk() -> Compiler {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let handler = Arc::new(Handler::with_tty_emitter(
+        ColorConfig::Always,
+        true,
+        false,
+        Some(cm.clone()),
+    ));
+
+    Compiler::new(cm, handler)
+}
+
+/// jQuery minified by swc, as a stand-in for a large production bundle.
+fn minified_bundle(c: &Compiler) -> String {
+    let jquery = Fixture {
+        name: "jquery-1.9.1.js",
+        src: include_str!("../ecmascript/parser/benches/files/jquery-1.9.1.js").into(),
+        syntax: Syntax::default(),
+        is_module: false,
+    };
+    let program = jquery.parse(c);
+
+    c.print(
+        &program,
+        JscTarget::Es2020,
+        SourceMapsConfig::Bool(false),
+        None,
+        true,
+    )
+    .unwrap()
+    .code
+}
+
+fn fixtures(c: &Compiler) -> Vec<Fixture> {
+    vec![
+        Fixture {
+            name: "minified",
+            src: minified_bundle(c),
+            syntax: Syntax::default(),
+            is_module: false,
+        },
+        Fixture {
+            name: "typescript",
+            src: include_str!("assets/AjaxObservable.ts").into(),
+            syntax: Syntax::Typescript(TsConfig {
+                decorators: true,
+                ..Default::default()
+            }),
+            is_module: true,
+        },
+        Fixture {
+            name: "jsx",
+            src: include_str!("assets/TodoApp.jsx").into(),
+            syntax: Syntax::Es(EsConfig {
+                jsx: true,
+                ..Default::default()
+            }),
+            is_module: true,
+        },
+    ]
+}
+
+fn parse(cr: &mut Criterion) {
+    let c = mk();
+    let mut group = cr.benchmark_group("parse");
+
+    for f in fixtures(&c) {
+        group.throughput(Throughput::Bytes(f.src.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(f.name), &f, |b, f| {
+            b.iter(|| black_box(f.parse(&c)))
+        });
+    }
+
+    group.finish();
+}
+
+fn transform(cr: &mut Criterion) {
+    let c = mk();
+    let mut group = cr.benchmark_group("transform");
+
+    for f in fixtures(&c) {
+        let program = f.parse(&c);
+        let name = FileName::Custom(f.name.into());
+        let options = f.options();
+
+        group.throughput(Throughput::Bytes(f.src.len() as u64));
+        group.bench_function(BenchmarkId::from_parameter(f.name), |b| {
+            b.iter_batched(
+                || {
+                    let config = c.config_for_file(&options, &name).unwrap();
+                    (program.clone(), config)
+                },
+                |(program, config)| {
+                    black_box(c.transform(program, config.external_helpers, config.pass))
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn emit(cr: &mut Criterion) {
+    let c = mk();
+    let mut group = cr.benchmark_group("emit");
+
+    for f in fixtures(&c) {
+        let program = f.parse(&c);
+        let config = c
+            .config_for_file(&f.options(), &FileName::Custom(f.name.into()))
+            .unwrap();
+        let program = c.transform(program, config.external_helpers, config.pass);
+
+        group.throughput(Throughput::Bytes(f.src.len() as u64));
+        group.bench_function(BenchmarkId::from_parameter(f.name), |b| {
+            b.iter(|| {
+                black_box(
+                    c.print(
+                        &program,
+                        JscTarget::Es5,
+                        SourceMapsConfig::Bool(false),
+                        None,
+                        false,
+                    )
+                    .unwrap(),
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse, transform, emit);
+criterion_main!(benches);
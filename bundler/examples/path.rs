@@ -45,8 +45,12 @@ fn main() {
 
     let wr = stdout();
     let mut emitter = Emitter {
-        cfg: swc_ecma_codegen::Config { minify: false },
+        cfg: swc_ecma_codegen::Config {
+            minify: false,
+            ..Default::default()
+        },
         cm: cm.clone(),
+        ident_hook: None,
         comments: None,
         wr: Box::new(JsWriter::new(cm.clone(), "\n", wr.lock(), None)),
     };
@@ -505,6 +505,7 @@ where
                             src: src.clone(),
                             type_only: false,
                             asserts: None,
+                            with: false,
                         };
 
                         if self.top_level {
@@ -604,6 +605,7 @@ where
                         src,
                         type_only: false,
                         asserts: None,
+                        with: false,
                     };
 
                     // if self.top_level {
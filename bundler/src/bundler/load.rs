@@ -296,6 +296,7 @@ where
                             src,
                             type_only: false,
                             asserts: None,
+                            with: false,
                         },
                         true,
                         false,
@@ -14,6 +14,7 @@ pub(crate) fn calc_hash(cm: Lrc<SourceMap>, m: &Module) -> Result<String, Error>
             cfg: Default::default(),
             cm,
             comments: None,
+            ident_hook: None,
             wr: Box::new(&mut buf) as Box<dyn WriteJs>,
         };
 
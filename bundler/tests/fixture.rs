@@ -196,6 +196,7 @@ fn reference_tests(tests: &mut Vec<TestDescAndFn>, errors: bool) -> Result<(), i
                                     ..Default::default()
                                 },
                                 cm: cm.clone(),
+                                ident_hook: None,
                                 comments: None,
                                 wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
                             };
@@ -0,0 +1,307 @@
+//! Arena allocation for short-lived data like the ast.
+//!
+//! Nodes of the ast are stored in boxes and vectors, which are allocated by
+//! the global allocator. [ArenaAlloc] is a global allocator which bump
+//! allocates small values in large chunks while [scope] is running on the
+//! current thread, so parsing and emitting a file calls the system allocator a
+//! few times instead of once per node.
+//!
+//! A chunk is returned to the system allocator when all values allocated in it
+//! are dropped, so values may outlive [scope] and may be dropped by other
+//! threads. Note that a long-lived value, like an atom interned while parsing,
+//! keeps the whole chunk alive.
+//!
+//! ```ignore
+//! use swc_common::arena::{self, ArenaAlloc};
+//!
+//! #[global_allocator]
+//! static ALLOC: ArenaAlloc = ArenaAlloc;
+//!
+//! let code = arena::scope(|| {
+//!     let module = parser.parse_module()?;
+//!     emit(&module)
+//! });
+//! ```
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    mem, ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Size and alignment of a chunk.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// Allocations larger than this are passed to the system allocator.
+const MAX_ARENA_ALLOC: usize = CHUNK_SIZE / 8;
+
+/// Maximum number of live chunks.
+///
+/// Allocations are passed to the system allocator while there are this many
+/// chunks, so the arenas of all threads hold at most `MAX_CHUNKS * CHUNK_SIZE`
+/// bytes.
+const MAX_CHUNKS: usize = 1024;
+
+const EMPTY_SLOT: AtomicUsize = AtomicUsize::new(0);
+
+/// Addresses of live chunks. A slot is `0` if it's not used.
+static CHUNKS: [AtomicUsize; MAX_CHUNKS] = [EMPTY_SLOT; MAX_CHUNKS];
+
+/// One past the highest index of [CHUNKS] which has been used.
+static CHUNKS_LEN: AtomicUsize = AtomicUsize::new(0);
+
+/// Stored at the start of a chunk.
+#[repr(C)]
+struct Header {
+    /// Number of allocations in the chunk which are not freed yet, plus one
+    /// while a thread allocates in the chunk.
+    live: AtomicUsize,
+    /// Offset of the free space. Only accessed by the thread which allocates
+    /// in the chunk.
+    used: usize,
+}
+
+/// Numbers of allocations made by the current thread.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Allocations served from a chunk.
+    pub arena_allocs: usize,
+    /// Allocations and reallocations passed to the system allocator,
+    /// including chunks.
+    pub system_allocs: usize,
+    /// Chunks allocated.
+    pub chunks: usize,
+}
+
+/// State of the current thread.
+///
+/// This must not implement [Drop], because registering the destructor of a
+/// thread local allocates.
+struct State {
+    /// Number of nested [scope]s.
+    depth: Cell<usize>,
+    /// The chunk used for allocations, if any.
+    current: Cell<*mut Header>,
+    stats: Cell<Stats>,
+}
+
+thread_local!(static STATE: State = State {
+    depth: Cell::new(0),
+    current: Cell::new(ptr::null_mut()),
+    stats: Cell::new(Stats {
+        arena_allocs: 0,
+        system_allocs: 0,
+        chunks: 0,
+    }),
+});
+
+/// A global allocator which bump allocates in chunks while [scope] is running.
+///
+/// Large values and values allocated outside of [scope] are passed to
+/// [System].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ArenaAlloc;
+
+/// Runs `op` with arena allocation enabled on the current thread.
+///
+/// Allocations of other threads are not affected. This is a no-op unless
+/// [ArenaAlloc] is the global allocator.
+pub fn scope<F, Ret>(op: F) -> Ret
+where
+    F: FnOnce() -> Ret,
+{
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            STATE.with(|s| {
+                let depth = s.depth.get() - 1;
+                s.depth.set(depth);
+
+                if depth == 0 {
+                    let chunk = s.current.replace(ptr::null_mut());
+                    if !chunk.is_null() {
+                        unsafe { release(chunk) }
+                    }
+                }
+            })
+        }
+    }
+
+    STATE.with(|s| s.depth.set(s.depth.get() + 1));
+    let _reset = Reset;
+
+    op()
+}
+
+/// Returns the numbers of allocations made by the current thread.
+pub fn stats() -> Stats {
+    STATE.with(|s| s.stats.get())
+}
+
+impl State {
+    fn count(&self, op: impl FnOnce(&mut Stats)) {
+        let mut stats = self.stats.get();
+        op(&mut stats);
+        self.stats.set(stats);
+    }
+
+    /// Allocates `layout` in the current chunk, or in a new chunk if it's
+    /// full.
+    ///
+    /// Returns [None] if arena allocation is disabled or there are too many
+    /// chunks.
+    unsafe fn alloc(&self, layout: Layout) -> Option<*mut u8> {
+        if self.depth.get() == 0 {
+            return None;
+        }
+
+        let mut chunk = self.current.get();
+        if chunk.is_null() || align_up((*chunk).used, layout.align()) + layout.size() > CHUNK_SIZE {
+            let new = new_chunk()?;
+            self.count(|stats| {
+                stats.system_allocs += 1;
+                stats.chunks += 1;
+            });
+
+            if !chunk.is_null() {
+                release(chunk);
+            }
+            self.current.set(new);
+            chunk = new;
+        }
+
+        let offset = align_up((*chunk).used, layout.align());
+        (*chunk).used = offset + layout.size();
+        (*chunk).live.fetch_add(1, Ordering::Relaxed);
+        self.count(|stats| stats.arena_allocs += 1);
+
+        Some((chunk as *mut u8).add(offset))
+    }
+}
+
+fn align_up(offset: usize, align: usize) -> usize {
+    (offset + align - 1) & !(align - 1)
+}
+
+fn chunk_layout() -> Layout {
+    unsafe { Layout::from_size_align_unchecked(CHUNK_SIZE, CHUNK_SIZE) }
+}
+
+/// Allocates and registers a chunk, owned by the caller.
+unsafe fn new_chunk() -> Option<*mut Header> {
+    let chunk = System.alloc(chunk_layout()) as *mut Header;
+    if chunk.is_null() {
+        return None;
+    }
+    ptr::write(
+        chunk,
+        Header {
+            live: AtomicUsize::new(1),
+            used: mem::size_of::<Header>(),
+        },
+    );
+
+    let slot = CHUNKS.iter().position(|slot| {
+        slot.compare_exchange(0, chunk as usize, Ordering::AcqRel, Ordering::Relaxed)
+            .is_ok()
+    });
+    match slot {
+        Some(idx) => {
+            CHUNKS_LEN.fetch_max(idx + 1, Ordering::AcqRel);
+            Some(chunk)
+        }
+        None => {
+            System.dealloc(chunk as *mut u8, chunk_layout());
+            None
+        }
+    }
+}
+
+/// Returns the chunk which contains `ptr`, if `ptr` is allocated in one.
+fn chunk_of(ptr: *mut u8) -> Option<*mut Header> {
+    let addr = ptr as usize & !(CHUNK_SIZE - 1);
+    let len = CHUNKS_LEN.load(Ordering::Acquire);
+
+    if CHUNKS[..len]
+        .iter()
+        .any(|slot| slot.load(Ordering::Acquire) == addr)
+    {
+        Some(addr as *mut Header)
+    } else {
+        None
+    }
+}
+
+/// Decrements the live count of `chunk`, and frees it if it drops to zero.
+unsafe fn release(chunk: *mut Header) {
+    if (*chunk).live.fetch_sub(1, Ordering::AcqRel) != 1 {
+        return;
+    }
+
+    let len = CHUNKS_LEN.load(Ordering::Acquire);
+    for slot in &CHUNKS[..len] {
+        if slot
+            .compare_exchange(chunk as usize, 0, Ordering::AcqRel, Ordering::Relaxed)
+            .is_ok()
+        {
+            break;
+        }
+    }
+    System.dealloc(chunk as *mut u8, chunk_layout());
+}
+
+fn count_system_alloc() {
+    let _ = STATE.try_with(|s| s.count(|stats| stats.system_allocs += 1));
+}
+
+unsafe impl GlobalAlloc for ArenaAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() + layout.align() <= MAX_ARENA_ALLOC {
+            if let Ok(Some(ptr)) = STATE.try_with(|s| s.alloc(layout)) {
+                return ptr;
+            }
+        }
+
+        count_system_alloc();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        match chunk_of(ptr) {
+            Some(chunk) => release(chunk),
+            None => System.dealloc(ptr, layout),
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let chunk = match chunk_of(ptr) {
+            Some(chunk) => chunk,
+            None => {
+                count_system_alloc();
+                return System.realloc(ptr, layout, new_size);
+            }
+        };
+
+        // The last allocation of the current chunk can grow or shrink in place.
+        let offset = ptr as usize - chunk as usize;
+        let is_current = STATE
+            .try_with(|s| s.current.get() == chunk)
+            .unwrap_or(false);
+        if is_current && (*chunk).used == offset + layout.size() && offset + new_size <= CHUNK_SIZE
+        {
+            (*chunk).used = offset + new_size;
+            return ptr;
+        }
+        if new_size <= layout.size() {
+            return ptr;
+        }
+
+        let new = self.alloc(Layout::from_size_align_unchecked(new_size, layout.align()));
+        if !new.is_null() {
+            ptr::copy_nonoverlapping(ptr, new, layout.size());
+            release(chunk);
+        }
+        new
+    }
+}
@@ -37,6 +37,15 @@ pub trait Comments {
     fn has_trailing(&self, pos: BytePos) -> bool;
     fn move_trailing(&self, from: BytePos, to: BytePos);
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>>;
+
+    /// Removes leading comments for which `f` returns `false`.
+    ///
+    /// This visits all leading comments at once, so prefer this over calling
+    /// [Comments::take_leading] for every position in a range.
+    fn retain_leading(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool);
+
+    /// Removes trailing comments for which `f` returns `false`.
+    fn retain_trailing(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool);
 }
 
 macro_rules! delegate {
@@ -80,6 +89,14 @@ macro_rules! delegate {
         fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
             (**self).take_trailing(pos)
         }
+
+        fn retain_leading(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+            (**self).retain_leading(f)
+        }
+
+        fn retain_trailing(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+            (**self).retain_trailing(f)
+        }
     };
 }
 
@@ -181,6 +198,21 @@ impl Comments for SingleThreadedComments {
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
         self.trailing.borrow_mut().remove(&pos)
     }
+
+    fn retain_leading(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+        retain(&mut self.leading.borrow_mut(), f)
+    }
+
+    fn retain_trailing(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+        retain(&mut self.trailing.borrow_mut(), f)
+    }
+}
+
+fn retain(map: &mut SingleThreadedCommentsMapInner, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+    map.retain(|&pos, comments| {
+        comments.retain(|c| f(pos, c));
+        !comments.is_empty()
+    });
 }
 
 impl SingleThreadedComments {
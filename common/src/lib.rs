@@ -11,6 +11,10 @@
 //! ## `sourcemap`
 //!
 //! Adds methods to generator web sourcemap.
+//!
+//! ## `arena`
+//!
+//! Adds [arena::ArenaAlloc], a global allocator which bump allocates the ast.
 #![deny(unused)]
 
 pub use self::{
@@ -34,6 +38,8 @@ pub trait AstNode: Debug + PartialEq + Clone + Spanned + Serialize {
     const TYPE: &'static str;
 }
 
+#[cfg(feature = "arena")]
+pub mod arena;
 pub mod comments;
 pub mod errors;
 pub mod input;
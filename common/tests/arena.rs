@@ -0,0 +1,63 @@
+#![cfg(feature = "arena")]
+
+use std::thread;
+use swc_common::arena::{self, ArenaAlloc};
+
+#[global_allocator]
+static ALLOC: ArenaAlloc = ArenaAlloc;
+
+#[test]
+fn small_values() {
+    let before = arena::stats();
+    let values = arena::scope(|| (0..1000).map(Box::new).collect::<Vec<_>>());
+    let after = arena::stats();
+
+    assert!(after.arena_allocs - before.arena_allocs >= 1000);
+    assert!(after.system_allocs - before.system_allocs < 10);
+    assert_eq!(values.iter().map(|v| **v).sum::<i32>(), (0..1000).sum());
+}
+
+#[test]
+fn large_values() {
+    let before = arena::stats();
+    let value = arena::scope(|| vec![1u8; 1 << 20]);
+    let after = arena::stats();
+
+    assert_eq!(after.arena_allocs, before.arena_allocs);
+    assert_eq!(after.system_allocs - before.system_allocs, 1);
+    assert!(value.iter().all(|&v| v == 1));
+}
+
+#[test]
+fn grow() {
+    let values = arena::scope(|| {
+        let mut values = vec![];
+        for i in 0..100_000 {
+            values.push(i);
+        }
+        values
+    });
+
+    assert!(values.iter().copied().eq(0..100_000));
+}
+
+#[test]
+fn drop_on_other_thread() {
+    let values = arena::scope(|| (0..100_000).map(|i| vec![i; 4]).collect::<Vec<_>>());
+
+    let sum = thread::spawn(move || values.into_iter().map(|v| v.iter().sum::<usize>()).sum())
+        .join()
+        .unwrap();
+
+    assert_eq!(sum, (0..100_000).sum::<usize>() * 4);
+}
+
+#[test]
+fn nested() {
+    let (outer, inner) = arena::scope(|| {
+        let inner = arena::scope(|| Box::new(1));
+        (Box::new(2), inner)
+    });
+
+    assert_eq!(*outer + *inner, 3);
+}
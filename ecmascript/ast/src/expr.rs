@@ -151,6 +151,14 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    /// `#{ a: 1 }`
+    #[tag("RecordExpression")]
+    Record(RecordLit),
+
+    /// `#[1, 2]`
+    #[tag("TupleExpression")]
+    Tuple(TupleLit),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -184,6 +192,30 @@ pub struct ObjectLit {
     pub props: Vec<PropOrSpread>,
 }
 
+/// Record literal of the record and tuple proposal.
+#[ast_node("RecordExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct RecordLit {
+    pub span: Span,
+
+    #[serde(default, rename = "properties")]
+    pub props: Vec<PropOrSpread>,
+}
+
+/// Tuple literal of the record and tuple proposal.
+///
+/// Unlike arrays, tuples can't have holes.
+#[ast_node("TupleExpression")]
+#[derive(Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TupleLit {
+    pub span: Span,
+
+    #[serde(default, rename = "elements")]
+    pub elems: Vec<ExprOrSpread>,
+}
+
 #[ast_node]
 #[derive(Eq, Hash, Is)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -156,16 +156,51 @@ impl Hash for Number {
     }
 }
 
+/// Formats the number like `Number.prototype.toString()`, so the output can
+/// be used as a property key.
 impl Display for Number {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        if self.value.is_infinite() {
-            if self.value.is_sign_positive() {
-                Display::fmt("Infinity", f)
+        let value = self.value;
+        if value.is_nan() {
+            return Display::fmt("NaN", f);
+        }
+        if value == 0.0 {
+            return Display::fmt("0", f);
+        }
+        if value.is_infinite() {
+            if value.is_sign_positive() {
+                return Display::fmt("Infinity", f);
             } else {
-                Display::fmt("-Infinity", f)
+                return Display::fmt("-Infinity", f);
             }
+        }
+        if value < 0.0 {
+            Display::fmt("-", f)?;
+        }
+
+        // The shortest digits which round-trip, and the exponent of the first
+        // one.
+        let repr = format!("{:e}", value.abs());
+        let (mantissa, exp) = repr.split_at(repr.find('e').unwrap());
+        let digits = mantissa.replace('.', "");
+        let k = digits.len() as i32;
+        let n = exp[1..].parse::<i32>().unwrap() + 1;
+
+        if k <= n && n <= 21 {
+            write!(f, "{}{}", digits, "0".repeat((n - k) as usize))
+        } else if 0 < n && n <= 21 {
+            let (int, frac) = digits.split_at(n as usize);
+            write!(f, "{}.{}", int, frac)
+        } else if -6 < n && n <= 0 {
+            write!(f, "0.{}{}", "0".repeat(-n as usize), digits)
         } else {
-            Display::fmt(&self.value, f)
+            let sign = if n - 1 > 0 { '+' } else { '-' };
+            let (first, rest) = digits.split_at(1);
+            if rest.is_empty() {
+                write!(f, "{}e{}{}", first, sign, (n - 1).abs())
+            } else {
+                write!(f, "{}.{}e{}{}", first, rest, sign, (n - 1).abs())
+            }
         }
     }
 }
@@ -109,6 +109,9 @@ macro_rules! op {
     ("??") => {
         $crate::BinaryOp::NullishCoalescing
     };
+    ("|>") => {
+        $crate::BinaryOp::Pipeline
+    };
 
     ("=") => {
         $crate::AssignOp::Assign
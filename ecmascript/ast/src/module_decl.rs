@@ -78,6 +78,10 @@ pub struct ImportDecl {
 
     #[serde(default)]
     pub asserts: Option<ObjectLit>,
+
+    /// `true` if `asserts` are written with `with` instead of `assert`.
+    #[serde(default)]
+    pub with: bool,
 }
 
 /// `export * from 'mod'`
@@ -87,6 +87,12 @@ pub enum BinaryOp {
     /// `??`
     #[kind(precedence = "1")]
     NullishCoalescing,
+
+    /// `|>`
+    ///
+    /// Binds looser than all other binary operators.
+    #[kind(precedence = "0")]
+    Pipeline,
 }
 
 #[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
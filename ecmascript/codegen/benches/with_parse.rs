@@ -107,6 +107,7 @@ fn bench_emitter(b: &mut Bencher, s: &str) {
                     },
                     comments: None,
                     cm: cm.clone(),
+                    ident_hook: None,
                     wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                         cm.clone(),
                         "\n",
@@ -64,6 +64,15 @@ impl<'a> Emitter<'a> {
             None => return Ok(()),
         };
 
-        write_comments!(self, false, comments.take_leading(pos))
+        let cmts = comments.take_leading(pos);
+
+        if self.cfg.blank_lines.before_block_comments {
+            match cmts.as_ref().and_then(|cmts| cmts.first()) {
+                Some(cmt) if cmt.kind == CommentKind::Block => self.wr.write_blank_line()?,
+                _ => {}
+            }
+        }
+
+        write_comments!(self, false, cmts)
     }
 }
@@ -1,4 +1,98 @@
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// Controls trailing commas of lists printed on multiple lines.
+    ///
+    /// If [None], trailing commas of the input are preserved.
+    pub trailing_comma: Option<TrailingComma>,
+
+    /// If set, argument lists, parameter lists, array literals and chains of
+    /// binary expressions which would exceed this width are printed on
+    /// multiple lines.
+    ///
+    /// Lines are never broken inside a string or template literal, so the
+    /// width may still be exceeded. This has no effect if `minify` is true.
+    pub max_line_len: Option<usize>,
+
+    /// If false, semicolons are omitted unless the next statement would be
+    /// merged with the previous one by automatic semicolon insertion, like a
+    /// statement starting with `(`, `[` or `` ` ``. In that case the semicolon
+    /// is printed at the start of the statement.
+    ///
+    /// This only applies while emitting a [Module] or a [Script].
+    ///
+    /// Defaults to true.
+    ///
+    /// [Module]: swc_ecma_ast::Module
+    /// [Script]: swc_ecma_ast::Script
+    pub semicolons: bool,
+
+    /// Controls where blank lines are printed for readability.
+    pub blank_lines: BlankLines,
+
+    /// If set, string literals are printed with the given quotes, escaping
+    /// characters as needed.
+    ///
+    /// If [None], quotes of the input are preserved. Directives like
+    /// `'use strict'` and attributes of JSX elements are printed as written.
+    pub string_quotes: Option<Quotes>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            minify: false,
+            trailing_comma: None,
+            max_line_len: None,
+            semicolons: true,
+            blank_lines: Default::default(),
+            string_quotes: None,
+        }
+    }
+}
+
+/// Places where a blank line is printed. All of them are disabled by default.
+///
+/// Blank lines between top-level declarations and class members are printed
+/// regardless of [Config::minify].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BlankLines {
+    /// Print a blank line between top-level statements if one of them is a
+    /// function or class declaration, including exported ones.
+    pub between_top_level_decls: bool,
+
+    /// Print a blank line between class members.
+    pub between_class_members: bool,
+
+    /// Print a blank line before a block comment which starts a line, unless
+    /// it's the first line of a block.
+    pub before_block_comments: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingComma {
+    /// Never print trailing commas.
+    None,
+    /// Print trailing commas in array and object literals.
+    Es5,
+    /// Print trailing commas in array and object literals, parameter lists
+    /// and argument lists.
+    ///
+    /// Trailing commas in parameter lists and argument lists are syntax errors
+    /// before ES2017, so they are not printed if [WriteJs::target] is older
+    /// than [JscTarget::Es2017].
+    ///
+    /// [WriteJs::target]: crate::text_writer::WriteJs::target
+    /// [JscTarget::Es2017]: swc_ecma_parser::JscTarget::Es2017
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quotes {
+    Double,
+    Single,
+    /// Use the given quote, which is either `'` or `"`, unless the other one
+    /// requires fewer escapes.
+    Prefer(char),
 }
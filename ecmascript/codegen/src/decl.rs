@@ -33,6 +33,10 @@ impl<'a> Emitter<'a> {
         for dec in &node.class.decorators {
             emit!(dec);
         }
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         keyword!("class");
         space!();
         emit!(node.ident);
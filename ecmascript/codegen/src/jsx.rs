@@ -23,13 +23,19 @@ impl<'a> Emitter<'a> {
         punct!("<");
         emit!(node.name);
 
-        space!();
+        if let Some(type_args) = &node.type_args {
+            emit!(type_args);
+        }
 
-        self.emit_list(
-            node.span(),
-            Some(&node.attrs),
-            ListFormat::JsxElementAttributes,
-        )?;
+        if !node.attrs.is_empty() {
+            space!();
+
+            self.emit_list(
+                node.span(),
+                Some(&node.attrs),
+                ListFormat::JsxElementAttributes,
+            )?;
+        }
 
         if node.self_closing {
             punct!("/");
@@ -60,6 +66,8 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_jsx_attr_value(&mut self, node: &JSXAttrValue) -> Result {
         match *node {
+            // JSX attributes cannot contain escapes, so the quotes are kept.
+            JSXAttrValue::Lit(Lit::Str(ref n)) => self.emit_str_lit_as_written(n)?,
             JSXAttrValue::Lit(ref n) => emit!(n),
             JSXAttrValue::JSXExprContainer(ref n) => emit!(n),
             JSXAttrValue::JSXElement(ref n) => emit!(n),
@@ -70,7 +78,7 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_jsx_attr_name(&mut self, node: &JSXAttrName) -> Result {
         match *node {
-            JSXAttrName::Ident(ref n) => emit!(n),
+            JSXAttrName::Ident(ref n) => self.emit_ident_name(n)?,
             JSXAttrName::JSXNamespacedName(ref n) => emit!(n),
         }
     }
@@ -79,7 +87,11 @@ impl<'a> Emitter<'a> {
     fn emit_jsx_attr_or_spread(&mut self, node: &JSXAttrOrSpread) -> Result {
         match *node {
             JSXAttrOrSpread::JSXAttr(ref n) => emit!(n),
-            JSXAttrOrSpread::SpreadElement(ref n) => emit!(n),
+            JSXAttrOrSpread::SpreadElement(ref n) => {
+                punct!("{");
+                emit!(n);
+                punct!("}");
+            }
         }
     }
 
@@ -166,7 +178,7 @@ impl<'a> Emitter<'a> {
     fn emit_jsx_member_expr(&mut self, node: &JSXMemberExpr) -> Result {
         emit!(node.obj);
         punct!(".");
-        emit!(node.prop);
+        self.emit_ident_name(&node.prop)?;
     }
 
     #[emitter]
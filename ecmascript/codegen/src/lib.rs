@@ -1,7 +1,7 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{BlankLines, Config, Quotes, TrailingComma};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
@@ -53,6 +53,14 @@ pub struct Emitter<'a> {
     pub cfg: config::Config,
     pub cm: Lrc<SourceMap>,
     pub comments: Option<&'a dyn Comments>,
+    /// Called with the symbol and the syntax context of each binding and
+    /// reference. If it returns a name, the name is emitted instead of the
+    /// symbol.
+    ///
+    /// Property names and labels are not passed to the hook, and shorthand
+    /// properties and specifiers like `export { a }` are expanded if the
+    /// binding is renamed.
+    pub ident_hook: Option<&'a dyn Fn(&JsWord, SyntaxContext) -> Option<String>>,
     pub wr: Box<(dyn 'a + WriteJs)>,
 }
 
@@ -67,28 +75,70 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     pub fn emit_module(&mut self, node: &Module) -> Result {
+        if !self.cfg.semicolons {
+            return self.emit_with_asi_safe_semi(node);
+        }
+
         if let Some(ref shebang) = node.shebang {
             punct!("#!");
             self.wr.write_str_lit(DUMMY_SP, &*shebang)?;
             self.wr.write_line()?;
         }
-        for stmt in &node.body {
-            emit!(stmt);
+        for (i, item) in node.body.iter().enumerate() {
+            if i != 0 && (is_top_level_decl(&node.body[i - 1]) || is_top_level_decl(item)) {
+                self.write_top_level_blank_line()?;
+            }
+            emit!(item);
         }
     }
 
     #[emitter]
     pub fn emit_script(&mut self, node: &Script) -> Result {
+        if !self.cfg.semicolons {
+            return self.emit_with_asi_safe_semi(node);
+        }
+
         if let Some(ref shebang) = node.shebang {
             punct!("#!");
             self.wr.write_str_lit(DUMMY_SP, &*shebang)?;
             self.wr.write_line()?;
         }
-        for stmt in &node.body {
+        for (i, stmt) in node.body.iter().enumerate() {
+            if i != 0 && (is_fn_or_class_decl(&node.body[i - 1]) || is_fn_or_class_decl(stmt)) {
+                self.write_top_level_blank_line()?;
+            }
             emit!(stmt);
         }
     }
 
+    /// See [BlankLines::between_top_level_decls].
+    fn write_top_level_blank_line(&mut self) -> Result {
+        if self.cfg.blank_lines.between_top_level_decls {
+            self.wr.write_line()?;
+            self.wr.write_blank_line()?;
+        }
+
+        Ok(())
+    }
+
+    /// Emits `node` while omitting semicolons which are not required.
+    ///
+    /// See [Config::semicolons].
+    fn emit_with_asi_safe_semi<N: Node>(&mut self, node: &N) -> Result {
+        let mut emitter = Emitter {
+            cfg: Config {
+                // Semicolons are written to the inner emitter and dropped by the writer.
+                semicolons: true,
+                ..self.cfg
+            },
+            cm: self.cm.clone(),
+            ident_hook: self.ident_hook,
+            comments: self.comments,
+            wr: Box::new(text_writer::AsiSafeSemi::new(&mut *self.wr)),
+        };
+        node.emit_with(&mut emitter)
+    }
+
     #[emitter]
     fn emit_module_item(&mut self, node: &ModuleItem) -> Result {
         match *node {
@@ -210,13 +260,30 @@ impl<'a> Emitter<'a> {
 
         formatting_space!();
         emit!(node.src);
+
+        if let Some(asserts) = &node.asserts {
+            formatting_space!();
+            if node.with {
+                keyword!("with");
+            } else {
+                keyword!("assert");
+            }
+            formatting_space!();
+            emit!(asserts);
+        }
+
         semi!();
     }
 
     #[emitter]
     fn emit_import_specific(&mut self, node: &ImportNamedSpecifier) -> Result {
-        if let Some(ref imported) = node.imported {
-            emit!(imported);
+        let imported = match node.imported {
+            Some(ref imported) => Some(imported),
+            None if self.ident_name(&node.local).is_some() => Some(&node.local),
+            None => None,
+        };
+        if let Some(imported) = imported {
+            self.emit_ident_name(imported)?;
             space!();
             keyword!("as");
             space!();
@@ -244,21 +311,24 @@ impl<'a> Emitter<'a> {
         formatting_space!();
         keyword!("as");
         space!();
-        emit!(node.name);
+        self.emit_ident_name(&node.name)?;
     }
 
     #[emitter]
     fn emit_named_export_specifier(&mut self, node: &ExportNamedSpecifier) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        if let Some(ref exported) = node.exported {
-            emit!(node.orig);
+        let exported = match node.exported {
+            Some(ref exported) => Some(exported),
+            None if self.ident_name(&node.orig).is_some() => Some(&node.orig),
+            None => None,
+        };
+        emit!(node.orig);
+        if let Some(exported) = exported {
             space!();
             keyword!("as");
             space!();
-            emit!(node.exported);
-        } else {
-            emit!(node.orig);
+            self.emit_ident_name(exported)?;
         }
     }
 
@@ -379,8 +449,59 @@ impl<'a> Emitter<'a> {
         Ok(())
     }
 
+    /// Returns the original source text of a string literal used as a
+    /// statement, if it's available.
+    fn directive_text(&self, node: &Str) -> Option<String> {
+        if node.span.is_dummy() || is_single_quote(&self.cm, node.span).is_none() {
+            return None;
+        }
+
+        let raw = self.cm.span_to_snippet(node.span).ok()?;
+        // The span may have been copied from another node.
+        if raw.len() < 2 || (!node.has_escape && raw[1..raw.len() - 1] != *node.value) {
+            return None;
+        }
+
+        Some(raw)
+    }
+
     #[emitter]
     fn emit_str_lit(&mut self, node: &Str) -> Result {
+        match self.cfg.string_quotes {
+            Some(quotes) => self.emit_quoted_str_lit(node, quotes)?,
+            None => self.emit_str_lit_as_written(node)?,
+        }
+    }
+
+    fn emit_quoted_str_lit(&mut self, node: &Str, quotes: Quotes) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        let single_quote = match quotes {
+            Quotes::Double => false,
+            Quotes::Single => true,
+            Quotes::Prefer(c) => {
+                let singles = node.value.matches('\'').count();
+                let doubles = node.value.matches('"').count();
+
+                if c == '\'' {
+                    singles <= doubles
+                } else {
+                    singles < doubles
+                }
+            }
+        };
+        let value = escape_with_quote(&node.value, if single_quote { '\'' } else { '"' });
+
+        let quote = if single_quote { "'" } else { "\"" };
+        self.wr.write_punct(quote)?;
+        self.wr.write_str_lit(node.span, &value)?;
+        self.wr.write_punct(quote)?;
+
+        Ok(())
+    }
+
+    /// Emits `node` using the quotes of the input.
+    fn emit_str_lit_as_written(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
         let single_quote = is_single_quote(&self.cm, node.span);
@@ -401,14 +522,16 @@ impl<'a> Emitter<'a> {
         let single_quote = single_quote.unwrap_or(false);
 
         if single_quote {
-            punct!("'");
+            punct!(self, "'");
             self.wr.write_str_lit(node.span, &value)?;
-            punct!("'");
+            punct!(self, "'");
         } else {
-            punct!("\"");
+            punct!(self, "\"");
             self.wr.write_str_lit(node.span, &value)?;
-            punct!("\"");
+            punct!(self, "\"");
         }
+
+        Ok(())
     }
 
     #[emitter]
@@ -514,6 +637,8 @@ impl<'a> Emitter<'a> {
             Expr::TsConstAssertion(ref n) => emit!(n),
             Expr::TsTypeCast(ref n) => emit!(n),
             Expr::OptChain(ref n) => emit!(n),
+            Expr::Record(ref n) => emit!(n),
+            Expr::Tuple(ref n) => emit!(n),
             Expr::Invalid(ref n) => emit!(n),
         }
     }
@@ -560,6 +685,10 @@ impl<'a> Emitter<'a> {
 
         emit!(node.callee);
 
+        if let Some(type_args) = &node.type_args {
+            emit!(type_args);
+        }
+
         punct!("(");
         self.emit_expr_or_spreads(node.span(), &node.args, ListFormat::CallExpressionArguments)?;
         punct!(")");
@@ -605,7 +734,10 @@ impl<'a> Emitter<'a> {
                 self.emit_leading_comments_of_pos(node.prop.span().lo() - BytePos(1))?;
             }
             punct!(".");
-            emit!(node.prop);
+            match *node.prop {
+                Expr::Ident(ref prop) => self.emit_ident_name(prop)?,
+                _ => emit!(node.prop),
+            }
         }
     }
 
@@ -648,20 +780,37 @@ impl<'a> Emitter<'a> {
             punct!("*")
         }
 
+        emit!(node.type_params);
+
         let parens = !self.cfg.minify
+            || node.type_params.is_some()
+            || node.return_type.is_some()
             || match node.params.as_slice() {
-                [Pat::Ident(_)] => false,
+                [Pat::Ident(i)] => i.type_ann.is_some() || i.optional,
                 _ => true,
             };
 
+        let mut format = ListFormat::CommaListElements;
+        match node.params.last() {
+            Some(Pat::Rest(..)) => {}
+            _ if parens => format |= ListFormat::Es2017TrailingComma,
+            _ => {}
+        }
+
         if parens {
             punct!("(");
         }
-        self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
+        self.emit_list(node.span, Some(&node.params), format)?;
         if parens {
             punct!(")");
         }
 
+        if let Some(ty) = &node.return_type {
+            punct!(":");
+            formatting_space!();
+            emit!(ty);
+        }
+
         punct!("=>");
         emit!(node.body);
     }
@@ -670,9 +819,9 @@ impl<'a> Emitter<'a> {
     fn emit_meta_prop_expr(&mut self, node: &MetaPropExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        emit!(node.meta);
+        self.emit_ident_name(&node.meta)?;
         punct!(".");
-        emit!(node.prop);
+        self.emit_ident_name(&node.prop)?;
     }
 
     #[emitter]
@@ -706,7 +855,8 @@ impl<'a> Emitter<'a> {
 
     /// Prints operator and right node of a binary expression.
     #[inline(never)]
-    fn emit_bin_expr_trailing(&mut self, node: &BinExpr) -> Result {
+    /// If `wrap` is true, a line break is written after the operator.
+    fn emit_bin_expr_trailing(&mut self, node: &BinExpr, wrap: bool) -> Result {
         // let indent_before_op = needs_indention(node, &node.left, node.op);
         // let indent_after_op = needs_indention(node, node.op, &node.right);
         let need_space = match node.op {
@@ -731,7 +881,9 @@ impl<'a> Emitter<'a> {
                 Expr::Unary(..) | Expr::Update(UpdateExpr { prefix: true, .. }) => true,
                 _ => false,
             };
-        if need_post_space {
+        if wrap {
+            self.wr.write_line()?;
+        } else if need_post_space {
             space!(self);
         } else {
             formatting_space!(self);
@@ -745,6 +897,8 @@ impl<'a> Emitter<'a> {
     fn emit_bin_expr(&mut self, node: &BinExpr) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        let wrap = self.exceeds_max_line_len(|e| e.emit_bin_expr(node));
+
         {
             let mut left = Some(node);
             let mut lefts = vec![];
@@ -764,15 +918,21 @@ impl<'a> Emitter<'a> {
             for (i, left) in lefts.into_iter().rev().enumerate() {
                 if i == 0 {
                     emit!(left.left);
+                    if wrap {
+                        self.wr.increase_indent()?;
+                    }
                 }
                 // Check if it's last
                 if i + 1 != len {
-                    self.emit_bin_expr_trailing(left)?;
+                    self.emit_bin_expr_trailing(left, wrap)?;
                 }
             }
         }
 
-        self.emit_bin_expr_trailing(node)?;
+        self.emit_bin_expr_trailing(node, wrap)?;
+        if wrap {
+            self.wr.decrease_indent()?;
+        }
     }
 
     #[emitter]
@@ -792,6 +952,10 @@ impl<'a> Emitter<'a> {
             emit!(dec);
         }
 
+        if node.class.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
         keyword!("class");
 
         if let Some(ref i) = node.ident {
@@ -813,11 +977,29 @@ impl<'a> Emitter<'a> {
             keyword!("extends");
             space!();
             emit!(node.super_class);
+            emit!(node.super_type_params);
+            space!();
+        }
+
+        if !node.implements.is_empty() {
+            keyword!("implements");
+            space!();
+            self.emit_list(
+                node.span,
+                Some(&node.implements),
+                ListFormat::HeritageClauseTypes,
+            )?;
             space!();
         }
 
+        let format = if self.cfg.blank_lines.between_class_members {
+            ListFormat::ClassMembers | ListFormat::BlankLineBetweenSiblings
+        } else {
+            ListFormat::ClassMembers
+        };
+
         punct!("{");
-        self.emit_list(node.span, Some(&node.body), ListFormat::ClassMembers)?;
+        self.emit_list(node.span, Some(&node.body), format)?;
         punct!("}");
     }
 
@@ -829,7 +1011,10 @@ impl<'a> Emitter<'a> {
             ClassMember::Method(ref n) => emit!(n),
             ClassMember::PrivateMethod(ref n) => emit!(n),
             ClassMember::PrivateProp(ref n) => emit!(n),
-            ClassMember::TsIndexSignature(ref n) => emit!(n),
+            ClassMember::TsIndexSignature(ref n) => {
+                emit!(n);
+                semi!();
+            }
             ClassMember::Empty(ref n) => emit!(n),
         }
     }
@@ -878,7 +1063,7 @@ impl<'a> Emitter<'a> {
         if n.value {
             keyword!(n.span, "true")
         } else {
-            keyword!(n.span, "falsee")
+            keyword!(n.span, "false")
         }
     }
 
@@ -886,12 +1071,20 @@ impl<'a> Emitter<'a> {
     fn emit_class_method(&mut self, n: &ClassMethod) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        self.emit_list(n.span, Some(&n.function.decorators), ListFormat::Decorators)?;
+
         self.emit_accesibility(n.accessibility)?;
 
         if n.is_static {
             keyword!("static");
             space!();
         }
+
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
         match n.kind {
             MethodKind::Method => {
                 if n.function.is_async {
@@ -918,6 +1111,12 @@ impl<'a> Emitter<'a> {
             }
         }
 
+        if n.is_optional {
+            punct!("?");
+        }
+
+        emit!(n.function.type_params);
+
         punct!("(");
         self.emit_list(
             n.function.span,
@@ -972,26 +1171,47 @@ impl<'a> Emitter<'a> {
     fn emit_class_prop(&mut self, n: &ClassProp) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        if n.accessibility != Some(Accessibility::Public) {
-            self.emit_accesibility(n.accessibility)?;
-        }
+        self.emit_list(n.span, Some(&n.decorators), ListFormat::Decorators)?;
 
-        if n.readonly {
-            keyword!("readonly");
-            space!()
+        if n.declare {
+            keyword!("declare");
+            space!();
         }
 
+        self.emit_accesibility(n.accessibility)?;
+
         if n.is_static {
             keyword!("static");
             space!();
         }
 
+        if n.is_abstract {
+            keyword!("abstract");
+            space!();
+        }
+
+        if n.readonly {
+            keyword!("readonly");
+            space!()
+        }
+
         if n.computed {
             punct!("[");
             emit!(n.key);
             punct!("]");
         } else {
-            emit!(n.key);
+            match &*n.key {
+                Expr::Ident(key) => self.emit_ident_name(key)?,
+                key => emit!(key),
+            }
+        }
+
+        if n.is_optional {
+            punct!("?");
+        }
+
+        if n.definite {
+            punct!("!");
         }
 
         if let Some(ty) = &n.type_ann {
@@ -1045,7 +1265,7 @@ impl<'a> Emitter<'a> {
     #[emitter]
     fn emit_prop_name(&mut self, node: &PropName) -> Result {
         match *node {
-            PropName::Ident(ref n) => emit!(n),
+            PropName::Ident(ref n) => self.emit_ident_name(n)?,
             PropName::Str(ref n) => emit!(n),
             PropName::Num(ref n) => emit!(n),
             PropName::BigInt(ref n) => emit!(n),
@@ -1103,8 +1323,16 @@ impl<'a> Emitter<'a> {
             emit!(type_params);
         }
 
+        let mut format = ListFormat::CommaListElements;
+        match node.params.last() {
+            Some(Param {
+                pat: Pat::Rest(..), ..
+            }) => {}
+            _ => format |= ListFormat::Es2017TrailingComma,
+        }
+
         punct!("(");
-        self.emit_list(node.span, Some(&node.params), ListFormat::CommaListElements)?;
+        self.emit_list(node.span, Some(&node.params), format)?;
         punct!(")");
 
         if let Some(ty) = &node.return_type {
@@ -1149,6 +1377,9 @@ impl<'a> Emitter<'a> {
 
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        // Lines are never broken inside template literals.
+        let max_line_len = self.cfg.max_line_len.take();
+
         punct!("`");
         let i = 0;
 
@@ -1163,6 +1394,8 @@ impl<'a> Emitter<'a> {
         }
 
         punct!("`");
+
+        self.cfg.max_line_len = max_line_len;
     }
 
     #[emitter]
@@ -1173,6 +1406,9 @@ impl<'a> Emitter<'a> {
 
         emit!(node.tag);
         emit!(node.type_params);
+
+        let max_line_len = self.cfg.max_line_len.take();
+
         punct!("`");
         let i = 0;
 
@@ -1187,6 +1423,8 @@ impl<'a> Emitter<'a> {
         }
 
         punct!("`");
+
+        self.cfg.max_line_len = max_line_len;
     }
 
     #[emitter]
@@ -1288,12 +1526,13 @@ impl<'a> Emitter<'a> {
     fn emit_array_lit(&mut self, node: &ArrayLit) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        let mut format = ListFormat::ArrayLiteralExpressionElements;
+        if let Some(None) = node.elems.last() {
+            format |= ListFormat::ForceTrailingComma;
+        }
+
         punct!("[");
-        self.emit_list(
-            node.span(),
-            Some(&node.elems),
-            ListFormat::ArrayLiteralExpressionElements,
-        )?;
+        self.emit_list(node.span(), Some(&node.elems), format)?;
         punct!("]");
     }
 
@@ -1316,10 +1555,50 @@ impl<'a> Emitter<'a> {
         punct!("}");
     }
 
+    #[emitter]
+    fn emit_record_lit(&mut self, node: &RecordLit) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        punct!("#{");
+        if !self.cfg.minify {
+            self.wr.write_line()?;
+        }
+        self.emit_list(
+            node.span(),
+            Some(&node.props),
+            ListFormat::ObjectLiteralExpressionProperties,
+        )?;
+        if !self.cfg.minify {
+            self.wr.write_line()?;
+        }
+        punct!("}");
+    }
+
+    #[emitter]
+    fn emit_tuple_lit(&mut self, node: &TupleLit) -> Result {
+        self.emit_leading_comments_of_pos(node.span().lo())?;
+
+        punct!("#[");
+        self.emit_list(
+            node.span(),
+            Some(&node.elems),
+            ListFormat::ArrayLiteralExpressionElements,
+        )?;
+        punct!("]");
+    }
+
     #[emitter]
     fn emit_prop(&mut self, node: &Prop) -> Result {
         match *node {
-            Prop::Shorthand(ref n) => emit!(n),
+            Prop::Shorthand(ref n) => {
+                // `{ a }` => `{ a: b }`
+                if self.ident_name(n).is_some() {
+                    self.emit_ident_name(n)?;
+                    punct!(":");
+                    formatting_space!();
+                }
+                emit!(n)
+            }
             Prop::KeyValue(ref n) => emit!(n),
             Prop::Assign(ref n) => emit!(n),
             Prop::Getter(ref n) => emit!(n),
@@ -1410,7 +1689,7 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
         punct!("#");
-        emit!(n.id)
+        self.emit_ident_name(&n.id)?;
     }
 
     #[emitter]
@@ -1426,8 +1705,10 @@ impl<'a> Emitter<'a> {
             // )?;
             unimplemented!()
         } else {
+            let renamed = self.ident_name(ident);
             // TODO: span
-            self.wr.write_symbol(ident.span, &ident.sym)?;
+            self.wr
+                .write_symbol(ident.span, renamed.as_deref().unwrap_or(&*ident.sym))?;
             if ident.optional {
                 punct!("?");
             }
@@ -1449,6 +1730,22 @@ impl<'a> Emitter<'a> {
         // emitList(node, node.typeArguments, ListFormat::TypeParameters);
     }
 
+    /// Returns the name of a binding or a reference, if it's changed by
+    /// [Emitter::ident_hook].
+    fn ident_name(&self, ident: &Ident) -> Option<String> {
+        let hook = self.ident_hook?;
+        hook(&ident.sym, ident.span.ctxt)
+    }
+
+    /// Emits an identifier which is not a binding nor a reference, like a
+    /// property name.
+    fn emit_ident_name(&mut self, ident: &Ident) -> Result {
+        self.emit_leading_comments_of_pos(ident.span.lo())?;
+        self.wr.write_symbol(ident.span, &ident.sym)?;
+
+        Ok(())
+    }
+
     fn emit_list<N: Node>(
         &mut self,
         parent_node: Span,
@@ -1464,6 +1761,49 @@ impl<'a> Emitter<'a> {
         )
     }
 
+    /// Returns true if the output of `op`, written at the current column,
+    /// would exceed [Config::max_line_len].
+    ///
+    /// Only the first line of the output is measured.
+    fn exceeds_max_line_len<F>(&self, op: F) -> bool
+    where
+        F: FnOnce(&mut Emitter<'_>) -> Result,
+    {
+        let max_line_len = match self.cfg.max_line_len {
+            Some(v) if !self.cfg.minify => v,
+            _ => return false,
+        };
+        let column = match self.wr.column() {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Config {
+                    max_line_len: None,
+                    ..self.cfg
+                },
+                cm: self.cm.clone(),
+                ident_hook: self.ident_hook,
+                comments: None,
+                wr: Box::new(text_writer::JsWriter::new(
+                    self.cm.clone(),
+                    "\n",
+                    &mut buf,
+                    None,
+                )),
+            };
+            if op(&mut emitter).is_err() {
+                return false;
+            }
+        }
+
+        let width = buf.iter().position(|&b| b == b'\n').unwrap_or(buf.len());
+        column + width > max_line_len
+    }
+
     #[allow(clippy::cognitive_complexity)]
     fn emit_list5<N: Node>(
         &mut self,
@@ -1482,6 +1822,19 @@ impl<'a> Emitter<'a> {
             return Ok(());
         }
 
+        // Print bracketed lists which are too long on multiple lines.
+        let format = if !is_empty
+            && format.contains(ListFormat::CommaDelimited)
+            && format.intersects(ListFormat::Parenthesis | ListFormat::SquareBrackets)
+            && !format.contains(ListFormat::MultiLine)
+            && self
+                .exceeds_max_line_len(|e| e.emit_list5(parent_node, children, format, start, count))
+        {
+            (format - ListFormat::LinesMask) | ListFormat::MultiLine | ListFormat::Indented
+        } else {
+            format
+        };
+
         if format.contains(ListFormat::BracketsMask) {
             self.wr.write_punct(format.opening_bracket())?;
 
@@ -1583,6 +1936,11 @@ impl<'a> Emitter<'a> {
                     } else if format.contains(ListFormat::SpaceBetweenSiblings) {
                         formatting_space!(self);
                     }
+
+                    if format.contains(ListFormat::BlankLineBetweenSiblings) {
+                        self.wr.write_line()?;
+                        self.wr.write_blank_line()?;
+                    }
                 }
 
                 child.emit_with(self)?;
@@ -1604,22 +1962,45 @@ impl<'a> Emitter<'a> {
             }
 
             // Write a trailing comma, if requested.
-            let has_trailing_comma = format.contains(ListFormat::AllowTrailingComma) && {
-                match self.cm.span_to_snippet(parent_node) {
-                    Ok(snippet) => {
-                        if snippet.len() < 3 {
-                            false
-                        } else {
-                            snippet[..snippet.len() - 1].trim().ends_with(',')
+            let is_multi_line = self.cfg.trailing_comma.is_some()
+                && !self.cfg.minify
+                && self
+                    .cm
+                    .should_write_closing_line_terminator(parent_node, children, format);
+            let has_trailing_comma = format.contains(ListFormat::ForceTrailingComma)
+                || match self.cfg.trailing_comma {
+                    None => {
+                        format.contains(ListFormat::AllowTrailingComma) && {
+                            match self.cm.span_to_snippet(parent_node) {
+                                Ok(snippet) => {
+                                    if snippet.len() < 3 {
+                                        false
+                                    } else {
+                                        snippet[..snippet.len() - 1].trim().ends_with(',')
+                                    }
+                                }
+                                _ => false,
+                            }
                         }
                     }
-                    _ => false,
-                }
-            };
+                    Some(TrailingComma::None) => false,
+                    Some(TrailingComma::Es5) => {
+                        format.contains(ListFormat::Es5TrailingComma) && is_multi_line
+                    }
+                    Some(TrailingComma::All) => {
+                        let mut allowed = ListFormat::Es5TrailingComma;
+                        if self.wr.target() >= JscTarget::Es2017 {
+                            allowed |= ListFormat::Es2017TrailingComma;
+                        }
+                        format.intersects(allowed) && is_multi_line
+                    }
+                };
 
             if has_trailing_comma && format.contains(ListFormat::CommaDelimited) {
                 self.wr.write_punct(",")?;
-                formatting_space!(self);
+                if !is_multi_line {
+                    formatting_space!(self);
+                }
             }
 
             {
@@ -1824,6 +2205,12 @@ impl<'a> Emitter<'a> {
     fn emit_object_assign_pat(&mut self, node: &AssignPatProp) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
+        // `{ a = 1 }` => `{ a: b = 1 }`
+        if self.ident_name(&node.key).is_some() {
+            self.emit_ident_name(&node.key)?;
+            punct!(":");
+            formatting_space!();
+        }
         emit!(node.key);
         formatting_space!();
         if let Some(ref value) = node.value {
@@ -1879,7 +2266,18 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_expr_stmt(&mut self, e: &ExprStmt) -> Result {
-        emit!(e.expr);
+        match &*e.expr {
+            // Directives are recognized by their source text, so `'use\x20strict'`
+            // must not be printed as `"use strict"`.
+            Expr::Lit(Lit::Str(s)) => match self.directive_text(s) {
+                Some(raw) => {
+                    self.emit_leading_comments_of_pos(s.span.lo())?;
+                    self.wr.write_str_lit(s.span, &raw)?;
+                }
+                None => emit!(e.expr),
+            },
+            _ => emit!(e.expr),
+        }
         semi!();
     }
 
@@ -1900,7 +2298,8 @@ impl<'a> Emitter<'a> {
     fn emit_empty_stmt(&mut self, node: &EmptyStmt) -> Result {
         self.emit_leading_comments_of_pos(node.span().lo())?;
 
-        punct!(";");
+        // This is not a statement terminator, so it should never be omitted.
+        self.wr.write_punct(";")?;
     }
 
     #[emitter]
@@ -1951,7 +2350,7 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_labeled_stmt(&mut self, node: &LabeledStmt) -> Result {
-        emit!(node.label);
+        self.emit_ident_name(&node.label)?;
 
         // TODO: Comment
         punct!(":");
@@ -1965,7 +2364,7 @@ impl<'a> Emitter<'a> {
         keyword!("break");
         if let Some(ref label) = node.label {
             space!();
-            emit!(label);
+            self.emit_ident_name(label)?;
         }
         semi!();
     }
@@ -1975,7 +2374,7 @@ impl<'a> Emitter<'a> {
         keyword!("continue");
         if let Some(ref label) = node.label {
             space!();
-            emit!(label);
+            self.emit_ident_name(label)?;
         }
         semi!();
     }
@@ -2419,6 +2818,38 @@ fn unescape(s: &str) -> String {
     result
 }
 
+/// Escapes `s` to be printed between `quote`s.
+fn escape_with_quote(s: &str, quote: char) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\u{2028}' => buf.push_str("\\u2028"),
+            '\u{2029}' => buf.push_str("\\u2029"),
+            // `\0` followed by a digit is an octal escape.
+            '\0' => match chars.peek() {
+                Some('0'..='9') => buf.push_str("\\x00"),
+                _ => buf.push_str("\\0"),
+            },
+            c if c == quote => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            c if c.is_control() => {
+                let _ = write!(buf, "\\x{:02x}", c as u32);
+            }
+            c => buf.push(c),
+        }
+    }
+
+    buf
+}
+
 fn escape<'s>(
     cm: &SourceMap,
     target: JscTarget,
@@ -2583,6 +3014,34 @@ fn is_single_quote(cm: &SourceMap, span: Span) -> Option<bool> {
     Some(single_quote)
 }
 
+/// Returns true if `item` is a function or class declaration, or an export of
+/// one.
+fn is_top_level_decl(item: &ModuleItem) -> bool {
+    match item {
+        ModuleItem::Stmt(stmt) => is_fn_or_class_decl(stmt),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => match decl {
+            Decl::Fn(..) | Decl::Class(..) => true,
+            _ => false,
+        },
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Fn(..),
+            ..
+        }))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Class(..),
+            ..
+        })) => true,
+        _ => false,
+    }
+}
+
+fn is_fn_or_class_decl(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Decl(Decl::Fn(..)) | Stmt::Decl(Decl::Class(..)) => true,
+        _ => false,
+    }
+}
+
 #[cold]
 #[inline(never)]
 fn invalid_pat() -> ! {
@@ -78,6 +78,18 @@ add_bitflags!(
         /// If the literal is empty, do not add spaces between braces.
         NoSpaceIfEmpty: 1 << 18,
         SingleElement: 1 << 19,
+        /// Print a blank line between each node.
+        BlankLineBetweenSiblings: 1 << 23,
+    },
+    /// Trailing commas
+    Values {
+        /// A trailing comma is allowed since es5. (e.g. array literals)
+        Es5TrailingComma: 1 << 20,
+        /// A trailing comma is allowed since es2017. (e.g. argument lists)
+        Es2017TrailingComma: 1 << 21,
+        /// Always write a trailing comma. (e.g. array literals ending with a
+        /// hole)
+        ForceTrailingComma: 1 << 22,
     },
     /// Precomputed Formats
     Values {
@@ -115,20 +127,27 @@ add_bitflags!(
             | SpaceBetweenBraces
             | Indented
             | Braces
-            | NoSpaceIfEmpty,
+            | NoSpaceIfEmpty
+            | Es5TrailingComma,
         ArrayLiteralExpressionElements: PreserveLines
             | CommaDelimited
             | SpaceBetweenSiblings
             | AllowTrailingComma
             | Indented
-            | SquareBrackets,
+            | SquareBrackets
+            | Es5TrailingComma,
         CommaListElements: CommaDelimited | SpaceBetweenSiblings | SingleLine,
-        CallExpressionArguments: CommaDelimited | SpaceBetweenSiblings | SingleLine | Parenthesis,
+        CallExpressionArguments: CommaDelimited
+            | SpaceBetweenSiblings
+            | SingleLine
+            | Parenthesis
+            | Es2017TrailingComma,
         NewExpressionArguments: CommaDelimited
             | SpaceBetweenSiblings
             | SingleLine
             | Parenthesis
-            | OptionalIfUndefined,
+            | OptionalIfUndefined
+            | Es2017TrailingComma,
         TemplateExpressionSpans: SingleLine | NoInterveningComments,
         SingleLineBlockStatements: SpaceBetweenBraces | SpaceBetweenSiblings | SingleLine,
         MultiLineBlockStatements: Indented | MultiLine,
@@ -23,6 +23,7 @@ impl Builder {
         let mut e = Emitter {
             cfg: self.cfg,
             cm: self.cm.clone(),
+            ident_hook: None,
             wr: Box::new(text_writer::JsWriter::new(self.cm.clone(), "\n", s, None)),
             comments: Some(&self.comments),
         };
@@ -73,13 +74,27 @@ fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax) -> String {
 }
 
 pub(crate) fn assert_min(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: true }, Syntax::default());
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
 
 pub(crate) fn assert_pretty(from: &str, to: &str) {
-    let out = parse_then_emit(from, Config { minify: false }, Syntax::default());
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: false,
+            ..Default::default()
+        },
+        Syntax::default(),
+    );
 
     assert_eq!(DebugUsingDisplay(&out.trim()), DebugUsingDisplay(to),);
 }
@@ -160,6 +175,93 @@ a;",
     );
 }
 
+#[test]
+fn directive_use_client() {
+    test_identical(
+        r#""use client";
+import { useState } from "react";
+export default function Foo() {
+    "use strict";
+    return useState();
+}"#,
+    );
+}
+
+#[test]
+fn directive_raw() {
+    test_identical("'use\\x20strict';\n\"use client\";");
+    assert_min(
+        "'use\\x20strict'; 'use client'",
+        "'use\\x20strict';'use client'",
+    );
+}
+
+fn emit_with_ident_hook(from: &str) -> String {
+    fn upper_foo(sym: &JsWord, _: SyntaxContext) -> Option<String> {
+        if *sym == *"foo" {
+            Some("FOO".into())
+        } else {
+            None
+        }
+    }
+
+    ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
+        let comments = Default::default();
+        let syntax = Syntax::Es(EsConfig {
+            class_props: true,
+            ..Default::default()
+        });
+        let module = Parser::new(syntax, StringInput::from(&*src), Some(&comments))
+            .parse_module()
+            .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+        let cfg = Default::default();
+        Ok(Builder { cfg, cm, comments }.text(from, |e| {
+            e.ident_hook = Some(&upper_foo);
+            e.emit_module(&module).unwrap()
+        }))
+    })
+    .unwrap()
+}
+
+#[test]
+fn ident_hook() {
+    let out = emit_with_ident_hook(
+        "function foo({ foo = 1 }, bar) {
+    return foo + bar;
+}
+foo(obj.foo, { foo }, { foo: foo });
+export { foo };",
+    );
+
+    assert!(out.contains("function FOO("), "{}", out);
+    assert!(out.contains("foo: FOO = 1"), "{}", out);
+    assert!(out.contains("return FOO + bar;"), "{}", out);
+    assert!(out.contains("FOO(obj.foo, {"), "{}", out);
+    assert!(!out.contains("{ foo }"), "{}", out);
+    assert!(out.contains("FOO as foo"), "{}", out);
+
+    let out = emit_with_ident_hook("import { foo } from 'a'; import { foo as bar } from 'b';");
+    assert!(out.contains("foo as FOO"), "{}", out);
+    assert!(out.contains("foo as bar"), "{}", out);
+
+    let out = emit_with_ident_hook(
+        "class A {
+    foo = 1;
+}
+foo: for (;;) {
+    if (a) break foo;
+    continue foo;
+}",
+    );
+    assert!(out.contains("    foo = 1;"), "{}", out);
+    assert!(out.contains("foo: for"), "{}", out);
+    assert!(out.contains("break foo;"), "{}", out);
+    assert!(out.contains("continue foo;"), "{}", out);
+    assert!(!out.contains("FOO"), "{}", out);
+}
+
 #[test]
 fn no_octal_escape() {
     test_from_to(
@@ -184,7 +286,10 @@ fn empty_named_export_min() {
     test_from_to_custom_config(
         "export { }",
         "export{};",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -199,7 +304,10 @@ fn empty_named_export_from_min() {
     test_from_to_custom_config(
         "export { } from 'foo';",
         "export{}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -214,7 +322,10 @@ fn named_export_from_min() {
     test_from_to_custom_config(
         "export { bar } from 'foo';",
         "export{bar}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -237,7 +348,10 @@ fn export_namespace_from_min() {
     test_from_to_custom_config(
         "export * as Foo from 'foo';",
         "export*as Foo from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -245,6 +359,36 @@ fn export_namespace_from_min() {
     );
 }
 
+#[test]
+fn import_assertions() {
+    test_from_to_custom_config(
+        "import json from './foo.json' assert { type: 'json' };",
+        "import json from './foo.json' assert {
+    type: 'json'
+};",
+        Default::default(),
+        Syntax::Es(EsConfig {
+            import_assertions: true,
+            ..EsConfig::default()
+        }),
+    );
+}
+
+#[test]
+fn import_attributes_with() {
+    test_from_to_custom_config(
+        "import json from './foo.json' with { type: 'json' };",
+        "import json from './foo.json' with {
+    type: 'json'
+};",
+        Default::default(),
+        Syntax::Es(EsConfig {
+            import_assertions: true,
+            ..EsConfig::default()
+        }),
+    );
+}
+
 #[test]
 fn named_and_namespace_export_from() {
     test_from_to_custom_config(
@@ -263,7 +407,10 @@ fn named_and_namespace_export_from_min() {
     test_from_to_custom_config(
         "export * as Foo, { bar } from 'foo';",
         "export*as Foo,{bar}from'foo';",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             export_namespace_from: true,
             ..EsConfig::default()
@@ -432,6 +579,26 @@ fn jsx_1() {
     );
 }
 
+#[test]
+fn jsx_nested() {
+    let src = "<Foo bar={x} {...props}>
+    <>
+        <Bar.Baz qux=\"1\" {...rest} quux/>
+        {children}
+        <div/>
+    </>
+</Foo>;";
+    test_from_to_custom_config(
+        src,
+        src,
+        Default::default(),
+        Syntax::Es(EsConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+    );
+}
+
 #[test]
 fn deno_8162() {
     test_from_to(
@@ -486,6 +653,624 @@ fn dneo_8541_2() {
     );
 }
 
+const TRAILING_COMMA_INPUT: &str = "const arr = [
+    1,
+    2,
+];
+const obj = {
+    a: 1,
+};
+function foo(a, b,) {}
+foo(a, b,);";
+
+fn trailing_comma(trailing_comma: TrailingComma, expected: &str) {
+    test_from_to_custom_config(
+        TRAILING_COMMA_INPUT,
+        expected,
+        Config {
+            trailing_comma: Some(trailing_comma),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+#[test]
+fn trailing_comma_none() {
+    trailing_comma(
+        TrailingComma::None,
+        "const arr = [
+    1,
+    2
+];
+const obj = {
+    a: 1
+};
+function foo(a, b) {
+}
+foo(a, b);",
+    );
+}
+
+#[test]
+fn trailing_comma_es5() {
+    trailing_comma(
+        TrailingComma::Es5,
+        "const arr = [
+    1,
+    2,
+];
+const obj = {
+    a: 1,
+};
+function foo(a, b) {
+}
+foo(a, b);",
+    );
+}
+
+/// Parameters and arguments are printed on a single line, so they don't get
+/// a trailing comma.
+#[test]
+fn trailing_comma_all() {
+    trailing_comma(
+        TrailingComma::All,
+        "const arr = [
+    1,
+    2,
+];
+const obj = {
+    a: 1,
+};
+function foo(a, b) {
+}
+foo(a, b);",
+    );
+}
+
+#[test]
+fn trailing_comma_single_line_array() {
+    test_from_to_custom_config(
+        "const arr = [1, 2,];",
+        "const arr = [1, 2];",
+        Config {
+            trailing_comma: Some(TrailingComma::All),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+/// Writer which overrides [WriteJs::target].
+struct TargetWriter<W: WriteJs> {
+    target: JscTarget,
+    inner: W,
+}
+
+impl<W: WriteJs> WriteJs for TargetWriter<W> {
+    fn target(&self) -> JscTarget {
+        self.target
+    }
+
+    fn increase_indent(&mut self) -> Result {
+        self.inner.increase_indent()
+    }
+
+    fn decrease_indent(&mut self) -> Result {
+        self.inner.decrease_indent()
+    }
+
+    fn write_semi(&mut self) -> Result {
+        self.inner.write_semi()
+    }
+
+    fn write_space(&mut self) -> Result {
+        self.inner.write_space()
+    }
+
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> Result {
+        self.inner.write_keyword(span, s)
+    }
+
+    fn write_operator(&mut self, s: &str) -> Result {
+        self.inner.write_operator(s)
+    }
+
+    fn write_param(&mut self, s: &str) -> Result {
+        self.inner.write_param(s)
+    }
+
+    fn write_property(&mut self, s: &str) -> Result {
+        self.inner.write_property(s)
+    }
+
+    fn write_line(&mut self) -> Result {
+        self.inner.write_line()
+    }
+
+    fn write_blank_line(&mut self) -> Result {
+        self.inner.write_blank_line()
+    }
+
+    fn write_lit(&mut self, span: Span, s: &str) -> Result {
+        self.inner.write_lit(span, s)
+    }
+
+    fn write_comment(&mut self, span: Span, s: &str) -> Result {
+        self.inner.write_comment(span, s)
+    }
+
+    fn write_str_lit(&mut self, span: Span, s: &str) -> Result {
+        self.inner.write_str_lit(span, s)
+    }
+
+    fn write_str(&mut self, s: &str) -> Result {
+        self.inner.write_str(s)
+    }
+
+    fn write_symbol(&mut self, span: Span, s: &str) -> Result {
+        self.inner.write_symbol(span, s)
+    }
+
+    fn write_punct(&mut self, s: &'static str) -> Result {
+        self.inner.write_punct(s)
+    }
+
+    fn column(&self) -> Option<usize> {
+        self.inner.column()
+    }
+}
+
+fn trailing_comma_with_target(target: JscTarget, expected: &str) {
+    let from = "foo(aaaaaaaaaa, bbbbbbbbbb, cccccccccc, dddddddddd);
+const arr = [
+    1,
+    2,
+];";
+
+    let out = ::testing::run_test(false, |cm, handler| {
+        let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
+        let comments = SingleThreadedComments::default();
+        let module = Parser::new(Syntax::default(), StringInput::from(&*src), Some(&comments))
+            .parse_module()
+            .map_err(|e| e.into_diagnostic(handler).emit())?;
+
+        let mut buf = vec![];
+        {
+            let mut emitter = Emitter {
+                cfg: Config {
+                    trailing_comma: Some(TrailingComma::All),
+                    max_line_len: Some(40),
+                    ..Default::default()
+                },
+                cm: cm.clone(),
+                ident_hook: None,
+                comments: Some(&comments),
+                wr: Box::new(TargetWriter {
+                    target,
+                    inner: text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None),
+                }),
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap();
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay(expected.trim())
+    );
+}
+
+#[test]
+fn trailing_comma_es2017_target() {
+    trailing_comma_with_target(
+        JscTarget::Es2017,
+        "foo(
+    aaaaaaaaaa,
+    bbbbbbbbbb,
+    cccccccccc,
+    dddddddddd,
+);
+const arr = [
+    1,
+    2,
+];",
+    );
+}
+
+/// Trailing commas in argument lists are syntax errors before ES2017, but
+/// the ones in array literals are not.
+#[test]
+fn trailing_comma_es2016_target() {
+    trailing_comma_with_target(
+        JscTarget::Es2016,
+        "foo(
+    aaaaaaaaaa,
+    bbbbbbbbbb,
+    cccccccccc,
+    dddddddddd
+);
+const arr = [
+    1,
+    2,
+];",
+    );
+}
+
+#[test]
+fn trailing_comma_hole() {
+    test_from_to_custom_config(
+        "const arr = [1, , ];",
+        "const arr = [1, , ];",
+        Config {
+            trailing_comma: Some(TrailingComma::None),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+fn max_line_len(from: &str, to: &str) {
+    let out = parse_then_emit(
+        from,
+        Config {
+            max_line_len: Some(40),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+
+    assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to.trim()));
+}
+
+#[test]
+fn max_line_len_call() {
+    max_line_len(
+        "foo(aaaaaaaaaa, bbbbbbbbbb, cccccccccc, dddddddddd);",
+        "foo(
+    aaaaaaaaaa,
+    bbbbbbbbbb,
+    cccccccccc,
+    dddddddddd
+);",
+    );
+}
+
+#[test]
+fn max_line_len_short() {
+    max_line_len("foo(a, [b, c]);", "foo(a, [b, c]);");
+}
+
+#[test]
+fn max_line_len_nested() {
+    max_line_len(
+        "foo(aaaaaaaaaa, [bbbbbbbbbb, cccccccccc, dddddddddd, eeeeeeeeee]);",
+        "foo(
+    aaaaaaaaaa,
+    [
+        bbbbbbbbbb,
+        cccccccccc,
+        dddddddddd,
+        eeeeeeeeee
+    ]
+);",
+    );
+}
+
+#[test]
+fn max_line_len_bin_expr() {
+    max_line_len(
+        "const x = aaaaaaaaaa + bbbbbbbbbb + cccccccccc + dddddddddd;",
+        "const x = aaaaaaaaaa +
+    bbbbbbbbbb +
+    cccccccccc +
+    dddddddddd;",
+    );
+}
+
+#[test]
+fn max_line_len_str() {
+    max_line_len(
+        "foo('a string which does not fit in forty columns');",
+        "foo(
+    'a string which does not fit in forty columns'
+);",
+    );
+}
+
+#[test]
+fn max_line_len_tpl() {
+    max_line_len(
+        "foo(`${aaaaaaaaaa(bbbbbbbbbb, cccccccccc, dddddddddd)}`);",
+        "foo(
+    `${aaaaaaaaaa(bbbbbbbbbb, cccccccccc, dddddddddd)}`
+);",
+    );
+}
+
+#[test]
+fn max_line_len_trailing_comma() {
+    test_from_to_custom_config(
+        "foo(aaaaaaaaaa, bbbbbbbbbb, cccccccccc, dddddddddd);",
+        "foo(
+    aaaaaaaaaa,
+    bbbbbbbbbb,
+    cccccccccc,
+    dddddddddd,
+);",
+        Config {
+            trailing_comma: Some(TrailingComma::All),
+            max_line_len: Some(40),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+fn no_semicolons(from: &str, to: &str) {
+    test_from_to_custom_config(
+        from,
+        to,
+        Config {
+            semicolons: false,
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+#[test]
+fn no_semicolons_leading_paren() {
+    no_semicolons(
+        "a = b;
+(c || d).e();
+[1, 2].forEach(f);
+-x;",
+        "a = b
+;(c || d).e()
+;[1, 2].forEach(f)
+;-x",
+    );
+}
+
+#[test]
+fn no_semicolons_same_line() {
+    no_semicolons(
+        "if (x) y(); else z();
+for (;;) ;",
+        "if (x) y()
+else z()
+for(;;);",
+    );
+}
+
+#[test]
+fn no_semicolons_block() {
+    no_semicolons(
+        "function foo() {
+    bar();
+    return;
+}",
+        "function foo() {
+    bar()
+    return
+}",
+    );
+}
+
+fn blank_lines(from: &str, to: &str, blank_lines: BlankLines) {
+    test_from_to_custom_config(
+        from,
+        to,
+        Config {
+            blank_lines,
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+#[test]
+fn blank_lines_between_top_level_decls() {
+    blank_lines(
+        "function a() { return 1; }
+function b() { return 2; }
+var x = 1;
+var y = 2;",
+        "function a() {
+    return 1;
+}
+
+function b() {
+    return 2;
+}
+
+var x = 1;
+var y = 2;",
+        BlankLines {
+            between_top_level_decls: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn blank_lines_between_class_members() {
+    blank_lines(
+        "class A { a() { return 1; } b() { return 2; } }",
+        "class A {
+    a() {
+        return 1;
+    }
+
+    b() {
+        return 2;
+    }
+}",
+        BlankLines {
+            between_class_members: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn blank_lines_before_block_comments() {
+    blank_lines(
+        "a();
+/* b */
+b();
+function f() {
+    /* c */
+    c();
+}",
+        "a();
+
+/* b */ b();
+function f() {
+    /* c */ c();
+}",
+        BlankLines {
+            before_block_comments: true,
+            ..Default::default()
+        },
+    );
+}
+
+fn ts_identical(from: &str) {
+    test_from_to_custom_config(
+        from,
+        from,
+        Default::default(),
+        Syntax::Typescript(Default::default()),
+    );
+}
+
+#[test]
+fn ts_interface() {
+    ts_identical(
+        "interface Foo<T> extends Bar, Baz<T> {
+    a: string;
+    readonly b?: number;
+    c(x: T): void;
+    [key: string]: any;
+    new (x: T): Foo<T>;
+    (): void;
+}",
+    );
+}
+
+#[test]
+fn ts_typed_fn() {
+    ts_identical(
+        "function foo<T extends object = Foo>(a: T, b?: number, ...rest: string[]): T {
+    return a!;
+}",
+    );
+}
+
+#[test]
+fn ts_type_alias() {
+    ts_identical(
+        "type A<T> = T extends string ? keyof T : never;
+type M<T> = {
+    readonly [K in keyof T]?: T[K];
+};",
+    );
+}
+
+#[test]
+fn ts_class() {
+    ts_identical(
+        "abstract class Foo<T> extends Bar<T> implements Baz, Qux {
+    private readonly a: T;
+    static b?: number;
+    c!: string;
+    abstract d(): void;
+    e<U>(x: U): U {
+        return x;
+    }
+}",
+    );
+}
+
+#[test]
+fn ts_module() {
+    ts_identical(
+        "declare module \"foo\" {
+    export type A = string;
+}
+namespace A.B {
+    export type C = string;
+}",
+    );
+}
+
+#[test]
+fn ts_import_equals() {
+    ts_identical(
+        "import fs = require(\"fs\");
+import A = B.C;
+export = fs;",
+    );
+}
+
+#[test]
+fn ts_exprs() {
+    ts_identical(
+        "a = <string>b;
+c = d as number;
+e = f!.g;
+h<T>(i);",
+    );
+}
+
+fn string_quotes(from: &str, to: &str, quotes: Quotes) {
+    test_from_to_custom_config(
+        from,
+        to,
+        Config {
+            string_quotes: Some(quotes),
+            ..Default::default()
+        },
+        Default::default(),
+    );
+}
+
+#[test]
+fn string_quotes_single() {
+    string_quotes(
+        r#"a = "foo"; b = "it's"; c = '\n\\';"#,
+        r#"a = 'foo';
+b = 'it\'s';
+c = '\n\\';"#,
+        Quotes::Single,
+    );
+}
+
+#[test]
+fn string_quotes_prefer() {
+    string_quotes(
+        r#"a = "foo"; b = "it's"; c = '"a"';"#,
+        r#"a = 'foo';
+b = "it's";
+c = '"a"';"#,
+        Quotes::Prefer('\''),
+    );
+}
+
+#[test]
+fn string_quotes_directive() {
+    string_quotes(
+        r#""use strict"; a = "b";"#,
+        r#""use strict";
+a = 'b';"#,
+        Quotes::Single,
+    );
+}
+
 #[derive(Debug, Clone)]
 struct Buf(Arc<RwLock<Vec<u8>>>);
 impl Write for Buf {
@@ -1,3 +1,4 @@
+pub(crate) use self::semicolon::AsiSafeSemi;
 pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
 use super::*;
 use swc_common::Span;
@@ -38,6 +39,17 @@ pub trait WriteJs {
 
     fn write_line(&mut self) -> Result;
 
+    /// Writes an empty line, if the writer is at the start of a line which
+    /// does not follow an empty line or an opening bracket.
+    ///
+    /// This is used for [Config::blank_lines]. The default implementation
+    /// writes nothing.
+    ///
+    /// [Config::blank_lines]: crate::Config::blank_lines
+    fn write_blank_line(&mut self) -> Result {
+        Ok(())
+    }
+
     fn write_lit(&mut self, span: Span, s: &str) -> Result;
     fn write_comment(&mut self, span: Span, s: &str) -> Result;
 
@@ -47,6 +59,16 @@ pub trait WriteJs {
     fn write_symbol(&mut self, span: Span, s: &str) -> Result;
 
     fn write_punct(&mut self, s: &'static str) -> Result;
+
+    /// Returns the column the next character will be written at, if the
+    /// writer tracks it.
+    ///
+    /// [Config::max_line_len] has no effect if this returns [None].
+    ///
+    /// [Config::max_line_len]: crate::Config::max_line_len
+    fn column(&self) -> Option<usize> {
+        None
+    }
 }
 
 impl<W> WriteJs for Box<W>
@@ -83,6 +105,10 @@ where
         (**self).write_line()
     }
 
+    fn write_blank_line(&mut self) -> Result {
+        (**self).write_blank_line()
+    }
+
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         (**self).write_lit(span, s)
     }
@@ -105,4 +131,8 @@ where
     fn write_punct(&mut self, s: &'static str) -> Result {
         (**self).write_punct(s)
     }
+
+    fn column(&self) -> Option<usize> {
+        (**self).column()
+    }
 }
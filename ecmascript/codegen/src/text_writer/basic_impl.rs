@@ -2,6 +2,8 @@ use super::{Result, WriteJs};
 use std::io::{self, Write};
 use swc_common::{sync::Lrc, BytePos, LineCol, SourceMap, Span};
 
+const INDENT: &[u8] = b"    ";
+
 ///
 /// -----
 ///
@@ -19,6 +21,10 @@ pub struct JsWriter<'a, W: Write> {
     srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
     wr: W,
     written_bytes: usize,
+    /// The last byte written, excluding line terminators and indentation.
+    last_byte: Option<u8>,
+    /// True if the current line follows an empty line.
+    after_blank_line: bool,
 }
 
 impl<'a, W: Write> JsWriter<'a, W> {
@@ -38,12 +44,12 @@ impl<'a, W: Write> JsWriter<'a, W> {
             srcmap,
             wr,
             written_bytes: 0,
+            last_byte: None,
+            after_blank_line: false,
         }
     }
 
     fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
-
         let mut cnt = 0;
         for _ in 0..self.indent {
             cnt += self.raw_write(INDENT)?;
@@ -74,6 +80,8 @@ impl<'a, W: Write> JsWriter<'a, W> {
                 self.line_start = false;
             }
             cnt += self.raw_write(data.as_bytes())?;
+            self.last_byte = data.as_bytes().last().cloned();
+            self.after_blank_line = false;
 
             if let Some(span) = span {
                 if !span.is_dummy() {
@@ -148,6 +156,25 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
         Ok(())
     }
 
+    fn write_blank_line(&mut self) -> Result {
+        if !self.line_start || self.after_blank_line {
+            return Ok(());
+        }
+
+        match self.last_byte {
+            // Start of the output or of a block.
+            None | Some(b'{') | Some(b'(') | Some(b'[') => {}
+            _ => {
+                self.raw_write(self.new_line.as_bytes())?;
+                self.line_count += 1;
+                self.line_pos = 0;
+                self.after_blank_line = true;
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
             self.write(Some(span), s)?;
@@ -187,6 +214,14 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
         self.write(None, s)?;
         Ok(())
     }
+
+    fn column(&self) -> Option<usize> {
+        if self.line_start {
+            Some(self.indent * INDENT.len())
+        } else {
+            Some(self.line_pos)
+        }
+    }
 }
 
 fn compute_line_starts(s: &str) -> Vec<usize> {
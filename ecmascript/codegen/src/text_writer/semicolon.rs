@@ -1,5 +1,6 @@
 use super::{Result, WriteJs};
 use swc_common::Span;
+use swc_ecma_parser::JscTarget;
 
 pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
     OmitTrailingSemi {
@@ -49,11 +50,16 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     with_semi!(write_param(s: &str));
     with_semi!(write_property(s: &str));
     with_semi!(write_line());
+    with_semi!(write_blank_line());
     with_semi!(write_lit(span: Span, s: &str));
     with_semi!(write_str_lit(span: Span, s: &str));
     with_semi!(write_str(s: &str));
     with_semi!(write_symbol(span: Span, s: &str));
     with_semi!(write_punct(s: &'static str));
+
+    fn column(&self) -> Option<usize> {
+        self.inner.column()
+    }
 }
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
@@ -65,3 +71,126 @@ impl<W: WriteJs> OmitTrailingSemi<W> {
         Ok(())
     }
 }
+
+/// Omits semicolons which can be inserted by automatic semicolon insertion.
+///
+/// A semicolon is kept if the next token is on the same line, except for `}`.
+/// If the next token is on a new line and may continue the previous
+/// statement, the semicolon is printed at the start of the new line instead.
+pub(crate) struct AsiSafeSemi<'a, W: ?Sized + WriteJs> {
+    inner: &'a mut W,
+    pending_semi: bool,
+    /// True if a line break is written after the pending semicolon.
+    line_broken: bool,
+}
+
+impl<'a, W: ?Sized + WriteJs> AsiSafeSemi<'a, W> {
+    pub(crate) fn new(inner: &'a mut W) -> Self {
+        AsiSafeSemi {
+            inner,
+            pending_semi: false,
+            line_broken: false,
+        }
+    }
+
+    /// Called before `s` is written.
+    fn commit_pending_semi(&mut self, s: &str) -> Result {
+        if !self.pending_semi {
+            return Ok(());
+        }
+        self.pending_semi = false;
+
+        let needs_semi = if self.line_broken {
+            match s.chars().next() {
+                Some('(') | Some('[') | Some('`') | Some('+') | Some('-') | Some('/') => true,
+                _ => false,
+            }
+        } else {
+            s != "}"
+        };
+
+        if needs_semi {
+            self.inner.write_semi()?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! with_asi_semi {
+    (
+        $fn_name:ident
+        (
+            $(
+                $arg_name:ident
+                :
+                $arg_ty:ty
+            ),*
+        ),
+        $s:ident
+    ) => {
+        fn $fn_name(&mut self, $($arg_name: $arg_ty),* ) -> Result {
+            self.commit_pending_semi($s)?;
+
+            self.inner.$fn_name( $($arg_name),* )
+        }
+    };
+}
+
+impl<'a, W: ?Sized + WriteJs> WriteJs for AsiSafeSemi<'a, W> {
+    fn target(&self) -> JscTarget {
+        self.inner.target()
+    }
+
+    fn increase_indent(&mut self) -> Result {
+        self.inner.increase_indent()
+    }
+
+    fn decrease_indent(&mut self) -> Result {
+        self.inner.decrease_indent()
+    }
+
+    fn write_semi(&mut self) -> Result {
+        self.commit_pending_semi(";")?;
+        self.pending_semi = true;
+        self.line_broken = false;
+        Ok(())
+    }
+
+    fn write_space(&mut self) -> Result {
+        self.commit_pending_semi(" ")?;
+        self.inner.write_space()
+    }
+
+    fn write_line(&mut self) -> Result {
+        if self.pending_semi {
+            self.line_broken = true;
+        }
+        self.inner.write_line()
+    }
+
+    fn write_blank_line(&mut self) -> Result {
+        if self.pending_semi {
+            self.line_broken = true;
+        }
+        self.inner.write_blank_line()
+    }
+
+    fn write_comment(&mut self, span: Span, s: &str) -> Result {
+        // Comments don't affect automatic semicolon insertion.
+        self.inner.write_comment(span, s)
+    }
+
+    with_asi_semi!(write_keyword(span: Option<Span>, s: &'static str), s);
+    with_asi_semi!(write_operator(s: &str), s);
+    with_asi_semi!(write_param(s: &str), s);
+    with_asi_semi!(write_property(s: &str), s);
+    with_asi_semi!(write_lit(span: Span, s: &str), s);
+    with_asi_semi!(write_str_lit(span: Span, s: &str), s);
+    with_asi_semi!(write_str(s: &str), s);
+    with_asi_semi!(write_symbol(span: Span, s: &str), s);
+    with_asi_semi!(write_punct(s: &'static str), s);
+
+    fn column(&self) -> Option<usize> {
+        self.inner.column()
+    }
+}
@@ -46,9 +46,8 @@ impl<'a> Emitter<'a> {
         punct!(")");
 
         if let Some(type_ann) = &n.type_ann {
-            space!();
-            punct!("=>");
-            space!();
+            punct!(":");
+            formatting_space!();
 
             emit!(type_ann);
         }
@@ -82,7 +81,21 @@ impl<'a> Emitter<'a> {
     fn emit_ts_constructor_signature_decl(&mut self, n: &TsConstructSignatureDecl) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_constructor_signature_decl")
+        keyword!("new");
+        formatting_space!();
+
+        emit!(n.type_params);
+
+        punct!("(");
+        self.emit_list(n.span, Some(&n.params), ListFormat::Parameters)?;
+        punct!(")");
+
+        if let Some(type_ann) = &n.type_ann {
+            punct!(":");
+            formatting_space!();
+
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
@@ -111,10 +124,7 @@ impl<'a> Emitter<'a> {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
         match n {
-            TsEntityName::TsQualifiedName(n) => {
-                emit!(n);
-                punct!(".");
-            }
+            TsEntityName::TsQualifiedName(n) => emit!(n),
             TsEntityName::Ident(n) => emit!(n),
         }
     }
@@ -172,7 +182,13 @@ impl<'a> Emitter<'a> {
     fn emit_ts_export_assignment(&mut self, n: &TsExportAssignment) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_export_assignment")
+        keyword!("export");
+        formatting_space!();
+        punct!("=");
+        formatting_space!();
+
+        emit!(n.expr);
+        semi!();
     }
 
     #[emitter]
@@ -188,7 +204,10 @@ impl<'a> Emitter<'a> {
     fn emit_ts_external_module_ref(&mut self, n: &TsExternalModuleRef) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_external_module_ref")
+        keyword!("require");
+        punct!("(");
+        emit!(n.expr);
+        punct!(")");
     }
 
     #[emitter]
@@ -238,25 +257,34 @@ impl<'a> Emitter<'a> {
         }
 
         keyword!("import");
+        space!();
+        emit!(n.id);
         formatting_space!();
         punct!("=");
         formatting_space!();
 
         emit!(n.module_ref);
+        semi!();
     }
 
     #[emitter]
     fn emit_ts_index_signature(&mut self, n: &TsIndexSignature) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
+        if n.readonly {
+            keyword!("readonly");
+            space!();
+        }
+
         punct!("[");
         self.emit_list(n.span, Some(&n.params), ListFormat::Parameters)?;
         punct!("]");
 
-        punct!(":");
-        formatting_space!();
-        emit!(n.type_ann);
-        semi!();
+        if let Some(type_ann) = &n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
     }
 
     #[emitter]
@@ -303,6 +331,7 @@ impl<'a> Emitter<'a> {
         space!();
 
         emit!(n.id);
+        emit!(n.type_params);
 
         if !n.extends.is_empty() {
             space!();
@@ -422,10 +451,18 @@ impl<'a> Emitter<'a> {
         punct!("[");
         emit!(n.type_param.name);
 
-        if let Some(constraints) = &n.type_param.constraint {
+        if let Some(constraint) = &n.type_param.constraint {
             space!();
             keyword!("in");
             space!();
+            emit!(constraint);
+        }
+
+        if let Some(name_type) = &n.name_type {
+            space!();
+            keyword!("as");
+            space!();
+            emit!(name_type);
         }
 
         if let Some(default) = &n.type_param.default {
@@ -435,8 +472,6 @@ impl<'a> Emitter<'a> {
             emit!(default);
         }
 
-        emit!(n.type_param.constraint);
-
         punct!("]");
 
         match n.optional {
@@ -447,7 +482,7 @@ impl<'a> Emitter<'a> {
                 }
                 TruePlusMinus::Plus => {
                     punct!("+");
-                    punct!("/");
+                    punct!("?");
                 }
                 TruePlusMinus::Minus => {
                     punct!("-");
@@ -456,13 +491,15 @@ impl<'a> Emitter<'a> {
             },
         }
 
-        punct!(":");
-        space!();
-        emit!(n.type_ann);
+        if let Some(type_ann) = &n.type_ann {
+            punct!(":");
+            formatting_space!();
+            emit!(type_ann);
+        }
         semi!();
 
-        self.wr.write_line()?;
         self.wr.decrease_indent()?;
+        self.wr.write_line()?;
         punct!("}");
     }
 
@@ -472,6 +509,7 @@ impl<'a> Emitter<'a> {
 
         if n.readonly {
             keyword!("readonly");
+            space!();
         }
 
         if n.computed {
@@ -503,8 +541,11 @@ impl<'a> Emitter<'a> {
 
     #[emitter]
     fn emit_ts_module_block(&mut self, n: &TsModuleBlock) -> Result {
-        self.emit_list(n.span, Some(&n.body), ListFormat::SourceFileStatements)?;
         self.emit_leading_comments_of_pos(n.span().lo())?;
+
+        punct!("{");
+        self.emit_list(n.span, Some(&n.body), ListFormat::MultiLineBlockStatements)?;
+        punct!("}");
     }
 
     #[emitter]
@@ -516,13 +557,27 @@ impl<'a> Emitter<'a> {
             space!();
         }
 
-        keyword!("module");
-        space!();
-        emit!(n.id);
-        formatting_space!();
+        if n.global {
+            keyword!("global");
+        } else {
+            match n.id {
+                TsModuleName::Ident(..) => keyword!("namespace"),
+                TsModuleName::Str(..) => keyword!("module"),
+            }
+            space!();
+            emit!(n.id);
+        }
 
-        if let Some(body) = &n.body {
-            emit!(body);
+        match &n.body {
+            Some(TsNamespaceBody::TsModuleBlock(body)) => {
+                formatting_space!();
+                emit!(body);
+            }
+            Some(TsNamespaceBody::TsNamespaceDecl(body)) => {
+                punct!(".");
+                emit!(body);
+            }
+            None => semi!(),
         }
     }
 
@@ -538,42 +593,61 @@ impl<'a> Emitter<'a> {
     fn emit_ts_module_ref(&mut self, n: &TsModuleRef) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_module_ref")
+        match n {
+            TsModuleRef::TsEntityName(n) => emit!(n),
+            TsModuleRef::TsExternalModuleRef(n) => emit!(n),
+        }
     }
 
     #[emitter]
     fn emit_ts_ns_body(&mut self, n: &TsNamespaceBody) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        punct!("{");
-        self.wr.increase_indent()?;
         match n {
             TsNamespaceBody::TsModuleBlock(n) => emit!(n),
             TsNamespaceBody::TsNamespaceDecl(n) => emit!(n),
         }
-        self.wr.decrease_indent()?;
-        punct!("}");
     }
 
     #[emitter]
     fn emit_ts_ns_decl(&mut self, n: &TsNamespaceDecl) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_ns_decl")
+        // `a.b` in `namespace a.b {}`
+        emit!(n.id);
+
+        match &*n.body {
+            TsNamespaceBody::TsModuleBlock(body) => {
+                formatting_space!();
+                emit!(body);
+            }
+            TsNamespaceBody::TsNamespaceDecl(body) => {
+                punct!(".");
+                emit!(body);
+            }
+        }
     }
 
     #[emitter]
     fn emit_ts_ns_export_decl(&mut self, n: &TsNamespaceExportDecl) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_ns_export_decl")
+        keyword!("export");
+        space!();
+        keyword!("as");
+        space!();
+        keyword!("namespace");
+        space!();
+        emit!(n.id);
+        semi!();
     }
 
     #[emitter]
     fn emit_ts_non_null_expr(&mut self, n: &TsNonNullExpr) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_non_null_expr")
+        emit!(n.expr);
+        punct!("!");
     }
 
     #[emitter]
@@ -679,7 +753,13 @@ impl<'a> Emitter<'a> {
     fn emit_ts_signature_decl(&mut self, n: &TsSignatureDecl) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_signature_decl")
+        match n {
+            TsSignatureDecl::TsCallSignatureDecl(n) => emit!(n),
+            TsSignatureDecl::TsConstructSignatureDecl(n) => emit!(n),
+            TsSignatureDecl::TsMethodSignature(n) => emit!(n),
+            TsSignatureDecl::TsFnType(n) => emit!(n),
+            TsSignatureDecl::TsConstructorType(n) => emit!(n),
+        }
     }
 
     #[emitter]
@@ -761,11 +841,7 @@ impl<'a> Emitter<'a> {
             emit!(n);
         }
 
-        if let Some(type_args) = &n.type_args {
-            punct!("<");
-            emit!(type_args);
-            punct!(">");
-        }
+        emit!(n.type_args);
     }
 
     #[emitter]
@@ -807,7 +883,10 @@ impl<'a> Emitter<'a> {
     fn emit_ts_type_assertion(&mut self, n: &TsTypeAssertion) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_type_assertion")
+        punct!("<");
+        emit!(n.type_ann);
+        punct!(">");
+        emit!(n.expr);
     }
 
     #[emitter]
@@ -826,7 +905,10 @@ impl<'a> Emitter<'a> {
     fn emit_ts_type_cast_expr(&mut self, n: &TsTypeCastExpr) -> Result {
         self.emit_leading_comments_of_pos(n.span().lo())?;
 
-        unimplemented!("emit_ts_type_cast_expr")
+        emit!(n.expr);
+        punct!(":");
+        formatting_space!();
+        emit!(n.type_ann);
     }
 
     #[emitter]
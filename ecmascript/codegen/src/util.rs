@@ -210,7 +210,12 @@ impl StartsWithAlphaNum for Expr {
                 }
             }
 
-            Expr::Tpl(_) | Expr::Array(_) | Expr::Object(_) | Expr::Paren(_) => false,
+            Expr::Tpl(_)
+            | Expr::Array(_)
+            | Expr::Object(_)
+            | Expr::Record(_)
+            | Expr::Tuple(_)
+            | Expr::Paren(_) => false,
 
             Expr::TaggedTpl(TaggedTpl { ref tag, .. }) => tag.starts_with_alpha_num(),
 
@@ -0,0 +1,61 @@
+use swc_common::{
+    arena::{self, ArenaAlloc},
+    sync::Lrc,
+    FileName, SourceMap,
+};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::{Parser, StringInput, Syntax};
+
+#[global_allocator]
+static ALLOC: ArenaAlloc = ArenaAlloc;
+
+const SRC: &str = include_str!("../benches/large-partial.js");
+
+fn parse_and_emit(cm: &Lrc<SourceMap>) -> Result<String, ()> {
+    let fm = cm.new_source_file(FileName::Anon, SRC.into());
+    let module = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
+        .parse_module()
+        .map_err(|_| ())?;
+
+    let mut buf = vec![];
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            ident_hook: None,
+            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+        };
+        emitter.emit_module(&module).map_err(|_| ())?;
+    }
+
+    Ok(String::from_utf8(buf).unwrap())
+}
+
+#[test]
+fn parse_and_emit_in_scope() {
+    testing::run_test(false, |cm, _| {
+        let before = arena::stats();
+        let expected = parse_and_emit(&cm)?;
+        let without_arena = arena::stats().system_allocs - before.system_allocs;
+
+        let before = arena::stats();
+        let actual = arena::scope(|| parse_and_emit(&cm))?;
+        let after = arena::stats();
+
+        assert_eq!(actual, expected);
+        assert!(after.arena_allocs > before.arena_allocs);
+
+        // Most allocations are served from chunks.
+        let with_arena = after.system_allocs - before.system_allocs;
+        assert!(
+            with_arena * 10 < without_arena,
+            "system allocations: {} with arena, {} without arena",
+            with_arena,
+            without_arena
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
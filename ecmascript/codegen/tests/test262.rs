@@ -156,6 +156,7 @@ fn error_tests(tests: &mut Vec<TestDescAndFn>) -> Result<(), io::Error> {
                     let mut emitter = Emitter {
                         cfg: Default::default(),
                         cm: cm.clone(),
+                        ident_hook: None,
                         wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
                             cm, "\n", &mut wr, None,
                         )),
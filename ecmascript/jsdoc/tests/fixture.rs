@@ -183,4 +183,18 @@ impl Comments for SwcComments {
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
         self.trailing.remove(&pos).map(|v| v.1)
     }
+
+    fn retain_leading(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+        self.leading.retain(|&pos, comments| {
+            comments.retain(|c| f(pos, c));
+            !comments.is_empty()
+        });
+    }
+
+    fn retain_trailing(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+        self.trailing.retain(|&pos, comments| {
+            comments.retain(|c| f(pos, c));
+            !comments.is_empty()
+        });
+    }
 }
@@ -104,6 +104,13 @@ pub enum SyntaxError {
     NullishCoalescingWithLogicalOp,
     NullishCoalescingNotEnabled,
 
+    PipelineNotEnabled,
+    PipelineProposalRequired,
+
+    ImportAssertionsNotEnabled,
+
+    TupleHole,
+
     MultipleDefault {
         /// Span of the previous default case
         previous: Span,
@@ -116,6 +123,8 @@ pub enum SyntaxError {
     InvalidPat,
     InvalidExpr,
     NotSimpleAssign,
+    /// `a?.b = c`
+    AssignToOptChain,
     ExpectedIdent,
     ExpctedSemi,
     DuplicateLabel(JsWord),
@@ -314,6 +323,9 @@ impl SyntaxError {
             SyntaxError::InvalidExpr => "Not an expression".into(),
             // TODO
             SyntaxError::NotSimpleAssign => "Cannot assign to this".into(),
+            SyntaxError::AssignToOptChain => {
+                "Optional chaining cannot appear in the left-hand side of an assignment".into()
+            }
             SyntaxError::ExpectedIdent => "Expected ident".into(),
             SyntaxError::ExpctedSemi => "Expected ';' or line break".into(),
             SyntaxError::DuplicateLabel(ref label) => {
@@ -414,6 +426,19 @@ impl SyntaxError {
             SyntaxError::NullishCoalescingNotEnabled => {
                 "Nullish coalescing operator(??) requires jsc.parser.nullishCoalescing".into()
             }
+            SyntaxError::PipelineNotEnabled => {
+                "Pipeline operator(|>) requires jsc.parser.pipeline".into()
+            }
+            SyntaxError::PipelineProposalRequired => "Pipeline operator(|>) requires \
+                                                      jsc.parser.pipelineProposal to be one of \
+                                                      'minimal' or 'fsharp'"
+                .into(),
+
+            SyntaxError::ImportAssertionsNotEnabled => {
+                "Import assertions require jsc.parser.importAssertions".into()
+            }
+
+            SyntaxError::TupleHole => "Tuples can't have holes".into(),
 
             SyntaxError::TS1056 => {
                 "jsc.taraget should be es5 or upper to use getter / setter".into()
@@ -353,6 +353,12 @@ impl<'a, I: Input> Lexer<'a, I> {
                     })));
                 }
 
+                // '|>'
+                if c == '|' && self.input.cur() == Some('>') {
+                    self.input.bump();
+                    return Ok(Some(BinOp(Pipeline)));
+                }
+
                 BinOp(token)
             }
             '^' => {
@@ -451,7 +457,10 @@ impl<'a, I: Input> Lexer<'a, I> {
             return Ok(None);
         }
 
-        if self.syntax.class_private_props() || self.syntax.class_private_methods() {
+        if self.syntax.class_private_props()
+            || self.syntax.class_private_methods()
+            || self.syntax.record_and_tuple()
+        {
             self.input.bump(); // '#'
             return Ok(Some(Token::Hash));
         }
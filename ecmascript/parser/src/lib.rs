@@ -265,6 +265,32 @@ impl Syntax {
         }
     }
 
+    pub fn pipeline(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig { pipeline: true, .. }) => true,
+            _ => false,
+        }
+    }
+
+    pub fn pipeline_proposal(self) -> Option<PipelineProposal> {
+        match self {
+            Syntax::Es(EsConfig {
+                pipeline_proposal, ..
+            }) => pipeline_proposal,
+            _ => None,
+        }
+    }
+
+    pub fn record_and_tuple(self) -> bool {
+        match self {
+            Syntax::Es(EsConfig {
+                record_and_tuple: true,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
     pub fn dts(self) -> bool {
         match self {
             Syntax::Typescript(t) => t.dts,
@@ -322,6 +348,10 @@ pub enum JscTarget {
     Es2019,
     #[serde(rename = "es2020")]
     Es2020,
+    #[serde(rename = "es2021")]
+    Es2021,
+    #[serde(rename = "es2022")]
+    Es2022,
 }
 
 impl Default for JscTarget {
@@ -396,6 +426,35 @@ pub struct EsConfig {
     /// Stage 3.
     #[serde(default)]
     pub import_assertions: bool,
+
+    /// Stage 1.
+    ///
+    /// `pipeline_proposal` should also be set, as the semantics of each
+    /// proposal differ.
+    #[serde(default)]
+    pub pipeline: bool,
+
+    #[serde(default)]
+    pub pipeline_proposal: Option<PipelineProposal>,
+
+    /// Stage 2.
+    #[serde(default)]
+    pub record_and_tuple: bool,
+}
+
+/// Variant of the pipeline operator (`|>`) proposal.
+///
+/// The hack proposal (topic references) is not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PipelineProposal {
+    /// `x |> f` is `f(x)`. The right hand side is a plain expression.
+    Minimal,
+    /// The F# proposal.
+    ///
+    /// Parsed the same way as the minimal proposal. `x |> await` is not
+    /// supported yet.
+    Fsharp,
 }
 
 /// Syntactic context.
@@ -136,6 +136,8 @@ impl<'a, I: Tokens> Parser<I> {
                         .map(Box::new)
                         .map(PatOrExpr::Pat)?
                 } else {
+                    self.check_opt_chain_assign_target(&cond);
+
                     //It is an early Reference Error if IsValidSimpleAssignmentTarget of
                     // LeftHandSideExpression is false.
                     if !self.input.syntax().typescript()
@@ -271,6 +273,14 @@ impl<'a, I: Tokens> Parser<I> {
                     return self.parse_object();
                 }
 
+                tok!('#') => {
+                    if self.input.syntax().record_and_tuple()
+                        && (peeked_is!('{') || peeked_is!('['))
+                    {
+                        return self.parse_record_or_tuple();
+                    }
+                }
+
                 // Handle FunctionExpression and GeneratorExpression
                 tok!("function") => {
                     return self.parse_fn_expr();
@@ -415,6 +425,41 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(Box::new(Expr::Array(ArrayLit { span, elems })))
     }
 
+    /// `#{ ... }` or `#[ ... ]`
+    fn parse_record_or_tuple(&mut self) -> PResult<Box<Expr>> {
+        trace_cur!(parse_record_or_tuple);
+
+        let start = cur_pos!();
+
+        assert_and_bump!('#');
+        let expr = if is!('{') {
+            self.parse_object::<Box<Expr>>()?
+        } else {
+            self.parse_array_lit()?
+        };
+
+        let span = span!(start);
+        match *expr {
+            Expr::Object(ObjectLit { props, .. }) => {
+                Ok(Box::new(Expr::Record(RecordLit { span, props })))
+            }
+            Expr::Array(ArrayLit { elems, .. }) => {
+                let elems = elems
+                    .into_iter()
+                    .filter_map(|elem| {
+                        if elem.is_none() {
+                            self.emit_err(span, SyntaxError::TupleHole);
+                        }
+                        elem
+                    })
+                    .collect();
+
+                Ok(Box::new(Expr::Tuple(TupleLit { span, elems })))
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn parse_member_expr(&mut self) -> PResult<Box<Expr>> {
         self.parse_member_expr_or_new_expr(false)
     }
@@ -1553,6 +1598,10 @@ impl<'a, I: Tokens> Parser<I> {
     }
 
     pub(super) fn check_assign_target(&mut self, expr: &Expr, deny_call: bool) {
+        if self.check_opt_chain_assign_target(expr) {
+            return;
+        }
+
         // We follow behavior of tsc
         if self.input.syntax().typescript() && self.syntax().early_errors() {
             let is_eval_or_arguments = match *expr {
@@ -1590,6 +1639,40 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
     }
+
+    /// Emits an error and returns true if `expr` is an optional chain, like
+    /// `a?.b` or `a?.b.c`.
+    ///
+    /// The assignment target type of an optional chain is invalid, even if it
+    /// is parenthesized.
+    pub(super) fn check_opt_chain_assign_target(&mut self, expr: &Expr) -> bool {
+        fn is_opt_chain(e: &Expr) -> bool {
+            match e {
+                Expr::OptChain(..) => true,
+                Expr::Member(MemberExpr {
+                    obj: ExprOrSuper::Expr(obj),
+                    ..
+                }) => is_opt_chain(obj),
+                Expr::Call(CallExpr {
+                    callee: ExprOrSuper::Expr(callee),
+                    ..
+                }) => is_opt_chain(callee),
+                _ => false,
+            }
+        }
+
+        let mut target = expr;
+        while let Expr::Paren(ParenExpr { expr, .. }) = target {
+            target = expr;
+        }
+
+        if is_opt_chain(target) {
+            self.emit_err(target.span(), SyntaxError::AssignToOptChain);
+            return true;
+        }
+
+        false
+    }
 }
 
 fn is_import(obj: &ExprOrSuper) -> bool {
@@ -28,7 +28,31 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         return_if_arrow!(left);
-        self.parse_bin_op_recursively(left, 0)
+        let mut expr = self.parse_bin_op_recursively(left, 0)?;
+
+        // `|>` has lower precedence than any other binary operator.
+        while is!("|>") {
+            if !self.syntax().pipeline() {
+                self.emit_err(self.input.cur_span(), SyntaxError::PipelineNotEnabled);
+            } else if self.syntax().pipeline_proposal().is_none() {
+                self.emit_err(self.input.cur_span(), SyntaxError::PipelineProposalRequired);
+            }
+            bump!();
+
+            let right = {
+                let left_of_right = self.parse_unary_expr()?;
+                self.parse_bin_op_recursively(left_of_right, 0)?
+            };
+
+            expr = Box::new(Expr::Bin(BinExpr {
+                span: Span::new(expr.span().lo(), right.span().hi(), Default::default()),
+                op: op!("|>"),
+                left: expr,
+                right,
+            }));
+        }
+
+        Ok(expr)
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -374,6 +398,7 @@ impl<'a, I: Tokens> Parser<I> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{EsConfig, PipelineProposal};
     use swc_common::DUMMY_SP as span;
     use swc_ecma_visit::assert_eq_ignore_span;
 
@@ -406,4 +431,42 @@ mod tests {
             }))
         );
     }
+
+    fn pipeline(s: &'static str) -> Box<Expr> {
+        test_parser(
+            s,
+            Syntax::Es(EsConfig {
+                pipeline: true,
+                pipeline_proposal: Some(PipelineProposal::Minimal),
+                ..Default::default()
+            }),
+            |p| p.parse_bin_expr(),
+        )
+    }
+
+    #[test]
+    fn pipeline_left_assoc() {
+        assert_eq_ignore_span!(
+            pipeline("x |> f |> g"),
+            Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left: pipeline("x |> f"),
+                right: bin("g"),
+            }))
+        );
+    }
+
+    #[test]
+    fn pipeline_lowest_prec() {
+        assert_eq_ignore_span!(
+            pipeline("a || b + 1 |> f"),
+            Box::new(Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left: bin("a || b + 1"),
+                right: bin("f"),
+            }))
+        );
+    }
 }
@@ -312,6 +312,36 @@ fn iife() {
     )
 }
 
+#[test]
+fn nested_paren() {
+    let e = expr("((a))");
+
+    assert_eq_ignore_span!(
+        e.clone(),
+        Box::new(Expr::Paren(ParenExpr {
+            span,
+            expr: Box::new(Expr::Paren(ParenExpr {
+                span,
+                expr: Box::new(Expr::Ident(Ident::new("a".into(), span))),
+            })),
+        }))
+    );
+
+    match *e {
+        Expr::Paren(ParenExpr {
+            span: outer,
+            expr: ref inner,
+        }) => match **inner {
+            Expr::Paren(ParenExpr { span: inner, .. }) => {
+                assert_eq!(inner.lo().0, outer.lo().0 + 1);
+                assert_eq!(inner.hi().0 + 1, outer.hi().0);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn issue_319_1() {
     assert_eq_ignore_span!(
@@ -400,6 +430,172 @@ fn issue_675() {
     expr("Object.setPrototypeOf(this, new.target.prototype)");
 }
 
+fn record_and_tuple(s: &'static str) -> Box<Expr> {
+    test_parser(
+        s,
+        Syntax::Es(EsConfig {
+            record_and_tuple: true,
+            ..Default::default()
+        }),
+        |p| p.parse_expr(),
+    )
+}
+
+fn num(value: f64) -> Box<Expr> {
+    Box::new(Expr::Lit(Lit::Num(Number { span, value })))
+}
+
+#[test]
+fn nested_record_and_tuple() {
+    assert_eq_ignore_span!(
+        record_and_tuple("#{ a: #[1, #{ b: 2 }], ...c }"),
+        Box::new(Expr::Record(RecordLit {
+            span,
+            props: vec![
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(Ident::new("a".into(), span)),
+                    value: Box::new(Expr::Tuple(TupleLit {
+                        span,
+                        elems: vec![
+                            ExprOrSpread {
+                                spread: None,
+                                expr: num(1.0),
+                            },
+                            ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Record(RecordLit {
+                                    span,
+                                    props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(
+                                        KeyValueProp {
+                                            key: PropName::Ident(Ident::new("b".into(), span)),
+                                            value: num(2.0),
+                                        }
+                                    )))],
+                                })),
+                            },
+                        ],
+                    })),
+                }))),
+                PropOrSpread::Spread(SpreadElement {
+                    dot3_token: span,
+                    expr: Box::new(Expr::Ident(Ident::new("c".into(), span))),
+                }),
+            ],
+        }))
+    );
+}
+
+#[test]
+fn nested_tuple() {
+    assert_eq_ignore_span!(
+        record_and_tuple("#[#[], #[1]]"),
+        Box::new(Expr::Tuple(TupleLit {
+            span,
+            elems: vec![
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Tuple(TupleLit {
+                        span,
+                        elems: vec![],
+                    })),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Tuple(TupleLit {
+                        span,
+                        elems: vec![ExprOrSpread {
+                            spread: None,
+                            expr: num(1.0),
+                        }],
+                    })),
+                },
+            ],
+        }))
+    );
+}
+
+#[test]
+fn tuple_hole() {
+    test_parser(
+        "#[1, , 2]",
+        Syntax::Es(EsConfig {
+            record_and_tuple: true,
+            ..Default::default()
+        }),
+        |p| {
+            p.parse_expr()?;
+
+            let errors = p.take_errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].clone().kind(), SyntaxError::TupleHole);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn assign_to_opt_chain() {
+    test_parser("a?.b = 1", Default::default(), |p| {
+        p.parse_expr()?;
+
+        let errors = p.take_errors();
+        assert_eq!(errors.len(), 1);
+        let span = errors[0].span();
+        assert_eq!(span.hi.0 - span.lo.0, "a?.b".len() as u32);
+        assert_eq!(errors[0].clone().kind(), SyntaxError::AssignToOptChain);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn update_opt_chain() {
+    for src in &["a?.b.c += 1", "(a?.b) = 1", "a?.b++", "--a?.()[0]"] {
+        test_parser(*src, Default::default(), |p| {
+            p.parse_expr()?;
+
+            let errors = p.take_errors();
+            assert_eq!(errors.len(), 1, "{}", src);
+            assert_eq!(errors[0].clone().kind(), SyntaxError::AssignToOptChain);
+
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn destructure_to_opt_chain() {
+    for src in &[
+        "[a?.b] = []",
+        "[a?.b = 1] = []",
+        "[...a?.b] = []",
+        "({ x: a?.b } = o)",
+        "for (a?.b of xs);",
+    ] {
+        test_parser(*src, Default::default(), |p| {
+            p.parse_stmt(true)?;
+
+            let errors = p.take_errors();
+            assert_eq!(errors.len(), 1, "{}", src);
+            assert_eq!(errors[0].clone().kind(), SyntaxError::AssignToOptChain);
+
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn assign_to_paren_opt_chain_member() {
+    test_parser("(a?.b).c = 1", Default::default(), |p| {
+        p.parse_expr()?;
+
+        assert_eq!(p.take_errors(), vec![]);
+
+        Ok(())
+    });
+}
+
 #[bench]
 fn bench_new_expr_ts(b: &mut Bencher) {
     bench_parser(
@@ -513,6 +513,10 @@ impl<'a, I: Tokens> Parser<I> {
         // DestructuringAssignmentTarget:
         //      LeftHandSideExpression
         if pat_ty == PatType::AssignElement {
+            if self.check_opt_chain_assign_target(&expr) {
+                return Ok(Pat::Expr(expr));
+            }
+
             match *expr {
                 Expr::Array(..) | Expr::Object(..) => {}
 
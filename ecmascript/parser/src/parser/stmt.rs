@@ -28,7 +28,9 @@ impl<'a, I: Tokens> Parser<I> {
         } {
             let stmt = self.parse_stmt_like(true, top_level)?;
             if allow_directives {
-                allow_directives = false;
+                // A directive prologue may contain multiple directives, like
+                // `'use client'; 'use strict';`
+                allow_directives = stmt.is_directive();
                 if stmt.is_use_strict() {
                     let ctx = Context {
                         strict: true,
@@ -1103,6 +1105,15 @@ enum ForHead {
 
 pub(super) trait IsDirective {
     fn as_ref(&self) -> Option<&Stmt>;
+    fn is_directive(&self) -> bool {
+        match self.as_ref() {
+            Some(&Stmt::Expr(ref expr)) => match *expr.expr {
+                Expr::Lit(Lit::Str(..)) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
     fn is_use_strict(&self) -> bool {
         match self.as_ref() {
             Some(&Stmt::Expr(ref expr)) => match *expr.expr {
@@ -1193,6 +1204,27 @@ mod tests {
         )
     }
 
+    fn script_errors(s: &'static str) -> Vec<Error> {
+        test_parser(s, Syntax::default(), |p| {
+            p.parse_script()?;
+            Ok(p.take_errors())
+        })
+    }
+
+    #[test]
+    fn directive_prologue() {
+        assert!(script_errors("'use client'; eval += 1;").is_empty());
+    }
+
+    #[test]
+    fn use_strict_after_other_directive() {
+        assert_eq!(
+            script_errors("'use client'; 'use strict'; eval += 1;").len(),
+            1,
+            "'use strict' should be recognized after another directive"
+        );
+    }
+
     #[test]
     fn catch_rest_pat() {
         assert_eq_ignore_span!(
@@ -1713,4 +1745,75 @@ export default function waitUntil(callback, options = {}) {
         assert!(trailing.borrow().is_empty());
         assert_eq!(leading.borrow().len(), 1);
     }
+
+    #[test]
+    fn import_assertions_enabled() {
+        let m = test_parser(
+            "import json from './foo.json' assert { type: 'json' };",
+            Syntax::Es(EsConfig {
+                import_assertions: true,
+                ..Default::default()
+            }),
+            |p| p.parse_module(),
+        );
+
+        match &m.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                asserts: Some(..), ..
+            })) => {}
+            item => panic!("assertions are not parsed: {:?}", item),
+        }
+    }
+
+    #[test]
+    fn import_attributes_with() {
+        let m = test_parser(
+            "import json from './foo.json' with { type: 'json' };",
+            Syntax::Es(EsConfig {
+                import_assertions: true,
+                ..Default::default()
+            }),
+            |p| p.parse_module(),
+        );
+
+        match &m.body[0] {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                asserts: Some(asserts),
+                with,
+                ..
+            })) => {
+                assert_eq!(asserts.props.len(), 1);
+                assert!(with);
+            }
+            item => panic!("attributes are not parsed: {:?}", item),
+        }
+    }
+
+    #[test]
+    fn import_assertions_disabled() {
+        let errors = test_parser(
+            "import json from './foo.json' assert { type: 'json' };",
+            Syntax::default(),
+            |p| {
+                p.parse_module()?;
+                Ok(p.take_errors())
+            },
+        );
+
+        assert_eq!(
+            errors.into_iter().map(|e| e.kind()).collect::<Vec<_>>(),
+            vec![SyntaxError::ImportAssertionsNotEnabled]
+        );
+    }
+
+    #[test]
+    fn assert_call_after_import() {
+        let m = test_parser(
+            "import foo from 'foo'\nassert({ type: 'json' });",
+            Syntax::default(),
+            |p| p.parse_module(),
+        );
+
+        assert_eq!(m.body.len(), 2);
+    }
 }
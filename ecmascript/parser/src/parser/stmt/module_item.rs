@@ -69,6 +69,7 @@ impl<'a, I: Tokens> Parser<I> {
                 specifiers: vec![],
                 type_only: false,
                 asserts: None,
+                with: false,
             }))
             .map(ModuleItem::from);
         }
@@ -136,7 +137,17 @@ impl<'a, I: Tokens> Parser<I> {
             src
         };
 
-        let asserts = if self.input.syntax().import_assertions() && eat!("assert") {
+        // `with` is the newer syntax of the proposal (import attributes).
+        let with = is!("with");
+        let asserts = if (is!("assert") || is!("with")) && !self.input.had_line_break_before_cur() {
+            if !self.input.syntax().import_assertions() {
+                self.emit_err(
+                    self.input.cur_span(),
+                    SyntaxError::ImportAssertionsNotEnabled,
+                );
+            }
+            bump!();
+
             match *self.parse_object::<Box<Expr>>()? {
                 Expr::Object(v) => Some(v),
                 _ => unreachable!(),
@@ -153,6 +164,7 @@ impl<'a, I: Tokens> Parser<I> {
             src,
             type_only,
             asserts,
+            with,
         }))
         .map(ModuleItem::from)
     }
@@ -315,6 +315,8 @@ pub(super) trait ExprExt {
 
             Expr::TsConstAssertion(..) => false,
 
+            Expr::Record(..) | Expr::Tuple(..) => false,
+
             Expr::Invalid(..) => false,
         }
     }
@@ -28,6 +28,7 @@ pub enum Token {
     Arrow,
 
     /// '#'
+    #[kind(before_expr)]
     Hash,
 
     /// '@'
@@ -202,6 +203,9 @@ pub enum BinOpToken {
 
     /// `??`
     NullishCoalescing,
+
+    /// `|>`
+    Pipeline,
 }
 
 impl BinOpToken {
@@ -544,6 +548,7 @@ impl From<BinOpToken> for BinaryOp {
             BinOpToken::LogicalAnd => LogicalAnd,
             BinOpToken::Exp => Exp,
             BinOpToken::NullishCoalescing => NullishCoalescing,
+            BinOpToken::Pipeline => Pipeline,
         }
     }
 }
@@ -44,7 +44,7 @@ impl Entry {
 
     /// Add imports.
     /// Returns true if it's replaced.
-    fn add_all(&mut self, src: &str) -> bool {
+    pub(crate) fn add_all(&mut self, src: &str) -> bool {
         if src != "@babel/polyfill" && src != "@swc/polyfill" && src != "core-js" {
             return false;
         }
@@ -61,7 +61,7 @@ impl Entry {
 
     /// Add imports.
     /// Returns true if it's replaced.
-    fn add(&mut self, src: &str) -> bool {
+    pub(crate) fn add(&mut self, src: &str) -> bool {
         let Entry {
             is_any_target,
             target,
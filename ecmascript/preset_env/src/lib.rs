@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use st_map::StaticMap;
 use std::{
     convert::{TryFrom, TryInto},
+    fmt,
     process::Command,
 };
 use swc_atoms::{js_word, JsWord};
@@ -66,11 +67,20 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
 
     // ES2020
 
-    let pass = add!(pass, NullishCoalescing, es2020::nullish_coalescing());
+    let pass = add!(
+        pass,
+        NullishCoalescing,
+        es2020::nullish_coalescing(Default::default())
+    );
     let pass = add!(pass, OptionalChaining, es2020::optional_chaining());
-    let pass = add!(pass, ClassProperties, es2020::class_properties());
+    let pass = add!(
+        pass,
+        ClassProperties,
+        es2020::class_properties(Default::default())
+    );
 
     // ES2018
+    let pass = add!(pass, AsyncGeneratorFunctions, es2018::async_generator());
     let pass = add!(pass, ObjectRestSpread, es2018::object_rest_spread());
     let pass = add!(pass, OptionalCatchBinding, es2018::optional_catch_binding());
 
@@ -83,11 +93,15 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     // ES2015
     let pass = add!(pass, BlockScopedFunctions, es2015::block_scoped_functions());
     let pass = add!(pass, TemplateLiterals, es2015::template_literal(), true);
+    let pass = add!(pass, ObjectSuper, es2015::object_super());
     let pass = add!(pass, Classes, es2015::classes());
     let pass = add!(
         pass,
         Spread,
-        es2015::spread(es2015::spread::Config { loose }),
+        es2015::spread(es2015::spread::Config {
+            loose,
+            ..Default::default()
+        }),
         true
     );
     let pass = add!(pass, FunctionName, es2015::function_name());
@@ -123,11 +137,9 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
 
     // TODO:
     //    Literals,
-    //    ObjectSuper,
     //    DotAllRegex,
     //    UnicodeRegex,
     //    NewTarget,
-    //    AsyncGeneratorFunctions,
     //    UnicodePropertyRegex,
     //    JsonStrings,
     //    NamedCapturingGroupsRegex,
@@ -150,11 +162,12 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
         Polyfills {
             mode: c.mode,
             regenerator: should_enable!(Regenerator, true),
-            corejs: c.core_js.unwrap_or(Version {
+            corejs: check_corejs(c.core_js.unwrap_or(Version {
                 major: 3,
                 minor: 0,
                 patch: 0
-            }),
+            }))
+            .expect("failed to configure core-js"),
             shipped_proposals: c.shipped_proposals,
             targets,
             includes: included_modules,
@@ -163,6 +176,59 @@ pub fn preset_env(global_mark: Mark, c: Config) -> impl Fold {
     )
 }
 
+/// Injects polyfills for features which are not supported by `targets`,
+/// without applying syntax transforms.
+///
+/// In [Mode::Usage], `import "core-js/modules/..."` is prepended to modules
+/// for each used feature, and `require("core-js/modules/...")` to scripts.
+/// Polyfills which are already imported are not injected again.
+///
+/// In [Mode::Entry], `import "core-js"` in modules and `require("core-js")`
+/// in scripts are replaced with the polyfills required by `targets`.
+///
+/// `corejs` selects the major version of core-js. An error is returned if it's
+/// not 2 or 3.
+pub fn inject_polyfills(
+    targets: Versions,
+    mode: Mode,
+    corejs: Version,
+) -> Result<impl Fold, UnsupportedCoreJs> {
+    let corejs = check_corejs(corejs)?;
+
+    Ok(Polyfills {
+        mode: Some(mode),
+        regenerator: transform_data::Feature::Regenerator.should_enable(targets, true),
+        corejs,
+        shipped_proposals: false,
+        targets,
+        includes: Default::default(),
+        excludes: Default::default(),
+    })
+}
+
+/// The version of core-js is not supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedCoreJs(pub Version);
+
+impl fmt::Display for UnsupportedCoreJs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "core-js {}.{}.{} is not supported; use core-js 2 or 3",
+            self.0.major, self.0.minor, self.0.patch
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedCoreJs {}
+
+fn check_corejs(corejs: Version) -> Result<Version, UnsupportedCoreJs> {
+    match corejs.major {
+        2 | 3 => Ok(corejs),
+        _ => Err(UnsupportedCoreJs(corejs)),
+    }
+}
+
 /// A map without allocation.
 #[derive(Debug, Default, Deserialize, Clone, Copy, StaticMap)]
 #[serde(deny_unknown_fields)]
@@ -206,55 +272,49 @@ struct Polyfills {
     excludes: FxHashSet<String>,
 }
 
-impl Fold for Polyfills {
-    fn fold_module(&mut self, mut m: Module) -> Module {
-        let span = m.span;
-
-        let required = match self.mode {
-            None => Default::default(),
-            Some(Mode::Usage) => {
-                let mut r = match self.corejs {
-                    Version { major: 2, .. } => {
-                        let mut v = corejs2::UsageVisitor::new(self.targets);
-                        m.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
-
-                        v.required
-                    }
-                    Version { major: 3, .. } => {
-                        let mut v =
-                            corejs3::UsageVisitor::new(self.targets, self.shipped_proposals);
-                        m.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
-                        v.required
-                    }
-
-                    _ => unimplemented!("corejs version other than 2 / 3"),
-                };
+impl Polyfills {
+    /// Returns the modules required by the features used in `node`.
+    fn usage<T>(&self, node: &T) -> FxHashSet<&'static str>
+    where
+        T: VisitWith<corejs2::UsageVisitor>
+            + VisitWith<corejs3::UsageVisitor>
+            + VisitWith<regenerator::RegeneratorVisitor>,
+    {
+        let mut r = match self.corejs {
+            Version { major: 2, .. } => {
+                let mut v = corejs2::UsageVisitor::new(self.targets);
+                node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+                v.required
+            }
+            Version { major: 3, .. } => {
+                let mut v = corejs3::UsageVisitor::new(self.targets, self.shipped_proposals);
+                node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+                v.required
+            }
 
-                if regenerator::is_required(&m) {
-                    r.insert("regenerator-runtime/runtime".into());
-                }
+            _ => unreachable!("corejs version other than 2 / 3"),
+        };
 
-                r
-            }
-            Some(Mode::Entry) => match self.corejs {
-                Version { major: 2, .. } => {
-                    let mut v = corejs2::Entry::new(self.targets, self.regenerator);
-                    m = m.fold_with(&mut v);
-                    v.imports
-                }
+        if regenerator::is_required(node) {
+            r.insert("regenerator-runtime/runtime");
+        }
 
-                Version { major: 3, .. } => {
-                    let mut v = corejs3::Entry::new(self.targets, self.corejs, !self.regenerator);
-                    m = m.fold_with(&mut v);
-                    v.imports
-                }
+        r
+    }
 
-                _ => unimplemented!("corejs version other than 2 / 3"),
-            },
-        };
-        let required = required
+    /// Converts required modules to import sources.
+    ///
+    /// Sources in `existing` are skipped, so applying the pass multiple times
+    /// does not inject a polyfill twice.
+    fn sources(
+        &self,
+        required: FxHashSet<&'static str>,
+        mut existing: FxHashSet<JsWord>,
+    ) -> Vec<JsWord> {
+        let mut sources = required
             .into_iter()
-            .filter(|s| !self.excludes.contains(&**s))
+            .filter(|s| !self.excludes.contains(*s))
             .map(|s| -> JsWord {
                 if s != "regenerator-runtime/runtime" {
                     format!("core-js/modules/{}", s).into()
@@ -269,46 +329,70 @@ impl Fold for Polyfills {
                     format!("regenerator-runtime/runtime").into()
                 }
             }))
+            .filter(|s| existing.insert(s.clone()))
             .collect::<Vec<_>>();
 
         if cfg!(debug_assertions) {
-            let mut v = required.into_iter().collect::<Vec<_>>();
-            v.sort();
-            prepend_stmts(
-                &mut m.body,
-                v.into_iter().map(|src| {
-                    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                        span,
-                        specifiers: vec![],
-                        src: Str {
-                            span: DUMMY_SP,
-                            value: src,
-                            has_escape: false,
-                        },
-                        type_only: false,
-                        asserts: None,
-                    }))
-                }),
-            );
-        } else {
-            prepend_stmts(
-                &mut m.body,
-                required.into_iter().map(|src| {
-                    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                        span,
-                        specifiers: vec![],
-                        src: Str {
-                            span: DUMMY_SP,
-                            value: src,
-                            has_escape: false,
-                        },
-                        type_only: false,
-                        asserts: None,
-                    }))
-                }),
-            );
+            sources.sort();
         }
 
+        sources
+    }
+}
+
+impl Fold for Polyfills {
+    fn fold_module(&mut self, mut m: Module) -> Module {
+        let span = m.span;
+
+        let required = match self.mode {
+            None => Default::default(),
+            Some(Mode::Usage) => self.usage(&m),
+            Some(Mode::Entry) => match self.corejs {
+                Version { major: 2, .. } => {
+                    let mut v = corejs2::Entry::new(self.targets, self.regenerator);
+                    m = m.fold_with(&mut v);
+                    v.imports
+                }
+
+                Version { major: 3, .. } => {
+                    let mut v = corejs3::Entry::new(self.targets, self.corejs, !self.regenerator);
+                    m = m.fold_with(&mut v);
+                    v.imports
+                }
+
+                _ => unreachable!("corejs version other than 2 / 3"),
+            },
+        };
+        let existing = m
+            .body
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    specifiers, src, ..
+                })) if specifiers.is_empty() => Some(src.value.clone()),
+                _ => None,
+            })
+            .collect();
+        let required = self.sources(required, existing);
+
+        prepend_stmts(
+            &mut m.body,
+            required.into_iter().map(|src| {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span,
+                    specifiers: vec![],
+                    src: Str {
+                        span: DUMMY_SP,
+                        value: src,
+                        has_escape: false,
+                    },
+                    type_only: false,
+                    asserts: None,
+                    with: false,
+                }))
+            }),
+        );
+
         m.body.retain(|item| match item {
             ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
                 src:
@@ -325,8 +409,93 @@ impl Fold for Polyfills {
         m
     }
 
-    fn fold_script(&mut self, _: Script) -> Script {
-        unimplemented!("automatic polyfill for scripts")
+    fn fold_script(&mut self, mut s: Script) -> Script {
+        let span = s.span;
+
+        let required = match self.mode {
+            None => Default::default(),
+            Some(Mode::Usage) => self.usage(&s),
+            Some(Mode::Entry) => match self.corejs {
+                Version { major: 2, .. } => {
+                    let mut v = corejs2::Entry::new(self.targets, self.regenerator);
+                    s.body
+                        .retain(|stmt| !required_module(stmt).map_or(false, |src| v.add_all(src)));
+                    v.imports
+                }
+
+                Version { major: 3, .. } => {
+                    let mut v = corejs3::Entry::new(self.targets, self.corejs, !self.regenerator);
+                    s.body
+                        .retain(|stmt| !required_module(stmt).map_or(false, |src| v.add(src)));
+                    v.imports
+                }
+
+                _ => unreachable!("corejs version other than 2 / 3"),
+            },
+        };
+        let existing = s
+            .body
+            .iter()
+            .filter_map(|stmt| required_module(stmt).cloned())
+            .collect();
+        let required = self.sources(required, existing);
+
+        prepend_stmts(
+            &mut s.body,
+            required.into_iter().map(|src| {
+                Stmt::Expr(ExprStmt {
+                    span,
+                    expr: Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: ExprOrSuper::Expr(Box::new(Expr::Ident(Ident::new(
+                            js_word!("require"),
+                            DUMMY_SP,
+                        )))),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                                span: DUMMY_SP,
+                                value: src,
+                                has_escape: false,
+                            }))),
+                        }],
+                        type_args: Default::default(),
+                    })),
+                })
+            }),
+        );
+
+        s
+    }
+}
+
+/// Returns the source of `require('foo');`.
+fn required_module(stmt: &Stmt) -> Option<&JsWord> {
+    let call = match stmt {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Call(call) => call,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match &call.callee {
+        ExprOrSuper::Expr(callee) => match &**callee {
+            Expr::Ident(Ident {
+                sym: js_word!("require"),
+                ..
+            }) => {}
+            _ => return None,
+        },
+        _ => return None,
+    }
+
+    match &*call.args {
+        [ExprOrSpread { spread: None, expr }] => match &**expr {
+            Expr::Lit(Lit::Str(s)) => Some(&s.value),
+            _ => None,
+        },
+        _ => None,
     }
 }
 
@@ -580,7 +749,12 @@ impl TryFrom<Option<Targets>> for Versions {
 
 #[cfg(test)]
 mod tests {
-    use super::Query;
+    use super::{inject_polyfills, Mode, Query, UnsupportedCoreJs, Version, Versions};
+    use swc_common::{input::StringInput, FileName};
+    use swc_ecma_ast::{Module, Script};
+    use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+    use swc_ecma_parser::{Parser, Syntax};
+    use swc_ecma_visit::{Fold, FoldWith};
 
     #[test]
     fn test_empty() {
@@ -590,4 +764,130 @@ mod tests {
             "empty query should return non-empty result"
         );
     }
+
+    fn es5() -> Versions {
+        Versions {
+            ie: Some("11.0.0".parse().unwrap()),
+            ..Default::default()
+        }
+    }
+
+    fn corejs3() -> Version {
+        Version {
+            major: 3,
+            minor: 0,
+            patch: 0,
+        }
+    }
+
+    fn apply(src: &str, pass: &mut impl Fold) -> String {
+        testing::Tester::new()
+            .print_errors(|cm, handler| {
+                let fm = cm.new_source_file(FileName::Anon, src.into());
+                let mut p = Parser::new(Syntax::default(), StringInput::from(&*fm), None);
+                let m: Module = p
+                    .parse_module()
+                    .map_err(|e| e.into_diagnostic(&handler).emit())?;
+                let m = m.fold_with(pass);
+
+                let mut buf = vec![];
+                {
+                    let mut emitter = Emitter {
+                        cfg: Default::default(),
+                        comments: None,
+                        ident_hook: None,
+                        cm: cm.clone(),
+                        wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                    };
+                    emitter.emit_module(&m).unwrap();
+                }
+
+                Ok(String::from_utf8(buf).unwrap())
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn inject_promise() {
+        let mut pass = inject_polyfills(es5(), Mode::Usage, corejs3()).unwrap();
+
+        let once = apply("new Promise(resolve => resolve());", &mut pass);
+        assert!(
+            once.contains("import \"core-js/modules/es.promise\";"),
+            "promise should be polyfilled: {}",
+            once
+        );
+
+        let twice = apply(&once, &mut pass);
+        assert_eq!(
+            twice.matches("core-js/modules/es.promise\"").count(),
+            1,
+            "polyfills should not be injected twice: {}",
+            twice
+        );
+    }
+
+    #[test]
+    fn unsupported_corejs() {
+        let corejs = Version {
+            major: 4,
+            minor: 0,
+            patch: 0,
+        };
+
+        assert_eq!(
+            inject_polyfills(es5(), Mode::Usage, corejs).err(),
+            Some(UnsupportedCoreJs(corejs))
+        );
+    }
+
+    #[test]
+    fn inject_nothing_for_supported_targets() {
+        let targets = Versions {
+            chrome: Some("80.0.0".parse().unwrap()),
+            ..Default::default()
+        };
+        let mut pass = inject_polyfills(targets, Mode::Usage, corejs3()).unwrap();
+
+        let out = apply("new Promise(resolve => resolve());", &mut pass);
+        assert!(!out.contains("core-js"), "unexpected polyfill: {}", out);
+    }
+
+    #[test]
+    fn entry_script() {
+        let mut pass = inject_polyfills(es5(), Mode::Entry, corejs3()).unwrap();
+
+        let out = testing::Tester::new()
+            .print_errors(|cm, handler| {
+                let fm = cm.new_source_file(FileName::Anon, "require('core-js'); foo();".into());
+                let mut p = Parser::new(Syntax::default(), StringInput::from(&*fm), None);
+                let s: Script = p
+                    .parse_script()
+                    .map_err(|e| e.into_diagnostic(&handler).emit())?;
+                let s = s.fold_with(&mut pass);
+
+                let mut buf = vec![];
+                {
+                    let mut emitter = Emitter {
+                        cfg: Default::default(),
+                        comments: None,
+                        ident_hook: None,
+                        cm: cm.clone(),
+                        wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                    };
+                    emitter.emit_script(&s).unwrap();
+                }
+
+                Ok(String::from_utf8(buf).unwrap())
+            })
+            .unwrap();
+
+        assert!(
+            out.contains("require(\"core-js/modules/es.promise\");"),
+            "entry should be replaced by polyfills: {}",
+            out
+        );
+        assert!(!out.contains("require('core-js')"), "{}", out);
+        assert!(out.contains("foo();"), "{}", out);
+    }
 }
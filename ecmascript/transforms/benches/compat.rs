@@ -87,12 +87,12 @@ fn common_reserved_word(b: &mut Bencher) {
 
 #[bench]
 fn es2020(b: &mut Bencher) {
-    run(b, || compat::es2020());
+    run(b, || compat::es2020(Default::default()));
 }
 
 #[bench]
 fn es2020_nullish_coalescing(b: &mut Bencher) {
-    run(b, || compat::es2020::nullish_coalescing());
+    run(b, || compat::es2020::nullish_coalescing(Default::default()));
 }
 
 #[bench]
@@ -107,7 +107,7 @@ fn es2020_typescript_class_properties(b: &mut Bencher) {
 
 #[bench]
 fn es2020_class_properties(b: &mut Bencher) {
-    run(b, || compat::es2020::class_properties());
+    run(b, || compat::es2020::class_properties(Default::default()));
 }
 
 #[bench]
@@ -221,6 +221,16 @@ fn es2015_spread(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn es2015_spread_array_fast_path(b: &mut Bencher) {
+    run(b, || {
+        compat::es2015::spread(compat::es2015::spread::Config {
+            array_fast_path: true,
+            ..Default::default()
+        })
+    });
+}
+
 #[bench]
 fn es2015_sticky_regex(b: &mut Bencher) {
     run(b, || compat::es2015::sticky_regex());
@@ -240,7 +250,7 @@ fn es3(b: &mut Bencher) {
 fn full_es2016(b: &mut Bencher) {
     run(b, || {
         chain!(
-            compat::es2020(),
+            compat::es2020(Default::default()),
             compat::es2018(),
             compat::es2017(),
             compat::es2016(),
@@ -251,11 +261,17 @@ fn full_es2016(b: &mut Bencher) {
 #[bench]
 fn full_es2017(b: &mut Bencher) {
     run(b, || {
-        chain!(compat::es2020(), compat::es2018(), compat::es2017(),)
+        chain!(
+            compat::es2020(Default::default()),
+            compat::es2018(),
+            compat::es2017(),
+        )
     });
 }
 
 #[bench]
 fn full_es2018(b: &mut Bencher) {
-    run(b, || chain!(compat::es2020(), compat::es2018(),));
+    run(b, || {
+        chain!(compat::es2020(Default::default()), compat::es2018(),)
+    });
 }
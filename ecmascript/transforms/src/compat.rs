@@ -2,6 +2,7 @@
 
 pub use self::{
     es2015::es2015, es2016::es2016, es2017::es2017, es2018::es2018, es2020::es2020, es3::es3,
+    polyfill_builtins::polyfill_builtins, symbol_iterator::symbol_iterator,
 };
 
 pub mod es2015;
@@ -10,4 +11,6 @@ pub mod es2017;
 pub mod es2018;
 pub mod es2020;
 pub mod es3;
+mod polyfill_builtins;
 pub mod reserved_words;
+mod symbol_iterator;
@@ -2,9 +2,10 @@ pub use self::{
     arrow::arrow, block_scoped_fn::block_scoped_functions, block_scoping::block_scoping,
     classes::classes, computed_props::computed_properties, destructuring::destructuring,
     duplicate_keys::duplicate_keys, for_of::for_of, function_name::function_name,
-    instanceof::instance_of, parameters::parameters, regenerator::regenerator,
-    shorthand_property::shorthand, spread::spread, sticky_regex::sticky_regex,
-    template_literal::template_literal, typeof_symbol::typeof_symbol,
+    instanceof::instance_of, method_shorthand::method_shorthand, object_super::object_super,
+    parameters::parameters, regenerator::regenerator, shorthand_property::shorthand,
+    spread::spread, sticky_regex::sticky_regex, template_literal::template_literal,
+    typeof_symbol::typeof_symbol,
 };
 use serde::Deserialize;
 use swc_common::{chain, Mark};
@@ -20,6 +21,8 @@ mod duplicate_keys;
 pub mod for_of;
 mod function_name;
 mod instanceof;
+mod method_shorthand;
+mod object_super;
 mod parameters;
 mod regenerator;
 mod shorthand_property;
@@ -44,6 +47,8 @@ pub fn es2015(global_mark: Mark, c: Config) -> impl Fold {
     chain!(
         block_scoped_functions(),
         template_literal(),
+        // Should come before classes, which doesn't handle object literals.
+        object_super(),
         classes(),
         spread(c.spread),
         function_name(),
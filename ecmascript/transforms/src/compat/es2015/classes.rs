@@ -12,7 +12,7 @@ use crate::util::{
     ModuleItemLike, StmtLike,
 };
 use fxhash::FxBuildHasher;
-use std::iter;
+use std::{iter, mem::replace};
 use swc_common::{Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
@@ -371,7 +371,8 @@ impl Classes {
         class: Class,
     ) -> Vec<Stmt> {
         let is_named = class_name.is_some();
-        let class_name = class_name.unwrap_or_else(|| quote_ident!("_class"));
+        // The name of an anonymous class must not shadow bindings used in it.
+        let class_name = class_name.unwrap_or_else(|| private_ident!("_class"));
         let mut stmts = vec![];
 
         let mut priv_methods = vec![];
@@ -746,7 +747,36 @@ impl Classes {
 
         let (mut props, mut static_props) = (IndexMap::default(), IndexMap::default());
 
-        for m in methods {
+        // Instance methods are defined before static methods, so computed keys
+        // are evaluated in the source order only if no static computed key
+        // precedes an instance one. Otherwise keys are evaluated in advance.
+        let mut seen_static_computed = false;
+        let alias_keys = methods.iter().any(|m| match m.key {
+            PropName::Computed(..) if m.is_static => {
+                seen_static_computed = true;
+                false
+            }
+            PropName::Computed(..) => seen_static_computed,
+            _ => false,
+        });
+        let mut key_vars = vec![];
+
+        for mut m in methods {
+            if alias_keys {
+                if let PropName::Computed(c) = &mut m.key {
+                    let (ident, aliased) = alias_if_required(&c.expr, "key");
+                    if aliased {
+                        let expr = replace(&mut c.expr, Box::new(Expr::Ident(ident.clone())));
+                        key_vars.push(VarDeclarator {
+                            span: DUMMY_SP,
+                            name: Pat::Ident(ident),
+                            init: Some(expr),
+                            definite: false,
+                        });
+                    }
+                }
+            }
+
             let key = HashKey::from(&m.key);
             let key_prop = Box::new(mk_key_prop(&m.key));
             let computed = match m.key {
@@ -834,7 +864,17 @@ impl Classes {
         if props.is_empty() && static_props.is_empty() {
             return vec![];
         }
-        vec![mk_create_class_call(
+
+        let mut stmts = vec![];
+        if !key_vars.is_empty() {
+            stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: key_vars,
+            })));
+        }
+        stmts.push(mk_create_class_call(
             class_name,
             mk_arg_obj_for_create_class(props),
             if static_props.is_empty() {
@@ -842,7 +882,8 @@ impl Classes {
             } else {
                 Some(mk_arg_obj_for_create_class(static_props))
             },
-        )]
+        ));
+        stmts
     }
 }
 
@@ -1,5 +1,5 @@
 use super::get_prototype_of;
-use crate::util::{alias_ident_for, is_rest_arguments, ExprFactory};
+use crate::util::{alias_ident_for, is_rest_arguments, memoize_member, ExprFactory};
 use std::iter;
 use swc_atoms::js_word;
 use swc_common::{Mark, Span, DUMMY_SP};
@@ -107,6 +107,7 @@ impl<'a> Fold for SuperCalleeFolder<'a> {
                             span: super_token, ..
                         }),
                     prop,
+                    computed,
                     ..
                 }) => {
                     let op = match op {
@@ -118,6 +119,7 @@ impl<'a> Fold for SuperCalleeFolder<'a> {
                         super_token,
                         true,
                         prop,
+                        computed,
                         op,
                         Box::new(Expr::Lit(Lit::Num(Number {
                             span: DUMMY_SP,
@@ -149,9 +151,17 @@ impl<'a> Fold for SuperCalleeFolder<'a> {
                                     span: super_token, ..
                                 }),
                             prop,
+                            computed,
                             ..
                         }) => {
-                            return self.super_to_set_call(super_token, false, prop, op, right);
+                            return self.super_to_set_call(
+                                super_token,
+                                false,
+                                prop,
+                                computed,
+                                op,
+                                right,
+                            );
                         }
                         _ => {
                             left = PatOrExpr::Expr(expr);
@@ -167,9 +177,17 @@ impl<'a> Fold for SuperCalleeFolder<'a> {
                                         span: super_token, ..
                                     }),
                                 prop,
+                                computed,
                                 ..
                             }) => {
-                                return self.super_to_set_call(super_token, false, prop, op, right);
+                                return self.super_to_set_call(
+                                    super_token,
+                                    false,
+                                    prop,
+                                    computed,
+                                    op,
+                                    right,
+                                );
                             }
                             _ => {
                                 left = PatOrExpr::Pat(Box::new(Pat::Expr(expr)));
@@ -262,34 +280,35 @@ impl<'a> SuperCalleeFolder<'a> {
         super_token: Span,
         is_update: bool,
         prop: Box<Expr>,
+        computed: bool,
         op: AssignOp,
         rhs: Box<Expr>,
     ) -> Expr {
         self.inject_set = true;
 
-        let mut ref_ident = alias_ident_for(&rhs, "_ref");
-        ref_ident.span = ref_ident.span.apply_mark(Mark::fresh(Mark::root()));
+        let member = MemberExpr {
+            span: DUMMY_SP,
+            obj: ExprOrSuper::Super(Super { span: super_token }),
+            prop,
+            computed,
+        };
+        // The key is read by `_get` and `_set`, so it's memoized if the value
+        // is read.
+        let (member, member_ref) = match op {
+            op!("=") => (member.clone(), member),
+            _ => memoize_member(member, self.vars),
+        };
 
         let mut update_ident = alias_ident_for(&rhs, "_superRef");
         update_ident.span = update_ident.span.apply_mark(Mark::fresh(Mark::root()));
 
-        if op != op!("=") {
-            // Memoize
+        if op != op!("=") && is_update {
             self.vars.push(VarDeclarator {
                 span: DUMMY_SP,
-                name: Pat::Ident(ref_ident.clone()),
+                name: Pat::Ident(update_ident.clone()),
                 init: None,
                 definite: false,
             });
-
-            if is_update {
-                self.vars.push(VarDeclarator {
-                    span: DUMMY_SP,
-                    name: Pat::Ident(update_ident.clone()),
-                    init: None,
-                    definite: false,
-                });
-            }
         }
 
         let proto_arg = get_prototype_of(
@@ -299,37 +318,20 @@ impl<'a> SuperCalleeFolder<'a> {
         )
         .as_arg();
 
-        let prop_arg = match *prop {
-            Expr::Ident(Ident {
-                sym: ref value,
+        let prop_arg = match *member.prop {
+            Expr::Ident(Ident { sym, span, .. }) if !computed => Expr::Lit(Lit::Str(Str {
                 span,
-                ..
-            }) => Expr::Lit(Lit::Str(Str {
-                span,
-                value: value.clone(),
+                value: sym,
                 has_escape: false,
             })),
-            ref e => e.clone(),
-        };
-        let prop_arg = match op {
-            op!("=") => prop_arg.as_arg(),
-            _ => AssignExpr {
-                span: DUMMY_SP,
-                left: PatOrExpr::Pat(Box::new(Pat::Ident(ref_ident.clone()))),
-                op: op!("="),
-                right: prop,
-            }
-            .as_arg(),
-        };
+            e => e,
+        }
+        .as_arg();
 
         let rhs_arg = match op {
             op!("=") => rhs.as_arg(),
             _ => {
-                let left = Box::new(self.super_to_get_call(
-                    super_token,
-                    Box::new(Expr::Ident(ref_ident)),
-                    true,
-                ));
+                let left = Box::new(self.super_to_get_call(super_token, member_ref.prop, computed));
                 let left = if is_update {
                     Box::new(
                         AssignExpr {
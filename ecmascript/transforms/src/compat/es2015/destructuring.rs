@@ -726,7 +726,7 @@ impl Fold for AssignFolder {
                                         _ => false,
                                     };
 
-                                    exprs.push(Box::new(Expr::Assign(AssignExpr {
+                                    let expr = Expr::Assign(AssignExpr {
                                         span,
                                         left: PatOrExpr::Pat(value),
                                         op: op!("="),
@@ -735,7 +735,13 @@ impl Fold for AssignFolder {
                                             Box::new(prop_name_to_expr(key)),
                                             computed,
                                         )),
-                                    })));
+                                    })
+                                    .fold_with(self);
+
+                                    match expr {
+                                        Expr::Seq(seq) => exprs.extend(seq.exprs),
+                                        _ => exprs.push(Box::new(expr)),
+                                    }
                                 }
                                 ObjectPatProp::Assign(AssignPatProp { key, value, .. }) => {
                                     let computed = false;
@@ -798,7 +804,38 @@ impl Fold for AssignFolder {
                             exprs,
                         })
                     }
-                    Pat::Assign(pat) => unimplemented!("assignment pattern {:?}", pat),
+                    // `({ a: b = 1 } = obj)`
+                    Pat::Assign(AssignPat {
+                        span,
+                        left,
+                        right: def_value,
+                        ..
+                    }) => {
+                        // Only `undefined` triggers the default value, so the value is
+                        // stored in a temporary variable instead of using `||` or `??`.
+                        let ref_ident = make_ref_ident(self.c, &mut self.vars, None);
+
+                        Expr::Seq(SeqExpr {
+                            span: DUMMY_SP,
+                            exprs: vec![
+                                Box::new(Expr::Assign(AssignExpr {
+                                    span: DUMMY_SP,
+                                    left: PatOrExpr::Pat(Box::new(Pat::Ident(ref_ident.clone()))),
+                                    op: op!("="),
+                                    right,
+                                })),
+                                Box::new(
+                                    Expr::Assign(AssignExpr {
+                                        span,
+                                        left: PatOrExpr::Pat(left),
+                                        op: op!("="),
+                                        right: Box::new(make_cond_expr(ref_ident, def_value)),
+                                    })
+                                    .fold_with(self),
+                                ),
+                            ],
+                        })
+                    }
                     Pat::Rest(pat) => unimplemented!("rest pattern {:?}", pat),
 
                     Pat::Invalid(..) => unreachable!(),
@@ -1053,6 +1090,8 @@ fn can_be_null(e: &Expr) -> bool {
         Expr::Array(..)
         | Expr::Arrow(..)
         | Expr::Object(..)
+        | Expr::Record(..)
+        | Expr::Tuple(..)
         | Expr::Fn(..)
         | Expr::Class(..)
         | Expr::Tpl(..) => false,
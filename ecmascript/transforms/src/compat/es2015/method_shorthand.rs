@@ -0,0 +1,217 @@
+use crate::util::is_valid_ident;
+use std::mem;
+use swc_atoms::{js_word, JsWord};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+/// Converts object methods to properties, naming the function after the key
+/// so that `.name` is preserved.
+///
+/// ```js
+/// var o = {
+///     foo() {},
+///     'bar'() {},
+///     [baz]() {},
+///     get x() {}
+/// };
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// var o = {
+///     foo: function foo() {},
+///     'bar': function bar() {},
+///     [baz]: function () {},
+///     get x() {}
+/// };
+/// ```
+///
+/// Accessors are not changed. Computed keys are kept as-is, so
+/// `computed_properties` should be applied to define them.
+///
+/// The function is not named if the key is not a valid binding name, or if
+/// the name would shadow a reference in the body. Methods using `super` are
+/// not converted.
+pub fn method_shorthand() -> impl Fold {
+    as_folder(MethodShorthand)
+}
+
+struct MethodShorthand;
+
+impl VisitMut for MethodShorthand {
+    noop_visit_mut_type!();
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        prop.visit_mut_children_with(self);
+
+        match prop {
+            Prop::Method(method) if !uses_super(&method.function) => {}
+            _ => return,
+        }
+        let MethodProp { key, function } =
+            match mem::replace(prop, Prop::Shorthand(Ident::new(js_word!(""), DUMMY_SP))) {
+                Prop::Method(method) => method,
+                _ => unreachable!(),
+            };
+
+        let ident = match &key {
+            PropName::Ident(Ident { sym, span, .. })
+            | PropName::Str(Str {
+                value: sym, span, ..
+            }) if can_name(sym, &function) => Some(Ident::new(sym.clone(), *span)),
+            _ => None,
+        };
+
+        *prop = Prop::KeyValue(KeyValueProp {
+            key,
+            value: Box::new(Expr::Fn(FnExpr { ident, function })),
+        });
+    }
+}
+
+fn can_name(sym: &JsWord, function: &Function) -> bool {
+    if !is_valid_ident(sym) || sym.is_reserved_for_es3() {
+        return false;
+    }
+    match *sym {
+        js_word!("arguments") | js_word!("eval") | js_word!("await") | js_word!("yield") => {
+            return false
+        }
+        _ => {}
+    }
+
+    let mut v = Finder {
+        sym: Some(sym),
+        found: false,
+    };
+    function.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    !v.found
+}
+
+fn uses_super(function: &Function) -> bool {
+    let mut v = Finder {
+        sym: None,
+        found: false,
+    };
+    function.visit_children_with(&mut v);
+    v.found
+}
+
+/// Finds `super`, or references to `sym` if it's set.
+struct Finder<'a> {
+    sym: Option<&'a JsWord>,
+    found: bool,
+}
+
+impl Visit for Finder<'_> {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        if self.sym == Some(&i.sym) {
+            self.found = true;
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_super(&mut self, _: &Super, _: &dyn Node) {
+        if self.sym.is_none() {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, f: &Function, _: &dyn Node) {
+        // `super` in a nested function belongs to that function.
+        if self.sym.is_some() {
+            f.visit_children_with(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::method_shorthand;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| method_shorthand(),
+        named,
+        "
+var o = {
+    foo() {},
+    'bar'(a) { return a; },
+    async baz() {},
+    *gen() {},
+    1() {},
+    'a-b'() {},
+    [qux]() {},
+    get x() { return 1; },
+    set x(v) {}
+};
+",
+        "
+var o = {
+    foo: function foo() {},
+    'bar': function bar(a) { return a; },
+    baz: async function baz() {},
+    gen: function* gen() {},
+    1: function () {},
+    'a-b': function () {},
+    [qux]: function () {},
+    get x() { return 1; },
+    set x(v) {}
+};
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| method_shorthand(),
+        shadowing,
+        "
+var o = {
+    foo() { return foo; },
+    delete() {},
+    bar() { return super.bar(); },
+    baz() { return () => super.x; },
+    qux() { return { quux() { return super.x; } }; }
+};
+",
+        "
+var o = {
+    foo: function () { return foo; },
+    delete: function () {},
+    bar() { return super.bar(); },
+    baz() { return () => super.x; },
+    qux: function qux() { return { quux() { return super.x; } }; }
+};
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| method_shorthand(),
+        name_exec,
+        "
+const o = {
+    foo() { return 1; },
+    'bar'() { return 2; },
+};
+
+expect(o.foo.name).toBe('foo');
+expect(o.bar.name).toBe('bar');
+expect(o.foo()).toBe(1);
+"
+    );
+}
@@ -0,0 +1,498 @@
+use crate::util::{memoize_member, ExprFactory, StmtLike};
+use std::{iter, mem::replace};
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-transform-object-super`
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const obj = {
+///     foo() {
+///         return super.foo() + super.bar;
+///     },
+/// };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// let _obj;
+/// const obj = _obj = {
+///     foo() {
+///         return _get(_getPrototypeOf(_obj), "foo", this).call(this) +
+///             _get(_getPrototypeOf(_obj), "bar", this);
+///     },
+/// };
+/// ```
+///
+/// The home object is stored in a block-scoped variable declared next to the
+/// object literal, so each evaluation of the literal gets its own binding.
+///
+/// `super` in class methods is handled by [classes](super::classes).
+pub fn object_super() -> impl Fold {
+    ObjectSuper::default()
+}
+
+#[derive(Default)]
+struct ObjectSuper {
+    vars: Vec<VarDeclarator>,
+}
+
+impl ObjectSuper {
+    fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
+    where
+        T: FoldWith<Self> + StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(decl(replace(&mut self.vars, vec![]))));
+            }
+
+            buf.push(stmt);
+        }
+
+        buf
+    }
+}
+
+fn decl(decls: Vec<VarDeclarator>) -> Stmt {
+    Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Let,
+        declare: false,
+        decls,
+    }))
+}
+
+fn declarator(name: Ident) -> VarDeclarator {
+    VarDeclarator {
+        span: DUMMY_SP,
+        name: Pat::Ident(name),
+        init: None,
+        definite: false,
+    }
+}
+
+impl Fold for ObjectSuper {
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    /// The home object of an object literal in the expression body of an arrow
+    /// function is declared in the body, as the arrow may be called multiple
+    /// times.
+    fn fold_arrow_expr(&mut self, f: ArrowExpr) -> ArrowExpr {
+        let old = replace(&mut self.vars, vec![]);
+        let mut f = f.fold_children_with(self);
+        let vars = replace(&mut self.vars, old);
+
+        if vars.is_empty() {
+            return f;
+        }
+
+        f.body = match f.body {
+            BlockStmtOrExpr::Expr(expr) => BlockStmtOrExpr::BlockStmt(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![
+                    decl(vars),
+                    Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(expr),
+                    }),
+                ],
+            }),
+            // Default values of parameters can't see variables in the body.
+            body => {
+                self.vars.extend(vars);
+                body
+            }
+        };
+
+        f
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        let mut obj = match e {
+            Expr::Object(obj) => obj,
+            _ => return e,
+        };
+
+        let home = private_ident!("_obj");
+        let mut replacer = SuperReplacer {
+            home: &home,
+            vars: vec![],
+            replaced: false,
+        };
+
+        obj.props = obj.props.fold_with(&mut replacer);
+        if !replacer.replaced {
+            return Expr::Object(obj);
+        }
+
+        self.vars.push(declarator(home.clone()));
+
+        Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(home))),
+            right: Box::new(Expr::Object(obj)),
+        })
+    }
+}
+
+/// Replaces `super` in the methods of an object literal.
+///
+/// Object literals nested in the methods are already processed.
+struct SuperReplacer<'a> {
+    home: &'a Ident,
+    /// Variables which should be declared in the current method.
+    vars: Vec<VarDeclarator>,
+    replaced: bool,
+}
+
+impl SuperReplacer<'_> {
+    fn fold_method_body(&mut self, body: Option<BlockStmt>) -> Option<BlockStmt> {
+        let old = replace(&mut self.vars, vec![]);
+        let mut body = body.fold_with(self);
+        let vars = replace(&mut self.vars, old);
+
+        if let Some(body) = &mut body {
+            if !vars.is_empty() {
+                body.stmts.insert(0, decl(vars));
+            }
+        }
+
+        body
+    }
+
+    /// `_getPrototypeOf(_obj)`
+    fn proto(&mut self) -> ExprOrSpread {
+        self.replaced = true;
+
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(get_prototype_of, "getPrototypeOf"),
+            args: vec![self.home.clone().as_arg()],
+            type_args: Default::default(),
+        })
+        .as_arg()
+    }
+
+    /// `_get(_getPrototypeOf(_obj), key, this)`
+    fn get(&mut self, span: Span, key: Expr) -> Expr {
+        Expr::Call(CallExpr {
+            span,
+            callee: helper!(get, "get"),
+            args: vec![
+                self.proto(),
+                key.as_arg(),
+                ThisExpr { span: DUMMY_SP }.as_arg(),
+            ],
+            type_args: Default::default(),
+        })
+    }
+
+    /// `_set(_getPrototypeOf(_obj), key, value, this, true)`
+    fn set(&mut self, span: Span, key: Expr, value: Expr) -> Expr {
+        Expr::Call(CallExpr {
+            span,
+            callee: helper!(set, "set"),
+            args: vec![
+                self.proto(),
+                key.as_arg(),
+                value.as_arg(),
+                ThisExpr { span: DUMMY_SP }.as_arg(),
+                Expr::from(true).as_arg(),
+            ],
+            type_args: Default::default(),
+        })
+    }
+
+    /// Returns the key of `super.prop` and its second use. The key is stored in
+    /// a variable if it may have side effects.
+    fn memoized_key(&mut self, prop: Box<Expr>, computed: bool) -> (Expr, Expr) {
+        if !computed {
+            let key = self.key(prop, computed);
+            return (key.clone(), key);
+        }
+
+        let member = MemberExpr {
+            span: DUMMY_SP,
+            obj: ExprOrSuper::Super(Super { span: DUMMY_SP }),
+            prop: prop.fold_with(self),
+            computed,
+        };
+        let (first, rest) = memoize_member(member, &mut self.vars);
+
+        (*first.prop, *rest.prop)
+    }
+
+    fn key(&mut self, prop: Box<Expr>, computed: bool) -> Expr {
+        match *prop {
+            Expr::Ident(Ident { sym, span, .. }) if !computed => Expr::Lit(Lit::Str(Str {
+                span,
+                value: sym,
+                has_escape: false,
+            })),
+            prop => prop.fold_with(self),
+        }
+    }
+
+    fn fold_assign(
+        &mut self,
+        span: Span,
+        op: AssignOp,
+        prop: Box<Expr>,
+        computed: bool,
+        right: Box<Expr>,
+    ) -> Expr {
+        let right = right.fold_with(self);
+
+        if op == op!("=") {
+            let key = self.key(prop, computed);
+            return self.set(span, key, *right);
+        }
+
+        let (key, key2) = self.memoized_key(prop, computed);
+        match op {
+            // `super.x ||= v` is `_get(...) || _set(...)`
+            op!("&&=") | op!("||=") | op!("??=") => {
+                let op = match op {
+                    op!("&&=") => op!("&&"),
+                    op!("||=") => op!("||"),
+                    _ => op!("??"),
+                };
+                let left = self.get(DUMMY_SP, key);
+                let right = self.set(DUMMY_SP, key2, *right);
+
+                Expr::Bin(BinExpr {
+                    span,
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            }
+            _ => {
+                let value = Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: to_bin_op(op),
+                    left: Box::new(self.get(DUMMY_SP, key2)),
+                    right,
+                });
+
+                self.set(span, key, value)
+            }
+        }
+    }
+
+    fn fold_update(
+        &mut self,
+        span: Span,
+        op: UpdateOp,
+        prefix: bool,
+        prop: Box<Expr>,
+        computed: bool,
+    ) -> Expr {
+        let (key, key2) = self.memoized_key(prop, computed);
+
+        // +_get(...)
+        let mut old = Expr::Unary(UnaryExpr {
+            span: DUMMY_SP,
+            op: op!(unary, "+"),
+            arg: Box::new(self.get(DUMMY_SP, key2)),
+        });
+        let old_value = if prefix {
+            None
+        } else {
+            let tmp = private_ident!("_old");
+            self.vars.push(declarator(tmp.clone()));
+            old = Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(tmp.clone()))),
+                right: Box::new(old),
+            })
+            .wrap_with_paren();
+            Some(tmp)
+        };
+
+        let value = Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: match op {
+                op!("++") => op!(bin, "+"),
+                op!("--") => op!(bin, "-"),
+            },
+            left: Box::new(old),
+            right: Box::new(Expr::from(1.0)),
+        });
+        let set = self.set(span, key, value);
+
+        match old_value {
+            Some(old_value) => Expr::Seq(SeqExpr {
+                span,
+                exprs: vec![Box::new(set), Box::new(Expr::Ident(old_value))],
+            }),
+            None => set,
+        }
+    }
+}
+
+impl Fold for SuperReplacer<'_> {
+    noop_fold_type!();
+
+    /// `super` in classes is handled by the class transform.
+    fn fold_class(&mut self, c: Class) -> Class {
+        c
+    }
+
+    fn fold_function(&mut self, mut f: Function) -> Function {
+        f.params = f.params.fold_with(self);
+        f.body = self.fold_method_body(f.body);
+        f
+    }
+
+    fn fold_getter_prop(&mut self, mut p: GetterProp) -> GetterProp {
+        p.key = p.key.fold_with(self);
+        p.body = self.fold_method_body(p.body);
+        p
+    }
+
+    fn fold_setter_prop(&mut self, mut p: SetterProp) -> SetterProp {
+        p.key = p.key.fold_with(self);
+        p.param = p.param.fold_with(self);
+        p.body = self.fold_method_body(p.body);
+        p
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        match e {
+            Expr::Member(MemberExpr {
+                span,
+                obj: ExprOrSuper::Super(..),
+                prop,
+                computed,
+            }) => {
+                let key = self.key(prop, computed);
+                self.get(span, key)
+            }
+
+            // super.foo(args) => _get(...).call(this, args)
+            Expr::Call(CallExpr {
+                span,
+                callee: ExprOrSuper::Expr(callee),
+                args,
+                type_args,
+            }) if is_super_member(&callee) => {
+                let callee = callee.fold_with(self);
+                let args = args.fold_with(self);
+
+                Expr::Call(CallExpr {
+                    span,
+                    callee: (*callee).make_member(quote_ident!("call")).as_callee(),
+                    args: iter::once(ThisExpr { span: DUMMY_SP }.as_arg())
+                        .chain(args)
+                        .collect(),
+                    type_args,
+                })
+            }
+
+            Expr::Assign(AssignExpr {
+                span,
+                op,
+                left,
+                right,
+            }) => {
+                let member = match left {
+                    PatOrExpr::Expr(expr) if is_super_member(&expr) => expr,
+                    PatOrExpr::Pat(pat) => match *pat {
+                        Pat::Expr(expr) if is_super_member(&expr) => expr,
+                        pat => {
+                            return Expr::Assign(AssignExpr {
+                                span,
+                                op,
+                                left: PatOrExpr::Pat(Box::new(pat)),
+                                right,
+                            })
+                            .fold_children_with(self)
+                        }
+                    },
+                    left => {
+                        return Expr::Assign(AssignExpr {
+                            span,
+                            op,
+                            left,
+                            right,
+                        })
+                        .fold_children_with(self)
+                    }
+                };
+
+                match *member {
+                    Expr::Member(MemberExpr { prop, computed, .. }) => {
+                        self.fold_assign(span, op, prop, computed, right)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            Expr::Update(UpdateExpr {
+                span,
+                op,
+                prefix,
+                arg,
+            }) if is_super_member(&arg) => match *arg {
+                Expr::Member(MemberExpr { prop, computed, .. }) => {
+                    self.fold_update(span, op, prefix, prop, computed)
+                }
+                _ => unreachable!(),
+            },
+
+            _ => e.fold_children_with(self),
+        }
+    }
+}
+
+fn is_super_member(e: &Expr) -> bool {
+    match e {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Super(..),
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+fn to_bin_op(op: AssignOp) -> BinaryOp {
+    match op {
+        op!("+=") => op!(bin, "+"),
+        op!("-=") => op!(bin, "-"),
+        op!("*=") => op!("*"),
+        op!("/=") => op!("/"),
+        op!("%=") => op!("%"),
+        op!("<<=") => op!("<<"),
+        op!(">>=") => op!(">>"),
+        op!(">>>=") => op!(">>>"),
+        op!("|=") => op!("|"),
+        op!("&=") => op!("&"),
+        op!("^=") => op!("^"),
+        op!("**=") => op!("**"),
+        _ => unreachable!("to_bin_op({:?})", op),
+    }
+}
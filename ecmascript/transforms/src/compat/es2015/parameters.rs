@@ -1,8 +1,9 @@
 use crate::util::{prepend_stmts, ExprFactory};
 use arrayvec::ArrayVec;
+use swc_atoms::js_word;
 use swc_common::{Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
 
 pub fn parameters() -> impl 'static + Fold {
     Params
@@ -16,6 +17,15 @@ impl Params {
     fn fold_fn_like(&mut self, ps: Vec<Param>, body: BlockStmt) -> (Vec<Param>, BlockStmt) {
         let body = validate!(body);
 
+        // A function with non-simple parameters has an unmapped `arguments`
+        // object, so assigning to a parameter does not modify `arguments`.
+        // If `arguments` is used, simple parameters are also moved into the
+        // body to preserve it.
+        let unmapped_arguments = ps.iter().any(|param| match param.pat {
+            Pat::Ident(..) => false,
+            _ => true,
+        }) && uses_arguments(&ps, &body);
+
         let mut params = vec![];
         let mut decls = vec![];
         let mut unpack_rest = None;
@@ -25,8 +35,8 @@ impl Params {
             let span = param.span();
 
             match param.pat {
-                Pat::Ident(..) => params.push(param),
-                Pat::Array(..) | Pat::Object(..) => {
+                Pat::Ident(..) if !unmapped_arguments => params.push(param),
+                Pat::Ident(..) | Pat::Array(..) | Pat::Object(..) => {
                     let binding = private_ident!(span, "param");
 
                     params.push(Param {
@@ -251,3 +261,49 @@ impl Fold for Params {
 
     impl_fold_fn!();
 }
+
+fn uses_arguments(params: &[Param], body: &BlockStmt) -> bool {
+    let mut v = ArgumentsFinder { found: false };
+    for param in params {
+        param.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    }
+    body.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.found
+}
+
+/// Finds `arguments` which refers to the `arguments` of the current function.
+struct ArgumentsFinder {
+    found: bool,
+}
+
+impl Visit for ArgumentsFinder {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        if i.sym == js_word!("arguments") {
+            self.found = true;
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(n) = n {
+            n.visit_with(n as _, self);
+        }
+    }
+
+    /// `arguments` in a nested function refers to its own arguments.
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+    fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+}
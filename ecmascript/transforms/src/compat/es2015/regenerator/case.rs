@@ -272,6 +272,33 @@ impl CaseHandler<'_> {
         }
     }
 
+    /// Explodes the values of the properties of an object or a record.
+    fn explode_props(&mut self, props: Vec<PropOrSpread>) -> Vec<PropOrSpread> {
+        props
+            .into_iter()
+            .map(|prop| match prop {
+                PropOrSpread::Prop(p) => PropOrSpread::Prop(Box::new(match *p {
+                    p @ Prop::Method(_)
+                    | p @ Prop::Setter(_)
+                    | p @ Prop::Getter(_)
+                    | p @ Prop::Shorthand(_) => p,
+                    Prop::KeyValue(p) => Prop::KeyValue(KeyValueProp {
+                        value: p.value.map(|e| self.explode_expr(e, false)),
+                        ..p
+                    }),
+                    Prop::Assign(p) => Prop::Assign(AssignProp {
+                        value: p.value.map(|e| self.explode_expr(e, false)),
+                        ..p
+                    }),
+                })),
+                PropOrSpread::Spread(s) => PropOrSpread::Spread(SpreadElement {
+                    expr: s.expr.map(|e| self.explode_expr(e, false)),
+                    ..s
+                }),
+            })
+            .collect()
+    }
+
     fn explode_expr(&mut self, e: Expr, ignore_result: bool) -> Expr {
         let span = e.span();
 
@@ -489,33 +516,7 @@ impl CaseHandler<'_> {
             }
 
             Expr::Object(obj) => {
-                let props = obj
-                    .props
-                    .into_iter()
-                    .map(|prop| {
-                        //
-                        match prop {
-                            PropOrSpread::Prop(p) => {
-                                let p = *p;
-                                PropOrSpread::Prop(Box::new(match p {
-                                    Prop::Method(_)
-                                    | Prop::Setter(_)
-                                    | Prop::Getter(_)
-                                    | Prop::Shorthand(_) => p,
-                                    Prop::KeyValue(p) => Prop::KeyValue(KeyValueProp {
-                                        value: p.value.map(|e| self.explode_expr(e, false)),
-                                        ..p
-                                    }),
-                                    Prop::Assign(p) => Prop::Assign(AssignProp {
-                                        value: p.value.map(|e| self.explode_expr(e, false)),
-                                        ..p
-                                    }),
-                                }))
-                            }
-                            _ => prop,
-                        }
-                    })
-                    .collect();
+                let props = self.explode_props(obj.props);
 
                 let expr = Expr::Object(ObjectLit { props, ..obj });
                 finish!(expr)
@@ -532,6 +533,22 @@ impl CaseHandler<'_> {
                 ArrayLit { elems, ..arr }.into()
             }
 
+            Expr::Record(record) => {
+                let props = self.explode_props(record.props);
+
+                let expr = Expr::Record(RecordLit { props, ..record });
+                finish!(expr)
+            }
+
+            Expr::Tuple(tuple) => {
+                let elems = tuple.elems.move_map(|elem| ExprOrSpread {
+                    expr: elem.expr.map(|e| self.explode_expr(e, false)),
+                    ..elem
+                });
+
+                TupleLit { elems, ..tuple }.into()
+            }
+
             Expr::Seq(e) => {
                 let len = e.exprs.len();
                 let exprs = e
@@ -1,6 +1,8 @@
 use crate::{
     ext::ExprRefExt,
-    util::{alias_ident_for, is_literal, prepend, undefined, ExprFactory, StmtLike},
+    util::{
+        alias_ident_for, alias_if_required, is_literal, prepend, undefined, ExprFactory, StmtLike,
+    },
 };
 use serde::Deserialize;
 use std::mem;
@@ -17,6 +19,25 @@ pub fn spread(c: Config) -> impl Fold {
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     pub loose: bool,
+
+    /// Copies spread arrays with `slice` / `concat` instead of calling
+    /// `_toConsumableArray`, which is only used for values which are not
+    /// arrays.
+    ///
+    /// ```js
+    /// [...a];
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// Array.isArray(a) ? a.slice() : _toConsumableArray(a);
+    /// ```
+    ///
+    /// This assumes that arrays are iterated by the default array iterator
+    /// and do not contain holes. Ignored if `loose` is set.
+    #[serde(default)]
+    pub array_fast_path: bool,
 }
 
 /// es2015 - `SpreadElement`
@@ -227,7 +248,7 @@ impl Fold for ActualFolder {
 
 impl ActualFolder {
     fn concat_args(
-        &self,
+        &mut self,
         span: Span,
         args: impl ExactSizeIterator + Iterator<Item = Option<ExprOrSpread>>,
         need_array: bool,
@@ -303,51 +324,32 @@ impl ActualFolder {
                                     return if self.c.loose {
                                         *expr
                                     } else {
-                                        Expr::Call(CallExpr {
-                                            span,
-                                            callee: helper!(
-                                                to_consumable_array,
-                                                "toConsumableArray"
-                                            ),
-                                            args: vec![expr.as_arg()],
-                                            type_args: Default::default(),
-                                        })
+                                        self.to_array(span, expr, false)
                                     };
                                 }
-                                // [].concat(arr) is shorter than _toConsumableArray(arr)
                                 if args_len == 1 {
+                                    // `arr` is known to be an array, so `[...arr]` is a copy.
                                     return if self.c.loose {
                                         Expr::Call(CallExpr {
                                             span: DUMMY_SP,
-                                            callee: ArrayLit {
-                                                span: DUMMY_SP,
-                                                elems: vec![],
-                                            }
-                                            .make_member(quote_ident!("concat"))
-                                            .as_callee(),
-                                            args: vec![expr.as_arg()],
+                                            callee: expr
+                                                .make_member(quote_ident!("slice"))
+                                                .as_callee(),
+                                            args: vec![],
                                             type_args: Default::default(),
                                         })
                                     } else {
-                                        Expr::Call(CallExpr {
-                                            span,
-                                            callee: helper!(
-                                                to_consumable_array,
-                                                "toConsumableArray"
-                                            ),
-                                            args: vec![expr.as_arg()],
-                                            type_args: Default::default(),
-                                        })
+                                        self.to_array(span, expr, true)
                                     };
                                 }
 
-                                Expr::Call(CallExpr {
-                                    span,
-                                    callee: helper!(to_consumable_array, "toConsumableArray"),
-                                    args: vec![expr.as_arg()],
-                                    type_args: Default::default(),
-                                })
-                                .as_arg()
+                                // `concat` spreads arrays, so `[...a, ...b]` becomes
+                                // `a.concat(b)`.
+                                if self.c.loose {
+                                    expr.as_arg()
+                                } else {
+                                    self.to_array(span, expr, false).as_arg()
+                                }
                             }
                         });
                     }
@@ -411,6 +413,66 @@ impl ActualFolder {
             type_args: Default::default(),
         })
     }
+
+    /// Converts a spread argument to an array.
+    ///
+    /// If `copy` is false, the result may be `expr` itself.
+    fn to_array(&mut self, span: Span, expr: Box<Expr>, copy: bool) -> Expr {
+        let call_helper = |expr: Box<Expr>| {
+            Expr::Call(CallExpr {
+                span,
+                callee: helper!(to_consumable_array, "toConsumableArray"),
+                args: vec![expr.as_arg()],
+                type_args: Default::default(),
+            })
+        };
+        if !self.c.array_fast_path {
+            return call_helper(expr);
+        }
+
+        // `Array.isArray(_a = a()) ? _a.slice() : _toConsumableArray(_a)`
+        let (arr, aliased) = alias_if_required(&expr, "_arr");
+        let test_arg = if aliased {
+            self.vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(arr.clone()),
+                init: None,
+                definite: false,
+            });
+            Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(arr.clone()))),
+                right: expr,
+            }))
+        } else {
+            expr
+        };
+
+        let cons = Box::new(Expr::Ident(arr.clone()));
+        let cons = if copy {
+            Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: cons.make_member(quote_ident!("slice")).as_callee(),
+                args: vec![],
+                type_args: Default::default(),
+            }))
+        } else {
+            cons
+        };
+
+        Expr::Cond(CondExpr {
+            span,
+            test: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: member_expr!(DUMMY_SP, Array.isArray).as_callee(),
+                args: vec![test_arg.as_arg()],
+                type_args: Default::default(),
+            })),
+            cons,
+            alt: Box::new(call_helper(Box::new(Expr::Ident(arr)))),
+        })
+    }
 }
 
 fn expand_literal_args(
@@ -13,13 +13,30 @@ pub fn template_literal() -> impl Fold {
 }
 
 struct TemplateLiteral {
-    added: Vec<Stmt>,
+    /// Variables storing the strings arrays of tagged templates.
+    added: Vec<VarDeclarator>,
     /// Applied to [Str] created by this pass.
     ///
     /// This is to workaround codegen issue.
     str_ctxt: SyntaxContext,
 }
 
+impl TemplateLiteral {
+    /// Returns the declaration of the variables in `added`.
+    fn take_decl(&mut self) -> Option<Stmt> {
+        if self.added.is_empty() {
+            return None;
+        }
+
+        Some(Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: mem::take(&mut self.added),
+        })))
+    }
+}
+
 impl Fold for TemplateLiteral {
     noop_fold_type!();
 
@@ -199,140 +216,80 @@ impl Fold for TemplateLiteral {
             }) => {
                 assert_eq!(quasis.len(), exprs.len() + 1);
 
-                let fn_ident = private_ident!("_templateObject");
+                let obj_ident = private_ident!("_templateObject");
 
-                let f = Function {
+                // _taggedTemplateLiteral(["first", "second"])
+                let obj = Expr::Call(CallExpr {
                     span: DUMMY_SP,
-                    is_async: false,
-                    is_generator: false,
-                    params: vec![],
-                    body: {
-                        // const data = _taggedTemplateLiteral(["first", "second"]);
-                        let data_decl = VarDecl {
-                            span: DUMMY_SP,
-                            kind: VarDeclKind::Const,
-                            declare: false,
-                            decls: vec![VarDeclarator {
-                                span: DUMMY_SP,
-                                name: quote_ident!("data").into(),
-                                definite: false,
-                                init: Some(Box::new(Expr::Call(CallExpr {
+                    callee: helper!(tagged_template_literal, "taggedTemplateLiteral"),
+                    args: {
+                        let has_escape = quasis
+                            .iter()
+                            .any(|s| s.cooked.as_ref().map(|s| s.has_escape).unwrap_or(true));
+
+                        let raw = if has_escape {
+                            Some(
+                                ArrayLit {
                                     span: DUMMY_SP,
-                                    callee: helper!(
-                                        tagged_template_literal,
-                                        "taggedTemplateLiteral"
-                                    ),
-                                    args: {
-                                        let has_escape = quasis.iter().any(|s| {
-                                            s.cooked.as_ref().map(|s| s.has_escape).unwrap_or(true)
-                                        });
-
-                                        let raw = if has_escape {
-                                            Some(
-                                                ArrayLit {
-                                                    span: DUMMY_SP,
-                                                    elems: quasis
-                                                        .iter()
-                                                        .cloned()
-                                                        .map(|elem| Lit::Str(elem.raw).as_arg())
-                                                        .map(Some)
-                                                        .collect(),
-                                                }
-                                                .as_arg(),
-                                            )
-                                        } else {
-                                            None
-                                        };
-
-                                        iter::once(
-                                            ArrayLit {
-                                                span: DUMMY_SP,
-                                                elems: quasis
-                                                    .into_iter()
-                                                    .map(|elem| {
-                                                        Lit::Str(elem.cooked.unwrap_or(elem.raw))
-                                                            .as_arg()
-                                                    })
-                                                    .map(Some)
-                                                    .collect(),
-                                            }
-                                            .as_arg(),
-                                        )
-                                        .chain(raw)
-                                        .collect()
-                                    },
-                                    type_args: Default::default(),
-                                }))),
-                            }],
+                                    elems: quasis
+                                        .iter()
+                                        .cloned()
+                                        .map(|elem| Lit::Str(elem.raw).as_arg())
+                                        .map(Some)
+                                        .collect(),
+                                }
+                                .as_arg(),
+                            )
+                        } else {
+                            None
                         };
 
-                        // _templateObject2 = function () {
-                        //     return data;
-                        // };
-                        let assign_expr = {
-                            Expr::Assign(AssignExpr {
+                        iter::once(
+                            ArrayLit {
                                 span: DUMMY_SP,
-                                left: PatOrExpr::Pat(Box::new(fn_ident.clone().into())),
-                                op: op!("="),
-                                right: Box::new(Expr::Fn(FnExpr {
-                                    ident: None,
-                                    function: Function {
-                                        span: DUMMY_SP,
-                                        is_async: false,
-                                        is_generator: false,
-                                        params: vec![],
-                                        body: Some(BlockStmt {
-                                            span: DUMMY_SP,
-                                            stmts: vec![Stmt::Return(ReturnStmt {
-                                                span: DUMMY_SP,
-                                                arg: Some(Box::new(quote_ident!("data").into())),
-                                            })],
-                                        }),
-                                        decorators: Default::default(),
-                                        type_params: Default::default(),
-                                        return_type: Default::default(),
-                                    },
-                                })),
-                            })
-                        };
-
-                        Some(BlockStmt {
-                            span: DUMMY_SP,
-
-                            stmts: vec![
-                                Stmt::Decl(Decl::Var(data_decl)),
-                                assign_expr.into_stmt(),
-                                Stmt::Return(ReturnStmt {
-                                    span: DUMMY_SP,
-                                    arg: Some(Box::new(quote_ident!("data").into())),
-                                }),
-                            ],
-                        })
+                                elems: quasis
+                                    .into_iter()
+                                    .map(|elem| Lit::Str(elem.cooked.unwrap_or(elem.raw)).as_arg())
+                                    .map(Some)
+                                    .collect(),
+                            }
+                            .as_arg(),
+                        )
+                        .chain(raw)
+                        .collect()
                     },
-                    decorators: Default::default(),
-                    type_params: Default::default(),
-                    return_type: Default::default(),
-                };
-                self.added.push(Stmt::Decl(Decl::Fn(FnDecl {
-                    declare: false,
-                    ident: fn_ident.clone(),
-                    function: f,
-                })));
+                    type_args: Default::default(),
+                });
+
+                // The strings array must be the same object each time the template is
+                // evaluated, so it's created once and stored in a variable declared at the
+                // top level, like
+                //
+                // _templateObject || (_templateObject = _taggedTemplateLiteral(["first"]))
+                self.added.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(obj_ident.clone()),
+                    init: None,
+                    definite: false,
+                });
+                let obj = Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("||"),
+                    left: Box::new(Expr::Ident(obj_ident.clone())),
+                    right: Box::new(Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: PatOrExpr::Pat(Box::new(Pat::Ident(obj_ident))),
+                        right: Box::new(obj),
+                    })),
+                });
 
                 validate!(Expr::Call(CallExpr {
                     span: DUMMY_SP,
                     callee: tag.as_callee(),
-                    args: iter::once(
-                        Expr::Call(CallExpr {
-                            span: DUMMY_SP,
-                            callee: fn_ident.as_callee(),
-                            args: vec![],
-                            type_args: Default::default(),
-                        })
-                        .as_arg(),
-                    )
-                    .chain(exprs.into_iter().map(|e| e.as_arg()))
-                    .collect(),
+                    args: iter::once(obj.as_arg())
+                        .chain(exprs.into_iter().map(|e| e.as_arg()))
+                        .collect(),
                     type_args: Default::default(),
                 }))
             }
@@ -344,7 +301,9 @@ impl Fold for TemplateLiteral {
     fn fold_module(&mut self, m: Module) -> Module {
         let mut body = m.body.fold_children_with(self);
 
-        prepend_stmts(&mut body, self.added.drain(..).map(ModuleItem::from_stmt));
+        if let Some(decl) = self.take_decl() {
+            prepend_stmts(&mut body, iter::once(ModuleItem::from_stmt(decl)));
+        }
 
         Module { body, ..m }
     }
@@ -352,7 +311,9 @@ impl Fold for TemplateLiteral {
     fn fold_script(&mut self, m: Script) -> Script {
         let mut body = m.body.fold_children_with(self);
 
-        prepend_stmts(&mut body, self.added.drain(..));
+        if let Some(decl) = self.take_decl() {
+            prepend_stmts(&mut body, iter::once(decl));
+        }
 
         Script { body, ..m }
     }
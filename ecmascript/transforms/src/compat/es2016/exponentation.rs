@@ -1,9 +1,9 @@
 use crate::{
     ext::PatOrExprExt,
     perf::Check,
-    util::{ExprFactory, StmtLike},
+    util::{memoize_member, ExprFactory, StmtLike},
 };
-use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_common::{Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_macros::fast_path;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
@@ -27,6 +27,10 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 ///
 /// x = Math.pow(x, 3);
 /// ```
+///
+/// `Math.pow` throws if an operand is a `BigInt`, so `**` is not changed if an
+/// operand is known to be one, like `2n ** x`. Engines which support `BigInt`
+/// support `**` as well.
 pub fn exponentation() -> impl Fold {
     Exponentation
 }
@@ -44,7 +48,26 @@ impl Fold for AssignFolder {
     noop_fold_type!();
 
     fn fold_expr(&mut self, e: Expr) -> Expr {
+        // Checked before folding, as nested operations are replaced with calls.
+        let is_big_int_op = match &e {
+            Expr::Bin(BinExpr {
+                op: op!("**"),
+                left,
+                right,
+                ..
+            }) => is_big_int(left) || is_big_int(right),
+            Expr::Assign(AssignExpr {
+                op: op!("**="),
+                right,
+                ..
+            }) => is_big_int(right),
+            _ => false,
+        };
+
         let e = e.fold_children_with(self);
+        if is_big_int_op {
+            return e;
+        }
 
         match e {
             Expr::Assign(AssignExpr {
@@ -53,27 +76,35 @@ impl Fold for AssignFolder {
                 op: op!("**="),
                 right,
             }) => {
-                let lhs: Ident = match left {
-                    _ if left.as_ident().is_some() => left.as_ident().unwrap().clone(),
-
-                    // unimplemented
-                    PatOrExpr::Expr(ref e) => {
-                        let ref_ident = private_ident!(e.span(), "ref");
-
-                        self.vars.push(VarDeclarator {
-                            span: DUMMY_SP,
-                            name: ref_ident.clone().into(),
-                            init: Some(e.clone()),
-                            definite: false,
-                        });
-                        ref_ident
+                let (left, lhs) = match left.normalize_expr() {
+                    left if left.as_ident().is_some() => {
+                        let lhs = left.as_ident().unwrap().clone();
+                        (left, Expr::Ident(lhs))
                     }
 
+                    PatOrExpr::Expr(e) => match *e {
+                        Expr::Member(e) => {
+                            let (left, lhs) = memoize_member(e, &mut self.vars);
+                            (
+                                PatOrExpr::Expr(Box::new(Expr::Member(left))),
+                                Expr::Member(lhs),
+                            )
+                        }
+                        e => {
+                            return Expr::Assign(AssignExpr {
+                                span,
+                                left: PatOrExpr::Expr(Box::new(e)),
+                                op: op!("**="),
+                                right,
+                            })
+                        }
+                    },
+
                     left => {
                         return Expr::Assign(AssignExpr {
                             span,
                             left,
-                            op: op!("="),
+                            op: op!("**="),
                             right,
                         });
                     }
@@ -82,7 +113,7 @@ impl Fold for AssignFolder {
                     span,
                     left,
                     op: op!("="),
-                    right: Box::new(mk_call(span, Box::new(lhs.into()), right)),
+                    right: Box::new(mk_call(span, Box::new(lhs), right)),
                 })
             }
             Expr::Bin(BinExpr {
@@ -146,10 +177,53 @@ impl Exponentation {
     }
 }
 
+/// Returns true if `e` is known to evaluate to a `BigInt` or throw.
+fn is_big_int(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::BigInt(..)) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_big_int(expr),
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-"),
+            arg,
+            ..
+        })
+        | Expr::Unary(UnaryExpr {
+            op: op!("~"), arg, ..
+        }) => is_big_int(arg),
+        Expr::Bin(BinExpr {
+            op, left, right, ..
+        }) => match op {
+            // `"a" + 1n` is a string.
+            op!(bin, "+") => is_big_int(left) && is_big_int(right),
+            op!(bin, "-")
+            | op!("*")
+            | op!("/")
+            | op!("%")
+            | op!("**")
+            | op!("&")
+            | op!("|")
+            | op!("^")
+            | op!("<<")
+            | op!(">>") => is_big_int(left) || is_big_int(right),
+            _ => false,
+        },
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(callee),
+            ..
+        }) => match &**callee {
+            Expr::Ident(Ident { sym, span, .. }) => {
+                &**sym == "BigInt" && span.ctxt() == SyntaxContext::empty()
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 fn mk_call(span: Span, left: Box<Expr>, right: Box<Expr>) -> Expr {
-    // Math.pow()
     Expr::Call(CallExpr {
         span,
+        // Math.pow()
         callee: member_expr!(span, Math.pow).as_callee(),
 
         args: vec![left.as_arg(), right.as_arg()],
@@ -201,7 +275,6 @@ mod tests {
     );
 
     test_exec!(
-        ignore,
         ::swc_ecma_parser::Syntax::default(),
         |_| Exponentation,
         babel_comprehensive,
@@ -229,8 +302,6 @@ expect(2 ** 3 ** 2).toBe(512);"#
     );
 
     test_exec!(
-        // FIXME
-        ignore,
         ::swc_ecma_parser::Syntax::default(),
         |_| Exponentation,
         babel_memoize_object,
@@ -290,7 +361,83 @@ expect(counters).toBe(1);"#
         |_| Exponentation,
         assign_to_object_property,
         r#"var self = {}; self.x **= 3"#,
-        r#"var self = {}; var ref = self.x; self.x = Math.pow(ref, 3);"#,
+        r#"var self = {}; var _self; (_self = self).x = Math.pow(_self.x, 3);"#,
+        ok_if_code_eq
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| Exponentation,
+        assign_to_computed_member,
+        r#"a[i++] **= 2"#,
+        r#"var _a, _ref; (_a = a)[_ref = i++] = Math.pow(_a[_ref], 2);"#,
         ok_if_code_eq
     );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| Exponentation,
+        assign_to_computed_member_exec,
+        r#"var calls = 0;
+var arr = [2, 3];
+var obj = {
+  get arr() {
+    calls++;
+    return arr;
+  }
+};
+var i = 0;
+
+obj.arr[i++] **= 2;
+expect(arr).toEqual([4, 3]);
+expect(i).toBe(1);
+expect(calls).toBe(1);
+
+obj.arr[i] **= (i = 0, 3);
+expect(arr).toEqual([4, 27]);"#
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| Exponentation,
+        big_int,
+        r#"
+a = 2n ** x;
+b = (x ** 2n) ** y;
+c = BigInt(x) ** -y;
+d **= 3n;
+e = x ** y;
+f = (x + 1n) ** 2;
+g = (1n - x) ** 2;
+"#,
+        r#"
+a = 2n ** x;
+b = (x ** 2n) ** y;
+c = BigInt(x) ** -y;
+d **= 3n;
+e = Math.pow(x, y);
+f = Math.pow(x + 1n, 2);
+g = (1n - x) ** 2;
+"#
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| Exponentation,
+        big_int_exec,
+        r#"
+expect(2n ** 10n).toBe(1024n);
+expect((-3n) ** 3n).toBe(-27n);
+expect(5n ** 0n).toBe(1n);
+expect(0n ** 0n).toBe(1n);
+expect(2n ** 100n).toBe(1267650600228229401496703205376n);
+
+var x = 7n;
+x **= 2n;
+expect(x).toBe(49n);
+
+expect(() => 2n ** -1n).toThrow(RangeError);
+expect(() => 2n ** 1).toThrow(TypeError);
+"#
+    );
 }
@@ -1,12 +1,33 @@
 pub use self::{
-    object_rest_spread::object_rest_spread, optional_catch_binding::optional_catch_binding,
+    async_generator::async_generator,
+    object_rest_spread::{object_rest_spread, object_rest_spread_with_config},
+    optional_catch_binding::optional_catch_binding,
+    promise_finally::promise_finally,
 };
+use serde::Deserialize;
 use swc_common::chain;
 use swc_ecma_visit::Fold;
 
-mod object_rest_spread;
+mod async_generator;
+pub mod object_rest_spread;
 mod optional_catch_binding;
+pub mod promise_finally;
 
 pub fn es2018() -> impl Fold {
-    chain!(object_rest_spread(), optional_catch_binding())
+    es2018_with_config(Default::default())
+}
+
+pub fn es2018_with_config(c: Config) -> impl Fold {
+    chain!(
+        async_generator(),
+        object_rest_spread(),
+        optional_catch_binding(),
+        promise_finally(c.promise_finally)
+    )
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(flatten)]
+    pub promise_finally: promise_finally::Config,
 }
@@ -0,0 +1,393 @@
+use crate::util::ExprFactory;
+use std::mem;
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// `@babel/plugin-proposal-async-generator-functions`
+///
+/// ## In
+///
+/// ```js
+/// async function* foo(a) {
+///     yield await a;
+///     yield* bar();
+/// }
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// function foo() {
+///     return _wrapAsyncGenerator(function* (a) {
+///         yield yield _awaitAsyncGenerator(a);
+///         yield* _asyncGeneratorDelegate(_asyncIterator(bar()), _awaitAsyncGenerator);
+///     }).apply(this, arguments);
+/// }
+/// ```
+///
+/// `for await` loops in the body of an async generator are converted too.
+///
+/// # Limitation
+///
+/// `super` in async generator methods is not supported.
+pub fn async_generator() -> impl Fold {
+    as_folder(AsyncGenerator)
+}
+
+struct AsyncGenerator;
+
+impl VisitMut for AsyncGenerator {
+    noop_visit_mut_type!();
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        f.visit_mut_children_with(self);
+
+        if !f.is_async || !f.is_generator {
+            return;
+        }
+        let mut body = match f.body.take() {
+            Some(body) => body,
+            None => return,
+        };
+        body.visit_mut_with(&mut BodyFolder);
+
+        let generator = Expr::Fn(FnExpr {
+            ident: None,
+            function: Function {
+                params: mem::replace(&mut f.params, vec![]),
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(body),
+                is_generator: true,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        });
+
+        // _wrapAsyncGenerator(function* () {}).apply(this, arguments)
+        let wrapped = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(wrap_async_generator, "wrapAsyncGenerator"),
+            args: vec![generator.as_arg()],
+            type_args: Default::default(),
+        });
+        let call = Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: wrapped.make_member(quote_ident!("apply")).as_callee(),
+            args: vec![
+                ThisExpr { span: DUMMY_SP }.as_arg(),
+                quote_ident!("arguments").as_arg(),
+            ],
+            type_args: Default::default(),
+        });
+
+        f.is_async = false;
+        f.is_generator = false;
+        f.body = Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(call)),
+            })],
+        });
+    }
+}
+
+/// Converts `await`, `yield*` and `for await` in the body of an async
+/// generator.
+struct BodyFolder;
+
+/// `yield _awaitAsyncGenerator(arg)`
+fn await_value(span: Span, arg: Box<Expr>) -> Expr {
+    Expr::Yield(YieldExpr {
+        span,
+        delegate: false,
+        arg: Some(Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(await_async_generator, "awaitAsyncGenerator"),
+            args: vec![arg.as_arg()],
+            type_args: Default::default(),
+        }))),
+    })
+}
+
+fn async_iterator(arg: Box<Expr>) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: helper!(async_iterator, "asyncIterator"),
+        args: vec![arg.as_arg()],
+        type_args: Default::default(),
+    })
+}
+
+impl VisitMut for BodyFolder {
+    noop_visit_mut_type!();
+
+    /// Nested functions are handled by [AsyncGenerator].
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        match e {
+            Expr::Await(AwaitExpr { span, arg }) => {
+                let arg = mem::replace(arg, Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })));
+                *e = await_value(*span, arg);
+            }
+
+            Expr::Yield(YieldExpr {
+                delegate: true,
+                arg: Some(arg),
+                ..
+            }) => {
+                let inner = mem::replace(arg, Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })));
+                *arg = Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(async_generator_delegate, "asyncGeneratorDelegate"),
+                    args: vec![
+                        async_iterator(inner).as_arg(),
+                        helper_expr!(await_async_generator, "awaitAsyncGenerator").as_arg(),
+                    ],
+                    type_args: Default::default(),
+                }));
+            }
+
+            _ => {}
+        }
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        match s {
+            Stmt::ForOf(ForOfStmt {
+                await_token: Some(..),
+                ..
+            }) => {}
+            _ => return,
+        }
+
+        let stmt = mem::replace(s, Stmt::Empty(EmptyStmt { span: DUMMY_SP }));
+        *s = match stmt {
+            Stmt::ForOf(stmt) => for_await(stmt),
+            _ => unreachable!(),
+        };
+    }
+}
+
+/// Converts
+///
+/// ```js
+/// for await (const x of xs) {
+///     body();
+/// }
+/// ```
+///
+/// into
+///
+/// ```js
+/// var _iteratorAbruptCompletion = false, _didIteratorError = false, _iteratorError;
+/// try {
+///     for (
+///         var _iterator = _asyncIterator(xs), _step;
+///         _iteratorAbruptCompletion = !(_step = yield _awaitAsyncGenerator(_iterator.next())).done;
+///         _iteratorAbruptCompletion = false
+///     ) {
+///         const x = _step.value;
+///         body();
+///     }
+/// } catch (err) {
+///     _didIteratorError = true;
+///     _iteratorError = err;
+/// } finally {
+///     try {
+///         if (_iteratorAbruptCompletion && _iterator.return != null) {
+///             yield _awaitAsyncGenerator(_iterator.return());
+///         }
+///     } finally {
+///         if (_didIteratorError) {
+///             throw _iteratorError;
+///         }
+///     }
+/// }
+/// ```
+fn for_await(stmt: ForOfStmt) -> Stmt {
+    let abrupt_completion = private_ident!("_iteratorAbruptCompletion");
+    let did_error = private_ident!("_didIteratorError");
+    let error = private_ident!("_iteratorError");
+    let iterator = private_ident!("_iterator");
+    let step = private_ident!("_step");
+    let err = private_ident!("err");
+
+    let step_value = Box::new(step.clone().make_member(quote_ident!("value")));
+    let assign_value = match stmt.left {
+        VarDeclOrPat::VarDecl(mut var) => {
+            var.decls[0].init = Some(step_value);
+            Stmt::Decl(Decl::Var(var))
+        }
+        VarDeclOrPat::Pat(pat) => AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(pat)),
+            right: step_value,
+        }
+        .into_stmt(),
+    };
+    let body = match *stmt.body {
+        Stmt::Block(mut block) => {
+            block.stmts.insert(0, assign_value);
+            block
+        }
+        body => BlockStmt {
+            span: body.span(),
+            stmts: vec![assign_value, body],
+        },
+    };
+
+    let set = |id: &Ident, value: Expr| -> Expr {
+        Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(id.clone()))),
+            right: Box::new(value),
+        })
+    };
+    let declarator = |id: &Ident, init: Option<Expr>| VarDeclarator {
+        span: DUMMY_SP,
+        name: Pat::Ident(id.clone()),
+        init: init.map(Box::new),
+        definite: false,
+    };
+    let call_iterator = |method: &str| -> Box<Expr> {
+        Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: iterator
+                .clone()
+                .make_member(quote_ident!(method))
+                .as_callee(),
+            args: vec![],
+            type_args: Default::default(),
+        }))
+    };
+
+    // _iteratorAbruptCompletion = !(_step = yield
+    // _awaitAsyncGenerator(_iterator.next())).done
+    let test = set(
+        &abrupt_completion,
+        Expr::Unary(UnaryExpr {
+            span: DUMMY_SP,
+            op: op!("!"),
+            arg: Box::new(
+                set(&step, await_value(DUMMY_SP, call_iterator("next")))
+                    .wrap_with_paren()
+                    .make_member(quote_ident!("done")),
+            ),
+        }),
+    );
+
+    let for_stmt = Stmt::For(ForStmt {
+        span: stmt.span,
+        init: Some(VarDeclOrExpr::VarDecl(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![
+                declarator(&iterator, Some(async_iterator(stmt.right))),
+                declarator(&step, None),
+            ],
+        })),
+        test: Some(Box::new(test)),
+        update: Some(Box::new(set(&abrupt_completion, Expr::from(false)))),
+        body: Box::new(Stmt::Block(body)),
+    });
+
+    let handler = CatchClause {
+        span: DUMMY_SP,
+        param: Some(Pat::Ident(err.clone())),
+        body: BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![
+                set(&did_error, Expr::from(true)).into_stmt(),
+                set(&error, Expr::Ident(err)).into_stmt(),
+            ],
+        },
+    };
+
+    // if (_iteratorAbruptCompletion && _iterator.return != null)
+    let close = Stmt::If(IfStmt {
+        span: DUMMY_SP,
+        test: Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: op!("&&"),
+            left: Box::new(Expr::Ident(abrupt_completion.clone())),
+            right: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: op!("!="),
+                left: Box::new(iterator.clone().make_member(quote_ident!("return"))),
+                right: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+            })),
+        })),
+        cons: Box::new(Stmt::Block(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![await_value(DUMMY_SP, call_iterator("return")).into_stmt()],
+        })),
+        alt: None,
+    });
+    let rethrow = Stmt::If(IfStmt {
+        span: DUMMY_SP,
+        test: Box::new(Expr::Ident(did_error.clone())),
+        cons: Box::new(Stmt::Block(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Throw(ThrowStmt {
+                span: DUMMY_SP,
+                arg: Box::new(Expr::Ident(error.clone())),
+            })],
+        })),
+        alt: None,
+    });
+
+    let finalizer = BlockStmt {
+        span: DUMMY_SP,
+        stmts: vec![Stmt::Try(TryStmt {
+            span: DUMMY_SP,
+            block: BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![close],
+            },
+            handler: None,
+            finalizer: Some(BlockStmt {
+                span: DUMMY_SP,
+                stmts: vec![rethrow],
+            }),
+        })],
+    };
+
+    Stmt::Block(BlockStmt {
+        span: DUMMY_SP,
+        stmts: vec![
+            Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![
+                    declarator(&abrupt_completion, Some(Expr::from(false))),
+                    declarator(&did_error, Some(Expr::from(false))),
+                    declarator(&error, None),
+                ],
+            })),
+            Stmt::Try(TryStmt {
+                span: DUMMY_SP,
+                block: BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![for_stmt],
+                },
+                handler: Some(handler),
+                finalizer: Some(finalizer),
+            }),
+        ],
+    })
+}
@@ -4,6 +4,7 @@ use crate::{
         alias_ident_for, alias_if_required, is_literal, var::VarCollector, ExprFactory, StmtLike,
     },
 };
+use serde::Deserialize;
 use std::{iter, mem};
 use swc_common::{chain, util::move_map::MoveMap, Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -12,7 +13,33 @@ use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Node, Visit, VisitWith};
 
 /// `@babel/plugin-proposal-object-rest-spread`
 pub fn object_rest_spread() -> impl Fold {
-    chain!(ObjectRest, ObjectSpread)
+    object_rest_spread_with_config(Default::default())
+}
+
+pub fn object_rest_spread_with_config(c: Config) -> impl Fold {
+    chain!(ObjectRest, ObjectSpread { c })
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Uses `Object.assign` instead of the `_objectSpread` helper, for
+    /// targets which support `Object.assign` but not object spread.
+    ///
+    /// ```js
+    /// const b = { ...a, b: 1 };
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// const b = Object.assign({}, a, { b: 1 });
+    /// ```
+    ///
+    /// Object literals with getters, setters or `__proto__` still use the
+    /// helper, as `Object.assign` does not define properties.
+    #[serde(default)]
+    pub use_builtins: bool,
 }
 
 struct ObjectRest;
@@ -818,14 +845,17 @@ impl RestFolder {
                             let value = value.clone();
                             (key, Box::new(Expr::Ident(quote_ident!(span, value))))
                         }
-                        PropName::Num(Number { span, value }) => (
-                            key,
-                            Box::new(Expr::Lit(Lit::Str(Str {
-                                span,
-                                value: format!("{}", value).into(),
-                                has_escape: false,
-                            }))),
-                        ),
+                        PropName::Num(ref n) => {
+                            let (span, value) = (n.span, n.to_string());
+                            (
+                                key,
+                                Box::new(Expr::Lit(Lit::Str(Str {
+                                    span,
+                                    value: value.into(),
+                                    has_escape: false,
+                                }))),
+                            )
+                        }
                         PropName::BigInt(BigInt { span, ref value }) => {
                             let value = value.clone();
                             (
@@ -952,10 +982,10 @@ fn object_without_properties(obj: Box<Expr>, excluded_props: Vec<Option<ExprOrSp
         .into_iter()
         .map(|v| {
             v.map(|v| match *v.expr {
-                Expr::Lit(Lit::Num(Number { span, value })) => ExprOrSpread {
+                Expr::Lit(Lit::Num(n)) => ExprOrSpread {
                     expr: Box::new(Expr::Lit(Lit::Str(Str {
-                        span,
-                        value: value.to_string().into(),
+                        span: n.span,
+                        value: n.to_string().into(),
                         has_escape: false,
                     }))),
                     ..v
@@ -1007,9 +1037,9 @@ fn excluded_props(props: &[ObjectPatProp]) -> Vec<Option<ExprOrSpread>> {
                 })
                 .as_arg(),
                 PropName::Str(s) => Lit::Str(s.clone()).as_arg(),
-                PropName::Num(Number { span, value }) => Lit::Str(Str {
-                    span: *span,
-                    value: format!("{}", value).into(),
+                PropName::Num(n) => Lit::Str(Str {
+                    span: n.span,
+                    value: n.to_string().into(),
                     has_escape: false,
                 })
                 .as_arg(),
@@ -1078,7 +1108,9 @@ fn simplify_pat(pat: Pat) -> Pat {
     pat.fold_with(&mut PatSimplifier)
 }
 
-struct ObjectSpread;
+struct ObjectSpread {
+    c: Config,
+}
 
 #[fast_path(SpreadVisitor)]
 impl Fold for ObjectSpread {
@@ -1097,11 +1129,41 @@ impl Fold for ObjectSpread {
                     return Expr::Object(ObjectLit { span, props });
                 }
 
+                // `Object.assign` invokes setters instead of defining properties, so
+                // accessors and `__proto__` are handled by the helper.
+                let use_builtins = self.c.use_builtins
+                    && !props.iter().any(|p| match p {
+                        PropOrSpread::Prop(p) => match &**p {
+                            Prop::Getter(..) | Prop::Setter(..) => true,
+                            Prop::KeyValue(KeyValueProp {
+                                key: PropName::Ident(Ident { sym, .. }),
+                                ..
+                            })
+                            | Prop::KeyValue(KeyValueProp {
+                                key: PropName::Str(Str { value: sym, .. }),
+                                ..
+                            }) => &**sym == "__proto__",
+                            _ => false,
+                        },
+                        _ => false,
+                    });
+
                 let mut first = true;
 
                 // { foo, ...x } => ({ foo }, x)
                 let args = {
                     let mut buf = vec![];
+                    if use_builtins {
+                        // Properties are always copied to a new object.
+                        buf.push(
+                            ObjectLit {
+                                span: DUMMY_SP,
+                                props: vec![],
+                            }
+                            .as_arg(),
+                        );
+                        first = false;
+                    }
                     let mut obj = ObjectLit {
                         span: DUMMY_SP,
                         props: vec![],
@@ -1135,9 +1197,15 @@ impl Fold for ObjectSpread {
                     buf
                 };
 
+                let callee = if use_builtins {
+                    member_expr!(DUMMY_SP, Object.assign).as_callee()
+                } else {
+                    helper!(object_spread, "objectSpread")
+                };
+
                 Expr::Call(CallExpr {
                     span,
-                    callee: helper!(object_spread, "objectSpread"),
+                    callee,
                     args,
                     type_args: Default::default(),
                 })
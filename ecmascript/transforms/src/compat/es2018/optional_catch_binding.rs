@@ -5,6 +5,20 @@ use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visi
 
 struct OptionalCatchBinding;
 
+/// Adds an unused binding to `catch` clauses without one.
+///
+/// ```js
+/// try {} catch {}
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// try {} catch (_unused) {}
+/// ```
+///
+/// The binding is private, so the hygiene pass renames it if the catch body
+/// references another `_unused`.
 pub fn optional_catch_binding() -> impl Fold {
     OptionalCatchBinding
 }
@@ -21,7 +35,7 @@ impl Fold for OptionalCatchBinding {
         }
 
         CatchClause {
-            param: Some(private_ident!("e").into()),
+            param: Some(private_ident!("_unused").into()),
             ..cc
         }
     }
@@ -58,6 +72,24 @@ mod tests {
         |_| tr(),
         issue_411,
         "try {} catch {}",
-        "try {} catch(e) {}"
+        "try {} catch(_unused) {}"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| tr(),
+        hygiene,
+        "
+const _unused = 1;
+try {} catch {
+    (() => _unused)();
+}
+",
+        "
+const _unused = 1;
+try {} catch(_unused1) {
+    (() => _unused)();
+}
+"
     );
 }
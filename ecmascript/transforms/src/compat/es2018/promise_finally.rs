@@ -0,0 +1,240 @@
+use crate::util::ExprFactory;
+use serde::Deserialize;
+use std::mem::replace;
+use swc_atoms::{js_word, JsWord};
+use swc_common::SyntaxContext;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Lowers `Promise.prototype.finally`.
+///
+/// ```js
+/// fetchData().then(render).finally(hideSpinner);
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// _promiseFinally(fetchData().then(render), hideSpinner);
+/// ```
+///
+/// The helper calls `onFinally` on both fulfillment and rejection, and
+/// resolves to the original value or rejects with the original reason.
+///
+/// By default, a call is rewritten only if the receiver is known to be a
+/// promise, like `new Promise(..)`, `Promise.resolve(..)`, a call of an async
+/// function expression, or the result of `.then()`, `.catch()` or
+/// `.finally()` on one of them. If [Config::assume_promise] is true, every
+/// call of a method named `finally` is rewritten.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver.
+pub fn promise_finally(c: Config) -> impl Fold {
+    as_folder(PromiseFinally { c })
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Assume that every receiver of `.finally()` is a promise.
+    #[serde(default)]
+    pub assume_promise: bool,
+}
+
+struct PromiseFinally {
+    c: Config,
+}
+
+impl VisitMut for PromiseFinally {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let call = match e {
+            Expr::Call(call) => call,
+            _ => return,
+        };
+
+        match method_of(&call.callee) {
+            Some((obj, method))
+                if *method == js_word!("finally") && (self.c.assume_promise || is_promise(obj)) => {
+            }
+            _ => return,
+        }
+
+        let callee = replace(&mut call.callee, helper!(promise_finally, "promiseFinally"));
+        if let ExprOrSuper::Expr(callee) = callee {
+            if let Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                ..
+            }) = *callee
+            {
+                call.args.insert(0, obj.as_arg());
+            }
+        }
+    }
+}
+
+/// Returns the object and the name of the method if `callee` is `obj.name`.
+fn method_of(callee: &ExprOrSuper) -> Option<(&Expr, &JsWord)> {
+    match callee {
+        ExprOrSuper::Expr(callee) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed: false,
+                ..
+            }) => match &**prop {
+                Expr::Ident(prop) => Some((obj, &prop.sym)),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_global_promise(e: &Expr) -> bool {
+    match e {
+        Expr::Ident(Ident {
+            sym: js_word!("Promise"),
+            span,
+            ..
+        }) => span.ctxt() == SyntaxContext::empty(),
+        _ => false,
+    }
+}
+
+/// Returns true if `e` always evaluates to a promise.
+fn is_promise(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(ParenExpr { expr, .. }) => is_promise(expr),
+        Expr::New(NewExpr { callee, .. }) => is_global_promise(callee),
+        Expr::Call(CallExpr { callee, .. }) => {
+            if let ExprOrSuper::Expr(callee) = callee {
+                match &**callee {
+                    Expr::Fn(FnExpr { function, .. }) => {
+                        return function.is_async && !function.is_generator
+                    }
+                    Expr::Arrow(ArrowExpr { is_async, .. }) => return *is_async,
+                    Expr::Paren(ParenExpr { expr, .. }) => match &**expr {
+                        Expr::Fn(FnExpr { function, .. }) => {
+                            return function.is_async && !function.is_generator
+                        }
+                        Expr::Arrow(ArrowExpr { is_async, .. }) => return *is_async,
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+
+            match method_of(callee) {
+                Some((obj, method)) => match &**method {
+                    "then" | "catch" | "finally" => is_promise(obj),
+                    "resolve" | "reject" | "all" | "allSettled" | "any" | "race" => {
+                        is_global_promise(obj)
+                    }
+                    _ => false,
+                },
+                None => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{promise_finally, Config};
+    use crate::resolver;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), promise_finally(Default::default())),
+        known_promise,
+        "
+Promise.resolve(1).then(f).finally(g);
+new Promise(executor).finally(g);
+(async () => {})().finally(g);
+unknown.finally(g);
+",
+        "
+_promiseFinally(Promise.resolve(1).then(f), g);
+_promiseFinally(new Promise(executor), g);
+_promiseFinally((async () => {})(), g);
+unknown.finally(g);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(
+            resolver(),
+            promise_finally(Config {
+                assume_promise: true
+            })
+        ),
+        assume_promise,
+        "
+unknown.finally(g);
+function f(Promise) {
+    Promise.resolve(1).finally(g);
+}
+",
+        "
+_promiseFinally(unknown, g);
+function f(Promise) {
+    _promiseFinally(Promise.resolve(1), g);
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), promise_finally(Default::default())),
+        shadowed_promise,
+        "
+function f(Promise) {
+    Promise.resolve(1).finally(g);
+}
+",
+        "
+function f(Promise) {
+    Promise.resolve(1).finally(g);
+}
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), promise_finally(Default::default())),
+        exec,
+        "
+const calls = [];
+const fulfilled = Promise.resolve(1).finally(() => {
+    calls.push('fulfilled');
+    return 2;
+});
+const rejected = Promise.reject(new Error('failed')).finally(() => {
+    calls.push('rejected');
+});
+
+return Promise.all([
+    fulfilled,
+    rejected.then(
+        () => {
+            throw new Error('should be rejected');
+        },
+        (err) => err.message
+    ),
+]).then(([value, reason]) => {
+    expect(value).toBe(1);
+    expect(reason).toBe('failed');
+    expect(calls).toEqual(['fulfilled', 'rejected']);
+});
+"
+    );
+}
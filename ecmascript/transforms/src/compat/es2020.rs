@@ -1,22 +1,37 @@
 pub use self::{
+    bigint::bigint,
     class_properties::{class_properties, typescript_class_properties},
     export_namespace_from::export_namespace_from,
+    global_this::global_this,
     nullish_coalescing::nullish_coalescing,
     opt_chaining::optional_chaining,
 };
+use serde::Deserialize;
 use swc_common::chain;
 use swc_ecma_visit::Fold;
 
-mod class_properties;
+pub mod bigint;
+pub mod class_properties;
 mod export_namespace_from;
-mod nullish_coalescing;
+mod global_this;
+pub mod nullish_coalescing;
 mod opt_chaining;
 
-pub fn es2020() -> impl Fold {
+pub fn es2020(c: Config) -> impl Fold {
     chain!(
-        nullish_coalescing(),
+        nullish_coalescing(c.nullish_coalescing),
         optional_chaining(),
-        class_properties(),
+        class_properties(c.class_properties),
         export_namespace_from(),
+        global_this(),
     )
 }
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(flatten)]
+    pub nullish_coalescing: nullish_coalescing::Config,
+
+    #[serde(flatten)]
+    pub class_properties: class_properties::Config,
+}
@@ -0,0 +1,201 @@
+use crate::util::{ExprFactory, HANDLER};
+use serde::Deserialize;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Handles BigInt literals for engines which do not support BigInt.
+///
+/// BigInt can't be implemented faithfully by a polyfill, because operators
+/// like `+` can't be overloaded. So by default, this pass reports an error
+/// for each BigInt literal.
+///
+/// With [Behavior::BestEffort], a runtime polyfill is assumed to be present
+/// and literals are rewritten to calls instead.
+///
+/// ```js
+/// const a = 10n;
+/// const b = { 20n: a };
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const a = BigInt("10");
+/// const b = { "20": a };
+/// ```
+pub fn bigint(c: Config) -> impl Fold {
+    as_folder(BigIntLit { c })
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    #[serde(default)]
+    pub behavior: Behavior,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Behavior {
+    /// Reports an error for each BigInt literal.
+    #[serde(rename = "error")]
+    Error,
+    /// Rewrites `1n` to `BigInt("1")`.
+    #[serde(rename = "bestEffort")]
+    BestEffort,
+}
+
+/// This value defaults to `Error`
+impl Default for Behavior {
+    fn default() -> Self {
+        Behavior::Error
+    }
+}
+
+struct BigIntLit {
+    c: Config,
+}
+
+impl BigIntLit {
+    /// Returns `true` if the literal should be rewritten.
+    fn check(&self, lit: &BigInt) -> bool {
+        match self.c.behavior {
+            Behavior::Error => {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            lit.span,
+                            "BigInt is not supported by the target environment and can't be \
+                             polyfilled",
+                        )
+                        .emit()
+                });
+                false
+            }
+            Behavior::BestEffort => true,
+        }
+    }
+}
+
+impl VisitMut for BigIntLit {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let lit = match e {
+            Expr::Lit(Lit::BigInt(lit)) => lit,
+            _ => return,
+        };
+        if !self.check(lit) {
+            return;
+        }
+
+        *e = Expr::Call(CallExpr {
+            span: lit.span,
+            callee: quote_ident!("BigInt").as_callee(),
+            args: vec![Lit::Str(Str {
+                span: DUMMY_SP,
+                value: lit.value.to_string().into(),
+                has_escape: false,
+            })
+            .as_arg()],
+            type_args: Default::default(),
+        });
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        n.visit_mut_children_with(self);
+
+        let lit = match n {
+            PropName::BigInt(lit) => lit,
+            _ => return,
+        };
+        if !self.check(lit) {
+            return;
+        }
+
+        // Property keys are converted to strings.
+        *n = PropName::Str(Str {
+            span: lit.span,
+            value: lit.value.to_string().into(),
+            has_escape: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_ecma_parser::Syntax;
+    use swc_ecma_visit::FoldWith;
+
+    fn best_effort() -> impl Fold {
+        bigint(Config {
+            behavior: Behavior::BestEffort,
+        })
+    }
+
+    test!(
+        Syntax::default(),
+        |_| best_effort(),
+        best_effort_rewrite,
+        "
+const a = 10n;
+const b = {
+    20n: a,
+    [30n]: 0x10n
+};
+",
+        "
+const a = BigInt('10');
+const b = {
+    '20': a,
+    [BigInt('30')]: BigInt('16')
+};
+"
+    );
+
+    test_exec!(
+        Syntax::default(),
+        |_| best_effort(),
+        best_effort_exec,
+        "
+expect(10n).toBe(BigInt(10));
+expect(2n ** 64n).toBe(18446744073709551616n);
+expect({ 20n: 1 }['20']).toBe(1);
+"
+    );
+
+    #[test]
+    fn error() {
+        let stderr = ::testing::run_test(false, |cm, handler| {
+            HANDLER.set(handler, || {
+                let fm = cm.new_source_file(
+                    swc_common::FileName::Anon,
+                    "const a = 1; const b = 10n;".into(),
+                );
+                let mut p = swc_ecma_parser::Parser::new(
+                    Syntax::default(),
+                    swc_ecma_parser::StringInput::from(&*fm),
+                    None,
+                );
+                let m = p
+                    .parse_module()
+                    .map_err(|e| e.into_diagnostic(handler).emit())?;
+                m.fold_with(&mut bigint(Default::default()));
+
+                // Return the emitted diagnostics.
+                Err(())
+            })
+        })
+        .unwrap_err();
+
+        assert!(
+            stderr.contains("BigInt is not supported"),
+            "should report BigInt literals:\n{}",
+            stderr
+        );
+        assert_eq!(stderr.matches("error").count(), 1, "{}", stderr);
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
         undefined, ExprFactory, ModuleItemLike, StmtLike,
     },
 };
+use serde::Deserialize;
 use std::{collections::HashSet, mem::take};
 use swc_atoms::JsWord;
 use swc_common::{util::move_map::MoveMap, Mark, Spanned, DUMMY_SP};
@@ -31,8 +32,9 @@ mod used_name;
 /// # Impl note
 ///
 /// We use custom helper to handle export defaul class
-pub fn class_properties() -> impl Fold {
+pub fn class_properties(c: Config) -> impl Fold {
     ClassProperties {
+        c,
         typescript: false,
         mark: Mark::root(),
     }
@@ -41,13 +43,24 @@ pub fn class_properties() -> impl Fold {
 /// Class properties pass for the typescript.
 pub fn typescript_class_properties() -> impl Fold {
     ClassProperties {
+        c: Default::default(),
         typescript: true,
         mark: Mark::root(),
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// If true, public class fields are initialized using assignments instead
+    /// of `_defineProperty`.
+    #[serde(default)]
+    pub set_public_class_fields: bool,
+}
+
 #[derive(Clone)]
 struct ClassProperties {
+    c: Config,
     typescript: bool,
     mark: Mark,
 }
@@ -434,7 +447,7 @@ impl ClassProperties {
                         value
                     };
 
-                    if self.typescript {
+                    if self.typescript || self.c.set_public_class_fields {
                         if prop.is_static {
                             extra_stmts.push(
                                 AssignExpr {
@@ -453,7 +466,7 @@ impl ClassProperties {
                                 }
                                 .into_stmt(),
                             );
-                        } else if assigned_value {
+                        } else if assigned_value || !self.typescript {
                             constructor_exprs.push(Box::new(Expr::Assign(AssignExpr {
                                 span: DUMMY_SP,
                                 left: (PatOrExpr::Expr(Box::new(
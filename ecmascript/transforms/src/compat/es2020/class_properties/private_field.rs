@@ -1,10 +1,10 @@
 use crate::{
     ext::{AsOptExpr, PatOrExprExt},
-    util::{alias_ident_for, alias_if_required, prepend, ExprFactory},
+    util::{alias_ident_for, alias_if_required, memoize_member, prepend, ExprFactory},
 };
 use std::{collections::HashSet, iter, mem};
 use swc_atoms::JsWord;
-use swc_common::{Mark, Spanned, DUMMY_SP};
+use swc_common::{Mark, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 
@@ -73,7 +73,7 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                     }
                 };
 
-                let obj = match arg.obj {
+                match arg.obj {
                     ExprOrSuper::Super(..) => {
                         return Expr::Update(UpdateExpr {
                             span,
@@ -83,8 +83,8 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                         })
                         .fold_children_with(self);
                     }
-                    ExprOrSuper::Expr(ref obj) => obj.clone(),
-                };
+                    ExprOrSuper::Expr(..) => {}
+                }
 
                 let is_static = self.statics.contains(&n.id.sym);
                 let ident = Ident::new(
@@ -92,29 +92,10 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                     n.id.span.apply_mark(self.mark),
                 );
 
-                let var = alias_ident_for(&obj, "_ref");
-
-                let this = if match *obj {
-                    Expr::This(..) => true,
-                    _ => false,
-                } {
-                    ThisExpr { span: DUMMY_SP }.as_arg()
-                } else if is_static {
-                    obj.as_arg()
-                } else {
-                    self.vars.push(VarDeclarator {
-                        span: DUMMY_SP,
-                        name: Pat::Ident(var.clone()),
-                        init: None,
-                        definite: false,
-                    });
-                    AssignExpr {
-                        span: obj.span(),
-                        left: PatOrExpr::Pat(Box::new(Pat::Ident(var.clone()))),
-                        op: op!("="),
-                        right: obj,
-                    }
-                    .as_arg()
+                let (arg, arg_ref) = memoize_member(arg, &mut self.vars);
+                let this = match arg.obj {
+                    ExprOrSuper::Expr(obj) => obj.as_arg(),
+                    ExprOrSuper::Super(..) => unreachable!(),
                 };
                 // Used iff !prefix
                 let old_var = alias_ident_for(&arg.prop, "old");
@@ -128,7 +109,7 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                 }
 
                 let value = {
-                    let arg = Box::new(self.fold_private_get(arg, Some(var)).0);
+                    let arg = Box::new(self.fold_private_get(arg_ref, None).0);
                     let left = Box::new(Expr::Unary(UnaryExpr {
                         span: DUMMY_SP,
                         op: op!(unary, "+"),
@@ -218,7 +199,7 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                     }
                 };
 
-                let obj = match left.obj {
+                match left.obj {
                     ExprOrSuper::Super(..) => {
                         return Expr::Assign(AssignExpr {
                             span,
@@ -231,8 +212,8 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                         })
                         .fold_children_with(self);
                     }
-                    ExprOrSuper::Expr(ref obj) => obj.clone(),
-                };
+                    ExprOrSuper::Expr(..) => {}
+                }
 
                 let is_static = self.statics.contains(&n.id.sym);
                 let ident = Ident::new(
@@ -240,35 +221,20 @@ impl<'a> Fold for FieldAccessFolder<'a> {
                     n.id.span.apply_mark(self.mark),
                 );
 
-                let var = alias_ident_for(&obj, "_ref");
-
-                let this = if match *obj {
-                    Expr::This(..) => true,
-                    _ => false,
-                } {
-                    ThisExpr { span: DUMMY_SP }.as_arg()
-                } else if op == op!("=") {
-                    obj.as_arg()
+                let (left, left_ref) = if op == op!("=") {
+                    (left.clone(), left)
                 } else {
-                    self.vars.push(VarDeclarator {
-                        span: DUMMY_SP,
-                        name: Pat::Ident(var.clone()),
-                        init: None,
-                        definite: false,
-                    });
-                    AssignExpr {
-                        span: obj.span(),
-                        left: PatOrExpr::Pat(Box::new(Pat::Ident(var.clone()))),
-                        op: op!("="),
-                        right: obj,
-                    }
-                    .as_arg()
+                    memoize_member(left, &mut self.vars)
+                };
+                let this = match left.obj {
+                    ExprOrSuper::Expr(obj) => obj.as_arg(),
+                    ExprOrSuper::Super(..) => unreachable!(),
                 };
 
                 let value = if op == op!("=") {
                     right.as_arg()
                 } else {
-                    let left = Box::new(self.fold_private_get(left, Some(var)).0);
+                    let left = Box::new(self.fold_private_get(left_ref, None).0);
 
                     BinExpr {
                         span: DUMMY_SP,
@@ -0,0 +1,111 @@
+use swc_common::{Span, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Replaces `globalThis` with a helper which finds the global object.
+///
+/// ```js
+/// globalThis.foo = 1;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// _globalThis().foo = 1;
+/// ```
+///
+/// Bindings named `globalThis` are not changed.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver.
+pub fn global_this() -> impl Fold {
+    as_folder(GlobalThis)
+}
+
+struct GlobalThis;
+
+fn is_global_this(i: &Ident) -> bool {
+    &*i.sym == "globalThis" && i.span.ctxt() == SyntaxContext::empty()
+}
+
+fn global_this_call(span: Span) -> Expr {
+    Expr::Call(CallExpr {
+        span,
+        callee: helper!(global_this, "globalThis"),
+        args: vec![],
+        type_args: Default::default(),
+    })
+}
+
+impl VisitMut for GlobalThis {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Ident(i) = e {
+            if is_global_this(i) {
+                *e = global_this_call(i.span);
+            }
+        }
+    }
+
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        e.obj.visit_mut_with(self);
+        if e.computed {
+            e.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        p.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = p {
+            if is_global_this(i) {
+                *p = Prop::KeyValue(KeyValueProp {
+                    value: Box::new(global_this_call(i.span)),
+                    key: PropName::Ident(i.clone()),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::global_this;
+    use crate::resolver;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), global_this()),
+        global_this_member,
+        "
+globalThis.foo = 1;
+const g = { globalThis };
+function f(globalThis) {
+    return globalThis.foo + obj.globalThis;
+}
+",
+        "
+_globalThis().foo = 1;
+const g = { globalThis: _globalThis() };
+function f(globalThis) {
+    return globalThis.foo + obj.globalThis;
+}
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), global_this()),
+        exec,
+        "
+globalThis.__globalThisTest = 1;
+expect(globalThis.__globalThisTest).toBe(1);
+expect(typeof globalThis).toBe('object');
+"
+    );
+}
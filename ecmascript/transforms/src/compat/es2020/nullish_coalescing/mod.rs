@@ -1,8 +1,9 @@
 use crate::{
     ext::MapWithMut,
     perf::Check,
-    util::{alias_if_required, undefined, StmtLike},
+    util::{alias_ident_for, alias_if_required, memoize_member, undefined, StmtLike},
 };
+use serde::Deserialize;
 use std::mem::replace;
 use swc_common::{Span, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -12,12 +13,29 @@ use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visi
 #[cfg(test)]
 mod tests;
 
-pub fn nullish_coalescing() -> impl Fold + 'static {
-    NullishCoalescing::default()
+pub fn nullish_coalescing(c: Config) -> impl Fold + 'static {
+    NullishCoalescing {
+        c,
+        ..Default::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// If true, `a ?? b` is compiled to `a != null ? a : b`, which is wrong
+    /// only for `document.all`.
+    ///
+    /// This also compiles `a ?? b` to `a || b` if `a` is always an object,
+    /// like an object literal or a `new` expression, as `document.all` is the
+    /// only falsy object.
+    #[serde(default)]
+    pub no_document_all: bool,
 }
 
 #[derive(Debug, Default)]
 struct NullishCoalescing {
+    c: Config,
     vars: Vec<VarDeclarator>,
 }
 
@@ -53,12 +71,18 @@ impl Fold for NullishCoalescing {
 
     /// Prevents #1123
     fn fold_block_stmt(&mut self, s: BlockStmt) -> BlockStmt {
-        s.fold_children_with(&mut NullishCoalescing::default())
+        s.fold_children_with(&mut NullishCoalescing {
+            c: self.c,
+            ..Default::default()
+        })
     }
 
     /// Prevents #1123
     fn fold_switch_case(&mut self, s: SwitchCase) -> SwitchCase {
-        s.fold_children_with(&mut NullishCoalescing::default())
+        s.fold_children_with(&mut NullishCoalescing {
+            c: self.c,
+            ..Default::default()
+        })
     }
 
     fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
@@ -79,7 +103,15 @@ impl Fold for NullishCoalescing {
                 op: op!("??"),
                 right,
             }) => {
-                //
+                if self.c.no_document_all && is_object(&left) {
+                    return Expr::Bin(BinExpr {
+                        span,
+                        left,
+                        op: op!("||"),
+                        right,
+                    });
+                }
+
                 let (l, aliased) = alias_if_required(&left, "ref");
 
                 if aliased {
@@ -102,12 +134,47 @@ impl Fold for NullishCoalescing {
                     Expr::Ident(l.clone())
                 };
 
-                return make_cond(span, &l, var_expr, right);
+                return make_cond(self.c, span, &l, var_expr, right);
             }
 
             Expr::Assign(ref mut assign @ AssignExpr { op: op!("??="), .. }) => {
                 match &mut assign.left {
                     PatOrExpr::Expr(left) => {
+                        match *left.take() {
+                            // `(_ref = (_a = a).b) !== null && _ref !== void 0 ? _ref : _a.b = c`
+                            Expr::Member(m) => {
+                                let (first, rest) = memoize_member(m, &mut self.vars);
+                                let alias = alias_ident_for(&Expr::Member(rest.clone()), "ref");
+                                self.vars.push(VarDeclarator {
+                                    span: DUMMY_SP,
+                                    name: Pat::Ident(alias.clone()),
+                                    init: None,
+                                    definite: false,
+                                });
+
+                                let var_expr = Expr::Assign(AssignExpr {
+                                    span: DUMMY_SP,
+                                    op: op!("="),
+                                    left: PatOrExpr::Pat(Box::new(Pat::Ident(alias.clone()))),
+                                    right: Box::new(Expr::Member(first)),
+                                });
+
+                                return make_cond(
+                                    self.c,
+                                    assign.span,
+                                    &alias,
+                                    var_expr,
+                                    Box::new(Expr::Assign(AssignExpr {
+                                        span: DUMMY_SP,
+                                        op: op!("="),
+                                        left: PatOrExpr::Expr(Box::new(Expr::Member(rest))),
+                                        right: assign.right.take(),
+                                    })),
+                                );
+                            }
+                            other => **left = other,
+                        }
+
                         let (alias, aliased) = alias_if_required(&left, "ref$");
                         if aliased {
                             self.vars.push(VarDeclarator {
@@ -134,6 +201,7 @@ impl Fold for NullishCoalescing {
                             op: op!("="),
                             left: PatOrExpr::Pat(Box::new(Pat::Ident(alias.clone()))),
                             right: Box::new(make_cond(
+                                self.c,
                                 assign.span,
                                 &alias,
                                 var_expr,
@@ -148,6 +216,7 @@ impl Fold for NullishCoalescing {
                                 op: op!("="),
                                 left: PatOrExpr::Pat(Box::new(Pat::Ident(i.clone()))),
                                 right: Box::new(make_cond(
+                                    self.c,
                                     assign.span,
                                     &i,
                                     Expr::Ident(i.clone()),
@@ -199,7 +268,37 @@ impl Check for ShouldWork {
     }
 }
 
-fn make_cond(span: Span, alias: &Ident, var_expr: Expr, init: Box<Expr>) -> Expr {
+/// Returns true if `e` always evaluates to an object.
+fn is_object(e: &Expr) -> bool {
+    match e {
+        Expr::Object(..)
+        | Expr::Array(..)
+        | Expr::Fn(..)
+        | Expr::Arrow(..)
+        | Expr::Class(..)
+        | Expr::New(..)
+        | Expr::Lit(Lit::Regex(..)) => true,
+        Expr::Paren(e) => is_object(&e.expr),
+        Expr::Seq(e) => e.exprs.last().map(|e| is_object(e)).unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn make_cond(c: Config, span: Span, alias: &Ident, var_expr: Expr, init: Box<Expr>) -> Expr {
+    if c.no_document_all {
+        return Expr::Cond(CondExpr {
+            span,
+            test: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                left: Box::new(var_expr),
+                op: op!("!="),
+                right: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+            })),
+            cons: Box::new(Expr::Ident(alias.clone())),
+            alt: init,
+        });
+    }
+
     Expr::Cond(CondExpr {
         span,
         test: Box::new(Expr::Bin(BinExpr {
@@ -4,7 +4,7 @@ use swc_common::chain;
 use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
 
 fn tr(_: ()) -> impl Fold {
-    nullish_coalescing()
+    nullish_coalescing(Default::default())
 }
 
 fn syntax() -> Syntax {
@@ -171,3 +171,93 @@ test!(
     a = a !== null && a !== void 0 ? a : b;
     "
 );
+
+test!(
+    syntax(),
+    |_| nullish_coalescing(Config {
+        no_document_all: true
+    }),
+    no_document_all,
+    "
+    foo = bar ?? 'default';
+    a ??= b;
+    ",
+    "
+    foo = bar != null ? bar : 'default';
+    a = a != null ? a : b;
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(()),
+    object_operand,
+    "
+    foo = new Foo() ?? bar;
+    ",
+    "
+    var ref;
+    foo = (ref = new Foo()) !== null && ref !== void 0 ? ref : bar;
+    "
+);
+
+test!(
+    syntax(),
+    |_| nullish_coalescing(Config {
+        no_document_all: true
+    }),
+    object_operand_no_document_all,
+    "
+    foo = new Foo() ?? bar;
+    baz = ({ a: 1 }) ?? {};
+    qux = (a, []) ?? b;
+    quux = a.b ?? c;
+    ",
+    "
+    foo = new Foo() || bar;
+    baz = { a: 1 } || {};
+    qux = (a, []) || b;
+    var ref;
+    quux = (ref = a.b) != null ? ref : c;
+    "
+);
+
+test!(
+    syntax(),
+    |_| tr(()),
+    assign_member,
+    "
+    a.b ??= c;
+    ",
+    "
+    var _a, _b;
+    (_b = (_a = a).b) !== null && _b !== void 0 ? _b : _a.b = c;
+    "
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(()),
+    assign_member_exec,
+    "
+    let calls = 0;
+    const arr = [null, 1];
+    const obj = {
+        get arr() {
+            calls++;
+            return arr;
+        },
+    };
+    let i = 0;
+
+    expect(obj.arr[i++] ??= 2).toBe(2);
+    expect(arr).toEqual([2, 1]);
+    expect(i).toBe(1);
+    expect(calls).toBe(1);
+
+    expect(obj.arr[i++] ??= 3).toBe(1);
+    expect(arr).toEqual([2, 1]);
+    expect(i).toBe(2);
+    expect(calls).toBe(2);
+    "
+);
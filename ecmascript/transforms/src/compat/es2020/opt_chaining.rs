@@ -98,6 +98,7 @@ impl OptChaining {
     fn handle_unary(&mut self, e: UnaryExpr) -> Expr {
         let span = e.span;
 
+        // `delete a?.b` is `true` if `a` is nullish.
         if let op!("delete") = e.op {
             match *e.arg {
                 Expr::OptChain(o) => {
@@ -105,6 +106,7 @@ impl OptChaining {
 
                     return CondExpr {
                         span,
+                        cons: Box::new(Expr::Lit(Lit::Bool(Bool { span, value: true }))),
                         alt: Box::new(Expr::Unary(UnaryExpr {
                             span,
                             op: op!("delete"),
@@ -126,6 +128,7 @@ impl OptChaining {
 
                     return CondExpr {
                         span: DUMMY_SP,
+                        cons: Box::new(Expr::Lit(Lit::Bool(Bool { span, value: true }))),
                         alt: Box::new(Expr::Unary(UnaryExpr {
                             span,
                             op: op!("delete"),
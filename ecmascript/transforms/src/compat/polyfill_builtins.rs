@@ -0,0 +1,601 @@
+use crate::util::ExprFactory;
+use std::mem::replace;
+use swc_atoms::js_word;
+use swc_common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_parser::JscTarget;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Rewrites calls to built-in methods which are newer than the target into
+/// inline equivalents.
+///
+/// ```js
+/// [1, 2].at(-1);
+/// [[1], [2]].flat();
+/// [1, NaN].includes(NaN);
+/// Object.hasOwn(obj, "foo");
+/// Object.fromEntries(map);
+/// Object.entries(obj);
+/// `${id}`.padStart(4, "0");
+/// "a.b".replaceAll(".", "/");
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// _at([1, 2], -1);
+/// _flat([[1], [2]]);
+/// _includes([1, NaN], NaN);
+/// Object.prototype.hasOwnProperty.call(obj, "foo");
+/// _fromEntries(map);
+/// _objectEntries(obj);
+/// _padStart(`${id}`, 4, "0");
+/// "a.b".replace(/\./g, "/");
+/// ```
+///
+/// A call is rewritten only if the method is known to be the built-in one,
+/// i.e. `.at()` is called on an array, string or template literal, `.flat()`,
+/// `.flatMap()` and `.includes()` are called on an array literal and `Object`
+/// is a reference to the global. `.includes()` is not rewritten to `indexOf`,
+/// as it finds `NaN` while `indexOf` doesn't. `.padStart()`, `.padEnd()` and
+/// `.replaceAll()` are rewritten if they are called on a string or template
+/// literal, as other objects may have methods with the same names.
+/// `.replaceAll()` is rewritten only if the search argument is a string
+/// literal, so calls searching with a regular expression or a non-literal
+/// string are not changed.
+///
+/// Methods which exist in `target` are not rewritten.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver.
+pub fn polyfill_builtins(target: JscTarget) -> impl Fold {
+    as_folder(PolyfillBuiltins { target })
+}
+
+struct PolyfillBuiltins {
+    target: JscTarget,
+}
+
+impl VisitMut for PolyfillBuiltins {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let call = match e {
+            Expr::Call(call) => call,
+            _ => return,
+        };
+
+        let builtin = match builtin(call) {
+            Some(builtin) => builtin,
+            None => return,
+        };
+        if self.target >= builtin.since() {
+            return;
+        }
+
+        match builtin {
+            Builtin::At => prepend_receiver(call, helper!(at, "at")),
+            Builtin::Flat => prepend_receiver(call, helper!(flat, "flat")),
+            Builtin::FlatMap => prepend_receiver(call, helper!(flat_map, "flatMap")),
+            Builtin::Includes => prepend_receiver(call, helper!(includes, "includes")),
+
+            Builtin::PadEnd => prepend_receiver(call, helper!(pad_end, "padEnd")),
+            Builtin::PadStart => prepend_receiver(call, helper!(pad_start, "padStart")),
+
+            Builtin::Entries => call.callee = helper!(object_entries, "objectEntries"),
+            Builtin::FromEntries => call.callee = helper!(from_entries, "fromEntries"),
+            Builtin::Values => call.callee = helper!(object_values, "objectValues"),
+
+            Builtin::HasOwn => {
+                call.callee =
+                    member_expr!(DUMMY_SP, Object.prototype.hasOwnProperty.call).as_callee();
+            }
+
+            Builtin::ReplaceAll => {
+                if let ExprOrSuper::Expr(callee) = &mut call.callee {
+                    if let Expr::Member(MemberExpr { prop, .. }) = &mut **callee {
+                        *prop = Box::new(Expr::Ident(quote_ident!("replace")));
+                    }
+                }
+
+                if let Expr::Lit(Lit::Str(search)) = &*call.args[0].expr {
+                    call.args[0].expr = Box::new(Expr::Lit(Lit::Regex(Regex {
+                        span: search.span,
+                        exp: escape_regex(&search.value).into(),
+                        flags: "g".into(),
+                    })));
+                }
+            }
+        }
+    }
+}
+
+/// Replaces the callee of `call`, which is a method call, with `helper`, and
+/// passes the receiver as the first argument.
+fn prepend_receiver(call: &mut CallExpr, helper: ExprOrSuper) {
+    let callee = replace(&mut call.callee, helper);
+
+    if let ExprOrSuper::Expr(callee) = callee {
+        if let Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            ..
+        }) = *callee
+        {
+            call.args.insert(0, obj.as_arg());
+        }
+    }
+}
+
+enum Builtin {
+    /// `Array.prototype.at` and `String.prototype.at`
+    At,
+    /// `Object.entries`
+    Entries,
+    /// `Array.prototype.flat`
+    Flat,
+    /// `Array.prototype.flatMap`
+    FlatMap,
+    /// `Object.fromEntries`
+    FromEntries,
+    /// `Object.hasOwn`
+    HasOwn,
+    /// `Array.prototype.includes`
+    Includes,
+    /// `String.prototype.padEnd`
+    PadEnd,
+    /// `String.prototype.padStart`
+    PadStart,
+    /// `String.prototype.replaceAll` with a string literal as the search
+    /// argument.
+    ReplaceAll,
+    /// `Object.values`
+    Values,
+}
+
+impl Builtin {
+    /// Returns the first target which has the method.
+    fn since(&self) -> JscTarget {
+        match self {
+            Builtin::At | Builtin::HasOwn => JscTarget::Es2022,
+            Builtin::ReplaceAll => JscTarget::Es2021,
+            Builtin::Flat | Builtin::FlatMap | Builtin::FromEntries => JscTarget::Es2019,
+            Builtin::Entries | Builtin::PadEnd | Builtin::PadStart | Builtin::Values => {
+                JscTarget::Es2017
+            }
+            Builtin::Includes => JscTarget::Es2016,
+        }
+    }
+}
+
+fn builtin(call: &CallExpr) -> Option<Builtin> {
+    let (obj, prop) = match &call.callee {
+        ExprOrSuper::Expr(callee) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed: false,
+                ..
+            }) => match &**prop {
+                Expr::Ident(prop) => (obj, &prop.sym),
+                _ => return None,
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match (&**obj, &**prop) {
+        (Expr::Array(..), "at") | (Expr::Lit(Lit::Str(..)), "at") | (Expr::Tpl(..), "at") => {
+            Some(Builtin::At)
+        }
+        (Expr::Array(..), "flat") => Some(Builtin::Flat),
+        (Expr::Array(..), "flatMap") => Some(Builtin::FlatMap),
+        (Expr::Array(..), "includes") => Some(Builtin::Includes),
+
+        (
+            Expr::Ident(Ident {
+                sym: js_word!("Object"),
+                span,
+                ..
+            }),
+            "hasOwn",
+        ) if span.ctxt() == SyntaxContext::empty() => Some(Builtin::HasOwn),
+
+        (
+            Expr::Ident(Ident {
+                sym: js_word!("Object"),
+                span,
+                ..
+            }),
+            "fromEntries",
+        ) if span.ctxt() == SyntaxContext::empty() => Some(Builtin::FromEntries),
+
+        (
+            Expr::Ident(Ident {
+                sym: js_word!("Object"),
+                span,
+                ..
+            }),
+            "entries",
+        ) if span.ctxt() == SyntaxContext::empty() => Some(Builtin::Entries),
+
+        (
+            Expr::Ident(Ident {
+                sym: js_word!("Object"),
+                span,
+                ..
+            }),
+            "values",
+        ) if span.ctxt() == SyntaxContext::empty() => Some(Builtin::Values),
+
+        (Expr::Lit(Lit::Str(..)), "padEnd") | (Expr::Tpl(..), "padEnd") => Some(Builtin::PadEnd),
+        (Expr::Lit(Lit::Str(..)), "padStart") | (Expr::Tpl(..), "padStart") => {
+            Some(Builtin::PadStart)
+        }
+
+        (Expr::Lit(Lit::Str(..)), "replaceAll") | (Expr::Tpl(..), "replaceAll") => {
+            match &*call.args {
+                [ExprOrSpread {
+                    spread: None,
+                    expr: search,
+                }, ExprOrSpread { spread: None, .. }] => match &**search {
+                    Expr::Lit(Lit::Str(..)) => Some(Builtin::ReplaceAll),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+
+        _ => None,
+    }
+}
+
+/// Returns the source of a regular expression matching `s`.
+fn escape_regex(s: &str) -> String {
+    if s.is_empty() {
+        return "(?:)".into();
+    }
+
+    let mut buf = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' | '^' | '$' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+            | '/' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\u{2028}' => buf.push_str("\\u2028"),
+            '\u{2029}' => buf.push_str("\\u2029"),
+            _ => buf.push(c),
+        }
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::polyfill_builtins;
+    use crate::resolver;
+    use swc_common::chain;
+    use swc_ecma_parser::JscTarget;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        array_at,
+        "const last = [1, 2].at(-1);",
+        "
+const last = _at([1, 2], -1);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es2019)),
+        target,
+        "
+const a = [1, 2].at(-1);
+const b = [[1], [2]].flat();
+const c = [1, 2].includes(x);
+const d = Object.entries(obj);
+const e = Object.hasOwn(obj, 'foo');
+",
+        "
+const a = _at([1, 2], -1);
+const b = [[1], [2]].flat();
+const c = [1, 2].includes(x);
+const d = Object.entries(obj);
+const e = Object.prototype.hasOwnProperty.call(obj, 'foo');
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es2021)),
+        target_es2021,
+        "
+const a = [1, 2].at(-1);
+const b = Object.hasOwn(obj, 'foo');
+const c = 'a.b'.replaceAll('.', '/');
+",
+        "
+const a = _at([1, 2], -1);
+const b = Object.prototype.hasOwnProperty.call(obj, 'foo');
+const c = 'a.b'.replaceAll('.', '/');
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es2022)),
+        target_es2022,
+        "
+const a = [1, 2].at(-1);
+const b = Object.hasOwn(obj, 'foo');
+const c = 'a.b'.replaceAll('.', '/');
+",
+        "
+const a = [1, 2].at(-1);
+const b = Object.hasOwn(obj, 'foo');
+const c = 'a.b'.replaceAll('.', '/');
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        unknown_receiver,
+        "foo.at(-1); foo.flat(); foo.flatMap(f); foo.includes(x); 'abc'.includes('b');",
+        "foo.at(-1); foo.flat(); foo.flatMap(f); foo.includes(x); 'abc'.includes('b');"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        array_includes,
+        "
+const a = [1, 2].includes(x);
+const b = [1, 2].includes(x, 1);
+",
+        "
+const a = _includes([1, 2], x);
+const b = _includes([1, 2], x, 1);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        array_flat,
+        "
+const a = [[1], [2]].flat();
+const b = [[1], [[2]]].flat(Infinity);
+const c = [1, 2].flatMap((x) => [x, x * 2]);
+",
+        "
+const a = _flat([[1], [2]]);
+const b = _flat([[1], [[2]]], Infinity);
+const c = _flatMap([1, 2], (x) => [x, x * 2]);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        object_has_own,
+        "Object.hasOwn(obj, 'foo');",
+        "Object.prototype.hasOwnProperty.call(obj, 'foo');"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        object_has_own_shadowed,
+        "
+function foo(Object) {
+    return Object.hasOwn(obj, 'foo');
+}
+",
+        "
+function foo(Object) {
+    return Object.hasOwn(obj, 'foo');
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        object_from_entries,
+        "
+const a = Object.fromEntries(map);
+function foo(Object) {
+    return Object.fromEntries(map);
+}
+",
+        "
+const a = _fromEntries(map);
+function foo(Object) {
+    return Object.fromEntries(map);
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        object_entries_values,
+        "
+const a = Object.entries(obj);
+const b = Object.values(obj);
+function foo(Object) {
+    return [Object.entries(obj), Object.values(obj)];
+}
+",
+        "
+const a = _objectEntries(obj);
+const b = _objectValues(obj);
+function foo(Object) {
+    return [Object.entries(obj), Object.values(obj)];
+}
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        object_entries_values_exec,
+        "
+const sym = Symbol('sym');
+const obj = Object.create({ inherited: 1 });
+obj.b = 1;
+obj[sym] = 2;
+obj[2] = 3;
+obj.a = 4;
+obj[1] = 5;
+Object.defineProperty(obj, 'hidden', { value: 6, enumerable: false });
+
+expect(Object.entries(obj)).toEqual([['1', 5], ['2', 3], ['b', 1], ['a', 4]]);
+expect(Object.values(obj)).toEqual([5, 3, 1, 4]);
+
+expect(Object.entries('ab')).toEqual([['0', 'a'], ['1', 'b']]);
+expect(Object.values('ab')).toEqual(['a', 'b']);
+expect(Object.entries(1)).toEqual([]);
+expect(() => Object.entries(null)).toThrow(TypeError);
+expect(() => Object.values(undefined)).toThrow(TypeError);
+
+const removing = {
+    get a() {
+        delete this.b;
+        return 1;
+    },
+    b: 2,
+    c: 3,
+};
+expect(Object.entries(removing)).toEqual([['a', 1], ['c', 3]]);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        replace_all,
+        r#"
+"a".replaceAll("a.b*(c)", "d");
+`${b}`.replaceAll("/", (m) => m + m);
+"c".replaceAll(/x/g, "y");
+"d".replaceAll(search, "y");
+e.replaceAll("x", "y");
+"#,
+        r#"
+"a".replace(/a\.b\*\(c\)/g, "d");
+`${b}`.replace(/\//g, (m) => m + m);
+"c".replaceAll(/x/g, "y");
+"d".replaceAll(search, "y");
+e.replaceAll("x", "y");
+"#
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        pad,
+        "
+const a = `${id}`.padStart(4, '0');
+const b = 'name'.padEnd(10);
+const c = _.padStart('abc', 6);
+const d = name.padEnd(10);
+",
+        "
+const a = _padStart(`${id}`, 4, '0');
+const b = _padEnd('name', 10);
+const c = _.padStart('abc', 6);
+const d = name.padEnd(10);
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        pad_exec,
+        "
+expect('5'.padStart(3, '0')).toBe('005');
+expect('5'.padEnd(3, '0')).toBe('500');
+expect('abc'.padStart(5)).toBe('  abc');
+expect('abc'.padEnd(5)).toBe('abc  ');
+
+// The target length is less than the length of the string.
+expect('abcdef'.padStart(3, '0')).toBe('abcdef');
+expect('abcdef'.padEnd(-1, '0')).toBe('abcdef');
+
+// An empty pad string.
+expect('abc'.padStart(6, '')).toBe('abc');
+expect('abc'.padEnd(6, '')).toBe('abc');
+
+// A pad string truncated to fit.
+expect('abc'.padStart(8, '123')).toBe('12312abc');
+expect('abc'.padEnd(8, '123')).toBe('abc12312');
+expect('abc'.padStart(4, 'xyz')).toBe('xabc');
+
+expect('abc'.padStart(5.9, '-')).toBe('--abc');
+expect('abc'.padEnd(NaN, '-')).toBe('abc');
+expect(`${12}`.padStart(4, 0)).toBe('0012');
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), polyfill_builtins(JscTarget::Es5)),
+        exec,
+        "
+expect([1, 2].at(-1)).toBe(2);
+expect([1, 2].at(0)).toBe(1);
+expect([1, 2].at(2)).toBe(undefined);
+expect([1, 2].at(-3)).toBe(undefined);
+expect([1, 2].at(1.5)).toBe(2);
+expect([1, 2].at()).toBe(1);
+expect('abc'.at(-1)).toBe('c');
+
+expect([1, [2, [3, [4]]]].flat()).toEqual([1, 2, [3, [4]]]);
+expect([1, [2, [3, [4]]]].flat(2)).toEqual([1, 2, 3, [4]]);
+expect([1, [2, [3, [4]]]].flat(Infinity)).toEqual([1, 2, 3, 4]);
+expect([1, [2]].flat(0)).toEqual([1, [2]]);
+expect([1, , [2, , 3]].flat()).toEqual([1, 2, 3]);
+expect([1, 2].flatMap((x, i) => [x, i])).toEqual([1, 0, 2, 1]);
+expect([1, 2].flatMap((x) => [[x]])).toEqual([[1], [2]]);
+expect([1, 2].flatMap((x) => x * 2)).toEqual([2, 4]);
+expect([1].flatMap(function () { return this.v; }, { v: 3 })).toEqual([3]);
+
+expect([NaN].includes(NaN)).toBe(true);
+expect([1, 2, 3].includes(2)).toBe(true);
+expect([1, 2, 3].includes(4)).toBe(false);
+expect([1, 2, 3].includes(1, 1)).toBe(false);
+expect([1, 2, 3].includes(3, -1)).toBe(true);
+expect([1, 2, 3].includes(1, -10)).toBe(true);
+expect([0].includes(-0)).toBe(true);
+expect([, 1].includes(undefined)).toBe(true);
+expect(['1'].includes(1)).toBe(false);
+
+const obj = Object.create({ inherited: 1 });
+obj.own = 1;
+expect(Object.hasOwn(obj, 'own')).toBe(true);
+expect(Object.hasOwn(obj, 'inherited')).toBe(false);
+
+expect(Object.fromEntries(new Map([['a', 1], ['b', 2]]))).toEqual({ a: 1, b: 2 });
+expect(Object.fromEntries([['a', 1], ['a', 2]])).toEqual({ a: 2 });
+expect(Object.getPrototypeOf(Object.fromEntries([['__proto__', 1]]))).toBe(Object.prototype);
+expect(() => Object.fromEntries([1])).toThrow(TypeError);
+
+expect('a.b.c'.replaceAll('.', '/')).toBe('a/b/c');
+expect('[x]+[x]'.replaceAll('[x]', '$&$&')).toBe('[x][x]+[x][x]');
+expect('a\\\\b'.replaceAll('\\\\', '|')).toBe('a|b');
+expect('ab'.replaceAll('', '-')).toBe('-a-b-');
+expect('a^b'.replaceAll('^', (m, i) => i)).toBe('a1b');
+"
+    );
+}
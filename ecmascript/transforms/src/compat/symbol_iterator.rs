@@ -0,0 +1,199 @@
+use swc_atoms::js_word;
+use swc_common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Replaces `Symbol.iterator` with a helper which falls back to the
+/// `"@@iterator"` key if `Symbol` is not available.
+///
+/// ```js
+/// class Foo {
+///     [Symbol.iterator]() {}
+/// }
+/// foo[Symbol.iterator]();
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// class Foo {
+///     [_symbolIterator()]() {}
+/// }
+/// foo[_symbolIterator()]();
+/// ```
+///
+/// Iterables defined and consumed by the same compiled code work on engines
+/// without `Symbol`, as both sides use the same key. Helpers which iterate
+/// objects, like `_slicedToArray`, fall back to the same key.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver, and after passes which
+/// emit `Symbol.iterator` like `for_of`.
+pub fn symbol_iterator() -> impl Fold {
+    as_folder(SymbolIterator)
+}
+
+struct SymbolIterator;
+
+impl VisitMut for SymbolIterator {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if is_symbol_iterator(e) {
+            *e = Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(symbol_iterator, "symbolIterator"),
+                args: vec![],
+                type_args: Default::default(),
+            });
+        }
+    }
+}
+
+fn is_symbol_iterator(e: &Expr) -> bool {
+    match e {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => match (&**obj, &**prop) {
+            (Expr::Ident(obj), Expr::Ident(prop)) => {
+                obj.sym == js_word!("Symbol")
+                    && obj.span.ctxt() == SyntaxContext::empty()
+                    && &*prop.sym == "iterator"
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::symbol_iterator;
+    use crate::{compat::es2015::destructuring, resolver};
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), symbol_iterator()),
+        class_method,
+        "
+class Foo {
+    [Symbol.iterator]() {}
+}
+foo[Symbol.iterator]();
+",
+        "
+class Foo {
+    [_symbolIterator()]() {}
+}
+foo[_symbolIterator()]();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), symbol_iterator()),
+        shadowed,
+        "
+function foo(Symbol) {
+    return bar[Symbol.iterator];
+}
+",
+        "
+function foo(Symbol) {
+    return bar[Symbol.iterator];
+}
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), symbol_iterator()),
+        custom_iterable,
+        "
+function makeRange() {
+    return class Range {
+        constructor(n) {
+            this.n = n;
+        }
+
+        [Symbol.iterator]() {
+            let i = 0;
+            const n = this.n;
+            return {
+                next: () => (i < n ? { value: i++, done: false } : { value: undefined, done: true \
+         }),
+            };
+        }
+    };
+}
+
+function collect(iterable) {
+    const values = [];
+    const it = iterable[Symbol.iterator]();
+    for (let result = it.next(); !result.done; result = it.next()) {
+        values.push(result.value);
+    }
+    return values;
+}
+
+const Range = makeRange();
+expect(collect(new Range(3))).toEqual([0, 1, 2]);
+expect([...new Range(2)]).toEqual([0, 1]);
+
+const RealSymbol = global.Symbol;
+let LegacyRange, values;
+global.Symbol = undefined;
+try {
+    LegacyRange = makeRange();
+    values = collect(new LegacyRange(2));
+} finally {
+    global.Symbol = RealSymbol;
+}
+expect(values).toEqual([0, 1]);
+expect(Object.getOwnPropertyNames(LegacyRange.prototype)).toContain('@@iterator');
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(
+            resolver(),
+            destructuring(Default::default()),
+            symbol_iterator()
+        ),
+        helpers,
+        "
+class Range {
+    constructor(n) {
+        this.n = n;
+    }
+
+    ['@@iterator']() {
+        let i = 0;
+        const n = this.n;
+        return {
+            next: () => (i < n ? { value: i++, done: false } : { value: undefined, done: true }),
+        };
+    }
+}
+
+const RealSymbol = global.Symbol;
+let a, b;
+global.Symbol = undefined;
+try {
+    [a, b] = new Range(3);
+} finally {
+    global.Symbol = RealSymbol;
+}
+expect(a).toBe(0);
+expect(b).toBe(1);
+"
+    );
+}
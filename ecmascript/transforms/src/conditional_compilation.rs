@@ -0,0 +1,260 @@
+use crate::util::HANDLER;
+use std::collections::HashMap;
+use swc_atoms::JsWord;
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos, Span, Spanned,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Keeps or removes statements enclosed by `@if` / `@endif` comments.
+///
+/// ```js
+/// // @if DEBUG
+/// console.log('debug build');
+/// // @endif
+/// run();
+/// ```
+///
+/// becomes `run();` unless `DEBUG` is `true` in `flags`. Flags which are not
+/// in `flags` are treated as `false`.
+///
+/// Directives are read from `comments`, so a string or a template which
+/// looks like a directive has no effect. Directives may be nested, and are
+/// removed from `comments`.
+///
+/// # When to run
+///
+/// This pass should be applied right after parsing, as other passes may move
+/// statements out of the range of a directive.
+pub fn conditional_compilation<'a>(
+    comments: &'a dyn Comments,
+    flags: HashMap<JsWord, bool>,
+) -> impl 'a + Fold {
+    as_folder(ConditionalCompilation {
+        comments,
+        flags,
+        excluded: vec![],
+    })
+}
+
+struct ConditionalCompilation<'a> {
+    comments: &'a dyn Comments,
+    flags: HashMap<JsWord, bool>,
+    /// Source ranges of disabled branches.
+    excluded: Vec<Span>,
+}
+
+enum Directive {
+    If(JsWord),
+    EndIf,
+}
+
+fn parse_directive(c: &Comment) -> Option<Directive> {
+    let text = c.text.trim();
+    if text == "@endif" {
+        return Some(Directive::EndIf);
+    }
+    if text.starts_with("@if ") {
+        return Some(Directive::If(text["@if ".len()..].trim().into()));
+    }
+
+    None
+}
+
+impl ConditionalCompilation<'_> {
+    /// Takes directives from comments in `span`.
+    fn take_directives(&self, span: Span) -> Vec<(Span, Directive)> {
+        let mut directives = vec![];
+        if span.is_dummy() {
+            return directives;
+        }
+
+        let mut take = |pos: BytePos, c: &Comment| {
+            if pos < span.lo || span.hi < pos {
+                return true;
+            }
+            match parse_directive(c) {
+                Some(d) => {
+                    directives.push((c.span, d));
+                    false
+                }
+                None => true,
+            }
+        };
+        self.comments.retain_leading(&mut take);
+        self.comments.retain_trailing(&mut take);
+
+        directives.sort_by_key(|(span, _)| span.lo);
+        directives
+    }
+
+    fn analyze(&mut self, span: Span) {
+        // Start of the branch and whether it's kept.
+        let mut stack: Vec<(Span, bool)> = vec![];
+
+        for (span, directive) in self.take_directives(span) {
+            match directive {
+                Directive::If(flag) => {
+                    let enabled = self.flags.get(&flag).cloned().unwrap_or(false);
+                    stack.push((span, enabled));
+                }
+                Directive::EndIf => match stack.pop() {
+                    Some((start, enabled)) => {
+                        if !enabled {
+                            self.excluded.push(start.with_hi(span.hi));
+                        }
+                    }
+                    None => HANDLER.with(|handler| {
+                        handler
+                            .struct_span_err(span, "`@endif` without matching `@if`")
+                            .emit()
+                    }),
+                },
+            }
+        }
+
+        for (span, _) in stack {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(span, "`@if` without matching `@endif`")
+                    .emit()
+            });
+        }
+    }
+
+    fn is_excluded(&self, span: Span) -> bool {
+        self.excluded
+            .iter()
+            .any(|range| range.lo <= span.lo && span.hi <= range.hi)
+    }
+}
+
+impl VisitMut for ConditionalCompilation<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.analyze(m.span);
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.analyze(s.span);
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        if !self.excluded.is_empty() {
+            items.retain(|item| !self.is_excluded(item.span()));
+        }
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        if !self.excluded.is_empty() {
+            stmts.retain(|stmt| !self.is_excluded(stmt.span()));
+        }
+        stmts.visit_mut_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::conditional_compilation;
+    use crate::tests::Tester;
+    use swc_atoms::JsWord;
+    use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+    use swc_ecma_visit::FoldWith;
+
+    fn apply(src: &str, flags: &[(&str, bool)]) -> String {
+        let mut out = String::new();
+        Tester::run(|tester| {
+            let module = tester.parse_module("input.js", src)?;
+
+            let comments = tester.comments.clone();
+            let flags = flags
+                .iter()
+                .map(|&(flag, enabled)| (JsWord::from(flag), enabled))
+                .collect();
+            let module = module.fold_with(&mut conditional_compilation(&*comments, flags));
+
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: tester.cm.clone(),
+                    comments: Some(&*comments),
+                    ident_hook: None,
+                    wr: Box::new(JsWriter::new(tester.cm.clone(), "\n", &mut buf, None)),
+                };
+                emitter.emit_module(&module).unwrap();
+            }
+            out = String::from_utf8(buf).unwrap();
+
+            Ok(())
+        });
+        out
+    }
+
+    #[test]
+    fn keep_and_strip() {
+        let out = apply(
+            "// @if DEBUG
+log('debug');
+// @endif
+function run() {
+    // @if LEGACY
+    legacy();
+    // @endif
+    // kept
+    modern();
+}",
+            &[("DEBUG", true), ("LEGACY", false)],
+        );
+
+        assert_eq!(
+            out.trim(),
+            "log('debug');
+function run() {
+    // kept
+    modern();
+}"
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let out = apply(
+            "// @if A
+a();
+/* @if B */
+b();
+/* @endif */
+// @endif
+c();",
+            &[("B", true)],
+        );
+
+        assert_eq!(out.trim(), "c();");
+    }
+
+    #[test]
+    fn only_comments() {
+        let out = apply(
+            "const a = `
+// @if DEBUG
+`;
+const b = '// @endif';",
+            &[],
+        );
+
+        assert_eq!(
+            out.trim(),
+            "const a = `
+// @if DEBUG
+`;
+const b = '// @endif';"
+        );
+    }
+}
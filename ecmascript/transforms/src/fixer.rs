@@ -256,6 +256,15 @@ impl VisitMut for Fixer<'_> {
         }
     }
 
+    fn visit_mut_assign_pat(&mut self, node: &mut AssignPat) {
+        node.visit_mut_children_with(self);
+
+        // `function foo(a = (b, c)) {}` has one parameter
+        if node.right.is_seq() {
+            self.wrap(&mut node.right);
+        }
+    }
+
     fn visit_mut_assign_pat_prop(&mut self, node: &mut AssignPatProp) {
         node.key.visit_mut_children_with(self);
 
@@ -323,6 +332,15 @@ impl VisitMut for Fixer<'_> {
         }
     }
 
+    fn visit_mut_for_of_stmt(&mut self, node: &mut ForOfStmt) {
+        node.visit_mut_children_with(self);
+
+        // Unlike `for...in`, `for...of` requires an assignment expression.
+        if node.right.is_seq() {
+            self.wrap(&mut node.right);
+        }
+    }
+
     fn visit_mut_if_stmt(&mut self, node: &mut IfStmt) {
         node.visit_mut_children_with(self);
 
@@ -367,6 +385,15 @@ impl VisitMut for Fixer<'_> {
         }
     }
 
+    fn visit_mut_spread_element(&mut self, s: &mut SpreadElement) {
+        s.visit_mut_children_with(self);
+
+        // `{ ...(a, b) }` != `{ ...a, b }`
+        if s.expr.is_seq() {
+            self.wrap(&mut s.expr);
+        }
+    }
+
     fn visit_mut_expr_stmt(&mut self, s: &mut ExprStmt) {
         let old = self.ctx;
         self.ctx = Context::Default;
@@ -385,6 +412,16 @@ impl VisitMut for Fixer<'_> {
         self.ctx = old;
     }
 
+    fn visit_mut_yield_expr(&mut self, node: &mut YieldExpr) {
+        node.visit_mut_children_with(self);
+
+        // `yield (a, b)` != `yield a, b`
+        match &mut node.arg {
+            Some(arg) if arg.is_seq() => self.wrap(&mut **arg),
+            _ => {}
+        }
+    }
+
     fn visit_mut_module(&mut self, n: &mut Module) {
         debug_assert!(self.span_map.is_empty());
         self.span_map.clear();
@@ -823,6 +860,10 @@ function a() {
 
     test_fixer!(fixer_13, "delete (((1), a), (2));", "delete 2");
 
+    test_fixer!(nested_paren, "((a));", "a;");
+
+    test_fixer!(nested_paren_required, "((a + b)) * c;", "(a + b) * c;");
+
     identical!(issue_231, "'' + (truthy && '?') + truthy;");
 
     identical!(issue_252, "!!(a && b);");
@@ -1013,4 +1054,30 @@ var store = global[SHARED] || (global[SHARED] = {});
     );
 
     identical!(deno_8722, "console.log((true || false) ?? true);");
+
+    identical!(seq_arrow_body, "const f = () => (a(), b);");
+
+    identical!(seq_arrow_body_nested, "const f = () => () => (a(), b);");
+
+    test_fixer!(
+        seq_arrow_body_single,
+        "const f = () => ((a, b()));",
+        "const f = () => b();"
+    );
+
+    identical!(seq_for_init, "for (a(), b; c; d(), e) ;");
+
+    test_fixer!(seq_for_init_paren, "for ((a(), b);;) ;", "for (a(), b;;) ;");
+
+    identical!(seq_for_in_right, "for (x in a(), b) ;");
+
+    identical!(seq_for_of_right, "for (x of (a(), b)) ;");
+
+    identical!(seq_default_param, "function foo(x = (a(), b)) {}");
+
+    identical!(seq_object_spread, "const x = { ...(a(), b) };");
+
+    identical!(seq_yield_arg, "function* foo() { yield (a(), b); }");
+
+    identical!(seq_return_arg, "function foo() { return a(), b; }");
 }
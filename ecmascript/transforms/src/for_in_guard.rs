@@ -0,0 +1,188 @@
+use crate::{
+    ext::MapWithMut,
+    util::{alias_if_required, ExprFactory, StmtLike},
+};
+use std::mem::take;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Skips inherited properties in `for...in` loops.
+///
+/// ```js
+/// for (const key in getObject()) {
+///     use(key);
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// var _obj;
+/// for (const key in _obj = getObject()) {
+///     if (!Object.prototype.hasOwnProperty.call(_obj, key)) continue;
+///     use(key);
+/// }
+/// ```
+///
+/// The guard is the first statement of the loop body. Loops assigning to a
+/// pattern or to a member expression are not changed, as the key cannot be
+/// read without evaluating it again.
+pub fn for_in_guard() -> impl Fold {
+    as_folder(ForInGuard { vars: vec![] })
+}
+
+struct ForInGuard {
+    vars: Vec<VarDeclarator>,
+}
+
+impl ForInGuard {
+    fn visit_mut_stmt_likes<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: StmtLike + VisitMutWith<Self>,
+    {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in stmts.drain(..) {
+            stmt.visit_mut_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: take(&mut self.vars),
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        *stmts = buf;
+    }
+}
+
+/// Returns the identifier which `left` assigns the key to.
+fn key_of(left: &VarDeclOrPat) -> Option<&Ident> {
+    match left {
+        VarDeclOrPat::VarDecl(VarDecl { decls, .. }) if decls.len() == 1 => match &decls[0].name {
+            Pat::Ident(i) => Some(i),
+            _ => None,
+        },
+        VarDeclOrPat::Pat(Pat::Ident(i)) => Some(i),
+        VarDeclOrPat::Pat(Pat::Expr(e)) => match &**e {
+            Expr::Ident(i) => Some(i),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl VisitMut for ForInGuard {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_likes(items)
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_likes(stmts)
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, s: &mut ForInStmt) {
+        s.visit_mut_children_with(self);
+
+        let key = match key_of(&s.left) {
+            Some(key) => key.clone(),
+            None => return,
+        };
+
+        let (obj, aliased) = alias_if_required(&s.right, "_obj");
+        if aliased {
+            self.vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(obj.clone()),
+                init: None,
+                definite: false,
+            });
+            s.right = Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(obj.clone()))),
+                right: s.right.take(),
+            }));
+        }
+
+        // if (!Object.prototype.hasOwnProperty.call(obj, key)) continue;
+        let guard = Stmt::If(IfStmt {
+            span: DUMMY_SP,
+            test: Box::new(Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: op!("!"),
+                arg: Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: member_expr!(DUMMY_SP, Object.prototype.hasOwnProperty.call)
+                        .as_callee(),
+                    args: vec![obj.as_arg(), key.as_arg()],
+                    type_args: Default::default(),
+                })),
+            })),
+            cons: Box::new(Stmt::Continue(ContinueStmt {
+                span: DUMMY_SP,
+                label: None,
+            })),
+            alt: None,
+        });
+
+        match &mut *s.body {
+            Stmt::Block(BlockStmt { stmts, .. }) => stmts.insert(0, guard),
+            _ => {
+                let body = s.body.take();
+                s.body = Box::new(Stmt::Block(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![guard, *body],
+                }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::for_in_guard;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| for_in_guard(),
+        guard,
+        "
+for (const key in obj) {
+    use(key);
+}
+for (key in getObject()) use(key);
+",
+        "
+for (const key in obj) {
+    if (!Object.prototype.hasOwnProperty.call(obj, key)) continue;
+    use(key);
+}
+var _obj;
+for (key in _obj = getObject()) {
+    if (!Object.prototype.hasOwnProperty.call(_obj, key)) continue;
+    use(key);
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| for_in_guard(),
+        member_key,
+        "
+for (a.b in obj) use(a.b);
+",
+        "
+for (a.b in obj) use(a.b);
+"
+    );
+}
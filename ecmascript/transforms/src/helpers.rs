@@ -1,6 +1,12 @@
 use once_cell::sync::Lazy;
 use scoped_tls::scoped_thread_local;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
 use swc_common::{FileName, FilePathMapping, Mark, SourceMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
@@ -18,7 +24,7 @@ macro_rules! enable_helper {
 }
 
 macro_rules! add_to {
-    ($buf:expr, $name:ident, $b:expr, $mark:expr) => {{
+    ($buf:expr, $name:ident, $b:expr, $injected:expr, $mark:expr) => {{
         static STMTS: Lazy<Vec<Stmt>> = Lazy::new(|| {
             let cm = SourceMap::new(FilePathMapping::empty());
             let code = include_str!(concat!("helpers/_", stringify!($name), ".js"));
@@ -44,7 +50,7 @@ macro_rules! add_to {
             stmts
         });
 
-        let enable = $b.load(Ordering::Relaxed);
+        let enable = $b.load(Ordering::Relaxed) && !$injected.swap(true, Ordering::Relaxed);
         if enable {
             $buf.extend(
                 STMTS
@@ -63,11 +69,25 @@ macro_rules! add_to {
 scoped_thread_local!(pub static HELPERS: Helpers);
 
 /// Tracks used helper methods. (e.g. __extends)
+///
+/// Each helper is injected at most once, even if [inject_helpers] is applied
+/// multiple times.
 #[derive(Debug, Default)]
 pub struct Helpers {
     external: bool,
     mark: HelperMark,
     inner: Inner,
+    /// Helpers which are already injected.
+    injected: Inner,
+    /// Helpers imported from `@swc/helpers`, keyed by their local name.
+    imports: Mutex<BTreeMap<&'static str, ExternalHelper>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ExternalHelper {
+    /// Name exported by `@swc/helpers`.
+    imported: &'static str,
+    injected: bool,
 }
 
 impl Helpers {
@@ -76,6 +96,8 @@ impl Helpers {
             external,
             mark: Default::default(),
             inner: Default::default(),
+            injected: Default::default(),
+            imports: Default::default(),
         }
     }
 
@@ -85,6 +107,34 @@ impl Helpers {
     pub(crate) const fn external(&self) -> bool {
         self.external
     }
+
+    /// Records that the helper `imported` of `@swc/helpers` is referenced as
+    /// `local`.
+    pub(crate) fn add_import(&self, imported: &'static str, local: &'static str) {
+        self.imports
+            .lock()
+            .unwrap()
+            .entry(local)
+            .or_insert(ExternalHelper {
+                imported,
+                injected: false,
+            });
+    }
+
+    /// Returns `(local, imported)` pairs which are not injected yet, and marks
+    /// them as injected.
+    fn take_imports(&self) -> Vec<(&'static str, &'static str)> {
+        self.imports
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .filter(|(_, helper)| !helper.injected)
+            .map(|(local, helper)| {
+                helper.injected = true;
+                (*local, helper.imported)
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -95,6 +145,30 @@ impl Default for HelperMark {
     }
 }
 
+/// Helpers which are not exported by `@swc/helpers`. They are inlined even if
+/// external helpers are enabled.
+const INLINED_HELPERS: &[&str] = &[
+    "at",
+    "deep_freeze",
+    "flat",
+    "flat_map",
+    "from_entries",
+    "global_this",
+    "includes",
+    "object_entries",
+    "object_values",
+    "pad_end",
+    "pad_start",
+    "promise_finally",
+    "symbol_iterator",
+];
+
+/// Returns true if the helper `name` should be imported from `@swc/helpers`
+/// when external helpers are enabled.
+pub(crate) fn is_exported(name: &str) -> bool {
+    !INLINED_HELPERS.contains(&name)
+}
+
 macro_rules! define_helpers {
     (
         Helpers {
@@ -125,29 +199,29 @@ macro_rules! define_helpers {
                         self.inner.$name.store(true, Ordering::Relaxed);
                     }
                 )*
+
+                let imports = other.imports.lock().unwrap().clone();
+                for (local, helper) in imports {
+                    self.add_import(helper.imported, local);
+                }
             }
         }
 
         impl InjectHelpers {
-            fn is_helper_used(&self) -> bool{
-                let mut value = false;
-
-                HELPERS.with(|helpers|{
-                    $(
-                        value |= helpers.inner.$name.load(Ordering::Relaxed);
-                    )*
-                });
-
-                value
-            }
-
             fn build_helpers(&self) -> Vec<ModuleItem> {
                 let mut buf = vec![];
 
                 HELPERS.with(|helpers|{
-                    debug_assert!(!helpers.external);
                     $(
-                            add_to!(buf, $name, helpers.inner.$name, helpers.mark.0);
+                        if !helpers.external || !is_exported(stringify!($name)) {
+                            add_to!(
+                                buf,
+                                $name,
+                                helpers.inner.$name,
+                                helpers.injected.$name,
+                                helpers.mark.0
+                            );
+                        }
                     )*
                 });
 
@@ -166,6 +240,7 @@ define_helpers!(Helpers {
     async_generator_delegate: (),
     async_iterator: (),
     async_to_generator: (),
+    at: (),
     await_async_generator: (await_value),
     await_value: (),
     class_call_check: (),
@@ -181,12 +256,18 @@ define_helpers!(Helpers {
     construct: (set_prototype_of),
     create_class: (),
     decorate: (to_array, to_property_key),
+    deep_freeze: (),
     defaults: (),
     define_enumerable_properties: (),
     define_property: (),
     extends: (),
+    flat: (),
+    flat_map: (),
+    from_entries: (),
     get: (super_prop_base),
     get_prototype_of: (),
+    global_this: (),
+    includes: (),
     inherits: (set_prototype_of),
     inherits_loose: (),
     initializer_define_property: (),
@@ -202,10 +283,15 @@ define_helpers!(Helpers {
     new_arrow_check: (),
     non_iterable_rest: (),
     non_iterable_spread: (),
+    object_entries: (),
     object_spread: (define_property),
+    object_values: (),
     object_without_properties: (object_without_properties_loose),
     object_without_properties_loose: (),
+    pad_end: (),
+    pad_start: (),
     possible_constructor_return: (type_of, assert_this_initialized),
+    promise_finally: (),
     read_only_error: (),
     set: (super_prop_base, define_property),
     set_prototype_of: (),
@@ -217,6 +303,7 @@ define_helpers!(Helpers {
         non_iterable_rest
     ),
     super_prop_base: (get_prototype_of),
+    symbol_iterator: (),
     tagged_template_literal: (),
     tagged_template_literal_loose: (),
     // temporal_ref: (temporal_undefined),
@@ -248,20 +335,36 @@ impl InjectHelpers {
     fn mk_helpers(&self) -> Vec<ModuleItem> {
         let (mark, external) = HELPERS.with(|helper| (helper.mark(), helper.external()));
         if external {
-            if self.is_helper_used() {
-                vec![ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                    span: DUMMY_SP,
-                    specifiers: vec![ImportSpecifier::Namespace(ImportStarAsSpecifier {
+            let mut buf = vec![];
+            let specifiers: Vec<_> = HELPERS
+                .with(|helpers| helpers.take_imports())
+                .into_iter()
+                .map(|(local, imported)| {
+                    ImportSpecifier::Named(ImportNamedSpecifier {
                         span: DUMMY_SP,
-                        local: quote_ident!(DUMMY_SP.apply_mark(mark), "swcHelpers"),
-                    })],
+                        local: quote_ident!(DUMMY_SP.apply_mark(mark), local),
+                        imported: if imported == local {
+                            None
+                        } else {
+                            Some(quote_ident!(imported))
+                        },
+                    })
+                })
+                .collect();
+
+            if !specifiers.is_empty() {
+                buf.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers,
                     src: quote_str!("@swc/helpers"),
                     type_only: false,
                     asserts: None,
-                }))]
-            } else {
-                vec![]
+                    with: false,
+                })));
             }
+            buf.extend(self.build_helpers());
+
+            buf
         } else {
             self.build_helpers()
         }
@@ -292,12 +395,12 @@ impl VisitMut for Marker {
 mod tests {
     use super::*;
     use crate::pass::noop;
+    use swc_common::chain;
     use swc_ecma_visit::{as_folder, FoldWith};
 
     #[test]
     fn external_helper() {
-        let input = "_throw()
-swcHelpers._throw()";
+        let input = "foo();";
         crate::tests::Tester::run(|tester| {
             HELPERS.set(&Helpers::new(true), || {
                 let expected = tester.apply_transform(
@@ -306,11 +409,12 @@ swcHelpers._throw()";
                     }),
                     "output.js",
                     Default::default(),
-                    "import * as swcHelpers1 from '@swc/helpers';
-_throw();
-swcHelpers._throw();",
+                    "import { classCallCheck as _classCallCheck, _throw } from '@swc/helpers';
+foo();",
                 )?;
-                enable_helper!(throw);
+                let _ = helper_expr!(throw, "throw");
+                let _ = helper_expr!(class_call_check, "classCallCheck");
+                let _ = helper_expr!(throw, "throw");
 
                 eprintln!("----- Actual -----");
 
@@ -376,6 +480,81 @@ let _throw1 = null;
             false,
         )
     }
+    struct EnableExtends;
+
+    impl VisitMut for EnableExtends {
+        noop_visit_mut_type!();
+
+        fn visit_mut_module(&mut self, _: &mut Module) {
+            enable_helper!(extends);
+        }
+    }
+
+    #[test]
+    fn dedup() {
+        crate::tests::test_transform(
+            Default::default(),
+            |_| {
+                chain!(
+                    as_folder(EnableExtends),
+                    as_folder(InjectHelpers),
+                    as_folder(EnableExtends),
+                    as_folder(InjectHelpers)
+                )
+            },
+            "foo();",
+            r#"function _extends() {
+  _extends = Object.assign || function (target) {
+    for (var i = 1; i < arguments.length; i++) {
+      var source = arguments[i];
+
+      for (var key in source) {
+        if (Object.prototype.hasOwnProperty.call(source, key)) {
+          target[key] = source[key];
+        }
+      }
+    }
+
+    return target;
+  };
+
+  return _extends.apply(this, arguments);
+}
+foo();
+"#,
+            false,
+        )
+    }
+
+    #[test]
+    fn dependencies() {
+        crate::tests::test_transform(
+            Default::default(),
+            |_| {
+                enable_helper!(super_prop_base);
+                chain!(as_folder(InjectHelpers), as_folder(InjectHelpers))
+            },
+            "foo();",
+            "function _getPrototypeOf(o) {
+  _getPrototypeOf = Object.setPrototypeOf ? Object.getPrototypeOf : function _getPrototypeOf(o) {
+    return o.__proto__ || Object.getPrototypeOf(o);
+  };
+  return _getPrototypeOf(o);
+}
+function _superPropBase(object, property) {
+  while (!Object.prototype.hasOwnProperty.call(object, property)) {
+    object = _getPrototypeOf(object);
+    if (object === null) break;
+  }
+
+  return object;
+}
+foo();
+",
+            false,
+        )
+    }
+
     #[test]
     fn use_strict_abort() {
         crate::tests::test_transform(
@@ -15,11 +15,9 @@ function _asyncGeneratorDelegate(inner, awaitWrap) {
 
   ;
 
-  if (typeof Symbol === "function" && Symbol.iterator) {
-    iter[Symbol.iterator] = function () {
-      return this;
-    };
-  }
+  iter[typeof Symbol === "function" && Symbol.iterator || "@@iterator"] = function () {
+    return this;
+  };
 
   iter.next = function (value) {
     if (waiting) {
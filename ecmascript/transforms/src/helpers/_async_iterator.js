@@ -13,5 +13,8 @@ function _asyncIterator(iterable) {
     }
   }
 
+  method = iterable["@@iterator"];
+  if (method != null) return method.call(iterable);
+
   throw new TypeError("Object is not async iterable");
 }
@@ -0,0 +1,15 @@
+function _at(target, index) {
+  var length = target.length;
+  index = Number(index) || 0;
+  index = index < 0 ? Math.ceil(index) : Math.floor(index);
+
+  if (index < 0) {
+    index += length;
+  }
+
+  if (index < 0 || index >= length) {
+    return undefined;
+  }
+
+  return target[index];
+}
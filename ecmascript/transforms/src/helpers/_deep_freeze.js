@@ -0,0 +1,10 @@
+function _deepFreeze(value) {
+  if (value !== null && typeof value === "object" && !Object.isFrozen(value)) {
+    Object.freeze(value);
+    Object.getOwnPropertyNames(value).forEach(function (key) {
+      _deepFreeze(value[key]);
+    });
+  }
+
+  return value;
+}
@@ -0,0 +1,20 @@
+function _flat(target, depth) {
+  depth = depth === undefined ? 1 : Number(depth) || 0;
+  var result = [];
+
+  function flatten(array, depth) {
+    for (var i = 0; i < array.length; i++) {
+      if (!(i in array)) continue;
+
+      var value = array[i];
+      if (depth >= 1 && Array.isArray(value)) {
+        flatten(value, depth - 1);
+      } else {
+        result.push(value);
+      }
+    }
+  }
+
+  flatten(target, depth);
+  return result;
+}
@@ -0,0 +1,18 @@
+function _flatMap(target, callback, thisArg) {
+  var result = [];
+
+  for (var i = 0; i < target.length; i++) {
+    if (!(i in target)) continue;
+
+    var value = callback.call(thisArg, target[i], i, target);
+    if (Array.isArray(value)) {
+      for (var j = 0; j < value.length; j++) {
+        if (j in value) result.push(value[j]);
+      }
+    } else {
+      result.push(value);
+    }
+  }
+
+  return result;
+}
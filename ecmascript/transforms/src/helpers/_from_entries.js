@@ -0,0 +1,37 @@
+function _fromEntries(entries) {
+  var obj = {};
+
+  function add(entry) {
+    if (Object(entry) !== entry) {
+      throw new TypeError("Iterator value " + entry + " is not an entry object");
+    }
+
+    Object.defineProperty(obj, entry[0], {
+      value: entry[1],
+      enumerable: true,
+      configurable: true,
+      writable: true
+    });
+  }
+
+  var method = typeof Symbol !== "undefined" && entries[Symbol.iterator] || entries["@@iterator"];
+
+  if (method != null) {
+    var iterator = method.call(entries);
+
+    for (var step; !(step = iterator.next()).done;) {
+      try {
+        add(step.value);
+      } catch (e) {
+        if (typeof iterator.return === "function") iterator.return();
+        throw e;
+      }
+    }
+  } else {
+    for (var i = 0; i < entries.length; i++) {
+      add(entries[i]);
+    }
+  }
+
+  return obj;
+}
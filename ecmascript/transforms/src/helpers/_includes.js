@@ -0,0 +1,20 @@
+function _includes(target, value, fromIndex) {
+  var length = target.length;
+  var index = Number(fromIndex) || 0;
+  index = index < 0 ? Math.ceil(index) : Math.floor(index);
+
+  if (index < 0) {
+    index = Math.max(index + length, 0);
+  }
+
+  for (; index < length; index++) {
+    var element = target[index];
+
+    // SameValueZero, so that NaN is found.
+    if (element === value || (element !== element && value !== value)) {
+      return true;
+    }
+  }
+
+  return false;
+}
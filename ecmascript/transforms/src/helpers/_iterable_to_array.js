@@ -1,3 +1,3 @@
 function _iterableToArray(iter) {
-  if (Symbol.iterator in Object(iter) || Object.prototype.toString.call(iter) === "[object Arguments]") return Array.from(iter);
+  if (typeof Symbol !== "undefined" && Symbol.iterator in Object(iter) || iter != null && iter["@@iterator"] != null || Object.prototype.toString.call(iter) === "[object Arguments]") return Array.from(iter);
 }
@@ -1,7 +1,7 @@
 function _iterableToArrayLimitLoose(arr, i) {
   var _arr = [];
 
-  for (var _iterator = arr[Symbol.iterator](), _step; !(_step = _iterator.next()).done;) {
+  for (var _iterator = arr[typeof Symbol !== "undefined" && Symbol.iterator || "@@iterator"](), _step; !(_step = _iterator.next()).done;) {
     _arr.push(_step.value);
 
     if (i && _arr.length === i) break;
@@ -0,0 +1,20 @@
+function _objectValues(obj) {
+  if (obj == null) {
+    throw new TypeError("Cannot convert undefined or null to object");
+  }
+
+  var object = Object(obj);
+  var keys = Object.keys(object);
+  var result = [];
+
+  for (var i = 0; i < keys.length; i++) {
+    var key = keys[i];
+
+    // A getter may have removed the property or made it non-enumerable.
+    if (Object.prototype.propertyIsEnumerable.call(object, key)) {
+      result.push(object[key]);
+    }
+  }
+
+  return result;
+}
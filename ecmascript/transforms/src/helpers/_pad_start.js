@@ -0,0 +1,19 @@
+function _padStart(target, maxLength, fillString) {
+  if (target == null) {
+    throw new TypeError("String.prototype.padStart called on null or undefined");
+  }
+
+  var string = String(target);
+  var fillLength = Math.floor(Number(maxLength) || 0) - string.length;
+  var filler = fillString === undefined ? " " : String(fillString);
+
+  if (fillLength <= 0 || filler === "") {
+    return string;
+  }
+
+  while (filler.length < fillLength) {
+    filler += filler;
+  }
+
+  return filler.slice(0, fillLength) + string;
+}
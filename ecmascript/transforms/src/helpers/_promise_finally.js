@@ -0,0 +1,18 @@
+function _promiseFinally(promise, onFinally) {
+  if (typeof onFinally !== "function") {
+    return promise.then(onFinally, onFinally);
+  }
+
+  return promise.then(
+    function (value) {
+      return Promise.resolve(onFinally()).then(function () {
+        return value;
+      });
+    },
+    function (reason) {
+      return Promise.resolve(onFinally()).then(function () {
+        throw reason;
+      });
+    }
+  );
+}
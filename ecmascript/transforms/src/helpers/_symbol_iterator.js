@@ -0,0 +1,3 @@
+function _symbolIterator() {
+  return typeof Symbol === "function" && Symbol.iterator ? Symbol.iterator : "@@iterator";
+}
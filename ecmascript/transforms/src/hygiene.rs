@@ -4,6 +4,7 @@ use crate::{
     scope::{IdentType, ScopeKind},
 };
 use fxhash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
 use smallvec::{smallvec, SmallVec};
 use std::cell::RefCell;
 use swc_atoms::JsWord;
@@ -27,7 +28,22 @@ impl ToBoxedStr for JsWord {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Keeps `name` of renamed classes by defining it with
+    /// `Object.defineProperty`.
+    #[serde(default)]
+    pub keep_classnames: bool,
+
+    /// Keeps `name` of renamed functions by defining it with
+    /// `Object.defineProperty`.
+    #[serde(default)]
+    pub keep_fnames: bool,
+}
+
 struct Hygiene<'a> {
+    config: Config,
     current: Scope<'a>,
     ident_type: IdentType,
 }
@@ -153,8 +169,18 @@ impl<'a> Hygiene<'a> {
 }
 
 pub fn hygiene() -> impl Fold + 'static {
+    hygiene_with_config(Default::default())
+}
+
+/// Renames identifiers which would conflict if their syntax contexts were
+/// removed.
+///
+/// Renaming a class or a function changes its `name`, which can be
+/// preserved with [Config].
+pub fn hygiene_with_config(config: Config) -> impl Fold + 'static {
     chain!(
         as_folder(Hygiene {
+            config,
             current: Default::default(),
             ident_type: IdentType::Ref,
         }),
@@ -182,7 +208,7 @@ impl<'a> Hygiene<'a> {
         if ops.rename.is_empty() {
             return;
         }
-        node.visit_mut_with(&mut Operator(&ops))
+        node.visit_mut_with(&mut Operator(&ops, self.config))
     }
 }
 
@@ -196,6 +222,7 @@ impl<'a> Hygiene<'a> {
         }
 
         let mut folder = Hygiene {
+            config: self.config,
             current: Scope::new(ScopeKind::Fn, Some(&self.current)),
             ident_type: IdentType::Ref,
         };
@@ -484,6 +511,7 @@ impl<'a> VisitMut for Hygiene<'a> {
 
     fn visit_mut_arrow_expr(&mut self, node: &mut ArrowExpr) {
         let mut folder = Hygiene {
+            config: self.config,
             current: Scope::new(ScopeKind::Fn, Some(&self.current)),
             ident_type: IdentType::Ref,
         };
@@ -499,6 +527,7 @@ impl<'a> VisitMut for Hygiene<'a> {
 
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         let mut folder = Hygiene {
+            config: self.config,
             current: Scope::new(ScopeKind::Block, Some(&self.current)),
             ident_type: IdentType::Ref,
         };
@@ -509,6 +538,7 @@ impl<'a> VisitMut for Hygiene<'a> {
 
     fn visit_mut_catch_clause(&mut self, c: &mut CatchClause) {
         let mut folder = Hygiene {
+            config: self.config,
             current: Scope::new(ScopeKind::Fn, Some(&self.current)),
             ident_type: IdentType::Ref,
         };
@@ -594,6 +624,7 @@ impl<'a> VisitMut for Hygiene<'a> {
 
     fn visit_mut_object_lit(&mut self, node: &mut ObjectLit) {
         let mut folder = Hygiene {
+            config: self.config,
             current: Scope::new(ScopeKind::Block, Some(&self.current)),
             ident_type: IdentType::Ref,
         };
@@ -1,4 +1,8 @@
-use crate::ext::MapWithMut;
+use super::Config;
+use crate::{
+    ext::MapWithMut,
+    util::{prepend_stmts, ExprFactory},
+};
 use fxhash::FxHashMap;
 use swc_atoms::JsWord;
 use swc_common::{util::move_map::MoveMap, Spanned, SyntaxContext, DUMMY_SP};
@@ -10,16 +14,32 @@ pub(super) struct Operations {
     pub rename: FxHashMap<Id, JsWord>,
 }
 
-pub(super) struct Operator<'a>(pub &'a Operations);
+pub(super) struct Operator<'a>(pub &'a Operations, pub Config);
 
 impl<'a> VisitMut for Operator<'a> {
     noop_visit_mut_type!();
 
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
         let mut stmts = Vec::with_capacity(items.len());
+        // Names of function declarations are defined at the start, because
+        // their bindings are hoisted.
+        let mut hoisted = vec![];
 
         for mut item in items.take() {
             let span = item.span();
+            let kept_name = match &item {
+                ModuleItem::Stmt(Stmt::Decl(decl))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    match (decl, self.keep_name(decl)) {
+                        (Decl::Fn(..), Some(stmt)) => {
+                            hoisted.push(ModuleItem::Stmt(stmt));
+                            None
+                        }
+                        (_, kept_name) => kept_name,
+                    }
+                }
+                _ => None,
+            };
 
             macro_rules! export {
                 ($orig:expr, $ident:expr) => {
@@ -143,11 +163,65 @@ impl<'a> VisitMut for Operator<'a> {
                     stmts.push(item)
                 }
             }
+
+            stmts.extend(kept_name.map(ModuleItem::Stmt));
         }
 
+        prepend_stmts(&mut stmts, hoisted.into_iter());
+
         *items = stmts
     }
 
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        if !self.1.keep_classnames && !self.1.keep_fnames {
+            stmts.visit_mut_children_with(self);
+            return;
+        }
+
+        let mut buf = Vec::with_capacity(stmts.len());
+        let mut hoisted = vec![];
+        for mut stmt in stmts.take() {
+            let kept_name = match &stmt {
+                Stmt::Decl(decl) => match (decl, self.keep_name(decl)) {
+                    (Decl::Fn(..), Some(stmt)) => {
+                        hoisted.push(stmt);
+                        None
+                    }
+                    (_, kept_name) => kept_name,
+                },
+                _ => None,
+            };
+
+            stmt.visit_mut_with(self);
+            buf.push(stmt);
+            buf.extend(kept_name);
+        }
+
+        prepend_stmts(&mut buf, hoisted.into_iter());
+
+        *stmts = buf;
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        let name = match expr {
+            Expr::Class(ClassExpr {
+                ident: Some(ident), ..
+            }) if self.1.keep_classnames => Some(ident),
+            Expr::Fn(FnExpr {
+                ident: Some(ident), ..
+            }) if self.1.keep_fnames => Some(ident),
+            _ => None,
+        }
+        .filter(|ident| self.0.rename.contains_key(&ident.to_id()))
+        .map(|ident| ident.sym.clone());
+
+        expr.visit_mut_children_with(self);
+
+        if let Some(name) = name {
+            *expr = define_name(expr.take(), name);
+        }
+    }
+
     /// Preserve key of properties.
     fn visit_mut_assign_pat_prop(&mut self, p: &mut AssignPatProp) {
         match &mut p.value {
@@ -305,6 +379,28 @@ impl VisitMut for VarFolder<'_, '_> {
 }
 
 impl<'a> Operator<'a> {
+    /// Creates `Object.defineProperty(Foo1, "name", { value: "Foo" })` if
+    /// `decl` will be renamed and its name should be kept.
+    fn keep_name(&self, decl: &Decl) -> Option<Stmt> {
+        let ident = match decl {
+            Decl::Class(c) if self.1.keep_classnames => &c.ident,
+            Decl::Fn(f) if self.1.keep_fnames => &f.ident,
+            _ => return None,
+        };
+        let renamed = self.0.rename.get(&ident.to_id())?;
+
+        Some(Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(define_name(
+                Expr::Ident(Ident::new(
+                    renamed.clone(),
+                    ident.span.with_ctxt(SyntaxContext::empty()),
+                )),
+                ident.sym.clone(),
+            )),
+        }))
+    }
+
     /// Returns `Ok(renamed_ident)` if ident should be renamed.
     fn rename_ident(&mut self, ident: &mut Ident) -> Result<(), ()> {
         if let Some(sym) = self.0.rename.get(&ident.to_id()) {
@@ -316,3 +412,25 @@ impl<'a> Operator<'a> {
         Err(())
     }
 }
+
+/// Creates `Object.defineProperty(target, "name", { value: name })`, which
+/// evaluates to `target`.
+fn define_name(target: Expr, name: JsWord) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: member_expr!(DUMMY_SP, Object.defineProperty).as_callee(),
+        args: vec![
+            target.as_arg(),
+            Lit::Str(quote_str!("name")).as_arg(),
+            ObjectLit {
+                span: DUMMY_SP,
+                props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(quote_ident!("value")),
+                    value: Box::new(Expr::Lit(Lit::Str(quote_str!(name)))),
+                })))],
+            }
+            .as_arg(),
+        ],
+        type_args: Default::default(),
+    })
+}
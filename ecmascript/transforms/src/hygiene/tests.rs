@@ -67,6 +67,13 @@ where
 }
 
 fn test_module<F>(op: F, expected: &str)
+where
+    F: FnOnce(&mut crate::tests::Tester<'_>) -> Result<Module, ()>,
+{
+    test_module_with_config(Default::default(), op, expected)
+}
+
+fn test_module_with_config<F>(config: Config, op: F, expected: &str)
 where
     F: FnOnce(&mut crate::tests::Tester<'_>) -> Result<Module, ()>,
 {
@@ -81,7 +88,7 @@ where
             _ => {}
         }
 
-        let module = module.fold_with(&mut hygiene());
+        let module = module.fold_with(&mut hygiene_with_config(config));
 
         let actual = tester.print(&module);
 
@@ -1184,3 +1191,181 @@ fn exported_class_1() {
         export { Foo1 as Foo };",
     );
 }
+
+/// Marks `class Foo` in `f` with a different mark from the outer `Foo`.
+fn shadowed_class_and_fn() -> impl Fold {
+    let outer = Mark::fresh(Mark::root());
+    let inner = Mark::fresh(Mark::root());
+
+    OnceMarker::new(&[
+        ("Foo", &[outer, inner, inner, outer]),
+        ("bar", &[outer, inner, inner, outer]),
+    ])
+}
+
+const SHADOWED_CLASS_AND_FN: &str = "
+class Foo {}
+function bar() {}
+function f() {
+    class Foo {}
+    function bar() {}
+    return [Foo, bar, Foo, bar];
+}
+";
+
+#[test]
+fn keep_classnames() {
+    test_module_with_config(
+        Config {
+            keep_classnames: true,
+            ..Default::default()
+        },
+        |tester| {
+            Ok(tester
+                .parse_module("actual1.js", SHADOWED_CLASS_AND_FN)?
+                .fold_with(&mut shadowed_class_and_fn()))
+        },
+        r#"
+        class Foo {}
+        function bar() {}
+        function f() {
+            class Foo1 {}
+            Object.defineProperty(Foo1, "name", {
+                value: "Foo"
+            });
+            function bar1() {}
+            return [Foo1, bar1, Foo, bar];
+        }
+        "#,
+    );
+}
+
+#[test]
+fn keep_fnames() {
+    test_module_with_config(
+        Config {
+            keep_fnames: true,
+            ..Default::default()
+        },
+        |tester| {
+            Ok(tester
+                .parse_module("actual1.js", SHADOWED_CLASS_AND_FN)?
+                .fold_with(&mut shadowed_class_and_fn()))
+        },
+        r#"
+        class Foo {}
+        function bar() {}
+        function f() {
+            Object.defineProperty(bar1, "name", {
+                value: "bar"
+            });
+            class Foo1 {}
+            function bar1() {}
+            return [Foo1, bar1, Foo, bar];
+        }
+        "#,
+    );
+}
+
+#[test]
+fn keep_names_of_exprs() {
+    test_module_with_config(
+        Config {
+            keep_classnames: true,
+            keep_fnames: true,
+        },
+        |tester| {
+            let outer = Mark::fresh(Mark::root());
+            let inner = Mark::fresh(Mark::root());
+
+            Ok(tester
+                .parse_module(
+                    "actual1.js",
+                    "
+                    class Foo {}
+                    function bar() {}
+                    function f() {
+                        return [class Foo {}, function bar() {}, Foo, bar];
+                    }
+                    ",
+                )?
+                .fold_with(&mut OnceMarker::new(&[
+                    ("Foo", &[outer, inner, outer]),
+                    ("bar", &[outer, inner, outer]),
+                ])))
+        },
+        r#"
+        class Foo {}
+        function bar() {}
+        function f() {
+            return [
+                Object.defineProperty(class Foo1 {}, "name", {
+                    value: "Foo"
+                }),
+                Object.defineProperty(function bar1() {}, "name", {
+                    value: "bar"
+                }),
+                Foo,
+                bar
+            ];
+        }
+        "#,
+    );
+}
+
+test_exec!(
+    Syntax::default(),
+    |_| {
+        let outer = Mark::fresh(Mark::root());
+        let inner = Mark::fresh(Mark::root());
+
+        chain!(
+            OnceMarker::new(&[("bar", &[outer, inner, inner, outer])]),
+            hygiene_with_config(Config {
+                keep_classnames: false,
+                keep_fnames: true,
+            })
+        )
+    },
+    keep_fnames_before_declaration_exec,
+    "
+function bar() {}
+function f() {
+    const name = bar.name;
+    function bar() {}
+    return [name, bar];
+}
+
+const [name, outerBar] = f();
+expect(name).toBe('bar');
+expect(outerBar.name).toBe('bar');
+"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| chain!(
+        shadowed_class_and_fn(),
+        hygiene_with_config(Config {
+            keep_classnames: true,
+            keep_fnames: false,
+        })
+    ),
+    keep_classnames_exec,
+    "
+class Foo {}
+function bar() {}
+function f() {
+    class Foo {}
+    function bar() {}
+    return [Foo, bar, Foo, bar];
+}
+
+const [InnerFoo, innerBar, OuterFoo, outerBar] = f();
+expect(InnerFoo).not.toBe(OuterFoo);
+expect(InnerFoo.name).toBe('Foo');
+expect(OuterFoo.name).toBe('Foo');
+expect(innerBar).not.toBe(outerBar);
+expect(innerBar.name).toBe('bar1');
+"
+);
@@ -8,9 +8,13 @@ extern crate swc_ecma_utils;
 #[cfg(feature = "const-modules")]
 pub use self::const_modules::const_modules;
 pub use self::{
+    conditional_compilation::conditional_compilation,
     fixer::fixer,
-    hygiene::hygiene,
+    for_in_guard::for_in_guard,
+    hygiene::{hygiene, hygiene_with_config},
+    normalize::normalize_assignment_targets,
     resolver::{resolver, resolver_with_mark},
+    transform_runtime::transform_runtime,
 };
 
 #[macro_use]
@@ -24,12 +28,15 @@ mod quote;
 #[macro_use]
 pub mod helpers;
 #[macro_use]
-mod hygiene;
+pub mod hygiene;
 pub mod compat;
+mod conditional_compilation;
 mod const_modules;
 pub mod debug;
 mod fixer;
+mod for_in_guard;
 pub mod modules;
+mod normalize;
 pub mod optimization;
 pub mod pass;
 mod perf;
@@ -37,6 +44,7 @@ pub mod proposals;
 pub mod react;
 pub mod resolver;
 pub mod scope;
+pub mod transform_runtime;
 pub mod typescript;
 pub mod util {
     pub use swc_ecma_utils::*;
@@ -3,4 +3,10 @@ pub mod util;
 pub mod amd;
 pub mod common_js;
 pub mod import_analysis;
+pub mod import_meta_paths;
+pub mod inline_json;
+pub mod resolve_imports;
+pub mod strict_mode;
+pub mod top_level_await;
 pub mod umd;
+pub mod wrap_in_function;
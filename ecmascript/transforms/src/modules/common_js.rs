@@ -3,13 +3,13 @@ use super::util::{
     define_es_module, define_property, has_use_strict, initialize_to_undefined, make_descriptor,
     make_require_call, use_strict, ModulePass, Scope,
 };
-use crate::util::{var::VarCollector, DestructuringFinder, ExprFactory};
+use crate::util::{find_ids, var::VarCollector, DestructuringFinder, ExprFactory};
 use fxhash::FxHashSet;
 use swc_atoms::js_word;
 use swc_common::{Mark, Span, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_utils::ident::IdentLike;
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitWith};
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
 
 pub fn common_js(root_mark: Mark, config: Config) -> impl Fold {
     CommonJs {
@@ -43,6 +43,36 @@ impl Fold for CommonJs {
         let mut initialized = FxHashSet::default();
         let mut export_alls = vec![];
 
+        let batch = self.config.batch_exports && can_batch_exports(&items);
+        // Properties of `module.exports` if `batch` is true.
+        let mut batched = vec![];
+
+        macro_rules! export_value {
+            ($to:expr, $exported:expr, $value:expr) => {{
+                let exported: Ident = $exported;
+                let value: Box<Expr> = $value;
+                if batch {
+                    batched.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(exported),
+                        value,
+                    }))));
+                } else {
+                    $to.push(
+                        AssignExpr {
+                            span: DUMMY_SP,
+                            left: PatOrExpr::Expr(Box::new(
+                                quote_ident!("exports").make_member(exported),
+                            )),
+                            op: op!("="),
+                            right: value,
+                        }
+                        .into_stmt()
+                        .into(),
+                    );
+                }
+            }};
+        }
+
         for item in items {
             self.in_top_level = true;
 
@@ -58,7 +88,10 @@ impl Fold for CommonJs {
                     if !self.config.strict && !emitted_esmodule {
                         emitted_esmodule = true;
 
-                        stmts.push(ModuleItem::Stmt(define_es_module(quote_ident!("exports"))));
+                        // `module.exports` is replaced if exports are batched.
+                        if !batch {
+                            stmts.push(ModuleItem::Stmt(define_es_module(quote_ident!("exports"))));
+                        }
                     }
 
                     macro_rules! init_export {
@@ -141,18 +174,7 @@ impl Fold for CommonJs {
                                 &mut stmts
                             };
 
-                            append_to.push(
-                                AssignExpr {
-                                    span: DUMMY_SP,
-                                    left: PatOrExpr::Expr(Box::new(
-                                        quote_ident!("exports").make_member(ident.clone()),
-                                    )),
-                                    op: op!("="),
-                                    right: Box::new(ident.into()),
-                                }
-                                .into_stmt()
-                                .into(),
-                            );
+                            export_value!(append_to, ident.clone(), Box::new(ident.into()));
                         }
                         ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                             decl: Decl::Var(var),
@@ -182,17 +204,10 @@ impl Fold for CommonJs {
                                         .push((ident.sym.clone(), ident.span.ctxt()));
                                     init_export!(ident.sym);
 
-                                    extra_stmts.push(
-                                        AssignExpr {
-                                            span: DUMMY_SP,
-                                            left: PatOrExpr::Expr(Box::new(
-                                                quote_ident!("exports").make_member(ident.clone()),
-                                            )),
-                                            op: op!("="),
-                                            right: Box::new(ident.into()),
-                                        }
-                                        .into_stmt()
-                                        .into(),
+                                    export_value!(
+                                        extra_stmts,
+                                        ident.clone(),
+                                        Box::new(ident.into())
                                     );
                                 }
                             }
@@ -216,18 +231,10 @@ impl Fold for CommonJs {
                                         },
                                     ))));
 
-                                    extra_stmts.push(
-                                        AssignExpr {
-                                            span: DUMMY_SP,
-                                            left: PatOrExpr::Expr(member_expr!(
-                                                DUMMY_SP,
-                                                exports.default
-                                            )),
-                                            op: op!("="),
-                                            right: Box::new(ident.into()),
-                                        }
-                                        .into_stmt()
-                                        .into(),
+                                    export_value!(
+                                        extra_stmts,
+                                        quote_ident!("default"),
+                                        Box::new(ident.into())
                                     );
                                 }
                                 DefaultDecl::Fn(FnExpr { ident, function }) => {
@@ -244,18 +251,10 @@ impl Fold for CommonJs {
                                         .fold_with(self),
                                     ))));
 
-                                    extra_stmts.push(
-                                        AssignExpr {
-                                            span: DUMMY_SP,
-                                            left: PatOrExpr::Expr(member_expr!(
-                                                DUMMY_SP,
-                                                exports.default
-                                            )),
-                                            op: op!("="),
-                                            right: Box::new(ident.into()),
-                                        }
-                                        .into_stmt()
-                                        .into(),
+                                    export_value!(
+                                        extra_stmts,
+                                        quote_ident!("default"),
+                                        Box::new(ident.into())
                                     );
                                 }
                                 _ => extra_stmts.push(
@@ -283,15 +282,10 @@ impl Fold for CommonJs {
                                 }],
                                 declare: false,
                             }))));
-                            extra_stmts.push(
-                                AssignExpr {
-                                    span: DUMMY_SP,
-                                    left: PatOrExpr::Expr(member_expr!(DUMMY_SP, exports.default)),
-                                    op: op!("="),
-                                    right: Box::new(ident.into()),
-                                }
-                                .into_stmt()
-                                .into(),
+                            export_value!(
+                                extra_stmts,
+                                quote_ident!("default"),
+                                Box::new(ident.into())
                             );
                         }
 
@@ -397,19 +391,7 @@ impl Fold for CommonJs {
                                         .unwrap_or_else(|| orig.sym.clone());
                                     init_export!(exported_symbol);
 
-                                    extra_stmts.push(
-                                        AssignExpr {
-                                            span: DUMMY_SP,
-                                            left: PatOrExpr::Expr(Box::new(
-                                                quote_ident!("exports")
-                                                    .make_member(exported.unwrap_or(orig)),
-                                            )),
-                                            op: op!("="),
-                                            right: value,
-                                        }
-                                        .into_stmt()
-                                        .into(),
-                                    );
+                                    export_value!(extra_stmts, exported.unwrap_or(orig), value);
                                 } else {
                                     extra_stmts.push(
                                         define_property(vec![
@@ -493,7 +475,7 @@ impl Fold for CommonJs {
             )));
         }
 
-        if !initialized.is_empty() {
+        if !initialized.is_empty() && !batch {
             stmts.push(
                 initialize_to_undefined(quote_ident!("exports"), initialized)
                     .into_stmt()
@@ -618,6 +600,32 @@ impl Fold for CommonJs {
 
         stmts.append(&mut extra_stmts);
 
+        if batch {
+            // module.exports = { a: a, b: b };
+            if !batched.is_empty() {
+                stmts.push(
+                    AssignExpr {
+                        span: DUMMY_SP,
+                        left: PatOrExpr::Expr(member_expr!(DUMMY_SP, module.exports)),
+                        op: op!("="),
+                        right: Box::new(Expr::Object(ObjectLit {
+                            span: DUMMY_SP,
+                            props: batched,
+                        })),
+                    }
+                    .into_stmt()
+                    .into(),
+                );
+            }
+
+            if emitted_esmodule {
+                stmts.push(ModuleItem::Stmt(define_es_module(*member_expr!(
+                    DUMMY_SP,
+                    module.exports
+                ))));
+            }
+        }
+
         stmts
     }
 
@@ -731,3 +739,122 @@ pub(super) fn handle_dynamic_import(span: Span, args: Vec<ExprOrSpread>) -> Expr
         type_args: Default::default(),
     });
 }
+
+/// Returns true if the exports of `items` can be assigned as a single object.
+///
+/// This is not possible if a module is re-exported, or an exported binding is
+/// reassigned, as the exports would not reflect changes of the bindings.
+fn can_batch_exports(items: &[ModuleItem]) -> bool {
+    let mut imported = FxHashSet::default();
+    let mut exported = FxHashSet::default();
+
+    for item in items {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                imported.extend(import.specifiers.iter().map(|s| match s {
+                    ImportSpecifier::Named(s) => s.local.to_id(),
+                    ImportSpecifier::Default(s) => s.local.to_id(),
+                    ImportSpecifier::Namespace(s) => s.local.to_id(),
+                }));
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                src: Some(..), ..
+            })) => return false,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+                for s in &export.specifiers {
+                    if let ExportSpecifier::Named(s) = s {
+                        exported.insert(s.orig.to_id());
+                    }
+                }
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => match decl {
+                Decl::Class(c) => {
+                    exported.insert(c.ident.to_id());
+                }
+                Decl::Fn(f) => {
+                    exported.insert(f.ident.to_id());
+                }
+                Decl::Var(v) => {
+                    let ids: Vec<Id> = find_ids(&v.decls);
+                    exported.extend(ids);
+                }
+                _ => {}
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => match &export.decl {
+                DefaultDecl::Class(ClassExpr {
+                    ident: Some(ident), ..
+                })
+                | DefaultDecl::Fn(FnExpr {
+                    ident: Some(ident), ..
+                }) => {
+                    exported.insert(ident.to_id());
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    if exported.iter().any(|id| imported.contains(id)) {
+        return false;
+    }
+
+    let mut v = AssignedIds {
+        ids: Default::default(),
+    };
+    items.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+    v.ids.iter().all(|id| !exported.contains(id))
+}
+
+/// Collects the bindings which are assigned.
+struct AssignedIds {
+    ids: FxHashSet<Id>,
+}
+
+impl Visit for AssignedIds {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        match &e.left {
+            PatOrExpr::Pat(pat) => {
+                let ids: Vec<Id> = find_ids(&**pat);
+                self.ids.extend(ids);
+            }
+            PatOrExpr::Expr(expr) => {
+                if let Expr::Ident(i) = &**expr {
+                    self.ids.insert(i.to_id());
+                }
+            }
+        }
+    }
+
+    fn visit_for_in_stmt(&mut self, s: &ForInStmt, _: &dyn Node) {
+        s.visit_children_with(self);
+
+        if let VarDeclOrPat::Pat(pat) = &s.left {
+            let ids: Vec<Id> = find_ids(pat);
+            self.ids.extend(ids);
+        }
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt, _: &dyn Node) {
+        s.visit_children_with(self);
+
+        if let VarDeclOrPat::Pat(pat) = &s.left {
+            let ids: Vec<Id> = find_ids(pat);
+            self.ids.extend(ids);
+        }
+    }
+
+    fn visit_update_expr(&mut self, e: &UpdateExpr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        if let Expr::Ident(i) = &*e.arg {
+            self.ids.insert(i.to_id());
+        }
+    }
+}
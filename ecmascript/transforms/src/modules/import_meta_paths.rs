@@ -0,0 +1,215 @@
+use crate::util::ExprFactory;
+use swc_common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Replaces `__dirname` and `__filename` of CommonJS with expressions using
+/// `import.meta.url`, which is available in ECMAScript modules.
+///
+/// ```js
+/// const file = __filename;
+/// const dir = __dirname;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// import { fileURLToPath as _fileURLToPath } from 'url';
+/// import { dirname as _dirname } from 'path';
+/// const file = _fileURLToPath(import.meta.url);
+/// const dir = _dirname(_fileURLToPath(import.meta.url));
+/// ```
+///
+/// Only references to the globals are replaced, and the imports are added
+/// only if they are used.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver.
+pub fn import_meta_paths() -> impl Fold {
+    as_folder(ImportMetaPaths {
+        file_url_to_path: private_ident!("_fileURLToPath"),
+        dirname: private_ident!("_dirname"),
+        uses_file_url_to_path: false,
+        uses_dirname: false,
+    })
+}
+
+struct ImportMetaPaths {
+    file_url_to_path: Ident,
+    dirname: Ident,
+    uses_file_url_to_path: bool,
+    uses_dirname: bool,
+}
+
+impl ImportMetaPaths {
+    /// `_fileURLToPath(import.meta.url)`
+    fn filename(&mut self) -> Expr {
+        self.uses_file_url_to_path = true;
+
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: self.file_url_to_path.clone().as_callee(),
+            args: vec![Expr::Member(MemberExpr {
+                span: DUMMY_SP,
+                obj: ExprOrSuper::Expr(Box::new(Expr::MetaProp(MetaPropExpr {
+                    meta: quote_ident!("import"),
+                    prop: quote_ident!("meta"),
+                }))),
+                prop: Box::new(Expr::Ident(quote_ident!("url"))),
+                computed: false,
+            })
+            .as_arg()],
+            type_args: Default::default(),
+        })
+    }
+}
+
+fn import(local: Ident, imported: &str, src: &str) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+            span: DUMMY_SP,
+            local,
+            imported: Some(quote_ident!(imported)),
+        })],
+        src: Str {
+            span: DUMMY_SP,
+            value: src.into(),
+            has_escape: false,
+        },
+        type_only: false,
+        asserts: None,
+        with: false,
+    }))
+}
+
+impl VisitMut for ImportMetaPaths {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let sym = match e {
+            Expr::Ident(i) if i.span.ctxt() == SyntaxContext::empty() => i.sym.clone(),
+            _ => return,
+        };
+
+        match &*sym {
+            "__filename" => *e = self.filename(),
+            "__dirname" => {
+                self.uses_dirname = true;
+                *e = Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: self.dirname.clone().as_callee(),
+                    args: vec![self.filename().as_arg()],
+                    type_args: Default::default(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        e.obj.visit_mut_with(self);
+        if e.computed {
+            e.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        m.visit_mut_children_with(self);
+
+        let mut imports = vec![];
+        if self.uses_file_url_to_path {
+            imports.push(import(
+                self.file_url_to_path.clone(),
+                "fileURLToPath",
+                "url",
+            ));
+        }
+        if self.uses_dirname {
+            imports.push(import(self.dirname.clone(), "dirname", "path"));
+        }
+        m.body.splice(0..0, imports);
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        p.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = p {
+            let mut value = Expr::Ident(i.clone());
+            self.visit_mut_expr(&mut value);
+            if let Expr::Ident(..) = value {
+                return;
+            }
+
+            *p = Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(i.clone()),
+                value: Box::new(value),
+            });
+        }
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::import_meta_paths;
+    use crate::resolver;
+    use swc_common::chain;
+    use swc_ecma_parser::{EsConfig, Syntax};
+
+    fn syntax() -> Syntax {
+        Syntax::Es(EsConfig {
+            import_meta: true,
+            ..Default::default()
+        })
+    }
+
+    test!(
+        syntax(),
+        |_| chain!(resolver(), import_meta_paths()),
+        dirname,
+        "
+const dir = __dirname;
+const file = __filename;
+",
+        "
+import { fileURLToPath as _fileURLToPath } from 'url';
+import { dirname as _dirname } from 'path';
+const dir = _dirname(_fileURLToPath(import.meta.url));
+const file = _fileURLToPath(import.meta.url);
+"
+    );
+
+    test!(
+        syntax(),
+        |_| chain!(resolver(), import_meta_paths()),
+        filename_only,
+        "
+console.log({ __filename });
+",
+        "
+import { fileURLToPath as _fileURLToPath } from 'url';
+console.log({ __filename: _fileURLToPath(import.meta.url) });
+"
+    );
+
+    test!(
+        syntax(),
+        |_| chain!(resolver(), import_meta_paths()),
+        shadowed,
+        "
+function f(__dirname) {
+    return __dirname + obj.__filename;
+}
+",
+        "
+function f(__dirname) {
+    return __dirname + obj.__filename;
+}
+"
+    );
+}
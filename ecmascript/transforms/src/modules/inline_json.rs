@@ -0,0 +1,287 @@
+use crate::util::ExprFactory;
+use serde::{
+    de::{MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::fmt;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold};
+
+/// JSON documents larger or deeper than this are inlined as
+/// `JSON.parse('...')`, which is faster to evaluate and keeps the ast of the
+/// module shallow.
+const MAX_LITERAL_LEN: usize = 1024;
+const MAX_LITERAL_DEPTH: usize = 32;
+
+/// Inlines JSON modules imported with `assert { type: 'json' }`.
+///
+/// `load` is called with the import source and should return the content of
+/// the JSON file. If it returns [None] or the content is not valid JSON, the
+/// import is kept as-is, so a module transform can convert it to `require`.
+///
+/// ```js
+/// import data from './data.json' assert { type: 'json' };
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const data = _deepFreeze({
+///     "foo": 1
+/// });
+/// ```
+///
+/// The value is frozen deeply, so nested objects and arrays can't be modified
+/// either.
+///
+/// # When to run
+///
+/// Module transforms drop import assertions, so this pass should be applied
+/// before them.
+pub fn inline_json<F>(load: F) -> impl Fold
+where
+    F: Fn(&str) -> Option<String>,
+{
+    InlineJson { load }
+}
+
+struct InlineJson<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    load: F,
+}
+
+impl<F> InlineJson<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn inline(&self, import: &ImportDecl) -> Option<Stmt> {
+        if !is_json_import(import) {
+            return None;
+        }
+        let local = match &*import.specifiers {
+            [ImportSpecifier::Default(default)] => default.local.clone(),
+            _ => return None,
+        };
+
+        let content = (self.load)(&*import.src.value)?;
+        let value: Json = serde_json::from_str(&content).ok()?;
+
+        let value = if content.len() > MAX_LITERAL_LEN || depth(&value) > MAX_LITERAL_DEPTH {
+            Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: member_expr!(DUMMY_SP, JSON.parse).as_callee(),
+                args: vec![Lit::Str(Str {
+                    span: DUMMY_SP,
+                    value: content.trim().into(),
+                    has_escape: false,
+                })
+                .as_arg()],
+                type_args: Default::default(),
+            })
+        } else {
+            to_expr(value)
+        };
+
+        Some(Stmt::Decl(Decl::Var(VarDecl {
+            span: import.span,
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(local),
+                init: Some(Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(deep_freeze, "deepFreeze"),
+                    args: vec![value.as_arg()],
+                    type_args: Default::default(),
+                }))),
+                definite: false,
+            }],
+        })))
+    }
+}
+
+impl<F> Fold for InlineJson<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        items
+            .into_iter()
+            .map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => match self.inline(&import) {
+                    Some(stmt) => ModuleItem::Stmt(stmt),
+                    None => ModuleItem::ModuleDecl(ModuleDecl::Import(import)),
+                },
+                _ => item,
+            })
+            .collect()
+    }
+}
+
+fn is_json_import(import: &ImportDecl) -> bool {
+    let asserts = match &import.asserts {
+        Some(asserts) => asserts,
+        None => return false,
+    };
+
+    asserts.props.iter().any(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(KeyValueProp { key, value }) => {
+                let is_type = match key {
+                    PropName::Ident(i) => &*i.sym == "type",
+                    PropName::Str(s) => &*s.value == "type",
+                    _ => false,
+                };
+
+                is_type
+                    && match &**value {
+                        Expr::Lit(Lit::Str(s)) => &*s.value == "json",
+                        _ => false,
+                    }
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+/// A JSON value which keeps the order of object keys.
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl<'de> Deserialize<'de> for Json {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(JsonVisitor)
+    }
+}
+
+struct JsonVisitor;
+
+impl<'de> Visitor<'de> for JsonVisitor {
+    type Value = Json;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a json value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Json, E> {
+        Ok(Json::Null)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Json, E> {
+        Ok(Json::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Json, E> {
+        Ok(Json::Number(v as f64))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Json, E> {
+        Ok(Json::Number(v as f64))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Json, E> {
+        Ok(Json::Number(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Json, E> {
+        Ok(Json::String(v.into()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Json, E> {
+        Ok(Json::String(v))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Json, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elems = vec![];
+        while let Some(elem) = seq.next_element()? {
+            elems.push(elem);
+        }
+        Ok(Json::Array(elems))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Json, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut props = vec![];
+        while let Some(prop) = map.next_entry()? {
+            props.push(prop);
+        }
+        Ok(Json::Object(props))
+    }
+}
+
+fn depth(value: &Json) -> usize {
+    match value {
+        Json::Array(elems) => 1 + elems.iter().map(depth).max().unwrap_or(0),
+        Json::Object(props) => 1 + props.iter().map(|(_, v)| depth(v)).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn to_expr(value: Json) -> Expr {
+    match value {
+        Json::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+        Json::Bool(value) => Expr::from(value),
+        Json::Number(n) => Expr::from(n),
+        Json::String(s) => Expr::Lit(Lit::Str(Str {
+            span: DUMMY_SP,
+            value: s.into(),
+            has_escape: false,
+        })),
+        Json::Array(elems) => Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: elems
+                .into_iter()
+                .map(|elem| Some(to_expr(elem).as_arg()))
+                .collect(),
+        }),
+        Json::Object(props) => Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: props
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = Str {
+                        span: DUMMY_SP,
+                        value: key.into(),
+                        has_escape: false,
+                    };
+                    // `"__proto__": value` sets the prototype, while `JSON.parse`
+                    // creates an own property.
+                    let key = if &*key.value == "__proto__" {
+                        PropName::Computed(ComputedPropName {
+                            span: DUMMY_SP,
+                            expr: Box::new(Expr::Lit(Lit::Str(key))),
+                        })
+                    } else {
+                        PropName::Str(key)
+                    };
+
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key,
+                        value: Box::new(to_expr(value)),
+                    })))
+                })
+                .collect(),
+        }),
+    }
+}
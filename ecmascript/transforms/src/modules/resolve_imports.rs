@@ -0,0 +1,197 @@
+use swc_atoms::js_word;
+use swc_common::SyntaxContext;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Rewrites the sources of imports and exports, `require` calls and dynamic
+/// imports.
+///
+/// `resolve` is called with each source and should return the new source, or
+/// [None] to keep it as-is. This can be used to resolve path aliases or to add
+/// file extensions required by ECMAScript modules.
+///
+/// ```js
+/// import foo from '@app/foo';
+/// export * from './bar';
+/// const baz = require('@app/baz');
+/// const qux = import('@app/qux');
+/// ```
+///
+/// becomes (with `@app/` resolved to `./src/`)
+///
+/// ```js
+/// import foo from './src/foo';
+/// export * from './bar';
+/// const baz = require('./src/baz');
+/// const qux = import('./src/qux');
+/// ```
+///
+/// Only string literals are rewritten, and `require` calls are rewritten only
+/// if `require` is the unshadowed global.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver, and before the module
+/// transforms if the sources of the emitted `require` calls should be
+/// rewritten as well.
+pub fn resolve_imports<F>(resolve: F) -> impl Fold
+where
+    F: Fn(&str) -> Option<String>,
+{
+    as_folder(ResolveImports { resolve })
+}
+
+struct ResolveImports<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    resolve: F,
+}
+
+impl<F> ResolveImports<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn resolve(&self, src: &mut Str) {
+        if let Some(value) = (self.resolve)(&src.value) {
+            src.value = value.into();
+            src.has_escape = false;
+        }
+    }
+}
+
+/// Returns true if `call` is `require('...')` or `import('...')`.
+fn is_import_call(call: &CallExpr) -> bool {
+    match &call.callee {
+        ExprOrSuper::Expr(callee) => match &**callee {
+            Expr::Ident(Ident {
+                sym: js_word!("require"),
+                span,
+                ..
+            }) if span.ctxt() == SyntaxContext::empty() => {}
+            Expr::Ident(Ident {
+                sym: js_word!("import"),
+                ..
+            }) => {}
+            _ => return false,
+        },
+        _ => return false,
+    }
+
+    match &*call.args {
+        [ExprOrSpread { spread: None, expr }] => match &**expr {
+            Expr::Lit(Lit::Str(..)) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+impl<F> VisitMut for ResolveImports<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        call.visit_mut_children_with(self);
+
+        if !is_import_call(call) {
+            return;
+        }
+
+        if let Expr::Lit(Lit::Str(src)) = &mut *call.args[0].expr {
+            self.resolve(src);
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+        self.resolve(&mut export.src);
+    }
+
+    fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+        self.resolve(&mut import.src);
+    }
+
+    fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+        if let Some(src) = &mut export.src {
+            self.resolve(src);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_imports;
+    use crate::resolver;
+    use swc_common::chain;
+    use swc_ecma_parser::{EsConfig, Syntax};
+
+    fn syntax() -> Syntax {
+        Syntax::Es(EsConfig {
+            dynamic_import: true,
+            ..Default::default()
+        })
+    }
+
+    fn resolve(src: &str) -> Option<String> {
+        if src.starts_with("@app/") {
+            Some(format!("./src/{}", &src["@app/".len()..]))
+        } else {
+            None
+        }
+    }
+
+    test!(
+        syntax(),
+        |_| chain!(resolver(), resolve_imports(resolve)),
+        alias,
+        "
+import foo from '@app/foo';
+import './bar';
+export { baz } from '@app/baz';
+export * from '@app/qux';
+const a = require('@app/a');
+const b = import('@app/b');
+",
+        "
+import foo from './src/foo';
+import './bar';
+export { baz } from './src/baz';
+export * from './src/qux';
+const a = require('./src/a');
+const b = import('./src/b');
+"
+    );
+
+    test!(
+        syntax(),
+        |_| chain!(
+            resolver(),
+            resolve_imports(
+                |src: &str| if src.starts_with('.') && !src.ends_with(".js") {
+                    Some(format!("{}.js", src))
+                } else {
+                    None
+                }
+            )
+        ),
+        extension,
+        "
+import foo from './foo';
+import bar from './bar.js';
+import react from 'react';
+function f(require) {
+    return require('./baz');
+}
+",
+        "
+import foo from './foo.js';
+import bar from './bar.js';
+import react from 'react';
+function f(require) {
+    return require('./baz');
+}
+"
+    );
+}
@@ -0,0 +1,129 @@
+use super::util::{is_directive, use_strict};
+use crate::util::IsDirective;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Adds `"use strict"` to the start of a module, and removes the directives
+/// which are redundant because of it.
+///
+/// ```js
+/// function foo() {
+///     "use strict";
+///     return this;
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// "use strict";
+/// function foo() {
+///     return this;
+/// }
+/// ```
+///
+/// The directive is not added if the prologue of the module already contains
+/// it, so applying this pass multiple times doesn't add duplicates. Scripts
+/// are not changed, as making them strict can change their behavior.
+pub fn strict_mode() -> impl Fold {
+    as_folder(StrictMode)
+}
+
+struct StrictMode;
+
+impl VisitMut for StrictMode {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let prologue_len = m.body.iter().take_while(|item| is_directive(item)).count();
+        let first = m.body[..prologue_len]
+            .iter()
+            .position(|item| item.is_use_strict());
+
+        let mut idx = 0;
+        m.body.retain(|item| {
+            let keep = Some(idx) == first || !item.is_use_strict();
+            idx += 1;
+            keep
+        });
+        if first.is_none() {
+            m.body.insert(0, ModuleItem::Stmt(use_strict()));
+        }
+
+        m.body.visit_mut_children_with(&mut RemoveUseStrict);
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {}
+}
+
+/// Removes `"use strict"` from nested statements.
+struct RemoveUseStrict;
+
+impl VisitMut for RemoveUseStrict {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.retain(|s| !s.is_use_strict());
+
+        stmts.visit_mut_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strict_mode;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| strict_mode(),
+        add,
+        "
+export function foo() {
+    'use strict';
+    return () => {
+        'use strict';
+        return this;
+    };
+}
+",
+        "
+'use strict';
+export function foo() {
+    return () => {
+        return this;
+    };
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| strict_mode(),
+        existing,
+        "
+'use client';
+'use strict';
+'use strict';
+foo();
+",
+        "
+'use client';
+'use strict';
+foo();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(strict_mode(), strict_mode()),
+        idempotent,
+        "
+foo();
+",
+        "
+'use strict';
+foo();
+"
+    );
+}
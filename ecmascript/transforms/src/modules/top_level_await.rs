@@ -0,0 +1,662 @@
+use crate::util::{find_ids, var::VarCollector, ExprFactory, HANDLER};
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Node, Visit, VisitWith};
+
+/// Wraps the body of a module using top-level `await` in an async function,
+/// for module systems which don't support top-level `await`.
+///
+/// ```js
+/// import { load } from './config';
+/// const config = await load();
+/// export const port = config.port;
+/// export function getConfig() {
+///     return config;
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// import { load } from './config';
+/// let config, port;
+/// export { port };
+/// export function getConfig() {
+///     return config;
+/// }
+/// (async () => {
+///     config = await load();
+///     port = config.port;
+/// })().catch((err) => {
+///     setTimeout(() => {
+///         throw err;
+///     });
+/// });
+/// ```
+///
+/// Imports, exports and function declarations are kept at the top level.
+/// Top-level variables and classes, and `var` declarations nested in
+/// statements, are declared at the top level and assigned in the async
+/// function, so that exports and functions can refer to them. Modules without
+/// top-level `await` are not changed.
+///
+/// Exports assigned after the first `await` are `undefined` until the
+/// awaited promises resolve, as `require` returns before that. A warning is
+/// emitted for each of them. If the async function throws, the error is
+/// rethrown outside of the promise, so it's reported as an uncaught exception.
+///
+/// # When to run
+///
+/// This pass should be applied before the module transforms, so that they
+/// assign the deferred exports to `exports` when the async function assigns
+/// them.
+pub fn top_level_await() -> impl Fold {
+    TopLevelAwait
+}
+
+struct TopLevelAwait;
+
+impl Fold for TopLevelAwait {
+    noop_fold_type!();
+
+    fn fold_module(&mut self, m: Module) -> Module {
+        if !m.body.iter().any(|item| contains_await(item)) {
+            return m;
+        }
+
+        let mut wrapper = Wrapper::default();
+        for item in m.body {
+            wrapper.add(item);
+        }
+
+        Module {
+            body: wrapper.finish(),
+            ..m
+        }
+    }
+}
+
+#[derive(Default)]
+struct Wrapper {
+    imports: Vec<ModuleItem>,
+    /// Items kept at the top level, other than imports.
+    top: Vec<ModuleItem>,
+    vars: Vec<VarDeclarator>,
+    lets: Vec<VarDeclarator>,
+    exports: Vec<ExportSpecifier>,
+    /// Statements of the async function.
+    body: Vec<Stmt>,
+    seen_await: bool,
+}
+
+impl Wrapper {
+    fn add(&mut self, item: ModuleItem) {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => self.imports.push(item),
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::Var(var),
+            })) => {
+                self.seen_await |= contains_await(&var);
+                for id in find_ids(&var.decls) {
+                    self.export(span, id, None);
+                }
+                self.add_var(var);
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                span,
+                decl: Decl::Class(c),
+            })) => {
+                self.seen_await |= contains_await(&c.class);
+                self.export(span, c.ident.clone(), None);
+                self.add_class(c.ident, c.class);
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                span,
+                decl:
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident),
+                        class,
+                    }),
+            })) => {
+                self.seen_await |= contains_await(&class);
+                self.export(span, ident.clone(), Some(quote_ident!("default")));
+                self.add_class(ident, class);
+            }
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                span,
+                decl: DefaultDecl::Class(c),
+            })) => self.add_default(span, Box::new(Expr::Class(c))),
+
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                span,
+                expr,
+            })) => self.add_default(span, expr),
+
+            ModuleItem::ModuleDecl(..) | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(..))) => {
+                self.top.push(item)
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => self.add_var(var),
+
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))) => self.add_class(c.ident, c.class),
+
+            ModuleItem::Stmt(stmt) => {
+                let mut hoister = VarHoister { vars: vec![] };
+                let stmt = stmt.fold_with(&mut hoister);
+                self.vars.extend(hoister.vars.into_iter().map(declarator));
+                self.push(stmt)
+            }
+        }
+    }
+
+    /// Adds `export default expr`.
+    fn add_default(&mut self, span: Span, expr: Box<Expr>) {
+        self.seen_await |= contains_await(&*expr);
+        let ident = private_ident!("_default");
+        self.export(span, ident.clone(), Some(quote_ident!("default")));
+        self.lets.push(declarator(ident.clone()));
+
+        // `_default = class {}` would name the class `_default`, while it's
+        // `default` if it's exported.
+        let expr = if is_anonymous_fn(&expr) {
+            Box::new(
+                ObjectLit {
+                    span: DUMMY_SP,
+                    props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(quote_ident!("default")),
+                        value: expr,
+                    })))],
+                }
+                .make_member(quote_ident!("default")),
+            )
+        } else {
+            expr
+        };
+        self.push(assign(Pat::Ident(ident), expr));
+    }
+
+    /// Exports `local`, which is assigned by the statements added after this.
+    fn export(&mut self, span: Span, local: Ident, exported: Option<Ident>) {
+        if self.seen_await && HANDLER.is_set() {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_warn(
+                        span,
+                        &format!(
+                            "`{}` is exported after a top-level await, so it's undefined until \
+                             the module finishes evaluating",
+                            exported.as_ref().unwrap_or(&local).sym
+                        ),
+                    )
+                    .emit()
+            });
+        }
+
+        self.exports
+            .push(ExportSpecifier::Named(ExportNamedSpecifier {
+                span: DUMMY_SP,
+                orig: local,
+                exported,
+            }));
+    }
+
+    fn add_var(&mut self, var: VarDecl) {
+        let decls = match var.kind {
+            VarDeclKind::Var => &mut self.vars,
+            VarDeclKind::Let | VarDeclKind::Const => &mut self.lets,
+        };
+        decls.extend(find_ids(&var.decls).into_iter().map(declarator));
+
+        for decl in var.decls {
+            if let Some(init) = decl.init {
+                self.push(assign(decl.name, init));
+            }
+        }
+    }
+
+    fn add_class(&mut self, ident: Ident, class: Class) {
+        self.lets.push(declarator(ident.clone()));
+        self.push(assign(
+            Pat::Ident(ident.clone()),
+            Box::new(Expr::Class(ClassExpr {
+                ident: Some(ident),
+                class,
+            })),
+        ));
+    }
+
+    fn push(&mut self, stmt: Stmt) {
+        self.seen_await |= contains_await(&stmt);
+        self.body.push(stmt);
+    }
+
+    fn finish(self) -> Vec<ModuleItem> {
+        let mut items = self.imports;
+
+        for (kind, decls) in vec![(VarDeclKind::Var, self.vars), (VarDeclKind::Let, self.lets)] {
+            if !decls.is_empty() {
+                items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind,
+                    declare: false,
+                    decls,
+                }))));
+            }
+        }
+        if !self.exports.is_empty() {
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                NamedExport {
+                    span: DUMMY_SP,
+                    specifiers: self.exports,
+                    src: None,
+                    type_only: false,
+                },
+            )));
+        }
+        items.extend(self.top);
+
+        // (async () => { ... })().catch((err) => {
+        //     setTimeout(() => {
+        //         throw err;
+        //     });
+        // });
+        let promise = CallExpr {
+            span: DUMMY_SP,
+            callee: arrow(true, vec![], self.body).as_callee(),
+            args: vec![],
+            type_args: Default::default(),
+        };
+        let err = private_ident!("err");
+        let rethrow = CallExpr {
+            span: DUMMY_SP,
+            callee: quote_ident!("setTimeout").as_callee(),
+            args: vec![arrow(
+                false,
+                vec![],
+                vec![Stmt::Throw(ThrowStmt {
+                    span: DUMMY_SP,
+                    arg: Box::new(Expr::Ident(err.clone())),
+                })],
+            )
+            .as_arg()],
+            type_args: Default::default(),
+        };
+        items.push(ModuleItem::Stmt(
+            CallExpr {
+                span: DUMMY_SP,
+                callee: promise.make_member(quote_ident!("catch")).as_callee(),
+                args: vec![arrow(false, vec![Pat::Ident(err)], vec![rethrow.into_stmt()]).as_arg()],
+                type_args: Default::default(),
+            }
+            .into_stmt(),
+        ));
+
+        items
+    }
+}
+
+fn arrow(is_async: bool, params: Vec<Pat>, stmts: Vec<Stmt>) -> Expr {
+    Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params,
+        body: BlockStmtOrExpr::BlockStmt(BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        }),
+        is_async,
+        is_generator: false,
+        type_params: Default::default(),
+        return_type: Default::default(),
+    })
+}
+
+fn declarator(name: Ident) -> VarDeclarator {
+    VarDeclarator {
+        span: DUMMY_SP,
+        name: Pat::Ident(name),
+        init: None,
+        definite: false,
+    }
+}
+
+fn assign(left: Pat, right: Box<Expr>) -> Stmt {
+    Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Pat(Box::new(left)),
+        right,
+    })
+    .into_stmt()
+}
+
+/// Returns true if `e` is named by the binding it's assigned to.
+fn is_anonymous_fn(e: &Expr) -> bool {
+    match e {
+        Expr::Arrow(..) | Expr::Class(ClassExpr { ident: None, .. }) => true,
+        Expr::Fn(FnExpr { ident: None, .. }) => true,
+        Expr::Paren(ParenExpr { expr, .. }) => is_anonymous_fn(expr),
+        _ => false,
+    }
+}
+
+/// Replaces `var` declarations which are not in a function with assignments,
+/// and collects the declared variables.
+struct VarHoister {
+    vars: Vec<Ident>,
+}
+
+impl VarHoister {
+    /// Returns [None] if `var` has no initializer.
+    fn var_to_expr(&mut self, var: VarDecl) -> Option<Box<Expr>> {
+        let var = var.fold_children_with(self);
+
+        let mut ids = vec![];
+        var.visit_with(
+            &Invalid { span: DUMMY_SP } as _,
+            &mut VarCollector { to: &mut ids },
+        );
+        self.vars.extend(
+            ids.into_iter()
+                .map(|(sym, ctxt)| Ident::new(sym, DUMMY_SP.with_ctxt(ctxt))),
+        );
+
+        let mut exprs: Vec<_> = var
+            .decls
+            .into_iter()
+            .filter_map(|decl| {
+                let init = decl.init?;
+                Some(Box::new(Expr::Assign(AssignExpr {
+                    span: decl.span,
+                    op: op!("="),
+                    left: PatOrExpr::Pat(Box::new(decl.name)),
+                    right: init,
+                })))
+            })
+            .collect();
+
+        match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(Box::new(Expr::Seq(SeqExpr {
+                span: var.span,
+                exprs,
+            }))),
+        }
+    }
+}
+
+impl Fold for VarHoister {
+    noop_fold_type!();
+
+    fn fold_arrow_expr(&mut self, e: ArrowExpr) -> ArrowExpr {
+        e
+    }
+
+    fn fold_for_stmt(&mut self, s: ForStmt) -> ForStmt {
+        let init = match s.init {
+            Some(VarDeclOrExpr::VarDecl(var)) if var.kind == VarDeclKind::Var => {
+                self.var_to_expr(var).map(VarDeclOrExpr::Expr)
+            }
+            init => init,
+        };
+
+        ForStmt { init, ..s }.fold_children_with(self)
+    }
+
+    fn fold_function(&mut self, f: Function) -> Function {
+        f
+    }
+
+    fn fold_stmt(&mut self, s: Stmt) -> Stmt {
+        match s {
+            Stmt::Decl(Decl::Var(var)) if var.kind == VarDeclKind::Var => {
+                let span = var.span;
+                match self.var_to_expr(var) {
+                    Some(expr) => Stmt::Expr(ExprStmt { span, expr }),
+                    None => Stmt::Empty(EmptyStmt { span }),
+                }
+            }
+            _ => s.fold_children_with(self),
+        }
+    }
+
+    fn fold_var_decl_or_pat(&mut self, v: VarDeclOrPat) -> VarDeclOrPat {
+        match v {
+            VarDeclOrPat::VarDecl(mut var)
+                if var.kind == VarDeclKind::Var && var.decls.len() == 1 =>
+            {
+                let mut ids = vec![];
+                var.visit_with(
+                    &Invalid { span: DUMMY_SP } as _,
+                    &mut VarCollector { to: &mut ids },
+                );
+                self.vars.extend(
+                    ids.into_iter()
+                        .map(|(sym, ctxt)| Ident::new(sym, DUMMY_SP.with_ctxt(ctxt))),
+                );
+
+                VarDeclOrPat::Pat(var.decls.remove(0).name.fold_with(self))
+            }
+            _ => v.fold_children_with(self),
+        }
+    }
+}
+
+fn contains_await<N>(node: &N) -> bool
+where
+    N: VisitWith<AwaitFinder>,
+{
+    let mut v = AwaitFinder { found: false };
+    node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.found
+}
+
+/// Finds `await` which is not in a function.
+struct AwaitFinder {
+    found: bool,
+}
+
+impl Visit for AwaitFinder {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr, _: &dyn Node) {
+        self.found = true;
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt, _: &dyn Node) {
+        if s.await_token.is_some() {
+            self.found = true;
+        }
+        s.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::top_level_await;
+    use crate::util::HANDLER;
+    use swc_ecma_parser::{EsConfig, Syntax};
+    use swc_ecma_visit::FoldWith;
+
+    fn syntax() -> Syntax {
+        Syntax::Es(EsConfig {
+            top_level_await: true,
+            ..Default::default()
+        })
+    }
+
+    test!(
+        syntax(),
+        |_| top_level_await(),
+        export_after_await,
+        "
+import { load } from './config';
+const config = await load();
+export const port = config.port;
+export function getConfig() {
+    return config;
+}
+export default class {}
+",
+        "
+import { load } from './config';
+let config, port, _default;
+export { port, _default as default };
+export function getConfig() {
+    return config;
+}
+(async () => {
+    config = await load();
+    port = config.port;
+    _default = {
+        default: class {}
+    }.default;
+})().catch((err) => {
+    setTimeout(() => {
+        throw err;
+    });
+});
+"
+    );
+
+    test!(
+        syntax(),
+        |_| top_level_await(),
+        nested_var,
+        "
+for (var i = 0, j; i < 3; i++) await f(i);
+for (var k in obj) use(k);
+if (c) {
+    var x = await f(), y;
+    let z = 1;
+}
+export function get() {
+    return [i, j, k, x, y];
+}
+",
+        "
+var i, j, k, x, y;
+export function get() {
+    return [i, j, k, x, y];
+}
+(async () => {
+    for (i = 0; i < 3; i++) await f(i);
+    for (k in obj) use(k);
+    if (c) {
+        x = await f();
+        let z = 1;
+    }
+})().catch((err) => {
+    setTimeout(() => {
+        throw err;
+    });
+});
+"
+    );
+
+    test!(
+        syntax(),
+        |_| top_level_await(),
+        export_default_expr,
+        "
+const a = await f();
+export default () => {};
+",
+        "
+let a, _default;
+export { _default as default };
+(async () => {
+    a = await f();
+    _default = {
+        default: () => {}
+    }.default;
+})().catch((err) => {
+    setTimeout(() => {
+        throw err;
+    });
+});
+"
+    );
+
+    test!(
+        syntax(),
+        |_| top_level_await(),
+        export_before_await,
+        "
+export var a = 1, { b } = obj;
+for await (const x of xs) use(x);
+",
+        "
+var a, b;
+export { a, b };
+(async () => {
+    a = 1;
+    ({ b } = obj);
+    for await (const x of xs) use(x);
+})().catch((err) => {
+    setTimeout(() => {
+        throw err;
+    });
+});
+"
+    );
+
+    test!(
+        syntax(),
+        |_| top_level_await(),
+        no_top_level_await,
+        "
+export const a = 1;
+async function f() {
+    await a;
+}
+",
+        "
+export const a = 1;
+async function f() {
+    await a;
+}
+"
+    );
+
+    #[test]
+    fn warn_exports_after_await() {
+        let stderr = ::testing::run_test(false, |cm, handler| {
+            HANDLER.set(handler, || {
+                let fm = cm.new_source_file(
+                    swc_common::FileName::Anon,
+                    "export const a = 1;
+export const b = await load();
+export default await f();"
+                        .into(),
+                );
+                let mut p = swc_ecma_parser::Parser::new(
+                    syntax(),
+                    swc_ecma_parser::StringInput::from(&*fm),
+                    None,
+                );
+                let m = p
+                    .parse_module()
+                    .map_err(|e| e.into_diagnostic(handler).emit())?;
+                m.fold_with(&mut top_level_await());
+
+                // Return the emitted diagnostics.
+                Err(())
+            })
+        })
+        .unwrap_err();
+
+        assert!(!stderr.contains("`a` is exported"), "{}", stderr);
+        assert!(stderr.contains("`b` is exported"), "{}", stderr);
+        assert!(stderr.contains("`default` is exported"), "{}", stderr);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::util::{undefined, DestructuringFinder, ExprFactory};
+use crate::util::{undefined, DestructuringFinder, ExprFactory, IsDirective, HANDLER};
 use fxhash::FxHashSet;
 use indexmap::IndexMap;
 use inflector::Inflector;
@@ -31,6 +31,18 @@ pub struct Config {
     pub lazy: Lazy,
     #[serde(default)]
     pub no_interop: bool,
+    /// Assigns the exports of a module as a single object, like
+    /// `module.exports = { a: a, b: b }`, instead of assigning each export to
+    /// `exports`.
+    ///
+    /// Exports are batched only if the module doesn't re-export other modules
+    /// and no exported binding is reassigned, as the object doesn't reflect
+    /// later changes of the bindings. Batched exports are assigned after the
+    /// body of the module is evaluated.
+    ///
+    /// Only the CommonJS transform supports this.
+    #[serde(default)]
+    pub batch_exports: bool,
 }
 
 impl Default for Config {
@@ -40,6 +52,7 @@ impl Default for Config {
             strict_mode: default_strict_mode(),
             lazy: Lazy::default(),
             no_interop: false,
+            batch_exports: false,
         }
     }
 }
@@ -48,10 +61,18 @@ const fn default_strict_mode() -> bool {
     true
 }
 
+/// Controls which imports are loaded lazily.
+///
+/// A lazy import is replaced with a function which calls `require` on first
+/// use and then replaces itself with the cached value, so the dependency is
+/// not evaluated until one of its bindings is accessed. Imports used by
+/// `export * from` or referenced at top level are always loaded eagerly.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged, deny_unknown_fields, rename_all = "camelCase")]
 pub enum Lazy {
+    /// `true` makes all imports from non-relative sources lazy.
     Bool(bool),
+    /// Imports from listed sources are lazy.
     List(Vec<JsWord>),
 }
 
@@ -276,6 +297,21 @@ impl Scope {
     }
 
     pub fn insert_import(&mut self, mut import: ImportDecl) {
+        // `require` has no way to pass assertions, so they are dropped.
+        if let Some(asserts) = import.asserts.take() {
+            if HANDLER.is_set() {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_warn(
+                            asserts.span,
+                            "import assertions are not supported by this module system and are \
+                             removed",
+                        )
+                        .emit()
+                });
+            }
+        }
+
         if import.specifiers.is_empty() {
             // import 'foo';
             //   -> require('foo');
@@ -726,7 +762,10 @@ pub(super) fn define_property(args: Vec<ExprOrSpread>) -> Expr {
 ///       value: true
 ///  });
 /// ```
-pub(super) fn define_es_module(exports: Ident) -> Stmt {
+pub(super) fn define_es_module<T>(exports: T) -> Stmt
+where
+    T: Into<Expr>,
+{
     define_property(vec![
         exports.as_arg(),
         Lit::Str(quote_str!("__esModule")).as_arg(),
@@ -748,20 +787,24 @@ pub(super) fn define_es_module(exports: Ident) -> Stmt {
     .into_stmt()
 }
 
+/// Returns true if the directive prologue of `stmts` contains `"use strict"`.
 pub(super) fn has_use_strict(stmts: &[ModuleItem]) -> bool {
-    if stmts.is_empty() {
-        return false;
-    }
+    stmts
+        .iter()
+        .take_while(|item| is_directive(*item))
+        .any(|item| item.is_use_strict())
+}
 
-    match &*stmts.first().unwrap() {
+/// Returns true if `item` is a string literal statement, which is a directive
+/// if it's in the prologue.
+pub(super) fn is_directive(item: &ModuleItem) -> bool {
+    match item {
         ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
-            Expr::Lit(Lit::Str(Str { ref value, .. })) => return &*value == "use strict",
-            _ => {}
+            Expr::Lit(Lit::Str(..)) => true,
+            _ => false,
         },
-        _ => {}
+        _ => false,
     }
-
-    false
 }
 
 pub(super) fn use_strict() -> Stmt {
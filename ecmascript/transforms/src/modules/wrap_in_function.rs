@@ -0,0 +1,119 @@
+use crate::util::{contains_this_expr, ExprFactory};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold};
+
+/// Wraps the whole program in an immediately invoked function expression.
+///
+/// Each `(name, value)` pair of `args` becomes a parameter named `name`,
+/// and `value` is passed for it.
+///
+/// ```js
+/// var a = global.foo;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// (function(global) {
+///     var a = global.foo;
+/// })(window);
+/// ```
+///
+/// Top-level `var`s and functions are hoisted to the wrapper function, so
+/// they behave the same way inside it. If the program refers to `this`, the
+/// wrapper is invoked with `.call(this, ...)`.
+///
+/// # When to run
+///
+/// Import and export declarations cannot be moved into a function, so they
+/// are kept at the top level. Run this pass after the module transforms to
+/// wrap them too.
+pub fn wrap_in_function(args: Vec<(JsWord, Expr)>) -> impl Fold {
+    WrapInFunction { args }
+}
+
+struct WrapInFunction {
+    args: Vec<(JsWord, Expr)>,
+}
+
+impl WrapInFunction {
+    fn wrap(&self, stmts: Vec<Stmt>) -> Stmt {
+        let uses_this = contains_this_expr(&stmts);
+
+        let mut params = Vec::with_capacity(self.args.len());
+        let mut args = Vec::with_capacity(self.args.len() + 1);
+        if uses_this {
+            args.push(ThisExpr { span: DUMMY_SP }.as_arg());
+        }
+        for (name, value) in &self.args {
+            params.push(Param {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                pat: Pat::Ident(Ident::new(name.clone(), DUMMY_SP)),
+            });
+            args.push(value.clone().as_arg());
+        }
+
+        let function = Expr::Fn(FnExpr {
+            ident: None,
+            function: Function {
+                params,
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        });
+
+        let callee = if uses_this {
+            function.make_member(quote_ident!("call")).as_callee()
+        } else {
+            function.as_callee()
+        };
+
+        Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee,
+                args,
+                type_args: Default::default(),
+            })),
+        })
+    }
+}
+
+impl Fold for WrapInFunction {
+    noop_fold_type!();
+
+    fn fold_module(&mut self, m: Module) -> Module {
+        let mut body = Vec::with_capacity(m.body.len());
+        let mut stmts = Vec::with_capacity(m.body.len());
+
+        for item in m.body {
+            match item {
+                ModuleItem::ModuleDecl(..) => body.push(item),
+                ModuleItem::Stmt(stmt) => stmts.push(stmt),
+            }
+        }
+
+        body.push(ModuleItem::Stmt(self.wrap(stmts)));
+
+        Module { body, ..m }
+    }
+
+    fn fold_script(&mut self, s: Script) -> Script {
+        Script {
+            body: vec![self.wrap(s.body)],
+            ..s
+        }
+    }
+}
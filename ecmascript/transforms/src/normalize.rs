@@ -0,0 +1,126 @@
+use crate::ext::MapWithMut;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Converts hand-built ast nodes into the form the parser produces.
+///
+///  - `PatOrExpr::Pat(Pat::Expr(e))` becomes `PatOrExpr::Expr(e)`
+///  - A sequence expression with only one element is replaced by the element.
+///  - `((foo))` becomes `(foo)`
+///
+/// Codegen and ast comparisons assume this canonical form, so this pass
+/// should be applied to asts which are not created by the parser.
+pub fn normalize_assignment_targets() -> impl Fold {
+    as_folder(Normalizer)
+}
+
+struct Normalizer;
+
+impl VisitMut for Normalizer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_pat_or_expr(&mut self, n: &mut PatOrExpr) {
+        n.visit_mut_children_with(self);
+
+        match n {
+            PatOrExpr::Pat(pat) => match &mut **pat {
+                Pat::Expr(expr) => {
+                    let expr = expr.take();
+                    *n = PatOrExpr::Expr(expr);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        match e {
+            Expr::Seq(SeqExpr { exprs, .. }) if exprs.len() == 1 => {
+                let expr = exprs.pop().unwrap();
+                *e = *expr;
+            }
+            Expr::Paren(ParenExpr { span, expr }) => match &mut **expr {
+                Expr::Paren(ParenExpr { expr: inner, .. }) => {
+                    let inner = inner.take();
+                    *e = Expr::Paren(ParenExpr {
+                        span: *span,
+                        expr: inner,
+                    });
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_assignment_targets;
+    use swc_common::DUMMY_SP;
+    use swc_ecma_ast::*;
+    use swc_ecma_utils::quote_ident;
+    use swc_ecma_visit::FoldWith;
+
+    fn assign(left: PatOrExpr) -> Expr {
+        Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left,
+            right: Box::new(Expr::Ident(quote_ident!("b"))),
+        })
+    }
+
+    #[test]
+    fn pat_expr_target() {
+        let member = Box::new(Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: ExprOrSuper::Expr(Box::new(Expr::Ident(quote_ident!("foo")))),
+            prop: Box::new(Expr::Ident(quote_ident!("a"))),
+            computed: false,
+        }));
+
+        let actual = assign(PatOrExpr::Pat(Box::new(Pat::Expr(member.clone()))))
+            .fold_with(&mut normalize_assignment_targets());
+
+        assert_eq!(actual, assign(PatOrExpr::Expr(member)));
+    }
+
+    #[test]
+    fn pat_target_is_preserved() {
+        let left = PatOrExpr::Pat(Box::new(Pat::Ident(quote_ident!("a"))));
+
+        let actual = assign(left.clone()).fold_with(&mut normalize_assignment_targets());
+
+        assert_eq!(actual, assign(left));
+    }
+
+    #[test]
+    fn single_element_seq() {
+        let actual = Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![Box::new(Expr::Ident(quote_ident!("a")))],
+        })
+        .fold_with(&mut normalize_assignment_targets());
+
+        assert_eq!(actual, Expr::Ident(quote_ident!("a")));
+    }
+
+    #[test]
+    fn nested_paren() {
+        let paren = |expr| {
+            Expr::Paren(ParenExpr {
+                span: DUMMY_SP,
+                expr: Box::new(expr),
+            })
+        };
+
+        let actual = paren(paren(paren(Expr::Ident(quote_ident!("a")))))
+            .fold_with(&mut normalize_assignment_targets());
+
+        assert_eq!(actual, paren(Expr::Ident(quote_ident!("a"))));
+    }
+}
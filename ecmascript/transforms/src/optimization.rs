@@ -1,5 +1,33 @@
-pub use self::{inline_globals::inline_globals, json_parse::json_parse, simplify::simplifier};
+pub use self::{
+    arrow_bind::remove_arrow_bind, arrow_body::collapse_arrow_body, comments::CommentRelocator,
+    empty_stmts::remove_empty_stmts, hoist_regex::hoist_regex, if_to_switch::if_to_switch,
+    inline_globals::inline_globals, json_parse::json_parse, labels::simplify_labels,
+    member_chains::cache_member_chains, pure_annotations::annotate_pure_calls,
+    redundant_void::remove_redundant_void, simplify::simplifier, sort_keys::sort_object_keys,
+    split_vars::split_vars, string_concat::merge_string_concat, string_raw::inline_string_raw,
+    strip_comments::strip_comments, switch_to_if::switch_to_if, typeof_undefined::typeof_undefined,
+    unused_exports::remove_unused_exports, unused_imports::remove_unused_imports,
+};
 
+mod arrow_bind;
+mod arrow_body;
+mod comments;
+mod empty_stmts;
+mod hoist_regex;
+mod if_to_switch;
 mod inline_globals;
 mod json_parse;
+mod labels;
+mod member_chains;
+mod pure_annotations;
+mod redundant_void;
 pub mod simplify;
+mod sort_keys;
+mod split_vars;
+mod string_concat;
+mod string_raw;
+mod strip_comments;
+mod switch_to_if;
+mod typeof_undefined;
+mod unused_exports;
+mod unused_imports;
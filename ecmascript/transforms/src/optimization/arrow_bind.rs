@@ -0,0 +1,187 @@
+use crate::ext::MapWithMut;
+use fxhash::FxHashSet;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+/// Removes `.bind(this)` from arrow functions, which ignore the value of
+/// `this` passed to them.
+///
+/// ```js
+/// const onClick = () => this.setState({ clicked: true });
+/// button.addEventListener('click', onClick.bind(this));
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const onClick = () => this.setState({ clicked: true });
+/// button.addEventListener('click', onClick);
+/// ```
+///
+/// A call is removed only if the callee is an arrow function or a `const`
+/// initialized with one, and `this` is the only argument, as other arguments
+/// would be bound as leading parameters.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver.
+pub fn remove_arrow_bind() -> impl Fold {
+    as_folder(RemoveArrowBind {
+        arrows: Default::default(),
+    })
+}
+
+struct RemoveArrowBind {
+    /// `const` bindings initialized with an arrow function.
+    arrows: FxHashSet<Id>,
+}
+
+impl RemoveArrowBind {
+    fn is_arrow(&self, e: &Expr) -> bool {
+        match e {
+            Expr::Arrow(..) => true,
+            Expr::Paren(ParenExpr { expr, .. }) => self.is_arrow(expr),
+            Expr::Ident(i) => self.arrows.contains(&i.to_id()),
+            _ => false,
+        }
+    }
+
+    /// Returns the bound function if `call` is a redundant `bind`.
+    fn redundant_bind<'a>(&self, call: &'a mut CallExpr) -> Option<&'a mut Box<Expr>> {
+        match &*call.args {
+            [ExprOrSpread { spread: None, expr }] => match &**expr {
+                Expr::This(..) => {}
+                _ => return None,
+            },
+            _ => return None,
+        }
+
+        let callee = match &mut call.callee {
+            ExprOrSuper::Expr(callee) => &mut **callee,
+            _ => return None,
+        };
+
+        match callee {
+            Expr::Member(MemberExpr {
+                obj: ExprOrSuper::Expr(obj),
+                prop,
+                computed: false,
+                ..
+            }) if self.is_arrow(obj) => match &**prop {
+                Expr::Ident(Ident { sym, .. }) if &**sym == "bind" => Some(obj),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for RemoveArrowBind {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Call(call) = e {
+            if let Some(f) = self.redundant_bind(call) {
+                let mut f = f.take();
+                while let Expr::Paren(ParenExpr { expr, .. }) = f {
+                    f = *expr;
+                }
+                *e = f;
+            }
+        }
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        m.visit_with(
+            &Invalid { span: DUMMY_SP } as _,
+            &mut ArrowCollector(&mut self.arrows),
+        );
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        s.visit_with(
+            &Invalid { span: DUMMY_SP } as _,
+            &mut ArrowCollector(&mut self.arrows),
+        );
+        s.visit_mut_children_with(self);
+    }
+}
+
+struct ArrowCollector<'a>(&'a mut FxHashSet<Id>);
+
+impl Visit for ArrowCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_var_decl(&mut self, v: &VarDecl, _: &dyn Node) {
+        v.visit_children_with(self);
+
+        if v.kind != VarDeclKind::Const {
+            return;
+        }
+
+        for decl in &v.decls {
+            if let (Pat::Ident(i), Some(Expr::Arrow(..))) = (&decl.name, decl.init.as_deref()) {
+                self.0.insert(i.to_id());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_arrow_bind;
+    use crate::resolver;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), remove_arrow_bind()),
+        simple,
+        "
+class Foo {
+    render() {
+        const onClick = () => this.click();
+        use(onClick.bind(this), (() => this).bind(this));
+    }
+}
+",
+        "
+class Foo {
+    render() {
+        const onClick = () => this.click();
+        use(onClick, () => this);
+    }
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), remove_arrow_bind()),
+        not_redundant,
+        "
+function f(g) {
+    let a = () => 1;
+    const b = () => 2;
+    const c = function () {};
+    use(a.bind(this), b.bind(this, 1), b.bind(obj), c.bind(this), g.bind(this));
+}
+",
+        "
+function f(g) {
+    let a = () => 1;
+    const b = () => 2;
+    const c = function () {};
+    use(a.bind(this), b.bind(this, 1), b.bind(obj), c.bind(this), g.bind(this));
+}
+"
+    );
+}
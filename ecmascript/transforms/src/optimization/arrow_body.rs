@@ -0,0 +1,217 @@
+use crate::{ext::MapWithMut, optimization::CommentRelocator};
+use swc_common::Spanned;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Converts the bodies of arrow functions to expressions where it doesn't
+/// change the returned value.
+///
+/// ```js
+/// const double = (x) => {
+///     return x * 2;
+/// };
+/// (() => {
+///     init();
+/// })();
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const double = (x) => x * 2;
+/// (() => init())();
+/// ```
+///
+/// A body consisting of a single `return` with a value is always converted.
+/// A body consisting of a single expression statement changes the returned
+/// value from `undefined` to the value of the expression, so it's converted
+/// only if the arrow function is not async, is called immediately and the
+/// result of the call is unused.
+///
+/// Comments of a removed `return` are moved to the returned expression.
+pub fn collapse_arrow_body<'a>(comments: CommentRelocator<'a>) -> impl 'a + Fold {
+    as_folder(CollapseArrowBody { comments })
+}
+
+struct CollapseArrowBody<'a> {
+    comments: CommentRelocator<'a>,
+}
+
+/// Converts the body of an arrow function called by `e` if the result of `e`
+/// is unused.
+fn discard(e: &mut Expr) {
+    let mut callee = match e {
+        Expr::Call(CallExpr {
+            callee: ExprOrSuper::Expr(callee),
+            ..
+        }) => &mut **callee,
+        _ => return,
+    };
+    while let Expr::Paren(ParenExpr { expr, .. }) = callee {
+        callee = &mut **expr;
+    }
+
+    let body = match callee {
+        Expr::Arrow(ArrowExpr {
+            body,
+            is_async: false,
+            ..
+        }) => body,
+        _ => return,
+    };
+
+    if let BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) = body {
+        match &mut **stmts {
+            // Keep directives.
+            [Stmt::Expr(ExprStmt { expr, .. })] => match &**expr {
+                Expr::Lit(Lit::Str(..)) => {}
+                _ => *body = BlockStmtOrExpr::Expr(expr.take()),
+            },
+            _ => {}
+        }
+    }
+}
+
+impl VisitMut for CollapseArrowBody<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        f.visit_mut_children_with(self);
+
+        if let BlockStmtOrExpr::BlockStmt(BlockStmt { stmts, .. }) = &mut f.body {
+            match &mut **stmts {
+                [Stmt::Return(ReturnStmt {
+                    span,
+                    arg: Some(arg),
+                })] => {
+                    self.comments.relocate(*span, arg.span());
+                    f.body = BlockStmtOrExpr::Expr(arg.take());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_mut_expr_stmt(&mut self, s: &mut ExprStmt) {
+        s.visit_mut_children_with(self);
+
+        discard(&mut s.expr);
+    }
+
+    fn visit_mut_seq_expr(&mut self, e: &mut SeqExpr) {
+        e.visit_mut_children_with(self);
+
+        if let Some((_, exprs)) = e.exprs.split_last_mut() {
+            for expr in exprs {
+                discard(expr);
+            }
+        }
+    }
+
+    fn visit_mut_unary_expr(&mut self, e: &mut UnaryExpr) {
+        e.visit_mut_children_with(self);
+
+        if e.op == op!("void") {
+            discard(&mut e.arg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::collapse_arrow_body;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| collapse_arrow_body(Default::default()),
+        single_return,
+        "
+const double = (x) => {
+    return x * 2;
+};
+const point = () => {
+    return { x: 1, y: 2 };
+};
+const nested = () => {
+    return () => {
+        return a, b;
+    };
+};
+",
+        "
+const double = (x) => x * 2;
+const point = () => ({ x: 1, y: 2 });
+const nested = () => () => (a, b);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| collapse_arrow_body(Default::default()),
+        unused_result,
+        "
+(() => {
+    init();
+})();
+void (() => {
+    start();
+})();
+((() => {
+    a();
+})(), b);
+",
+        "
+(() => init())();
+void (() => start())();
+((() => a())(), b);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| collapse_arrow_body(Default::default()),
+        changes_returned_value,
+        "
+const f = () => {
+    foo();
+};
+const g = () => {
+    return;
+};
+const h = () => {
+    'use strict';
+};
+const result = (() => {
+    foo();
+})();
+(() => {
+    foo();
+    bar();
+})();
+(async () => {
+    await foo();
+})();
+",
+        "
+const f = () => {
+    foo();
+};
+const g = () => {
+    return;
+};
+const h = () => {
+    'use strict';
+};
+const result = (() => {
+    foo();
+})();
+(() => {
+    foo();
+    bar();
+})();
+(async () => {
+    await foo();
+})();
+"
+    );
+}
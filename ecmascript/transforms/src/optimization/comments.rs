@@ -0,0 +1,50 @@
+use std::fmt::{self, Debug, Formatter};
+use swc_common::{comments::Comments, Span};
+
+/// Keeps comments attached to nodes moved or merged by optimization passes.
+///
+/// Comments are stored by position, so a comment is lost if the node it was
+/// attached to is replaced by a node with a different span, e.g. when a block
+/// statement is merged into its parent. Passes should call
+/// [CommentRelocator::relocate] on such rewrites.
+#[derive(Clone, Copy, Default)]
+pub struct CommentRelocator<'a> {
+    comments: Option<&'a dyn Comments>,
+}
+
+impl<'a> CommentRelocator<'a> {
+    pub fn new(comments: Option<&'a dyn Comments>) -> Self {
+        CommentRelocator { comments }
+    }
+
+    /// Moves leading comments of the node at `from` to the node at `to`,
+    /// which replaces it.
+    ///
+    /// The moved comments are placed before the comments of `to`, as the
+    /// replaced node usually contains `to`.
+    pub fn relocate(&self, from: Span, to: Span) {
+        let comments = match self.comments {
+            Some(comments) => comments,
+            None => return,
+        };
+
+        if from.is_dummy() || to.is_dummy() || from.lo == to.lo {
+            return;
+        }
+
+        let mut moved = match comments.take_leading(from.lo) {
+            Some(moved) => moved,
+            None => return,
+        };
+        moved.extend(comments.take_leading(to.lo).unwrap_or_default());
+        comments.add_leading_comments(to.lo, moved);
+    }
+}
+
+impl Debug for CommentRelocator<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommentRelocator")
+            .field("enabled", &self.comments.is_some())
+            .finish()
+    }
+}
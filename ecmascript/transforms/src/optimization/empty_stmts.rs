@@ -0,0 +1,179 @@
+use crate::{
+    ext::MapWithMut,
+    util::{ExprExt, StmtLike},
+};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Removes empty statements and empty blocks.
+///
+/// ```js
+/// ;
+/// {}
+/// if (a) b(); else {}
+/// if (c()) {}
+/// if (d) {} else e();
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// if (a) b();
+/// c();
+/// if (!d) e();
+/// ```
+///
+/// Empty statements are preserved where a statement is required, like the
+/// body of a loop.
+pub fn remove_empty_stmts() -> impl Fold {
+    as_folder(EmptyStmts)
+}
+
+struct EmptyStmts;
+
+impl EmptyStmts {
+    fn visit_mut_stmt_likes<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: StmtLike + VisitMutWith<Self>,
+    {
+        stmts.visit_mut_children_with(self);
+
+        stmts.retain(|stmt| match stmt.as_stmt() {
+            Some(stmt) => !is_empty(stmt),
+            None => true,
+        });
+    }
+}
+
+impl VisitMut for EmptyStmts {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_likes(items)
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_likes(stmts)
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        let stmt = match s {
+            Stmt::If(stmt) => stmt,
+            _ => return,
+        };
+
+        if stmt.alt.as_ref().map(|alt| is_empty(alt)).unwrap_or(false) {
+            stmt.alt = None;
+        }
+        if !is_empty(&stmt.cons) {
+            return;
+        }
+
+        let span = stmt.span;
+        let test = stmt.test.take();
+        *s = match stmt.alt.take() {
+            // `if (a) {} else b();` => `if (!a) b();`
+            Some(alt) => Stmt::If(IfStmt {
+                span,
+                test: Box::new(Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!("!"),
+                    arg: test,
+                })),
+                cons: alt,
+                alt: None,
+            }),
+            None if test.may_have_side_effects() => Stmt::Expr(ExprStmt { span, expr: test }),
+            None => Stmt::Empty(EmptyStmt { span }),
+        };
+    }
+}
+
+fn is_empty(s: &Stmt) -> bool {
+    match s {
+        Stmt::Empty(..) => true,
+        Stmt::Block(b) => b.stmts.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_empty_stmts;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_empty_stmts(),
+        empty,
+        "
+;
+foo();;
+{}
+{ ; {} }
+function bar() {
+    ;
+}
+",
+        "
+foo();
+function bar() {
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_empty_stmts(),
+        empty_else,
+        "
+if (a) b(); else {}
+if (c) { d(); } else ;
+if (e) f(); else if (g) {} else {}
+",
+        "
+if (a) b();
+if (c) {
+    d();
+}
+if (e) f();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_empty_stmts(),
+        empty_cons,
+        "
+if (a) {}
+if (b()) {}
+if (c) {} else d();
+if (e && f()) ; else { g(); }
+",
+        "
+b();
+if (!c) d();
+if (!(e && f())) {
+    g();
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_empty_stmts(),
+        required_stmt,
+        "
+while (a());
+for (;;) {}
+label: ;
+",
+        "
+while (a());
+for (;;) {}
+label: ;
+"
+    );
+}
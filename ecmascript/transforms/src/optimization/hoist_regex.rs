@@ -0,0 +1,252 @@
+use crate::util::StmtLike;
+use std::mem::{replace, take};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Moves regular expression literals out of loops, so that a new object is
+/// not created on each iteration.
+///
+/// ```js
+/// for (const line of lines) {
+///     if (/^\s*$/.test(line)) continue;
+///     use(line);
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// var _re = /^\s*$/;
+/// for (const line of lines) {
+///     if (_re.test(line)) continue;
+///     use(line);
+/// }
+/// ```
+///
+/// Regular expressions with the `g` or `y` flag are not moved, as they store
+/// the position of the last match in `lastIndex`. Literals in functions
+/// declared in a loop are moved only out of loops in the function.
+pub fn hoist_regex() -> impl Fold {
+    as_folder(HoistRegex {
+        in_loop: false,
+        vars: vec![],
+    })
+}
+
+struct HoistRegex {
+    in_loop: bool,
+    /// Declarations of the literals moved out of the current loop.
+    vars: Vec<VarDeclarator>,
+}
+
+impl HoistRegex {
+    fn visit_mut_stmt_likes<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: StmtLike + VisitMutWith<Self>,
+    {
+        // Declarations are inserted before the outermost loop.
+        if self.in_loop {
+            stmts.visit_mut_children_with(self);
+            return;
+        }
+
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in stmts.drain(..) {
+            stmt.visit_mut_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: take(&mut self.vars),
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        *stmts = buf;
+    }
+
+    fn in_loop<N>(&mut self, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let old = replace(&mut self.in_loop, true);
+        node.visit_mut_with(self);
+        self.in_loop = old;
+    }
+
+    /// Visits the body of a function, which is not evaluated by the loop
+    /// containing the function.
+    fn in_fn<N>(&mut self, node: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let old_in_loop = replace(&mut self.in_loop, false);
+        let old_vars = take(&mut self.vars);
+        node.visit_mut_children_with(self);
+        self.in_loop = old_in_loop;
+        self.vars = old_vars;
+    }
+}
+
+/// Returns true if `re` has a flag which makes it depend on `lastIndex`.
+fn is_stateful(re: &Regex) -> bool {
+    re.flags.contains('g') || re.flags.contains('y')
+}
+
+impl VisitMut for HoistRegex {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        self.in_fn(f)
+    }
+
+    fn visit_mut_constructor(&mut self, c: &mut Constructor) {
+        self.in_fn(c)
+    }
+
+    fn visit_mut_do_while_stmt(&mut self, s: &mut DoWhileStmt) {
+        self.in_loop(&mut s.body);
+        self.in_loop(&mut s.test);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if !self.in_loop {
+            return;
+        }
+
+        if let Expr::Lit(Lit::Regex(re)) = e {
+            if is_stateful(re) {
+                return;
+            }
+
+            let ident = private_ident!("_re");
+            self.vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(ident.clone()),
+                init: Some(Box::new(Expr::Lit(Lit::Regex(re.clone())))),
+                definite: false,
+            });
+            *e = Expr::Ident(ident);
+        }
+    }
+
+    fn visit_mut_for_in_stmt(&mut self, s: &mut ForInStmt) {
+        s.left.visit_mut_with(self);
+        s.right.visit_mut_with(self);
+        self.in_loop(&mut s.body);
+    }
+
+    fn visit_mut_for_of_stmt(&mut self, s: &mut ForOfStmt) {
+        s.left.visit_mut_with(self);
+        s.right.visit_mut_with(self);
+        self.in_loop(&mut s.body);
+    }
+
+    fn visit_mut_for_stmt(&mut self, s: &mut ForStmt) {
+        s.init.visit_mut_with(self);
+        self.in_loop(&mut s.test);
+        self.in_loop(&mut s.update);
+        self.in_loop(&mut s.body);
+    }
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        self.in_fn(f)
+    }
+
+    fn visit_mut_getter_prop(&mut self, p: &mut GetterProp) {
+        self.in_fn(p)
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_likes(items)
+    }
+
+    fn visit_mut_setter_prop(&mut self, p: &mut SetterProp) {
+        self.in_fn(p)
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_likes(stmts)
+    }
+
+    fn visit_mut_while_stmt(&mut self, s: &mut WhileStmt) {
+        self.in_loop(&mut s.test);
+        self.in_loop(&mut s.body);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hoist_regex;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| hoist_regex(),
+        for_loop,
+        r#"
+function f(lines) {
+    for (let i = 0; i < lines.length; i++) {
+        if (/^\s*$/i.test(lines[i])) continue;
+        use(lines[i].replace(/a/g, 'b'));
+    }
+}
+"#,
+        r#"
+function f(lines) {
+    var _re = /^\s*$/i;
+    for (let i = 0; i < lines.length; i++) {
+        if (_re.test(lines[i])) continue;
+        use(lines[i].replace(/a/g, 'b'));
+    }
+}
+"#
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| hoist_regex(),
+        nested,
+        r#"
+while (a) {
+    for (const b of c) {
+        if (/b/.test(b)) {
+            d(/d/y, () => /e/.test(b));
+        }
+    }
+}
+const f = /f/;
+"#,
+        r#"
+var _re = /b/;
+while (a) {
+    for (const b of c) {
+        if (_re.test(b)) {
+            d(/d/y, () => /e/.test(b));
+        }
+    }
+}
+const f = /f/;
+"#
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| hoist_regex(),
+        exec,
+        r#"
+const matches = [];
+for (const s of ['a1', 'b', 'c2']) {
+    if (/\d/.test(s)) matches.push(s);
+}
+expect(matches).toEqual(['a1', 'c2']);
+"#
+    );
+}
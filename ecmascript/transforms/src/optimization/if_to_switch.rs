@@ -0,0 +1,380 @@
+use super::switch_to_if::contains_unlabeled_break;
+use crate::ext::MapWithMut;
+use std::slice;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Shorter chains are easier to read as `if` statements.
+const MIN_CASES: usize = 3;
+
+/// Converts `if` / `else if` chains comparing an identifier with constants
+/// into `switch` statements. This is the inverse of `switch_to_if`.
+///
+/// ```js
+/// if (x === 'a') {
+///     a();
+/// } else if (x === 'b') {
+///     b();
+/// } else if (x === 'c') {
+///     c();
+/// } else {
+///     d();
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// switch (x) {
+///     case 'a':
+///         a();
+///         break;
+///     case 'b':
+///         b();
+///         break;
+///     case 'c':
+///         c();
+///         break;
+///     default:
+///         d();
+/// }
+/// ```
+///
+/// A chain is converted only if
+///
+///  - it has at least three comparisons,
+///  - each test is a `===` comparison of the same identifier with a literal,
+///  - no branch contains a `break` which targets an enclosing statement.
+pub fn if_to_switch() -> impl Fold {
+    as_folder(IfToSwitch)
+}
+
+struct IfToSwitch;
+
+impl VisitMut for IfToSwitch {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        // Converted before visiting children, so that the tail of a chain is
+        // not converted on its own.
+        let discriminant = match s {
+            Stmt::If(stmt) => can_convert(stmt),
+            _ => None,
+        };
+
+        if let Some(discriminant) = discriminant {
+            let mut stmt = match s.take() {
+                Stmt::If(stmt) => stmt,
+                _ => unreachable!(),
+            };
+            let span = stmt.span;
+            let mut cases = vec![];
+
+            loop {
+                let test = match *stmt.test {
+                    Expr::Bin(BinExpr { left, right, .. }) => match *left {
+                        Expr::Ident(..) => right,
+                        _ => left,
+                    },
+                    _ => unreachable!(),
+                };
+                cases.push(SwitchCase {
+                    span: DUMMY_SP,
+                    test: Some(test),
+                    cons: case_body(*stmt.cons, true),
+                });
+
+                match stmt.alt.map(|alt| *alt) {
+                    Some(Stmt::If(alt)) if is_comparison(&alt.test, &discriminant) => {
+                        stmt = alt;
+                    }
+                    Some(alt) => {
+                        cases.push(SwitchCase {
+                            span: DUMMY_SP,
+                            test: None,
+                            cons: case_body(alt, false),
+                        });
+                        break;
+                    }
+                    None => break,
+                }
+            }
+
+            *s = Stmt::Switch(SwitchStmt {
+                span,
+                discriminant: Box::new(Expr::Ident(discriminant)),
+                cases,
+            });
+        }
+
+        s.visit_mut_children_with(self);
+    }
+}
+
+/// Returns the discriminant if the chain starting at `s` can be converted.
+fn can_convert(s: &IfStmt) -> Option<Ident> {
+    let discriminant = match &*s.test {
+        Expr::Bin(BinExpr {
+            op: op!("==="),
+            left,
+            right,
+            ..
+        }) => match (&**left, &**right) {
+            (Expr::Ident(i), _) | (_, Expr::Ident(i)) => i.clone(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let mut count = 0;
+    let mut cur = s;
+
+    loop {
+        if !is_comparison(&cur.test, &discriminant)
+            || contains_unlabeled_break(slice::from_ref(&*cur.cons))
+        {
+            return None;
+        }
+        count += 1;
+
+        match cur.alt.as_deref() {
+            Some(Stmt::If(alt)) if is_comparison(&alt.test, &discriminant) => cur = alt,
+            Some(alt) => {
+                if contains_unlabeled_break(slice::from_ref(alt)) {
+                    return None;
+                }
+                break;
+            }
+            None => break,
+        }
+    }
+
+    if count < MIN_CASES {
+        return None;
+    }
+
+    Some(discriminant)
+}
+
+/// Returns true if `e` is `discriminant === literal` or `literal ===
+/// discriminant`.
+fn is_comparison(e: &Expr, discriminant: &Ident) -> bool {
+    let (left, right) = match e {
+        Expr::Bin(BinExpr {
+            op: op!("==="),
+            left,
+            right,
+            ..
+        }) => (&**left, &**right),
+        _ => return false,
+    };
+
+    let is_discriminant = |e: &Expr| match e {
+        Expr::Ident(i) => i.sym == discriminant.sym && i.span.ctxt() == discriminant.span.ctxt(),
+        _ => false,
+    };
+    let is_constant = |e: &Expr| match e {
+        Expr::Lit(Lit::Str(..))
+        | Expr::Lit(Lit::Num(..))
+        | Expr::Lit(Lit::Bool(..))
+        | Expr::Lit(Lit::Null(..)) => true,
+        _ => false,
+    };
+
+    (is_discriminant(left) && is_constant(right)) || (is_constant(left) && is_discriminant(right))
+}
+
+/// Converts the body of a branch into the statements of a case.
+fn case_body(body: Stmt, needs_break: bool) -> Vec<Stmt> {
+    let mut stmts = match body {
+        Stmt::Block(BlockStmt { stmts, .. })
+            if stmts.iter().all(|s| match s {
+                Stmt::Decl(Decl::Var(VarDecl {
+                    kind: VarDeclKind::Var,
+                    ..
+                })) => true,
+                Stmt::Decl(..) => false,
+                _ => true,
+            }) =>
+        {
+            stmts
+        }
+        body => vec![body],
+    };
+
+    let terminated = match stmts.last() {
+        Some(Stmt::Return(..)) | Some(Stmt::Throw(..)) => true,
+        _ => false,
+    };
+    if needs_break && !terminated {
+        stmts.push(Stmt::Break(BreakStmt {
+            span: DUMMY_SP,
+            label: None,
+        }));
+    }
+
+    stmts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::if_to_switch;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        simple,
+        "
+function foo(x) {
+    if (x === 'a') {
+        a();
+    } else if ('b' === x) {
+        return b();
+    } else if (x === 1) {
+        c();
+    } else {
+        d();
+    }
+}
+",
+        "
+function foo(x) {
+    switch (x) {
+        case 'a':
+            a();
+            break;
+        case 'b':
+            return b();
+        case 1:
+            c();
+            break;
+        default:
+            d();
+    }
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        block_scoped,
+        "
+if (x === 'a') {
+    let y = 1;
+    a(y);
+} else if (x === 'b') b();
+else if (x === 'c') c();
+",
+        "
+switch (x) {
+    case 'a':
+        {
+            let y = 1;
+            a(y);
+        }
+        break;
+    case 'b':
+        b();
+        break;
+    case 'c':
+        c();
+        break;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        too_short,
+        "
+if (x === 'a') a();
+else if (x === 'b') b();
+",
+        "
+if (x === 'a') a();
+else if (x === 'b') b();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        loose_equality,
+        "
+if (x === 'a') a();
+else if (x == 'b') b();
+else if (x === 'c') c();
+",
+        "
+if (x === 'a') a();
+else if (x == 'b') b();
+else if (x === 'c') c();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        mixed_discriminant,
+        "
+if (x === 'a') a();
+else if (y === 'b') b();
+else if (x === 'c') c();
+",
+        "
+if (x === 'a') a();
+else if (y === 'b') b();
+else if (x === 'c') c();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        nested_break,
+        "
+for (;;) {
+    if (x === 'a') a();
+    else if (x === 'b') break;
+    else if (x === 'c') c();
+}
+",
+        "
+for (;;) {
+    if (x === 'a') a();
+    else if (x === 'b') break;
+    else if (x === 'c') c();
+}
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| if_to_switch(),
+        exec,
+        "
+function f(x) {
+    const log = [];
+    if (x === 'a') {
+        log.push('a');
+    } else if (x === 1) {
+        log.push(1);
+    } else if (x === null) {
+        log.push(null);
+    } else {
+        log.push('default');
+    }
+    return log;
+}
+
+expect(f('a')).toEqual(['a']);
+expect(f(1)).toEqual([1]);
+expect(f(null)).toEqual([null]);
+expect(f('1')).toEqual(['default']);
+expect(f(undefined)).toEqual(['default']);
+"
+    );
+}
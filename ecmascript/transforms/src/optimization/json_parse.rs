@@ -97,7 +97,7 @@ fn jsonify(e: Expr) -> Value {
                     let key = match p.key {
                         PropName::Str(s) => s.value.to_string(),
                         PropName::Ident(id) => id.sym.to_string(),
-                        PropName::Num(n) => format!("{}", n.value),
+                        PropName::Num(n) => n.to_string(),
                         _ => unreachable!(),
                     };
                     (key, value)
@@ -0,0 +1,287 @@
+use crate::{ext::MapWithMut, optimization::CommentRelocator};
+use swc_atoms::JsWord;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+/// Removes labels of loops if every `break` and `continue` targeting the
+/// label would target the loop without it.
+///
+/// ```js
+/// outer: for (const a of b) {
+///     if (a) continue outer;
+///     foo();
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// for (const a of b) {
+///     if (a) continue;
+///     foo();
+/// }
+/// ```
+///
+/// The label is preserved if it's referenced from a nested loop, or by a
+/// `break` in a nested `switch`. Comments of a removed label are moved to the
+/// loop.
+pub fn simplify_labels<'a>(comments: CommentRelocator<'a>) -> impl 'a + Fold {
+    as_folder(SimplifyLabels { comments })
+}
+
+struct SimplifyLabels<'a> {
+    comments: CommentRelocator<'a>,
+}
+
+impl VisitMut for SimplifyLabels<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        let labeled = match s {
+            Stmt::Labeled(labeled) if is_loop(&labeled.body) => labeled,
+            _ => return,
+        };
+
+        let mut v = LabelUsage {
+            label: &labeled.label.sym,
+            loops: 0,
+            switches: 0,
+            removable: true,
+        };
+        labeled
+            .body
+            .visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+        if !v.removable {
+            return;
+        }
+
+        let label = labeled.label.sym.clone();
+        labeled
+            .body
+            .visit_mut_children_with(&mut RemoveLabel { label: &label });
+        self.comments.relocate(labeled.span, labeled.body.span());
+        *s = *labeled.body.take();
+    }
+}
+
+fn is_loop(s: &Stmt) -> bool {
+    match s {
+        Stmt::For(..) | Stmt::ForIn(..) | Stmt::ForOf(..) | Stmt::While(..) | Stmt::DoWhile(..) => {
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Checks if `break` and `continue` statements targeting `label` can omit it.
+struct LabelUsage<'a> {
+    label: &'a JsWord,
+    /// Number of loops containing the current node, including the labeled
+    /// loop.
+    loops: usize,
+    /// Number of `switch` statements between the labeled loop and the current
+    /// node.
+    switches: usize,
+    removable: bool,
+}
+
+macro_rules! nested_loop {
+    ($name:ident, $T:ty) => {
+        fn $name(&mut self, n: &$T, _: &dyn Node) {
+            self.loops += 1;
+            n.visit_children_with(self);
+            self.loops -= 1;
+        }
+    };
+}
+
+impl Visit for LabelUsage<'_> {
+    noop_visit_type!();
+
+    nested_loop!(visit_for_stmt, ForStmt);
+    nested_loop!(visit_for_in_stmt, ForInStmt);
+    nested_loop!(visit_for_of_stmt, ForOfStmt);
+    nested_loop!(visit_while_stmt, WhileStmt);
+    nested_loop!(visit_do_while_stmt, DoWhileStmt);
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt, _: &dyn Node) {
+        self.switches += 1;
+        n.visit_children_with(self);
+        self.switches -= 1;
+    }
+
+    fn visit_break_stmt(&mut self, n: &BreakStmt, _: &dyn Node) {
+        match &n.label {
+            Some(label) if label.sym == *self.label => {
+                if self.loops > 1 || self.switches != 0 {
+                    self.removable = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_continue_stmt(&mut self, n: &ContinueStmt, _: &dyn Node) {
+        match &n.label {
+            Some(label) if label.sym == *self.label => {
+                if self.loops > 1 {
+                    self.removable = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Labels are not visible from functions.
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    /// Labels are not visible from functions.
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+}
+
+struct RemoveLabel<'a> {
+    label: &'a JsWord,
+}
+
+impl VisitMut for RemoveLabel<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_break_stmt(&mut self, n: &mut BreakStmt) {
+        if n.label.as_ref().map(|l| l.sym == *self.label) == Some(true) {
+            n.label = None;
+        }
+    }
+
+    fn visit_mut_continue_stmt(&mut self, n: &mut ContinueStmt) {
+        if n.label.as_ref().map(|l| l.sym == *self.label) == Some(true) {
+            n.label = None;
+        }
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simplify_labels;
+    use crate::{optimization::CommentRelocator, tests::Tester};
+    use swc_common::{comments::Comments, Spanned};
+    use swc_ecma_ast::*;
+    use swc_ecma_visit::FoldWith;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| simplify_labels(Default::default()),
+        single,
+        "
+outer: for (const a of b) {
+    if (a) continue outer;
+    if (!a) break outer;
+    foo();
+}
+unused: while (x) {
+    x = f();
+}
+",
+        "
+for (const a of b) {
+    if (a) continue;
+    if (!a) break;
+    foo();
+}
+while (x) {
+    x = f();
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| simplify_labels(Default::default()),
+        nested,
+        "
+outer: for (const a of b) {
+    inner: for (const c of a) {
+        if (c) continue outer;
+        if (!c) continue inner;
+    }
+}
+loop: for (;;) {
+    switch (x) {
+        case 1:
+            break loop;
+        case 2:
+            continue loop;
+    }
+}
+",
+        "
+outer: for (const a of b) {
+    for (const c of a) {
+        if (c) continue outer;
+        if (!c) continue;
+    }
+}
+loop: for (;;) {
+    switch (x) {
+        case 1:
+            break loop;
+        case 2:
+            continue loop;
+    }
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| simplify_labels(Default::default()),
+        block,
+        "
+block: {
+    if (a) break block;
+    b();
+}
+",
+        "
+block: {
+    if (a) break block;
+    b();
+}
+"
+    );
+
+    #[test]
+    fn comment_of_removed_label() {
+        Tester::run(|tester| {
+            let module = tester.parse_module(
+                "input.js",
+                "// outer
+outer: for (;;) {
+    break outer;
+}",
+            )?;
+
+            let comments = tester.comments.clone();
+            let module = module.fold_with(&mut simplify_labels(CommentRelocator::new(Some(
+                &*comments,
+            ))));
+
+            let span = match &module.body[0] {
+                ModuleItem::Stmt(s @ Stmt::For(..)) => s.span(),
+                item => unreachable!("unexpected item: {:?}", item),
+            };
+            assert!(comments.has_leading(span.lo), "comment is lost");
+
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,625 @@
+use crate::util::alias_ident_for;
+use fxhash::{FxHashMap, FxHashSet};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_ids, ident::IdentLike, Id};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+/// Caches member expressions like `a.b.c` which are read multiple times in a
+/// block.
+///
+/// ```js
+/// function foo(a) {
+///     const x = a.b.c.d;
+///     const y = a.b.c.e;
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// function foo(a) {
+///     const _c = a.b.c;
+///     const x = _c.d;
+///     const y = _c.e;
+/// }
+/// ```
+///
+/// A member expression is cached only if it's evaluated unconditionally by
+/// the statement which first uses it, and only in statements which cannot
+/// change its value. Statements with calls, assignments to members, `delete`,
+/// `await` or `yield` end the range where the cached value is used.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver. It's only correct if
+/// getters don't have side effects, so it should be enabled only if the
+/// `pureGetters` assumption is set.
+pub fn cache_member_chains() -> impl Fold {
+    as_folder(CacheMemberChains)
+}
+
+struct CacheMemberChains;
+
+impl VisitMut for CacheMemberChains {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        cache(stmts);
+
+        stmts.visit_mut_children_with(self);
+    }
+}
+
+fn cache(stmts: &mut Vec<Stmt>) {
+    let infos: Vec<StmtInfo> = stmts.iter().map(StmtInfo::new).collect();
+
+    let mut decls = vec![];
+    let mut start = 0;
+    while start < stmts.len() {
+        let end = infos[start..]
+            .iter()
+            .position(|info| info.mutates)
+            .map(|pos| start + pos)
+            .unwrap_or(stmts.len());
+
+        for (idx, chain, member) in select(start, &infos[start..end]) {
+            let ident = alias_ident_for(&Expr::Member(member.clone()), "_ref");
+
+            let mut replacer = Replacer {
+                chain,
+                ident: ident.clone(),
+            };
+            for stmt in &mut stmts[idx..end] {
+                stmt.visit_mut_with(&mut replacer);
+            }
+
+            decls.push((
+                idx,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Const,
+                    declare: false,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(ident),
+                        init: Some(Box::new(Expr::Member(member))),
+                        definite: false,
+                    }],
+                })),
+            ));
+        }
+
+        // A mutating statement can't use a cached value.
+        start = end + 1;
+    }
+
+    // Insert from the back so indices remain valid.
+    decls.sort_by_key(|(idx, _)| *idx);
+    for (idx, decl) in decls.into_iter().rev() {
+        stmts.insert(idx, decl);
+    }
+}
+
+/// Returns member expressions to cache, with the index of the statement the
+/// declaration should be inserted before.
+///
+/// `infos` is a range of non-mutating statements starting at `offset`.
+fn select(offset: usize, infos: &[StmtInfo]) -> Vec<(usize, Chain, MemberExpr)> {
+    let mut first: Vec<(&Chain, (usize, &MemberExpr))> = vec![];
+    for (i, info) in infos.iter().enumerate() {
+        for (chain, member) in &info.unconditional {
+            if first.iter().all(|(c, _)| *c != chain) {
+                first.push((chain, (i, member)));
+            }
+        }
+    }
+
+    let mut candidates: Vec<(&Chain, usize, &MemberExpr, usize)> = first
+        .into_iter()
+        .filter_map(|(chain, (i, member))| {
+            let rest = &infos[i..];
+            if let Root::Ident(root) = &chain.root {
+                if rest.iter().any(|info| info.assigned.contains(root)) {
+                    return None;
+                }
+            }
+
+            let count: usize = rest
+                .iter()
+                .map(|info| info.counts.get(chain).copied().unwrap_or(0))
+                .sum();
+            if count < 2 {
+                return None;
+            }
+            Some((chain, i, member, count))
+        })
+        .collect();
+
+    // Prefer `a.b.c` over `a.b` if `a.b` is only used as a part of `a.b.c`.
+    let all = candidates.clone();
+    candidates.retain(|&(chain, _, _, count)| {
+        !all.iter().any(|&(other, _, _, other_count)| {
+            other_count == count
+                && other.props.len() == chain.props.len() + 1
+                && chain.is_prefix_of(other)
+        })
+    });
+    candidates.sort_by_key(|&(chain, ..)| chain.props.len());
+
+    let mut selected: Vec<(usize, Chain, MemberExpr)> = vec![];
+    for (chain, i, member, _) in candidates {
+        if selected
+            .iter()
+            .any(|(_, other, _)| other.is_prefix_of(chain))
+        {
+            continue;
+        }
+        selected.push((offset + i, chain.clone(), member.clone()));
+    }
+
+    selected
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Root {
+    This,
+    Ident(Id),
+}
+
+/// `a.b.c` is `{ root: a, props: [b, c] }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Chain {
+    root: Root,
+    props: Vec<JsWord>,
+}
+
+impl Chain {
+    /// Returns [None] if `e` is not a chain of non-computed member expressions
+    /// on an identifier or `this`.
+    fn of(e: &MemberExpr) -> Option<Self> {
+        if e.computed {
+            return None;
+        }
+        let prop = match &*e.prop {
+            Expr::Ident(i) => i.sym.clone(),
+            _ => return None,
+        };
+
+        let mut chain = match &e.obj {
+            ExprOrSuper::Expr(obj) => match &**obj {
+                Expr::Ident(i) => Chain {
+                    root: Root::Ident(i.to_id()),
+                    props: vec![],
+                },
+                Expr::This(..) => Chain {
+                    root: Root::This,
+                    props: vec![],
+                },
+                Expr::Member(obj) => Chain::of(obj)?,
+                _ => return None,
+            },
+            ExprOrSuper::Super(..) => return None,
+        };
+        chain.props.push(prop);
+        Some(chain)
+    }
+
+    fn is_prefix_of(&self, other: &Chain) -> bool {
+        self.root == other.root
+            && self.props.len() <= other.props.len()
+            && self.props[..] == other.props[..self.props.len()]
+    }
+}
+
+/// Calls `op` with `e` and member expressions in `e.obj` which can be cached.
+fn for_each_chain<'a, F>(mut e: &'a MemberExpr, mut op: F)
+where
+    F: FnMut(Chain, &'a MemberExpr),
+{
+    loop {
+        if let Some(chain) = Chain::of(e) {
+            // `a.b` is not worth caching.
+            if chain.props.len() >= 2 {
+                op(chain, e);
+            }
+        }
+
+        e = match &e.obj {
+            ExprOrSuper::Expr(obj) => match &**obj {
+                Expr::Member(obj) => obj,
+                _ => return,
+            },
+            _ => return,
+        };
+    }
+}
+
+struct StmtInfo {
+    /// True if the statement may change the value of a member expression.
+    mutates: bool,
+    /// Identifiers declared or assigned by the statement.
+    assigned: FxHashSet<Id>,
+    /// Member expressions which are always evaluated by the statement.
+    unconditional: Vec<(Chain, MemberExpr)>,
+    counts: FxHashMap<Chain, usize>,
+}
+
+impl StmtInfo {
+    fn new(s: &Stmt) -> Self {
+        let mut v = MutationFinder {
+            mutates: false,
+            assigned: Default::default(),
+        };
+        s.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+        let mut counter = ChainCounter {
+            counts: Default::default(),
+        };
+        s.visit_with(&Invalid { span: DUMMY_SP } as _, &mut counter);
+
+        let mut unconditional = UnconditionalChains { chains: vec![] };
+        unconditional.visit_stmt_top(s);
+
+        StmtInfo {
+            mutates: v.mutates,
+            assigned: v.assigned,
+            unconditional: unconditional.chains,
+            counts: counter.counts,
+        }
+    }
+}
+
+/// Functions and classes are not visited by the visitors below, as their
+/// bodies are not evaluated by the statement.
+macro_rules! skip_fn {
+    () => {
+        fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+        fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+        fn visit_constructor(&mut self, _: &Constructor, _: &dyn Node) {}
+
+        fn visit_getter_prop(&mut self, _: &GetterProp, _: &dyn Node) {}
+
+        fn visit_setter_prop(&mut self, _: &SetterProp, _: &dyn Node) {}
+    };
+}
+
+struct MutationFinder {
+    mutates: bool,
+    assigned: FxHashSet<Id>,
+}
+
+impl MutationFinder {
+    fn assign(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Expr(..) => self.mutates = true,
+            _ => {
+                let ids: Vec<Id> = find_ids(pat);
+                self.assigned.extend(ids);
+            }
+        }
+    }
+}
+
+impl Visit for MutationFinder {
+    noop_visit_type!();
+
+    skip_fn!();
+
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        match e {
+            Expr::Call(..)
+            | Expr::New(..)
+            | Expr::TaggedTpl(..)
+            | Expr::Await(..)
+            | Expr::Yield(..)
+            | Expr::JSXElement(..)
+            | Expr::JSXFragment(..)
+            | Expr::Unary(UnaryExpr {
+                op: op!("delete"), ..
+            }) => {
+                self.mutates = true;
+            }
+            Expr::Update(UpdateExpr { arg, .. }) => match &**arg {
+                Expr::Ident(i) => {
+                    self.assigned.insert(i.to_id());
+                }
+                _ => self.mutates = true,
+            },
+            Expr::Assign(AssignExpr { left, .. }) => match left {
+                PatOrExpr::Pat(pat) => self.assign(pat),
+                PatOrExpr::Expr(e) => match &**e {
+                    Expr::Ident(i) => {
+                        self.assigned.insert(i.to_id());
+                    }
+                    _ => self.mutates = true,
+                },
+            },
+            _ => {}
+        }
+
+        e.visit_children_with(self);
+    }
+
+    fn visit_expr_or_spread(&mut self, e: &ExprOrSpread, _: &dyn Node) {
+        // Spread calls the iterator.
+        if e.spread.is_some() {
+            self.mutates = true;
+        }
+        e.visit_children_with(self);
+    }
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {
+        // Computed keys and the super class are evaluated.
+        self.mutates = true;
+    }
+
+    fn visit_for_of_stmt(&mut self, s: &ForOfStmt, _: &dyn Node) {
+        self.mutates = true;
+        s.visit_children_with(self);
+    }
+
+    fn visit_with_stmt(&mut self, s: &WithStmt, _: &dyn Node) {
+        self.mutates = true;
+        s.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, d: &VarDeclarator, _: &dyn Node) {
+        self.assign(&d.name);
+        d.visit_children_with(self);
+    }
+
+    fn visit_array_pat(&mut self, p: &ArrayPat, _: &dyn Node) {
+        // Destructuring an array calls the iterator.
+        self.mutates = true;
+        p.visit_children_with(self);
+    }
+
+    fn visit_for_in_stmt(&mut self, s: &ForInStmt, _: &dyn Node) {
+        if let VarDeclOrPat::Pat(pat) = &s.left {
+            self.assign(pat);
+        }
+        s.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, f: &FnDecl, _: &dyn Node) {
+        self.assigned.insert(f.ident.to_id());
+    }
+
+    fn visit_class_decl(&mut self, c: &ClassDecl, _: &dyn Node) {
+        self.assigned.insert(c.ident.to_id());
+        c.class.visit_with(c as _, self);
+    }
+
+    fn visit_catch_clause(&mut self, c: &CatchClause, _: &dyn Node) {
+        if let Some(param) = &c.param {
+            self.assign(param);
+        }
+        c.visit_children_with(self);
+    }
+}
+
+struct ChainCounter {
+    counts: FxHashMap<Chain, usize>,
+}
+
+impl Visit for ChainCounter {
+    noop_visit_type!();
+
+    skip_fn!();
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        if Chain::of(e).is_none() {
+            e.visit_children_with(self);
+            return;
+        }
+
+        let counts = &mut self.counts;
+        for_each_chain(e, |chain, _| *counts.entry(chain).or_default() += 1);
+    }
+}
+
+/// Collects member expressions which are evaluated whenever the statement is
+/// executed.
+struct UnconditionalChains {
+    chains: Vec<(Chain, MemberExpr)>,
+}
+
+impl UnconditionalChains {
+    fn visit_stmt_top(&mut self, s: &Stmt) {
+        match s {
+            Stmt::Expr(s) => self.visit_expr_top(&s.expr),
+            Stmt::Decl(Decl::Var(var)) => {
+                for decl in &var.decls {
+                    if let Some(init) = &decl.init {
+                        self.visit_expr_top(init);
+                    }
+                }
+            }
+            Stmt::Return(ReturnStmt { arg: Some(arg), .. })
+            | Stmt::Throw(ThrowStmt { arg, .. }) => self.visit_expr_top(arg),
+            Stmt::If(IfStmt { test, .. }) => self.visit_expr_top(test),
+            Stmt::Switch(SwitchStmt { discriminant, .. }) => self.visit_expr_top(discriminant),
+            _ => {}
+        }
+    }
+
+    fn visit_expr_top(&mut self, e: &Expr) {
+        e.visit_with(&Invalid { span: DUMMY_SP } as _, self);
+    }
+}
+
+impl Visit for UnconditionalChains {
+    noop_visit_type!();
+
+    skip_fn!();
+
+    fn visit_class(&mut self, _: &Class, _: &dyn Node) {}
+
+    fn visit_bin_expr(&mut self, e: &BinExpr, _: &dyn Node) {
+        e.left.visit_with(e as _, self);
+
+        match e.op {
+            op!("&&") | op!("||") | op!("??") => {}
+            _ => e.right.visit_with(e as _, self),
+        }
+    }
+
+    fn visit_cond_expr(&mut self, e: &CondExpr, _: &dyn Node) {
+        e.test.visit_with(e as _, self);
+    }
+
+    fn visit_opt_chain_expr(&mut self, _: &OptChainExpr, _: &dyn Node) {}
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        if Chain::of(e).is_none() {
+            e.visit_children_with(self);
+            return;
+        }
+
+        let chains = &mut self.chains;
+        for_each_chain(e, |chain, member| chains.push((chain, member.clone())));
+    }
+}
+
+struct Replacer {
+    chain: Chain,
+    ident: Ident,
+}
+
+impl VisitMut for Replacer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::Member(member) = e {
+            if Chain::of(member).as_ref() == Some(&self.chain) {
+                *e = Expr::Ident(self.ident.clone());
+                return;
+            }
+        }
+
+        e.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_getter_prop(&mut self, _: &mut GetterProp) {}
+
+    fn visit_mut_setter_prop(&mut self, _: &mut SetterProp) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cache_member_chains;
+    use crate::resolver;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), cache_member_chains()),
+        simple,
+        "
+function foo(a) {
+    const x = a.b.c.d;
+    const y = a.b.c.e;
+    return x + y;
+}
+",
+        "
+function foo(a) {
+    const _c = a.b.c;
+    const x = _c.d;
+    const y = _c.e;
+    return x + y;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), cache_member_chains()),
+        this_root,
+        "
+function foo() {
+    return this.props.a.x + this.props.a.y;
+}
+",
+        "
+function foo() {
+    const _a = this.props.a;
+    return _a.x + _a.y;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), cache_member_chains()),
+        call_between,
+        "
+function foo(a) {
+    const x = a.b.c.d;
+    bar();
+    const y = a.b.c.e;
+}
+",
+        "
+function foo(a) {
+    const x = a.b.c.d;
+    bar();
+    const y = a.b.c.e;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), cache_member_chains()),
+        conditional,
+        "
+function foo(a, x) {
+    const y = x && a.b.c.d;
+    return a.b.c.e + a.b.c.f;
+}
+",
+        "
+function foo(a, x) {
+    const y = x && a.b.c.d;
+    const _c = a.b.c;
+    return _c.e + _c.f;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), cache_member_chains()),
+        assigned_root,
+        "
+function foo(a, b) {
+    const x = a.b.c.d;
+    a = b;
+    const y = a.b.c.e;
+}
+",
+        "
+function foo(a, b) {
+    const x = a.b.c.d;
+    a = b;
+    const y = a.b.c.e;
+}
+"
+    );
+}
@@ -0,0 +1,223 @@
+use crate::util::ExprExt;
+use swc_common::{
+    comments::{Comment, CommentKind, Comments},
+    SyntaxContext, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut};
+
+/// Global functions which don't have side effects.
+const PURE_GLOBALS: &[&str] = &["Symbol", "Object.create"];
+
+/// Adds `/*#__PURE__*/` to calls which initialize top-level variables, so
+/// that bundlers can remove them if the variables are not used.
+///
+/// ```js
+/// const Context = createContext(null);
+/// const id = Symbol('id');
+/// ```
+///
+/// becomes (with `createContext` in `allowlist`)
+///
+/// ```js
+/// const Context = /*#__PURE__*/ createContext(null);
+/// const id = /*#__PURE__*/ Symbol('id');
+/// ```
+///
+/// A call is annotated if its callee is listed in `allowlist`, like
+/// `createContext` or `React.createContext`, or is an unshadowed global
+/// function known not to have side effects. Calls with arguments which may
+/// have side effects are not annotated, as bundlers remove the arguments
+/// with the call.
+///
+/// The annotations are added to `comments`, so calls synthesized by other
+/// passes can't be annotated.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver.
+pub fn annotate_pure_calls<'a>(
+    comments: &'a dyn Comments,
+    allowlist: Vec<String>,
+) -> impl 'a + Fold {
+    as_folder(AnnotatePureCalls {
+        comments,
+        allowlist,
+    })
+}
+
+struct AnnotatePureCalls<'a> {
+    comments: &'a dyn Comments,
+    allowlist: Vec<String>,
+}
+
+/// Returns the name of `callee` like `React.createContext`, and true if the
+/// object of it is an unshadowed global.
+fn callee_name(callee: &Expr) -> Option<(String, bool)> {
+    match callee {
+        Expr::Ident(i) => Some((i.sym.to_string(), i.span.ctxt() == SyntaxContext::empty())),
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => match &**prop {
+            Expr::Ident(prop) => {
+                let (obj, is_global) = callee_name(obj)?;
+                Some((format!("{}.{}", obj, prop.sym), is_global))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl AnnotatePureCalls<'_> {
+    fn is_pure(&self, call: &CallExpr) -> bool {
+        let callee = match &call.callee {
+            ExprOrSuper::Expr(callee) => callee,
+            _ => return false,
+        };
+        let (name, is_global) = match callee_name(callee) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        if !self.allowlist.contains(&name) && !(is_global && PURE_GLOBALS.contains(&&*name)) {
+            return false;
+        }
+
+        call.args
+            .iter()
+            .all(|arg| arg.spread.is_none() && !arg.expr.may_have_side_effects())
+    }
+
+    fn annotate(&self, init: &Expr) {
+        let call = match init {
+            Expr::Call(call) if !call.span.is_dummy() => call,
+            _ => return,
+        };
+        if !self.is_pure(call) {
+            return;
+        }
+
+        let pos = call.span.lo;
+        let mut leading = self.comments.take_leading(pos).unwrap_or_default();
+        if !leading.iter().any(|c| c.text.contains("__PURE__")) {
+            leading.push(Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: "#__PURE__".into(),
+            });
+        }
+        self.comments.add_leading_comments(pos, leading);
+    }
+
+    fn visit_var_decl(&self, var: &VarDecl) {
+        for decl in &var.decls {
+            if let Some(init) = &decl.init {
+                self.annotate(init);
+            }
+        }
+    }
+}
+
+impl VisitMut for AnnotatePureCalls<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var),
+                ..
+            })) => self.visit_var_decl(var),
+            _ => {}
+        }
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        if let Stmt::Decl(Decl::Var(var)) = s {
+            self.visit_var_decl(var);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::annotate_pure_calls;
+    use crate::{resolver, tests::Tester};
+    use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+    use swc_ecma_visit::FoldWith;
+
+    fn apply(src: &str, allowlist: &[&str]) -> String {
+        let mut out = String::new();
+        Tester::run(|tester| {
+            let module = tester.parse_module("input.js", src)?;
+
+            let comments = tester.comments.clone();
+            let allowlist = allowlist.iter().map(|s| s.to_string()).collect();
+            let module = module
+                .fold_with(&mut resolver())
+                .fold_with(&mut annotate_pure_calls(&*comments, allowlist));
+
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: tester.cm.clone(),
+                    comments: Some(&*comments),
+                    ident_hook: None,
+                    wr: Box::new(JsWriter::new(tester.cm.clone(), "\n", &mut buf, None)),
+                };
+                emitter.emit_module(&module).unwrap();
+            }
+            out = String::from_utf8(buf).unwrap();
+
+            Ok(())
+        });
+        out
+    }
+
+    #[test]
+    fn allowlist() {
+        let out = apply(
+            "const a = createThing(1, 'a');
+export const b = React.createContext(null);
+const c = other();
+const d = createThing(sideEffect());
+function f() {
+    const e = createThing();
+}",
+            &["createThing", "React.createContext"],
+        );
+
+        assert_eq!(
+            out.trim(),
+            "const a = /*#__PURE__*/ createThing(1, 'a');
+export const b = /*#__PURE__*/ React.createContext(null);
+const c = other();
+const d = createThing(sideEffect());
+function f() {
+    const e = createThing();
+}"
+        );
+    }
+
+    #[test]
+    fn globals() {
+        let out = apply(
+            "const a = Symbol('a');
+const b = /*#__PURE__*/ Object.create(null);
+const c = Object.create(proto());",
+            &[],
+        );
+
+        assert_eq!(
+            out.trim(),
+            "const a = /*#__PURE__*/ Symbol('a');
+const b = /*#__PURE__*/ Object.create(null);
+const c = Object.create(proto());"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::ext::MapWithMut;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Removes `void` from expressions whose value is discarded.
+///
+/// ```js
+/// void foo();
+/// void a(), void b();
+/// for (;; void i++);
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// foo();
+/// a(), b();
+/// for (;; i++);
+/// ```
+///
+/// The operand is always kept, so side effects are preserved. `void` of a
+/// string literal is kept in statement position, as the literal would otherwise
+/// be parsed as a directive.
+///
+/// # When to run
+///
+/// The fixer should be applied after this pass, as `void function () {}()`
+/// becomes a function expression in statement position.
+pub fn remove_redundant_void() -> impl Fold {
+    as_folder(RedundantVoid)
+}
+
+struct RedundantVoid;
+
+/// Removes `void` from `e`, which is evaluated only for side effects.
+fn discard(e: &mut Expr) {
+    match e {
+        Expr::Unary(UnaryExpr {
+            op: op!("void"),
+            arg,
+            ..
+        }) => {
+            *e = *arg.take();
+            discard(e);
+        }
+        Expr::Paren(ParenExpr { expr, .. }) => discard(expr),
+        Expr::Seq(SeqExpr { exprs, .. }) => {
+            for e in exprs {
+                discard(e);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns true if `e` would become a string literal, which is a directive in
+/// statement position, if `void` is removed.
+fn is_void_str(e: &Expr) -> bool {
+    match e {
+        Expr::Unary(UnaryExpr {
+            op: op!("void"),
+            arg: e,
+            ..
+        })
+        | Expr::Paren(ParenExpr { expr: e, .. }) => is_void_str(e),
+        Expr::Lit(Lit::Str(..)) => true,
+        _ => false,
+    }
+}
+
+impl VisitMut for RedundantVoid {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr_stmt(&mut self, s: &mut ExprStmt) {
+        s.visit_mut_children_with(self);
+
+        // `void "use strict";` is not a directive.
+        if is_void_str(&s.expr) {
+            return;
+        }
+
+        discard(&mut s.expr);
+    }
+
+    fn visit_mut_for_stmt(&mut self, s: &mut ForStmt) {
+        s.visit_mut_children_with(self);
+
+        if let Some(VarDeclOrExpr::Expr(init)) = &mut s.init {
+            discard(init);
+        }
+        if let Some(update) = &mut s.update {
+            discard(update);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_redundant_void;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_redundant_void(),
+        stmt,
+        "
+void foo();
+void void a;
+void b(), c, void d;
+for (void init(); i < 10; void i++);
+",
+        "
+foo();
+a;
+b(), c, d;
+for (init(); i < 10; i++);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_redundant_void(),
+        value_used,
+        "
+const a = void foo();
+bar(void 0);
+f = () => void d();
+",
+        "
+const a = void foo();
+bar(void 0);
+f = () => void d();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_redundant_void(),
+        directive,
+        "
+void 'use strict';
+function foo() {
+    void ('use strict');
+    void void 'use strict', bar();
+}
+",
+        "
+void 'use strict';
+function foo() {
+    void 'use strict';
+    'use strict', bar();
+}
+"
+    );
+}
@@ -1,4 +1,5 @@
 use crate::{
+    optimization::CommentRelocator,
     pass::RepeatedJsPass,
     util::{StmtLike, *},
 };
@@ -18,17 +19,20 @@ mod tests;
 /// Not intended for general use. Use [simplifier] instead.
 ///
 /// Ported from `PeepholeRemoveDeadCode` of google closure compiler.
-pub fn dead_branch_remover() -> impl RepeatedJsPass + 'static {
-    Remover::default()
+pub fn dead_branch_remover<'a>(comments: CommentRelocator<'a>) -> impl RepeatedJsPass + 'a {
+    Remover {
+        comments,
+        ..Default::default()
+    }
 }
 
-impl CompilerPass for Remover {
+impl CompilerPass for Remover<'_> {
     fn name() -> Cow<'static, str> {
         Cow::Borrowed("branch")
     }
 }
 
-impl Repeated for Remover {
+impl Repeated for Remover<'_> {
     fn changed(&self) -> bool {
         self.changed
     }
@@ -39,12 +43,13 @@ impl Repeated for Remover {
 }
 
 #[derive(Debug, Default)]
-struct Remover {
+struct Remover<'a> {
     changed: bool,
     normal_block: bool,
+    comments: CommentRelocator<'a>,
 }
 
-impl Fold for Remover {
+impl Fold for Remover<'_> {
     noop_fold_type!();
 
     fn fold_array_pat(&mut self, p: ArrayPat) -> ArrayPat {
@@ -419,7 +424,9 @@ impl Fold for Remover {
                 if stmts.is_empty() {
                     Stmt::Empty(EmptyStmt { span })
                 } else if stmts.len() == 1 && !is_block_scoped_stuff(&stmts[0]) {
-                    stmts.into_iter().next().unwrap().fold_with(self)
+                    let stmt = stmts.into_iter().next().unwrap();
+                    self.comments.relocate(span, stmt.span());
+                    stmt.fold_with(self)
                 } else {
                     Stmt::Block(BlockStmt { span, stmts })
                 }
@@ -926,7 +933,7 @@ impl Fold for Remover {
     }
 }
 
-impl Remover {
+impl Remover<'_> {
     fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
     where
         T: StmtLike + VisitWith<Hoister> + FoldWith<Self>,
@@ -993,13 +1000,17 @@ impl Remover {
                                 }
                                 .into()
                             } else {
+                                let is_not_empty = |s: &Stmt| match s {
+                                    Stmt::Empty(..) => false,
+                                    _ => true,
+                                };
+                                if let Some(first) = stmts.iter().find(|s| is_not_empty(s)) {
+                                    self.comments.relocate(span, first.span());
+                                }
                                 buf.extend(
                                     stmts
                                         .into_iter()
-                                        .filter(|s| match s {
-                                            Stmt::Empty(..) => false,
-                                            _ => true,
-                                        })
+                                        .filter(|s| is_not_empty(s))
                                         .map(T::from_stmt),
                                 );
                                 continue;
@@ -1,11 +1,14 @@
 use super::{super::expr_simplifier, dead_branch_remover};
-use swc_common::chain;
+use crate::optimization::CommentRelocator;
+use swc_common::{chain, comments::Comments, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::FoldWith;
 
 macro_rules! test_stmt {
     ($l:expr, $r:expr) => {
         test_transform!(
             ::swc_ecma_parser::Syntax::default(),
-            |_| chain!(expr_simplifier(), dead_branch_remover()),
+            |_| chain!(expr_simplifier(), dead_branch_remover(Default::default())),
             $l,
             $r
         )
@@ -1721,3 +1724,81 @@ c = 3;
 console.log(c);",
     );
 }
+
+/// The leading comment of `src` should be attached to the second statement of
+/// the output.
+fn test_comment_kept(src: &str) {
+    crate::tests::Tester::run(|tester| {
+        let module = tester.parse_module("input.js", src)?;
+
+        let comments = tester.comments.clone();
+        let module = module.fold_with(&mut dead_branch_remover(CommentRelocator::new(Some(
+            &*comments,
+        ))));
+
+        let span = match &module.body[1] {
+            ModuleItem::Stmt(Stmt::Expr(s)) => s.span(),
+            item => unreachable!("unexpected item: {:?}", item),
+        };
+        assert!(comments.has_leading(span.lo), "comment is lost");
+
+        Ok(())
+    });
+}
+
+#[test]
+fn comment_of_merged_block() {
+    test_comment_kept(
+        "foo();
+// comment
+{
+    bar();
+    baz();
+}",
+    );
+}
+
+#[test]
+fn comment_of_removed_if() {
+    test_comment_kept(
+        "foo();
+// comment
+if (true) {
+    bar();
+}",
+    );
+}
+
+#[test]
+fn comment_order_of_merged_block() {
+    crate::tests::Tester::run(|tester| {
+        let module = tester.parse_module(
+            "input.js",
+            "foo();
+// outer
+{
+    // inner
+    bar();
+}",
+        )?;
+
+        let comments = tester.comments.clone();
+        let module = module.fold_with(&mut dead_branch_remover(CommentRelocator::new(Some(
+            &*comments,
+        ))));
+
+        let span = match &module.body[1] {
+            ModuleItem::Stmt(Stmt::Expr(s)) => s.span(),
+            item => unreachable!("unexpected item: {:?}", item),
+        };
+        let texts: Vec<_> = comments
+            .take_leading(span.lo)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| c.text.trim().to_string())
+            .collect();
+        assert_eq!(texts, vec!["outer", "inner"]);
+
+        Ok(())
+    });
+}
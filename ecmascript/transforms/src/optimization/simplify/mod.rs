@@ -1,6 +1,6 @@
 //! Ported from closure compiler.
 pub use self::{branch::dead_branch_remover, expr::expr_simplifier};
-use crate::pass::RepeatedJsPass;
+use crate::{optimization::CommentRelocator, pass::RepeatedJsPass};
 use swc_common::{chain, pass::Repeat};
 
 mod branch;
@@ -13,6 +13,8 @@ pub mod inlining;
 pub struct Config<'a> {
     pub dce: dce::Config<'a>,
     pub inlining: inlining::Config,
+    /// Used to keep comments attached to merged statements.
+    pub comments: CommentRelocator<'a>,
 }
 
 /// Performs simplify-expr, inlining, remove-dead-branch and dce until nothing
@@ -21,7 +23,7 @@ pub fn simplifier<'a>(c: Config<'a>) -> impl RepeatedJsPass + 'a {
     Repeat::new(chain!(
         expr_simplifier(),
         inlining::inlining(c.inlining),
-        dead_branch_remover(),
+        dead_branch_remover(c.comments),
         dce::dce(c.dce)
     ))
 }
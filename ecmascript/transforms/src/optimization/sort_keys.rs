@@ -0,0 +1,131 @@
+use crate::util::ExprExt;
+use swc_atoms::JsWord;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Sorts the keys of object literals alphabetically, to make the output
+/// deterministic.
+///
+/// ```js
+/// const style = { width: 10, color: 'red', height: 20 };
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const style = { color: 'red', height: 20, width: 10 };
+/// ```
+///
+/// An object is sorted only if
+///
+///  - it has no getters, setters or spreads,
+///  - it has no computed keys,
+///  - no value has a side effect, as values are evaluated in order.
+///
+/// The sort is stable, so the last one of duplicate keys still wins.
+pub fn sort_object_keys() -> impl Fold {
+    as_folder(SortObjectKeys)
+}
+
+struct SortObjectKeys;
+
+impl VisitMut for SortObjectKeys {
+    noop_visit_mut_type!();
+
+    fn visit_mut_object_lit(&mut self, obj: &mut ObjectLit) {
+        obj.visit_mut_children_with(self);
+
+        let mut keys = Vec::with_capacity(obj.props.len());
+        for prop in &obj.props {
+            match key_of(prop) {
+                Some(key) => keys.push(key),
+                None => return,
+            }
+        }
+
+        let mut props: Vec<_> = keys.into_iter().zip(obj.props.drain(..)).collect();
+        props.sort_by(|(a, _), (b, _)| a.cmp(b));
+        obj.props = props.into_iter().map(|(_, prop)| prop).collect();
+    }
+}
+
+/// Returns the key of `prop` if it can be moved.
+fn key_of(prop: &PropOrSpread) -> Option<JsWord> {
+    let prop = match prop {
+        PropOrSpread::Prop(prop) => prop,
+        PropOrSpread::Spread(..) => return None,
+    };
+
+    let key = match &**prop {
+        Prop::Shorthand(i) => return Some(i.sym.clone()),
+        Prop::KeyValue(KeyValueProp { value, .. }) if value.may_have_side_effects() => return None,
+        Prop::KeyValue(KeyValueProp { key, .. }) => key,
+        Prop::Method(MethodProp { key, .. }) => key,
+        _ => return None,
+    };
+
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        PropName::Num(n) => Some(n.to_string().into()),
+        PropName::BigInt(n) => Some(n.value.to_string().into()),
+        PropName::Computed(..) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sort_object_keys;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| sort_object_keys(),
+        simple,
+        "
+const a = { width: 10, 'color': 'red', height, draw() {}, 1: one };
+",
+        "
+const a = { 1: one, 'color': 'red', draw() {}, height, width: 10 };
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| sort_object_keys(),
+        nested,
+        "
+const a = { b: { d: 1, c: 2 }, a: 1 };
+",
+        "
+const a = { a: 1, b: { c: 2, d: 1 } };
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| sort_object_keys(),
+        spread,
+        "
+const a = { b: 1, ...rest, a: 2 };
+",
+        "
+const a = { b: 1, ...rest, a: 2 };
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| sort_object_keys(),
+        unsafe_props,
+        "
+const a = { b: 1, get a() { return 2; } };
+const b = { b: 1, [a]: 2 };
+const c = { b: f(), a: g() };
+",
+        "
+const a = { b: 1, get a() { return 2; } };
+const b = { b: 1, [a]: 2 };
+const c = { b: f(), a: g() };
+"
+    );
+}
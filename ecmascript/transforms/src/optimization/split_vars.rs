@@ -0,0 +1,148 @@
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Splits variable declarations so that each declares one variable.
+///
+/// ```js
+/// const a = 1, b = a + 1;
+/// export let c, d;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const a = 1;
+/// const b = a + 1;
+/// export let c;
+/// export let d;
+/// ```
+///
+/// Declarations in the head of `for` loops are not changed.
+pub fn split_vars() -> impl Fold {
+    as_folder(SplitVars)
+}
+
+struct SplitVars;
+
+fn split(var: VarDecl) -> impl Iterator<Item = VarDecl> {
+    let VarDecl {
+        span,
+        kind,
+        declare,
+        decls,
+    } = var;
+
+    decls.into_iter().map(move |decl| VarDecl {
+        span,
+        kind,
+        declare,
+        decls: vec![decl],
+    })
+}
+
+impl VisitMut for SplitVars {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        if !items.iter().any(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var),
+                ..
+            })) => var.decls.len() > 1,
+            _ => false,
+        }) {
+            return;
+        }
+
+        let mut buf = Vec::with_capacity(items.len() + 4);
+        for item in items.drain(..) {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+                    buf.extend(split(var).map(|var| ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))))
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: Decl::Var(var),
+                })) => buf.extend(split(var).map(|var| {
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        span,
+                        decl: Decl::Var(var),
+                    }))
+                })),
+                _ => buf.push(item),
+            }
+        }
+        *items = buf;
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        if !stmts.iter().any(|stmt| match stmt {
+            Stmt::Decl(Decl::Var(var)) => var.decls.len() > 1,
+            _ => false,
+        }) {
+            return;
+        }
+
+        let mut buf = Vec::with_capacity(stmts.len() + 4);
+        for stmt in stmts.drain(..) {
+            match stmt {
+                Stmt::Decl(Decl::Var(var)) => {
+                    buf.extend(split(var).map(|var| Stmt::Decl(Decl::Var(var))))
+                }
+                _ => buf.push(stmt),
+            }
+        }
+        *stmts = buf;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_vars;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| split_vars(),
+        module_const,
+        "
+const a = 1, b = a + 1, { c } = d;
+export let e, f = b;
+",
+        "
+const a = 1;
+const b = a + 1;
+const { c } = d;
+export let e;
+export let f = b;
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| split_vars(),
+        nested,
+        "
+function foo() {
+    var a = 1, b;
+    for (let i = 0, j = 1; i < j; i++) {
+        let c = i, d = j;
+    }
+}
+",
+        "
+function foo() {
+    var a = 1;
+    var b;
+    for (let i = 0, j = 1; i < j; i++) {
+        let c = i;
+        let d = j;
+    }
+}
+"
+    );
+}
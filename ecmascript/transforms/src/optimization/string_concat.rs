@@ -0,0 +1,275 @@
+use std::mem;
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Merges concatenations of string literals and template literals.
+///
+/// ```js
+/// "a" + `b${x}` + "c";
+/// `a${`b${x}`}`;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// `ab${x}c`;
+/// `ab${x}`;
+/// ```
+///
+/// Only operands which are already strings are merged, so `` `a` + x `` is
+/// kept as-is: `+` converts `x` with `valueOf` while an interpolation uses
+/// `toString`. Interpolations keep their relative order, so they are evaluated
+/// in the same order.
+pub fn merge_string_concat() -> impl Fold {
+    as_folder(MergeStringConcat)
+}
+
+struct MergeStringConcat;
+
+impl VisitMut for MergeStringConcat {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let bin = match e {
+            Expr::Bin(bin) if bin.op == op!(bin, "+") && is_string_lit(&bin.right) => bin,
+            _ => return,
+        };
+
+        if is_string_lit(&bin.left) {
+            let left = mem::replace(&mut *bin.left, Expr::Invalid(Invalid { span: DUMMY_SP }));
+            let right = mem::replace(&mut *bin.right, Expr::Invalid(Invalid { span: DUMMY_SP }));
+            *e = concat(bin.span, left, right);
+            return;
+        }
+
+        // `(x + "a") + "b"` is `x + "ab"` because `x + "a"` is a string.
+        match &mut *bin.left {
+            Expr::Bin(left) if left.op == op!(bin, "+") && is_string_lit(&left.right) => {
+                let l = mem::replace(&mut *left.right, Expr::Invalid(Invalid { span: DUMMY_SP }));
+                let r = mem::replace(&mut *bin.right, Expr::Invalid(Invalid { span: DUMMY_SP }));
+                *left.right = concat(left.span, l, r);
+                left.span = bin.span;
+            }
+            _ => return,
+        }
+
+        let left = mem::replace(
+            &mut bin.left,
+            Box::new(Expr::Invalid(Invalid { span: DUMMY_SP })),
+        );
+        *e = *left;
+    }
+
+    fn visit_mut_tpl(&mut self, tpl: &mut Tpl) {
+        tpl.visit_mut_children_with(self);
+
+        if !tpl.exprs.iter().any(|e| is_string_lit(e)) {
+            return;
+        }
+
+        let mut quasis = mem::replace(&mut tpl.quasis, vec![]).into_iter();
+        let exprs = mem::replace(&mut tpl.exprs, vec![]);
+        let mut parts = Parts {
+            quasis: quasis.next().into_iter().collect(),
+            exprs: vec![],
+        };
+        for (expr, quasi) in exprs.into_iter().zip(quasis) {
+            if is_string_lit(&expr) {
+                parts.append(Parts::from(*expr));
+                parts.append(Parts {
+                    quasis: vec![quasi],
+                    exprs: vec![],
+                });
+            } else {
+                parts.exprs.push(expr);
+                parts.quasis.push(quasi);
+            }
+        }
+
+        *tpl = parts.into_tpl(tpl.span);
+    }
+}
+
+/// Returns true if `e` is a string literal or a template literal which can be
+/// merged.
+fn is_string_lit(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Str(..)) => true,
+        Expr::Tpl(tpl) => tpl.quasis.iter().all(|q| q.cooked.is_some()),
+        _ => false,
+    }
+}
+
+fn concat(span: Span, left: Expr, right: Expr) -> Expr {
+    match (left, right) {
+        (Expr::Lit(Lit::Str(l)), Expr::Lit(Lit::Str(r))) => Expr::Lit(Lit::Str(Str {
+            span,
+            value: format!("{}{}", l.value, r.value).into(),
+            has_escape: l.has_escape || r.has_escape,
+        })),
+        (left, right) => {
+            let mut parts = Parts::from(left);
+            parts.append(Parts::from(right));
+            Expr::Tpl(parts.into_tpl(span))
+        }
+    }
+}
+
+/// Quasis and interpolations of a template literal.
+///
+/// `quasis` has one more element than `exprs`.
+struct Parts {
+    quasis: Vec<TplElement>,
+    exprs: Vec<Box<Expr>>,
+}
+
+impl From<Expr> for Parts {
+    fn from(e: Expr) -> Self {
+        match e {
+            Expr::Lit(Lit::Str(s)) => Parts {
+                quasis: vec![TplElement {
+                    span: DUMMY_SP,
+                    tail: true,
+                    raw: Str {
+                        span: DUMMY_SP,
+                        value: escape_raw(&s.value).into(),
+                        has_escape: false,
+                    },
+                    cooked: Some(s),
+                }],
+                exprs: vec![],
+            },
+            Expr::Tpl(tpl) => Parts {
+                quasis: tpl.quasis,
+                exprs: tpl.exprs,
+            },
+            _ => unreachable!("Parts::from() called with {:?}", e),
+        }
+    }
+}
+
+impl Parts {
+    /// Appends `other`, merging the last quasi of `self` with the first quasi
+    /// of `other`.
+    fn append(&mut self, other: Parts) {
+        let mut quasis = other.quasis.into_iter();
+
+        if let (Some(last), Some(first)) = (self.quasis.last_mut(), quasis.next()) {
+            last.raw.value = format!("{}{}", last.raw.value, first.raw.value).into();
+            if let (Some(l), Some(r)) = (&mut last.cooked, first.cooked) {
+                l.value = format!("{}{}", l.value, r.value).into();
+                l.has_escape |= r.has_escape;
+            }
+        }
+        self.quasis.extend(quasis);
+        self.exprs.extend(other.exprs);
+    }
+
+    fn into_tpl(mut self, span: Span) -> Tpl {
+        let last = self.quasis.len() - 1;
+        for (i, quasi) in self.quasis.iter_mut().enumerate() {
+            quasi.tail = i == last;
+        }
+
+        Tpl {
+            span,
+            exprs: self.exprs,
+            quasis: self.quasis,
+        }
+    }
+}
+
+/// Escapes a cooked string so it can be used as the raw value of a quasi.
+fn escape_raw(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => buf.push_str("\\\\"),
+            '`' => buf.push_str("\\`"),
+            '\r' => buf.push_str("\\r"),
+            '$' if chars.peek() == Some(&'{') => buf.push_str("\\$"),
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_string_concat;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        str_and_tpl,
+        r#""a" + `b${x}`;"#,
+        "`ab${x}`;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        tpl_and_tpl,
+        "`a${x}` + `b${y}c`;",
+        "`a${x}b${y}c`;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        chain,
+        r#"x + "a" + `b${y}` + "c";"#,
+        "x + `ab${y}c`;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        nested,
+        r#"`a${`b${x}`}c${"d"}`;"#,
+        "`ab${x}cd`;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        escape,
+        r#""`${" + `${x}`;"#,
+        r"`\`\${${x}`;"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        non_string,
+        "`a` + x; x + `a`; 1 + `a`;",
+        "`a` + x; x + `a`; 1 + `a`;"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| merge_string_concat(),
+        evaluation_order,
+        r#"
+const log = [];
+const value = (name) => ({
+    toString() {
+        log.push(name);
+        return name;
+    },
+    valueOf() {
+        return 'valueOf';
+    },
+});
+
+expect("a" + `${value('b')}` + `${value('c')}\`` + "${d}").toBe("abc`${d}");
+expect(log).toEqual(['b', 'c']);
+"#
+    );
+}
@@ -0,0 +1,130 @@
+use swc_atoms::js_word;
+use swc_common::SyntaxContext;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Converts `String.raw` tagged templates without interpolations into string
+/// literals.
+///
+/// ```js
+/// String.raw`a\nb`
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// "a\\nb"
+/// ```
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver, as `String` is folded only
+/// if it's a reference to the global.
+pub fn inline_string_raw() -> impl Fold {
+    as_folder(InlineStringRaw)
+}
+
+struct InlineStringRaw;
+
+impl VisitMut for InlineStringRaw {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let tpl = match e {
+            Expr::TaggedTpl(tpl) if tpl.exprs.is_empty() && is_global_string_raw(&tpl.tag) => tpl,
+            _ => return,
+        };
+
+        let value = match tpl.quasis.first() {
+            Some(quasi) => quasi.raw.value.clone(),
+            None => js_word!(""),
+        };
+
+        let has_escape = value
+            .chars()
+            .any(|c| matches!(c, '\\' | '\n' | '\r' | '\u{2028}' | '\u{2029}'));
+
+        *e = Expr::Lit(Lit::Str(Str {
+            span: tpl.span,
+            value,
+            has_escape,
+        }));
+    }
+}
+
+fn is_global_string_raw(tag: &Expr) -> bool {
+    match tag {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => match (&**obj, &**prop) {
+            (
+                Expr::Ident(Ident {
+                    sym: js_word!("String"),
+                    span,
+                    ..
+                }),
+                Expr::Ident(Ident { sym: prop, .. }),
+            ) => &**prop == "raw" && span.ctxt() == SyntaxContext::empty(),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inline_string_raw;
+    use crate::resolver;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), inline_string_raw()),
+        escape,
+        r#"String.raw`a\nb`;"#,
+        r#""a\\nb";"#
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), inline_string_raw()),
+        no_escape,
+        "const foo = String.raw`foo`;",
+        "const foo = 'foo';"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), inline_string_raw()),
+        interpolation,
+        r#"String.raw`a\n${b}`;"#,
+        r#"String.raw`a\n${b}`;"#
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), inline_string_raw()),
+        shadowed,
+        r#"
+const String = { raw: foo };
+String.raw`a\nb`;
+"#,
+        r#"
+const String = { raw: foo };
+String.raw`a\nb`;
+"#
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), inline_string_raw()),
+        other_tag,
+        r#"String.foo`a\nb`;"#,
+        r#"String.foo`a\nb`;"#
+    );
+}
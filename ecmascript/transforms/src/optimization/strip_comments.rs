@@ -0,0 +1,111 @@
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos, Span,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Removes all comments from `comments`, so that codegen emits none.
+///
+/// Unlike the preserve predicate of the `swc` crate, this removes `@license`
+/// and `@preserve` comments, too.
+///
+/// Comments are removed from every position in the source range of the
+/// program, and from the start and end of every span in it. The latter
+/// handles synthesized nodes which have spans outside of the program, like
+/// nodes copied from another file.
+pub fn strip_comments<'a>(comments: &'a dyn Comments) -> impl 'a + Fold {
+    as_folder(StripComments { comments })
+}
+
+struct StripComments<'a> {
+    comments: &'a dyn Comments,
+}
+
+impl StripComments<'_> {
+    fn strip(&self, pos: BytePos) {
+        self.comments.take_leading(pos);
+        self.comments.take_trailing(pos);
+    }
+
+    fn strip_range(&self, span: Span) {
+        if span.is_dummy() {
+            return;
+        }
+
+        let mut outside = |pos: BytePos, _: &Comment| pos < span.lo || span.hi < pos;
+        self.comments.retain_leading(&mut outside);
+        self.comments.retain_trailing(&mut outside);
+    }
+}
+
+impl VisitMut for StripComments<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.strip_range(m.span);
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.strip_range(s.span);
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        self.strip(span.lo);
+        self.strip(span.hi);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_comments;
+    use crate::tests::Tester;
+    use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+    use swc_ecma_visit::FoldWith;
+
+    #[test]
+    fn license() {
+        Tester::run(|tester| {
+            let module = tester.parse_module(
+                "input.js",
+                "/*! @license MIT */
+// leading
+foo(/* arg */ a); // trailing
+/**
+ * @preserve
+ */
+function bar() {
+    return; /* end */
+}",
+            )?;
+
+            let comments = tester.comments.clone();
+            let module = module.fold_with(&mut strip_comments(&*comments));
+
+            let mut buf = vec![];
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: tester.cm.clone(),
+                    comments: Some(&*comments),
+                    ident_hook: None,
+                    wr: Box::new(JsWriter::new(tester.cm.clone(), "\n", &mut buf, None)),
+                };
+                emitter.emit_module(&module).unwrap();
+            }
+            let out = String::from_utf8(buf).unwrap();
+
+            assert_eq!(
+                out.trim(),
+                "foo(a);
+function bar() {
+    return;
+}"
+            );
+
+            Ok(())
+        });
+    }
+}
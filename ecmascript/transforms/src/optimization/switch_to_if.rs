@@ -0,0 +1,353 @@
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+/// Engines compile large switches over constants into jump tables, so only
+/// small ones are converted.
+const MAX_CASES: usize = 4;
+
+/// Converts small `switch` statements into `if` / `else` chains.
+///
+/// ```js
+/// switch (x) {
+///     case 'a':
+///         a();
+///         break;
+///     case 'b':
+///         b();
+///         break;
+///     default:
+///         c();
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// if (x === 'a') {
+///     a();
+/// } else if (x === 'b') {
+///     b();
+/// } else {
+///     c();
+/// }
+/// ```
+///
+/// A switch is converted only if
+///
+///  - the discriminant is an identifier,
+///  - each case is a literal,
+///  - no case falls through to the next one,
+///  - `default` is the last case,
+///  - no case body contains a block-scoped declaration or a `break` other than
+///    the last statement.
+pub fn switch_to_if() -> impl Fold {
+    as_folder(SwitchToIf)
+}
+
+struct SwitchToIf;
+
+impl VisitMut for SwitchToIf {
+    noop_visit_mut_type!();
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        let switch = match s {
+            Stmt::Switch(switch) if can_convert(switch) => switch,
+            _ => return,
+        };
+
+        let span = switch.span;
+        let discriminant = match &*switch.discriminant {
+            Expr::Ident(i) => i.clone(),
+            _ => unreachable!(),
+        };
+
+        let mut alt: Option<Box<Stmt>> = None;
+
+        for case in switch.cases.drain(..).rev() {
+            let mut stmts = case.cons;
+            if let Some(Stmt::Break(BreakStmt { label: None, .. })) = stmts.last() {
+                stmts.pop();
+            }
+            let body = Box::new(Stmt::Block(BlockStmt {
+                span: case.span,
+                stmts,
+            }));
+
+            alt = Some(match case.test {
+                None => body,
+                Some(test) => Box::new(Stmt::If(IfStmt {
+                    span: DUMMY_SP,
+                    test: Box::new(Expr::Bin(BinExpr {
+                        span: DUMMY_SP,
+                        op: op!("==="),
+                        left: Box::new(Expr::Ident(discriminant.clone())),
+                        right: test,
+                    })),
+                    cons: body,
+                    alt,
+                })),
+            });
+        }
+
+        *s = match alt.map(|alt| *alt) {
+            Some(Stmt::If(stmt)) => Stmt::If(IfStmt { span, ..stmt }),
+            _ => unreachable!(),
+        };
+    }
+}
+
+fn can_convert(s: &SwitchStmt) -> bool {
+    match &*s.discriminant {
+        Expr::Ident(..) => {}
+        _ => return false,
+    }
+
+    if s.cases.is_empty() || s.cases.len() > MAX_CASES {
+        return false;
+    }
+
+    let last = s.cases.len() - 1;
+
+    for (i, case) in s.cases.iter().enumerate() {
+        match &case.test {
+            None if i == last && i != 0 => {}
+            Some(test) => match &**test {
+                Expr::Lit(Lit::Str(..))
+                | Expr::Lit(Lit::Num(..))
+                | Expr::Lit(Lit::Bool(..))
+                | Expr::Lit(Lit::Null(..)) => {}
+                _ => return false,
+            },
+            _ => return false,
+        }
+
+        let mut stmts = &*case.cons;
+        let terminated = match stmts.last() {
+            Some(Stmt::Break(BreakStmt { label: None, .. })) => {
+                stmts = &stmts[..stmts.len() - 1];
+                true
+            }
+            Some(Stmt::Return(..)) | Some(Stmt::Throw(..)) => true,
+            _ => false,
+        };
+
+        // Falls through to the next case.
+        if !terminated && i != last {
+            return false;
+        }
+
+        let has_decl = stmts.iter().any(|s| match s {
+            Stmt::Decl(Decl::Var(VarDecl {
+                kind: VarDeclKind::Var,
+                ..
+            })) => false,
+            Stmt::Decl(..) => true,
+            _ => false,
+        });
+        if has_decl || contains_unlabeled_break(stmts) {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub(super) fn contains_unlabeled_break(stmts: &[Stmt]) -> bool {
+    let mut v = BreakFinder { found: false };
+    for s in stmts {
+        s.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    }
+    v.found
+}
+
+/// Finds `break` statements which target the switch statement.
+struct BreakFinder {
+    found: bool,
+}
+
+impl Visit for BreakFinder {
+    noop_visit_type!();
+
+    fn visit_break_stmt(&mut self, s: &BreakStmt, _: &dyn Node) {
+        if s.label.is_none() {
+            self.found = true;
+        }
+    }
+
+    fn visit_do_while_stmt(&mut self, _: &DoWhileStmt, _: &dyn Node) {}
+
+    fn visit_for_in_stmt(&mut self, _: &ForInStmt, _: &dyn Node) {}
+
+    fn visit_for_of_stmt(&mut self, _: &ForOfStmt, _: &dyn Node) {}
+
+    fn visit_for_stmt(&mut self, _: &ForStmt, _: &dyn Node) {}
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_switch_stmt(&mut self, _: &SwitchStmt, _: &dyn Node) {}
+
+    fn visit_while_stmt(&mut self, _: &WhileStmt, _: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::switch_to_if;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| switch_to_if(),
+        simple,
+        "
+function foo(x) {
+    switch (x) {
+        case 'a':
+            a();
+            break;
+        case 'b':
+            return b();
+        default:
+            c();
+    }
+}
+",
+        "
+function foo(x) {
+    if (x === 'a') {
+        a();
+    } else if (x === 'b') {
+        return b();
+    } else {
+        c();
+    }
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| switch_to_if(),
+        fallthrough,
+        "
+switch (x) {
+    case 'a':
+        a();
+    case 'b':
+        b();
+        break;
+}
+",
+        "
+switch (x) {
+    case 'a':
+        a();
+    case 'b':
+        b();
+        break;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| switch_to_if(),
+        empty_case,
+        "
+switch (x) {
+    case 'a':
+    case 'b':
+        b();
+        break;
+}
+",
+        "
+switch (x) {
+    case 'a':
+    case 'b':
+        b();
+        break;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| switch_to_if(),
+        nested_break,
+        "
+switch (x) {
+    case 'a':
+        if (y) break;
+        a();
+        break;
+    case 'b':
+        for (;;) break;
+        break;
+}
+",
+        "
+switch (x) {
+    case 'a':
+        if (y) break;
+        a();
+        break;
+    case 'b':
+        for (;;) break;
+        break;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| switch_to_if(),
+        non_constant_case,
+        "
+switch (x) {
+    case foo:
+        a();
+        break;
+}
+",
+        "
+switch (x) {
+    case foo:
+        a();
+        break;
+}
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| switch_to_if(),
+        exec,
+        "
+function f(x) {
+    const log = [];
+    switch (x) {
+        case 'a':
+            log.push('a');
+            break;
+        case 1:
+            log.push(1);
+            break;
+        default:
+            log.push('default');
+    }
+    return log;
+}
+
+expect(f('a')).toEqual(['a']);
+expect(f(1)).toEqual([1]);
+expect(f('1')).toEqual(['default']);
+"
+    );
+}
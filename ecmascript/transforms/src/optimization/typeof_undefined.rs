@@ -0,0 +1,136 @@
+use crate::util::undefined;
+use std::mem;
+use swc_common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Rewrites `typeof x === 'undefined'` to `void 0 === x` if `x` is a declared
+/// binding.
+///
+/// ```js
+/// let a;
+/// typeof a === 'undefined';
+/// typeof b !== 'undefined';
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// let a;
+/// void 0 === a;
+/// typeof b !== 'undefined';
+/// ```
+///
+/// `typeof` is preserved for identifiers which are not declared, as reading
+/// them throws a `ReferenceError`.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver, as unresolved references
+/// are detected by their syntax context.
+pub fn typeof_undefined() -> impl Fold {
+    as_folder(TypeofUndefined)
+}
+
+struct TypeofUndefined;
+
+impl VisitMut for TypeofUndefined {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let bin = match e {
+            Expr::Bin(bin) => bin,
+            _ => return,
+        };
+        let op = match bin.op {
+            op!("===") | op!("==") => op!("==="),
+            op!("!==") | op!("!=") => op!("!=="),
+            _ => return,
+        };
+
+        let arg = match (&mut *bin.left, &mut *bin.right) {
+            (Expr::Unary(unary), other) | (other, Expr::Unary(unary))
+                if unary.op == op!("typeof") && is_undefined_str(other) =>
+            {
+                match &mut *unary.arg {
+                    Expr::Ident(i) if i.span.ctxt() != SyntaxContext::empty() => {
+                        mem::replace(&mut unary.arg, undefined(DUMMY_SP))
+                    }
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+
+        let span = bin.span;
+        *e = Expr::Bin(BinExpr {
+            span,
+            op,
+            left: undefined(DUMMY_SP),
+            right: arg,
+        });
+    }
+}
+
+fn is_undefined_str(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(Lit::Str(s)) => &*s.value == "undefined",
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::typeof_undefined;
+    use crate::resolver;
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), typeof_undefined()),
+        local,
+        "
+let a;
+function foo(b) {
+    return typeof b === 'undefined' || 'undefined' != typeof a;
+}
+",
+        "
+let a;
+function foo(b) {
+    return void 0 === b || void 0 !== a;
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), typeof_undefined()),
+        global,
+        "
+typeof window === 'undefined';
+typeof a.b === 'undefined';
+typeof c === 'object';
+",
+        "
+typeof window === 'undefined';
+typeof a.b === 'undefined';
+typeof c === 'object';
+"
+    );
+
+    test_exec!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(resolver(), typeof_undefined()),
+        exec,
+        "
+let a;
+const b = null;
+expect(typeof a === 'undefined').toBe(true);
+expect(typeof b === 'undefined').toBe(false);
+expect(typeof notDeclared === 'undefined').toBe(true);
+"
+    );
+}
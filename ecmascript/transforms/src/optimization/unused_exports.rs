@@ -0,0 +1,255 @@
+use fxhash::FxHashSet;
+use swc_atoms::{js_word, JsWord};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::find_ids;
+use swc_ecma_visit::{noop_fold_type, Fold};
+
+/// Removes exports whose names are not in `used`.
+///
+/// Exported declarations are kept as local declarations, so a dce pass
+/// applied after this pass can remove them if they are not referenced and
+/// have no side effect.
+///
+/// ```js
+/// export const a = 1;
+/// export const b = sideEffect();
+/// export default foo();
+/// ```
+///
+/// becomes, if only `a` is used,
+///
+/// ```js
+/// export const a = 1;
+/// const b = sideEffect();
+/// foo();
+/// ```
+///
+///  - A re-export which exports no used name becomes a side-effect import of
+///    the module. `export * from 'foo'` is kept as-is.
+///  - A declaration with multiple bindings is kept as an export if any of the
+///    bindings is used.
+///  - `export default` is removed unless `default` is in `used`.
+pub fn remove_unused_exports(used: FxHashSet<JsWord>) -> impl Fold {
+    RemoveUnusedExports { used }
+}
+
+struct RemoveUnusedExports {
+    used: FxHashSet<JsWord>,
+}
+
+impl RemoveUnusedExports {
+    fn is_used(&self, sym: &JsWord) -> bool {
+        self.used.contains(sym)
+    }
+
+    fn is_used_specifier(&self, s: &ExportSpecifier) -> bool {
+        let exported = match s {
+            ExportSpecifier::Namespace(s) => &s.name,
+            ExportSpecifier::Default(s) => &s.exported,
+            ExportSpecifier::Named(s) => s.exported.as_ref().unwrap_or(&s.orig),
+        };
+
+        self.is_used(&exported.sym)
+    }
+
+    fn is_used_decl(&self, decl: &Decl) -> bool {
+        match decl {
+            Decl::Class(c) => self.is_used(&c.ident.sym),
+            Decl::Fn(f) => self.is_used(&f.ident.sym),
+            Decl::Var(var) => var.decls.iter().any(|decl| {
+                let ids: Vec<Ident> = find_ids(&decl.name);
+                ids.iter().any(|id| self.is_used(&id.sym))
+            }),
+            Decl::TsEnum(e) => self.is_used(&e.id.sym),
+            // Types don't have a value.
+            _ => true,
+        }
+    }
+
+    /// Returns [None] if the item should be removed.
+    fn fold_item(&self, item: ModuleItem) -> Option<ModuleItem> {
+        let decl = match item {
+            ModuleItem::ModuleDecl(decl) => decl,
+            _ => return Some(item),
+        };
+
+        Some(match decl {
+            ModuleDecl::ExportDecl(export) => {
+                if self.is_used_decl(&export.decl) {
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export))
+                } else {
+                    ModuleItem::Stmt(Stmt::Decl(export.decl))
+                }
+            }
+
+            ModuleDecl::ExportNamed(mut export) => {
+                if export.specifiers.is_empty() {
+                    return Some(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)));
+                }
+
+                export.specifiers.retain(|s| self.is_used_specifier(s));
+                if !export.specifiers.is_empty() {
+                    return Some(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)));
+                }
+
+                // The module is still evaluated.
+                let src = export.src?;
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: export.span,
+                    specifiers: vec![],
+                    src,
+                    type_only: false,
+                    asserts: None,
+                    with: false,
+                }))
+            }
+
+            ModuleDecl::ExportDefaultDecl(export) if !self.is_used(&js_word!("default")) => {
+                match export.decl {
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident),
+                        function,
+                    }) => ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+                        ident,
+                        declare: false,
+                        function,
+                    }))),
+                    DefaultDecl::Fn(..) => return None,
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident),
+                        class,
+                    }) => ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl {
+                        ident,
+                        declare: false,
+                        class,
+                    }))),
+                    // An anonymous class may have side effects, like computed keys.
+                    DefaultDecl::Class(class) => ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                        span: export.span,
+                        expr: Box::new(Expr::Paren(ParenExpr {
+                            span: DUMMY_SP,
+                            expr: Box::new(Expr::Class(class)),
+                        })),
+                    })),
+                    DefaultDecl::TsInterfaceDecl(decl) => {
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                            decl: DefaultDecl::TsInterfaceDecl(decl),
+                            ..export
+                        }))
+                    }
+                }
+            }
+
+            ModuleDecl::ExportDefaultExpr(export) if !self.is_used(&js_word!("default")) => {
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                    span: export.span,
+                    expr: export.expr,
+                }))
+            }
+
+            _ => ModuleItem::ModuleDecl(decl),
+        })
+    }
+}
+
+impl Fold for RemoveUnusedExports {
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, items: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        items
+            .into_iter()
+            .filter_map(|item| self.fold_item(item))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_unused_exports;
+    use crate::{optimization::simplify::dce::dce, resolver};
+    use swc_common::chain;
+
+    fn used(names: &[&str]) -> fxhash::FxHashSet<swc_atoms::JsWord> {
+        names.iter().map(|&name| name.into()).collect()
+    }
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_unused_exports(used(&["a"])),
+        named,
+        "
+export const a = 1;
+export const b = 2;
+",
+        "
+export const a = 1;
+const b = 2;
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(
+            resolver(),
+            remove_unused_exports(used(&["a"])),
+            dce(Default::default())
+        ),
+        named_with_dce,
+        "
+export const a = 1;
+export const b = 2;
+",
+        "
+export const a = 1;
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_unused_exports(used(&["a"])),
+        side_effect,
+        "
+export const a = 1;
+export const b = sideEffect();
+export default foo();
+",
+        "
+export const a = 1;
+const b = sideEffect();
+foo();
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_unused_exports(used(&["b", "default"])),
+        specifiers,
+        "
+const a = 1, b = 2;
+export { a, b as default };
+export { b };
+export { c, d as e } from 'foo';
+export * from 'bar';
+",
+        "
+const a = 1, b = 2;
+export { b as default };
+export { b };
+import 'foo';
+export * from 'bar';
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| remove_unused_exports(used(&[])),
+        default_decl,
+        "
+export default function foo() {}
+",
+        "
+function foo() {}
+"
+    );
+}
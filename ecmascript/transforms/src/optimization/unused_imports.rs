@@ -0,0 +1,208 @@
+use fxhash::FxHashSet;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitWith,
+};
+
+/// Removes import specifiers which are not referenced by the module.
+///
+/// This is useful after passes which remove usages of imports, like the
+/// typescript stripper or the jsx transform.
+///
+/// ```js
+/// import foo, { bar } from 'foo';
+/// import * as ns from 'ns';
+/// import 'side-effect';
+///
+/// bar();
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// import { bar } from 'foo';
+/// import 'side-effect';
+///
+/// bar();
+/// ```
+///
+/// An import declaration is removed if all of its specifiers are removed.
+/// Imports without specifiers are preserved as they are imported only for
+/// their side effects.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver, as references are matched
+/// by their syntax context.
+pub fn remove_unused_imports() -> impl Fold {
+    as_folder(RemoveUnusedImports)
+}
+
+struct RemoveUnusedImports;
+
+impl VisitMut for RemoveUnusedImports {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let mut v = UsageCollector {
+            used: Default::default(),
+        };
+        m.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+        let used = v.used;
+
+        m.body.retain(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                import.specifiers.is_empty() || import.specifiers.iter().any(|s| is_used(&used, s))
+            }
+            _ => true,
+        });
+
+        for item in &mut m.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+                import.specifiers.retain(|s| is_used(&used, s));
+            }
+        }
+    }
+}
+
+fn is_used(used: &FxHashSet<Id>, s: &ImportSpecifier) -> bool {
+    let local = match s {
+        ImportSpecifier::Named(s) => &s.local,
+        ImportSpecifier::Default(s) => &s.local,
+        ImportSpecifier::Namespace(s) => &s.local,
+    };
+
+    used.contains(&local.to_id())
+}
+
+/// Collects identifiers which may refer to a binding.
+struct UsageCollector {
+    used: FxHashSet<Id>,
+}
+
+impl Visit for UsageCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        self.used.insert(i.to_id());
+    }
+
+    fn visit_import_decl(&mut self, _: &ImportDecl, _: &dyn Node) {}
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName, _: &dyn Node) {
+        if let PropName::Computed(n) = n {
+            n.visit_with(n as _, self);
+        }
+    }
+
+    fn visit_jsx_attr_name(&mut self, _: &JSXAttrName, _: &dyn Node) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remove_unused_imports;
+    use crate::{resolver, typescript::strip};
+    use swc_common::chain;
+    use swc_ecma_parser::Syntax;
+
+    test!(
+        Syntax::default(),
+        |_| chain!(resolver(), remove_unused_imports()),
+        specifiers,
+        "
+import foo, { bar, baz as qux } from 'foo';
+import * as ns from 'ns';
+import 'side-effect';
+
+bar();
+",
+        "
+import { bar } from 'foo';
+import 'side-effect';
+
+bar();
+"
+    );
+
+    test!(
+        Syntax::default(),
+        |_| chain!(resolver(), remove_unused_imports()),
+        member_prop,
+        "
+import { foo, bar } from 'foo';
+
+obj.foo;
+const o = { bar: 1 };
+",
+        "
+obj.foo;
+const o = {
+    bar: 1
+};
+"
+    );
+
+    test!(
+        Syntax::default(),
+        |_| chain!(resolver(), remove_unused_imports()),
+        shadowed,
+        "
+import { foo } from 'foo';
+
+function f(foo) {
+    return foo;
+}
+",
+        "
+function f(foo) {
+    return foo;
+}
+"
+    );
+
+    test!(
+        Syntax::default(),
+        |_| chain!(resolver(), remove_unused_imports()),
+        export,
+        "
+import { foo } from 'foo';
+import * as ns from 'ns';
+
+export { foo };
+ns.bar();
+",
+        "
+import { foo } from 'foo';
+import * as ns from 'ns';
+
+export { foo };
+ns.bar();
+"
+    );
+
+    test!(
+        Syntax::Typescript(Default::default()),
+        |_| chain!(strip(), resolver(), remove_unused_imports()),
+        after_strip,
+        "
+import * as types from './types';
+import { helper } from './helper';
+
+const x: types.Foo = helper();
+",
+        "
+import { helper } from './helper';
+
+const x = helper();
+"
+    );
+}
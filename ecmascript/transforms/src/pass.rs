@@ -1,3 +1,10 @@
+#[cfg(feature = "timings")]
+use scoped_tls::scoped_thread_local;
+#[cfg(feature = "timings")]
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
 use swc_common::pass::CompilerPass;
 pub use swc_common::pass::{Optional, Repeated};
 use swc_ecma_ast::{Module, Script};
@@ -26,3 +33,113 @@ impl<T: ?Sized> JsPass for T where T: CompilerPass + Fold {}
 pub trait RepeatedJsPass: Repeated + JsPass {}
 
 impl<T: ?Sized> RepeatedJsPass for T where T: Repeated + JsPass {}
+
+/// Records wall-clock time spent by `pass` while running inside
+/// [with_timings].
+///
+/// This returns `pass` as-is if the `timings` feature is disabled.
+#[cfg(not(feature = "timings"))]
+#[inline(always)]
+pub fn timed<P>(_name: &'static str, pass: P) -> impl Fold
+where
+    P: Fold,
+{
+    pass
+}
+
+/// Records wall-clock time spent by `pass` while running inside
+/// [with_timings].
+///
+/// This returns `pass` as-is if the `timings` feature is disabled.
+#[cfg(feature = "timings")]
+pub fn timed<P>(name: &'static str, pass: P) -> impl Fold
+where
+    P: Fold,
+{
+    Timed { name, pass }
+}
+
+#[cfg(feature = "timings")]
+scoped_thread_local!(static TIMINGS: RefCell<Vec<(String, Duration)>>);
+
+/// Runs `op` and returns time spent by each [timed] pass, in the order the
+/// passes finished.
+#[cfg(feature = "timings")]
+pub fn with_timings<F, Ret>(op: F) -> (Ret, Vec<(String, Duration)>)
+where
+    F: FnOnce() -> Ret,
+{
+    let timings = RefCell::new(vec![]);
+    let ret = TIMINGS.set(&timings, op);
+
+    (ret, timings.into_inner())
+}
+
+#[cfg(feature = "timings")]
+struct Timed<P> {
+    name: &'static str,
+    pass: P,
+}
+
+#[cfg(feature = "timings")]
+impl<P> Timed<P> {
+    fn record<N, F>(&mut self, node: N, op: F) -> N
+    where
+        F: FnOnce(&mut P, N) -> N,
+    {
+        if !TIMINGS.is_set() {
+            return op(&mut self.pass, node);
+        }
+
+        let start = Instant::now();
+        let node = op(&mut self.pass, node);
+        let elapsed = start.elapsed();
+
+        TIMINGS.with(|timings| {
+            timings.borrow_mut().push((self.name.to_string(), elapsed));
+        });
+
+        node
+    }
+}
+
+#[cfg(feature = "timings")]
+impl<P> Fold for Timed<P>
+where
+    P: Fold,
+{
+    fn fold_module(&mut self, m: Module) -> Module {
+        self.record(m, |pass, m| pass.fold_module(m))
+    }
+
+    fn fold_script(&mut self, s: Script) -> Script {
+        self.record(s, |pass, s| pass.fold_script(s))
+    }
+}
+
+#[cfg(all(test, feature = "timings"))]
+mod tests {
+    use super::{timed, with_timings};
+    use crate::{fixer, hygiene, resolver};
+    use swc_common::chain;
+    use swc_ecma_visit::FoldWith;
+
+    #[test]
+    fn multi_pass() {
+        crate::tests::Tester::run(|tester| {
+            let module = tester.parse_module("input.js", "let a = 1; { let a = 2; }")?;
+
+            let mut pass = chain!(
+                timed("resolver", resolver()),
+                timed("hygiene", hygiene()),
+                timed("fixer", fixer(None)),
+            );
+            let (_, timings) = with_timings(|| module.fold_with(&mut pass));
+
+            let names = timings.iter().map(|(name, _)| &**name).collect::<Vec<_>>();
+            assert_eq!(names, vec!["resolver", "hygiene", "fixer"]);
+
+            Ok(())
+        });
+    }
+}
@@ -1,8 +1,9 @@
 pub use self::{
     decorators::decorators, export_default_from::export_default_from,
-    import_assertions::import_assertions,
+    pipeline_operator::pipeline_operator, record_and_tuple::record_and_tuple,
 };
 
 pub mod decorators;
 mod export_default_from;
-mod import_assertions;
+mod pipeline_operator;
+mod record_and_tuple;
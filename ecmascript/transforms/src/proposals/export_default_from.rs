@@ -49,6 +49,7 @@ impl Fold for ExportDefaultFrom {
                                     .expect("`export default from` requires source"),
                                 type_only: false,
                                 asserts: None,
+                                with: false,
                             })));
                             extra_stmts.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
                                 NamedExport {
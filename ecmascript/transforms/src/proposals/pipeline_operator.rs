@@ -0,0 +1,194 @@
+use crate::util::{alias_if_required, ExprFactory, StmtLike};
+use std::mem::take;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
+
+/// `@babel/plugin-proposal-pipeline-operator`
+///
+/// Lowers `x |> f |> g` to `g(f(x))`. This is correct for both the minimal
+/// and the F# proposal.
+///
+/// If the left hand side is not an identifier or a literal, it's stored in a
+/// temporary variable so it's evaluated before the right hand side.
+pub fn pipeline_operator() -> impl Fold {
+    PipelineOperator::default()
+}
+
+#[derive(Default)]
+struct PipelineOperator {
+    vars: Vec<VarDeclarator>,
+}
+
+impl PipelineOperator {
+    fn fold_stmt_like<T>(&mut self, stmts: Vec<T>) -> Vec<T>
+    where
+        T: FoldWith<Self> + StmtLike,
+    {
+        let old = take(&mut self.vars);
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for stmt in stmts {
+            let stmt = stmt.fold_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: take(&mut self.vars),
+                    declare: false,
+                }))));
+            }
+
+            buf.push(stmt);
+        }
+
+        self.vars = old;
+
+        buf
+    }
+}
+
+impl Fold for PipelineOperator {
+    noop_fold_type!();
+
+    fn fold_module_items(&mut self, n: Vec<ModuleItem>) -> Vec<ModuleItem> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_stmts(&mut self, n: Vec<Stmt>) -> Vec<Stmt> {
+        self.fold_stmt_like(n)
+    }
+
+    fn fold_expr(&mut self, e: Expr) -> Expr {
+        let e = e.fold_children_with(self);
+
+        match e {
+            Expr::Bin(BinExpr {
+                span,
+                op: op!("|>"),
+                left,
+                right,
+            }) => {
+                let (alias, aliased) = alias_if_required(&left, "ref");
+
+                if !aliased {
+                    return Expr::Call(CallExpr {
+                        span,
+                        callee: right.as_callee(),
+                        args: vec![left.as_arg()],
+                        type_args: Default::default(),
+                    });
+                }
+
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(alias.clone()),
+                    init: None,
+                    definite: false,
+                });
+
+                Expr::Seq(SeqExpr {
+                    span,
+                    exprs: vec![
+                        Box::new(Expr::Assign(AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: PatOrExpr::Pat(Box::new(Pat::Ident(alias.clone()))),
+                            right: left,
+                        })),
+                        Box::new(Expr::Call(CallExpr {
+                            span: DUMMY_SP,
+                            callee: right.as_callee(),
+                            args: vec![alias.as_arg()],
+                            type_args: Default::default(),
+                        })),
+                    ],
+                })
+            }
+
+            _ => e,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pipeline_operator;
+    use swc_ecma_parser::{EsConfig, PipelineProposal, Syntax};
+
+    fn syntax() -> Syntax {
+        Syntax::Es(EsConfig {
+            pipeline: true,
+            pipeline_proposal: Some(PipelineProposal::Fsharp),
+            ..Default::default()
+        })
+    }
+
+    test!(
+        syntax(),
+        |_| pipeline_operator(),
+        simple,
+        "const result = x |> f |> g;",
+        "var _ref;
+const result = (_ref = f(x), g(_ref));"
+    );
+
+    test!(
+        syntax(),
+        |_| pipeline_operator(),
+        precedence,
+        "const result = a + 1 |> double;",
+        "var _ref;
+const result = (_ref = a + 1, double(_ref));"
+    );
+
+    test!(
+        syntax(),
+        |_| pipeline_operator(),
+        ident_left_member_right,
+        "x |> obj.method;",
+        "obj.method(x);"
+    );
+
+    test!(
+        syntax(),
+        |_| pipeline_operator(),
+        evaluation_order,
+        "
+function foo() {
+    return getValue() |> (v => v + 1);
+}
+",
+        "
+function foo() {
+    var _ref;
+    return _ref = getValue(), (v => v + 1)(_ref);
+}
+"
+    );
+
+    test_exec!(
+        syntax(),
+        |_| pipeline_operator(),
+        exec,
+        "
+const double = x => x * 2;
+const inc = x => x + 1;
+const log = [];
+const obj = {
+    get value() {
+        log.push('value');
+        return 5;
+    },
+};
+
+expect(obj.value |> double |> inc).toBe(11);
+expect(obj.value |> (log.push('fn'), double)).toBe(10);
+expect(log).toEqual(['value', 'value', 'fn']);
+
+let fn = double;
+expect((fn = x => x * 3, 1) |> fn).toBe(3);
+"
+    );
+}
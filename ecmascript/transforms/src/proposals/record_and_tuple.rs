@@ -0,0 +1,112 @@
+use crate::util::ExprFactory;
+use std::mem::take;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Lowers records and tuples to deeply frozen objects and arrays.
+///
+/// ```js
+/// const r = #{ a: #[1, 2] };
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// const r = _deepFreeze({ a: _deepFreeze([1, 2]) });
+/// ```
+///
+/// This is only an approximation of the proposal. Values are compared by
+/// reference, and objects spread into a record are frozen too.
+pub fn record_and_tuple() -> impl Fold {
+    as_folder(RecordAndTuple)
+}
+
+struct RecordAndTuple;
+
+impl VisitMut for RecordAndTuple {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let (span, value) = match e {
+            Expr::Record(RecordLit { span, props }) => (
+                *span,
+                Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props: take(props),
+                }),
+            ),
+            Expr::Tuple(TupleLit { span, elems }) => (
+                *span,
+                Expr::Array(ArrayLit {
+                    span: DUMMY_SP,
+                    elems: take(elems).into_iter().map(Some).collect(),
+                }),
+            ),
+            _ => return,
+        };
+
+        *e = Expr::Call(CallExpr {
+            span,
+            callee: helper!(deep_freeze, "deepFreeze"),
+            args: vec![value.as_arg()],
+            type_args: Default::default(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::record_and_tuple;
+    use swc_ecma_parser::{EsConfig, Syntax};
+
+    fn syntax() -> Syntax {
+        Syntax::Es(EsConfig {
+            record_and_tuple: true,
+            ..Default::default()
+        })
+    }
+
+    test!(
+        syntax(),
+        |_| record_and_tuple(),
+        nested,
+        "const r = #{ a: #[1, #{ b: 2 }], ...c };",
+        "
+const r = _deepFreeze({
+    a: _deepFreeze([1, _deepFreeze({ b: 2 })]),
+    ...c
+});
+"
+    );
+
+    test_exec!(
+        syntax(),
+        |_| record_and_tuple(),
+        mutation_throws,
+        "
+'use strict';
+
+const inner = { d: [1] };
+const r = #{ a: 1, b: #[1, #{ c: 2 }], ...{ inner } };
+
+expect(() => {
+    r.a = 2;
+}).toThrow(TypeError);
+expect(() => {
+    r.b[1].c = 3;
+}).toThrow(TypeError);
+expect(() => {
+    r.b.push(3);
+}).toThrow(TypeError);
+expect(() => {
+    r.inner.d.push(2);
+}).toThrow(TypeError);
+
+expect(r.b[1].c).toBe(2);
+expect(Object.isFrozen(r.inner.d)).toBe(true);
+"
+    );
+}
@@ -28,13 +28,13 @@ macro_rules! helper_expr {
         );
         let mark = enable_helper!($field_name);
         let span = $span.apply_mark(mark);
-        let external = crate::helpers::HELPERS.with(|helper| helper.external());
+        crate::helpers::HELPERS.with(|helpers| {
+            if helpers.external() && crate::helpers::is_exported(stringify!($field_name)) {
+                helpers.add_import(external_name!($s), concat!('_', $s));
+            }
+        });
 
-        if external {
-            quote_ident!(span, "swcHelpers").make_member(quote_ident!($span, external_name!($s)))
-        } else {
-            Expr::from(quote_ident!(span, concat!('_', $s)))
-        }
+        Expr::from(quote_ident!(span, concat!('_', $s)))
     }};
 }
 
@@ -2,17 +2,23 @@
 
 pub use self::{
     display_name::display_name,
+    hoist_members::hoist_jsx_members,
     jsx::{jsx, Options},
     jsx_self::jsx_self,
     jsx_src::jsx_src,
+    refresh::react_refresh,
+    remove_properties::{react_remove_properties, remove_prop_types},
 };
 use swc_common::{chain, comments::Comments, sync::Lrc, SourceMap};
 use swc_ecma_visit::Fold;
 
 mod display_name;
+mod hoist_members;
 mod jsx;
 mod jsx_self;
 mod jsx_src;
+mod refresh;
+mod remove_properties;
 
 /// `@babel/preset-react`
 ///
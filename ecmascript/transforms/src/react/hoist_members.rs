@@ -0,0 +1,171 @@
+use indexmap::IndexMap;
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitMutWith,
+    VisitWith,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Hoists component references like `<Icons.Close />` to module-scope
+/// constants if they are used more than once.
+///
+/// ```js
+/// import * as Icons from './icons';
+/// const a = <Icons.Close />;
+/// const b = <Icons.Close />;
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// import * as Icons from './icons';
+/// const _Close = Icons.Close;
+/// const a = <_Close />;
+/// const b = <_Close />;
+/// ```
+///
+/// Only members of namespace imports are hoisted, as properties of a module
+/// namespace object cannot be assigned.
+///
+/// Note that the exported bindings are assumed to be initialized when this
+/// module is evaluated and not reassigned by the exporting module, which
+/// does not hold for some circular imports.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver and before `jsx`.
+pub fn hoist_jsx_members() -> impl Fold {
+    as_folder(HoistJsxMembers {
+        hoisted: Default::default(),
+    })
+}
+
+struct HoistJsxMembers {
+    /// `(namespace, property)` to the hoisted binding.
+    hoisted: IndexMap<(Id, JsWord), Ident>,
+}
+
+/// Returns the namespace and the property if `name` is `Namespace.Property`.
+fn member_of(name: &JSXElementName) -> Option<(&Ident, &Ident)> {
+    match name {
+        JSXElementName::JSXMemberExpr(JSXMemberExpr {
+            obj: JSXObject::Ident(obj),
+            prop,
+        }) => Some((obj, prop)),
+        _ => None,
+    }
+}
+
+impl VisitMut for HoistJsxMembers {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let mut namespaces = HashSet::new();
+        for item in &m.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+                for s in &import.specifiers {
+                    if let ImportSpecifier::Namespace(s) = s {
+                        namespaces.insert(s.local.to_id());
+                    }
+                }
+            }
+        }
+        if namespaces.is_empty() {
+            return;
+        }
+
+        let mut v = UsageCounter {
+            namespaces: &namespaces,
+            usages: Default::default(),
+        };
+        m.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+        for (key, count) in v.usages {
+            if count < 2 {
+                continue;
+            }
+
+            let ident = private_ident!(format!("_{}", key.1));
+            self.hoisted.insert(key, ident);
+        }
+        if self.hoisted.is_empty() {
+            return;
+        }
+
+        m.visit_mut_children_with(self);
+
+        let pos = m
+            .body
+            .iter()
+            .rposition(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => true,
+                _ => false,
+            })
+            .map(|pos| pos + 1)
+            .unwrap_or(0);
+        let decls = self.hoisted.drain(..).map(|((ns, prop), ident)| {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(ident),
+                    init: Some(Box::new(Expr::Member(MemberExpr {
+                        span: DUMMY_SP,
+                        obj: ExprOrSuper::Expr(Box::new(Expr::Ident(Ident::new(
+                            ns.0,
+                            DUMMY_SP.with_ctxt(ns.1),
+                        )))),
+                        prop: Box::new(Expr::Ident(Ident::new(prop, DUMMY_SP))),
+                        computed: false,
+                    }))),
+                    definite: false,
+                }],
+            })))
+        });
+        m.body.splice(pos..pos, decls);
+    }
+
+    fn visit_mut_jsx_element_name(&mut self, n: &mut JSXElementName) {
+        let ident = match member_of(n) {
+            Some((obj, prop)) => match self.hoisted.get(&(obj.to_id(), prop.sym.clone())) {
+                Some(ident) => ident.clone(),
+                None => return,
+            },
+            None => return,
+        };
+
+        *n = JSXElementName::Ident(Ident {
+            span: ident.span.with_lo(n.span().lo).with_hi(n.span().hi),
+            ..ident
+        });
+    }
+}
+
+/// Counts elements named with a member of a namespace import.
+struct UsageCounter<'a> {
+    namespaces: &'a HashSet<Id>,
+    usages: IndexMap<(Id, JsWord), usize>,
+}
+
+impl Visit for UsageCounter<'_> {
+    noop_visit_type!();
+
+    fn visit_jsx_opening_element(&mut self, n: &JSXOpeningElement, _: &dyn Node) {
+        n.visit_children_with(self);
+
+        if let Some((obj, prop)) = member_of(&n.name) {
+            let obj = obj.to_id();
+            if self.namespaces.contains(&obj) {
+                *self.usages.entry((obj, prop.sym.clone())).or_default() += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use super::*;
+use crate::resolver;
+use swc_common::chain;
+
+fn tr() -> impl Fold {
+    chain!(resolver(), hoist_jsx_members())
+}
+
+fn syntax() -> ::swc_ecma_parser::Syntax {
+    ::swc_ecma_parser::Syntax::Es(::swc_ecma_parser::EsConfig {
+        jsx: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    repeated,
+    r#"
+import * as Icons from './icons';
+import { Close } from './close';
+
+const a = <Icons.Close size={1} />;
+function B() {
+    return <Icons.Close><Icons.Open /></Icons.Close>;
+}
+"#,
+    r#"
+import * as Icons from './icons';
+import { Close } from './close';
+const _Close = Icons.Close;
+
+const a = <_Close size={1} />;
+function B() {
+    return <_Close><Icons.Open /></_Close>;
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    not_namespace,
+    r#"
+import Icons from './icons';
+
+function A(Ns) {
+    return <Ns.Close><Ns.Close /></Ns.Close>;
+}
+const b = <Icons.Close><Icons.Close /></Icons.Close>;
+"#,
+    r#"
+import Icons from './icons';
+
+function A(Ns) {
+    return <Ns.Close><Ns.Close /></Ns.Close>;
+}
+const b = <Icons.Close><Icons.Close /></Icons.Close>;
+"#
+);
@@ -0,0 +1,605 @@
+use crate::{
+    ext::MapWithMut,
+    util::{ExprFactory, StmtLike},
+};
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Node, Visit, VisitMut, VisitWith,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Hooks provided by React. Calls of other hooks are passed to the
+/// signature, so the state is reset if one of them changes.
+static BUILTIN_HOOKS: &[&str] = &[
+    "useCallback",
+    "useContext",
+    "useDebugValue",
+    "useEffect",
+    "useImperativeHandle",
+    "useLayoutEffect",
+    "useMemo",
+    "useReducer",
+    "useRef",
+    "useState",
+];
+
+/// `react-refresh/babel`
+///
+/// Registers components for React Fast Refresh.
+///
+/// ```js
+/// export function Counter() {
+///     const [count, setCount] = useState(0);
+///     return <div>{count}</div>;
+/// }
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// var _s = $RefreshSig$();
+/// export function Counter() {
+///     _s();
+///     const [count, setCount] = useState(0);
+///     return <div>{count}</div>;
+/// }
+/// _s(Counter, "useState{[count, setCount]}");
+/// _c = Counter;
+/// var _c;
+/// $RefreshReg$(_c, "Counter");
+/// ```
+///
+/// Top-level functions with a capitalized name are registered as
+/// components. This includes functions wrapped in `memo` and `forwardRef`,
+/// which are registered as `Name$memo` and `Name$forwardRef`.
+///
+/// Top-level components and hooks which call hooks get a signature, so the
+/// state of a component is reset if the hooks it calls change. Unlike
+/// Babel, the key of a signature contains only the names of the hooks and the
+/// bindings it declares, and not the arguments.
+///
+/// # When to run
+///
+/// This pass should be applied before `jsx`, in development builds only.
+pub fn react_refresh() -> impl Fold {
+    as_folder(Refresh {
+        registrations: vec![],
+        signatures: 0,
+        before: vec![],
+        after: vec![],
+    })
+}
+
+struct Refresh {
+    /// Handles and ids of components.
+    registrations: Vec<(Ident, String)>,
+    /// Number of created signatures.
+    signatures: usize,
+    /// Statements to insert before the current statement.
+    before: Vec<Stmt>,
+    /// Statements to insert after the current statement.
+    after: Vec<Stmt>,
+}
+
+/// Hooks called by a function.
+struct Signature {
+    key: String,
+    custom_hooks: Vec<Expr>,
+}
+
+fn is_component_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_uppercase())
+}
+
+fn is_hook_name(name: &str) -> bool {
+    name == "use"
+        || (name.starts_with("use")
+            && name[3..]
+                .chars()
+                .next()
+                .map_or(false, |c| c.is_ascii_uppercase()))
+}
+
+/// Returns the name of `callee` if it's `memo`, `forwardRef` or a member
+/// named like them.
+fn hoc_name(callee: &ExprOrSuper) -> Option<String> {
+    let callee = match callee {
+        ExprOrSuper::Expr(callee) => &**callee,
+        _ => return None,
+    };
+
+    let (obj, prop) = match callee {
+        Expr::Ident(i) => (None, &i.sym),
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => match (&**obj, &**prop) {
+            (Expr::Ident(obj), Expr::Ident(prop)) => (Some(&obj.sym), &prop.sym),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match &**prop {
+        "memo" | "forwardRef" => {}
+        _ => return None,
+    }
+
+    Some(match obj {
+        Some(obj) => format!("{}.{}", obj, prop),
+        None => prop.to_string(),
+    })
+}
+
+fn assign(left: Ident, right: Expr) -> Expr {
+    Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Pat(Box::new(Pat::Ident(left))),
+        right: Box::new(right),
+    })
+}
+
+impl Refresh {
+    fn handle(&mut self, id: String) -> Ident {
+        let n = self.registrations.len() + 1;
+        let handle = if n == 1 {
+            private_ident!("_c")
+        } else {
+            private_ident!(format!("_c{}", n))
+        };
+        self.registrations.push((handle.clone(), id));
+        handle
+    }
+
+    /// Adds `_s();` to `body` and returns the signature, if `body` calls
+    /// hooks.
+    fn sign(&mut self, body: &mut BlockStmtOrExpr) -> Option<(Ident, Signature)> {
+        let mut v = HookFinder { hooks: vec![] };
+        body.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+        if v.hooks.is_empty() {
+            return None;
+        }
+
+        self.signatures += 1;
+        let sig = if self.signatures == 1 {
+            private_ident!("_s")
+        } else {
+            private_ident!(format!("_s{}", self.signatures))
+        };
+
+        // var _s = $RefreshSig$();
+        self.before.push(Stmt::Decl(Decl::Var(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(sig.clone()),
+                init: Some(Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: quote_ident!("$RefreshSig$").as_callee(),
+                    args: vec![],
+                    type_args: Default::default(),
+                }))),
+                definite: false,
+            }],
+        })));
+
+        let call = CallExpr {
+            span: DUMMY_SP,
+            callee: sig.clone().as_callee(),
+            args: vec![],
+            type_args: Default::default(),
+        }
+        .into_stmt();
+        match body {
+            BlockStmtOrExpr::BlockStmt(block) => block.stmts.insert(0, call),
+            BlockStmtOrExpr::Expr(expr) => {
+                let expr = expr.take();
+                *body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![
+                        call,
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(Box::new(expr)),
+                        }),
+                    ],
+                });
+            }
+        }
+
+        let key = v
+            .hooks
+            .iter()
+            .map(|(name, lhs, _)| format!("{}{{{}}}", name, lhs))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let custom_hooks = v
+            .hooks
+            .into_iter()
+            .filter_map(|(_, _, custom)| custom)
+            .collect();
+
+        Some((sig, Signature { key, custom_hooks }))
+    }
+
+    /// `_s(target, "key")`
+    fn sig_call(&self, sig: Ident, target: Expr, s: Signature) -> Expr {
+        let mut args = vec![
+            target.as_arg(),
+            Lit::Str(Str {
+                span: DUMMY_SP,
+                value: s.key.into(),
+                has_escape: false,
+            })
+            .as_arg(),
+        ];
+
+        if !s.custom_hooks.is_empty() {
+            // false, function () { return [useCustom]; }
+            args.push(
+                Lit::Bool(Bool {
+                    span: DUMMY_SP,
+                    value: false,
+                })
+                .as_arg(),
+            );
+            args.push(
+                Expr::Fn(FnExpr {
+                    ident: None,
+                    function: Function {
+                        params: vec![],
+                        decorators: vec![],
+                        span: DUMMY_SP,
+                        body: Some(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts: vec![Stmt::Return(ReturnStmt {
+                                span: DUMMY_SP,
+                                arg: Some(Box::new(Expr::Array(ArrayLit {
+                                    span: DUMMY_SP,
+                                    elems: s
+                                        .custom_hooks
+                                        .into_iter()
+                                        .map(|hook| Some(hook.as_arg()))
+                                        .collect(),
+                                }))),
+                            })],
+                        }),
+                        is_generator: false,
+                        is_async: false,
+                        type_params: None,
+                        return_type: None,
+                    },
+                })
+                .as_arg(),
+            );
+        }
+
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: sig.as_callee(),
+            args,
+            type_args: Default::default(),
+        })
+    }
+
+    /// Signs a function declared as `ident`.
+    fn sign_fn_decl(&mut self, ident: &Ident, function: &mut Function) {
+        let body = match function.body.take() {
+            Some(body) => body,
+            None => return,
+        };
+        let mut body = BlockStmtOrExpr::BlockStmt(body);
+        let sig = self.sign(&mut body);
+        function.body = match body {
+            BlockStmtOrExpr::BlockStmt(body) => Some(body),
+            _ => unreachable!(),
+        };
+
+        if let Some((sig, s)) = sig {
+            let call = self.sig_call(sig, Expr::Ident(ident.clone()), s);
+            self.after.push(call.into_stmt());
+        }
+    }
+
+    /// Signs `e` if it's a function expression.
+    fn sign_fn_expr(&mut self, e: &mut Expr) -> bool {
+        let sig = match e {
+            Expr::Fn(FnExpr { function, .. }) => {
+                let body = match function.body.take() {
+                    Some(body) => body,
+                    None => return false,
+                };
+                let mut body = BlockStmtOrExpr::BlockStmt(body);
+                let sig = self.sign(&mut body);
+                function.body = match body {
+                    BlockStmtOrExpr::BlockStmt(body) => Some(body),
+                    _ => unreachable!(),
+                };
+                sig
+            }
+            Expr::Arrow(ArrowExpr { body, .. }) => self.sign(body),
+            _ => return false,
+        };
+
+        if let Some((sig, s)) = sig {
+            let target = e.take();
+            *e = self.sig_call(sig, target, s);
+        }
+        true
+    }
+
+    /// Registers the function wrapped in `memo` or `forwardRef` by `e`.
+    fn register_wrapped(&mut self, e: &mut Expr, id: String) -> bool {
+        if self.sign_fn_expr(e) {
+            let handle = self.handle(id);
+            let target = e.take();
+            *e = assign(handle, target);
+            return true;
+        }
+
+        if let Expr::Call(CallExpr { callee, args, .. }) = e {
+            if let Some(name) = hoc_name(callee) {
+                if let Some(arg) = args.first_mut() {
+                    if self.register_wrapped(&mut arg.expr, format!("{}${}", id, name)) {
+                        let handle = self.handle(id);
+                        let target = e.take();
+                        *e = assign(handle, target);
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn register_after(&mut self, ident: &Ident) {
+        let handle = self.handle(ident.sym.to_string());
+        self.after
+            .push(assign(handle, Expr::Ident(ident.clone())).into_stmt());
+    }
+
+    fn visit_decl(&mut self, decl: &mut Decl) {
+        match decl {
+            Decl::Fn(FnDecl {
+                ident, function, ..
+            }) => {
+                if is_component_name(&ident.sym) {
+                    self.sign_fn_decl(ident, function);
+                    self.register_after(ident);
+                } else if is_hook_name(&ident.sym) {
+                    self.sign_fn_decl(ident, function);
+                }
+            }
+            Decl::Var(var) => {
+                for decl in &mut var.decls {
+                    let ident = match &decl.name {
+                        Pat::Ident(ident) => ident.clone(),
+                        _ => continue,
+                    };
+                    let init = match &mut decl.init {
+                        Some(init) => init,
+                        None => continue,
+                    };
+
+                    if is_component_name(&ident.sym) {
+                        if self.sign_fn_expr(init) {
+                            self.register_after(&ident);
+                        } else if let Expr::Call(CallExpr { callee, args, .. }) = &mut **init {
+                            let name = match hoc_name(callee) {
+                                Some(name) => name,
+                                None => continue,
+                            };
+                            if let Some(arg) = args.first_mut() {
+                                let id = format!("{}${}", ident.sym, name);
+                                if self.register_wrapped(&mut arg.expr, id) {
+                                    self.register_after(&ident);
+                                }
+                            }
+                        }
+                    } else if is_hook_name(&ident.sym) {
+                        self.sign_fn_expr(init);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_stmt_likes<T>(&mut self, stmts: &mut Vec<T>, visit: impl Fn(&mut Self, &mut T))
+    where
+        T: StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len());
+
+        for mut stmt in stmts.drain(..) {
+            visit(self, &mut stmt);
+
+            buf.extend(self.before.drain(..).map(T::from_stmt));
+            buf.push(stmt);
+            buf.extend(self.after.drain(..).map(T::from_stmt));
+        }
+
+        if !self.registrations.is_empty() {
+            // var _c, _c2;
+            buf.push(T::from_stmt(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: self
+                    .registrations
+                    .iter()
+                    .map(|(handle, _)| VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(handle.clone()),
+                        init: None,
+                        definite: false,
+                    })
+                    .collect(),
+            }))));
+
+            // $RefreshReg$(_c, "Name");
+            for (handle, id) in self.registrations.drain(..) {
+                buf.push(T::from_stmt(
+                    CallExpr {
+                        span: DUMMY_SP,
+                        callee: quote_ident!("$RefreshReg$").as_callee(),
+                        args: vec![
+                            handle.as_arg(),
+                            Lit::Str(Str {
+                                span: DUMMY_SP,
+                                value: id.into(),
+                                has_escape: false,
+                            })
+                            .as_arg(),
+                        ],
+                        type_args: Default::default(),
+                    }
+                    .into_stmt(),
+                ));
+            }
+        }
+
+        *stmts = buf;
+    }
+}
+
+impl VisitMut for Refresh {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.visit_stmt_likes(&mut m.body, |v, item| match item {
+            ModuleItem::Stmt(Stmt::Decl(decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                v.visit_decl(decl)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl:
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident),
+                        function,
+                    }),
+                ..
+            })) if is_component_name(&ident.sym) => {
+                v.sign_fn_decl(ident, function);
+                v.register_after(ident);
+            }
+            _ => {}
+        });
+    }
+
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.visit_stmt_likes(&mut s.body, |v, stmt| {
+            if let Stmt::Decl(decl) = stmt {
+                v.visit_decl(decl)
+            }
+        });
+    }
+}
+
+/// Finds hooks called by a function, with the pattern they're assigned to
+/// and the callee if it's not a builtin hook.
+struct HookFinder {
+    hooks: Vec<(JsWord, String, Option<Expr>)>,
+}
+
+impl HookFinder {
+    fn hook_of(&self, call: &CallExpr) -> Option<(JsWord, Option<Expr>)> {
+        let callee = match &call.callee {
+            ExprOrSuper::Expr(callee) => &**callee,
+            _ => return None,
+        };
+        let name = match callee {
+            Expr::Ident(i) => &i.sym,
+            Expr::Member(MemberExpr {
+                prop,
+                computed: false,
+                ..
+            }) => match &**prop {
+                Expr::Ident(prop) => &prop.sym,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if !is_hook_name(name) {
+            return None;
+        }
+
+        let custom = if BUILTIN_HOOKS.contains(&&**name) {
+            None
+        } else {
+            Some(callee.clone())
+        };
+        Some((name.clone(), custom))
+    }
+}
+
+/// Returns the bindings of `pat` like `[count, setCount]`.
+fn pat_key(pat: &Pat) -> String {
+    match pat {
+        Pat::Ident(i) => i.sym.to_string(),
+        Pat::Array(ArrayPat { elems, .. }) => format!(
+            "[{}]",
+            elems
+                .iter()
+                .map(|elem| match elem {
+                    Some(elem) => pat_key(elem),
+                    None => String::new(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Pat::Object(ObjectPat { props, .. }) => format!(
+            "{{{}}}",
+            props
+                .iter()
+                .map(|prop| match prop {
+                    ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => pat_key(value),
+                    ObjectPatProp::Assign(AssignPatProp { key, .. }) => key.sym.to_string(),
+                    ObjectPatProp::Rest(RestPat { arg, .. }) => format!("...{}", pat_key(arg)),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Pat::Assign(AssignPat { left, .. }) => pat_key(left),
+        _ => String::new(),
+    }
+}
+
+impl Visit for HookFinder {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr, _: &dyn Node) {}
+
+    fn visit_call_expr(&mut self, n: &CallExpr, _: &dyn Node) {
+        if let Some((name, custom)) = self.hook_of(n) {
+            self.hooks.push((name, String::new(), custom));
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function, _: &dyn Node) {}
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator, _: &dyn Node) {
+        if let Some(Expr::Call(call)) = n.init.as_deref() {
+            if let Some((name, custom)) = self.hook_of(call) {
+                self.hooks.push((name, pat_key(&n.name), custom));
+                call.args.visit_with(&Invalid { span: DUMMY_SP } as _, self);
+                return;
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
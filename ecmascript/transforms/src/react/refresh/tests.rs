@@ -0,0 +1,85 @@
+use super::*;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn tr() -> impl Fold {
+    react_refresh()
+}
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        jsx: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    function_component,
+    r#"
+export function Hello() {
+    return <h1>Hi</h1>;
+}
+function helper() {}
+"#,
+    r#"
+export function Hello() {
+    return <h1>Hi</h1>;
+}
+_c = Hello;
+function helper() {}
+var _c;
+$RefreshReg$(_c, "Hello");
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    wrapped_component,
+    r#"
+const Input = React.memo(forwardRef((props, ref) => <input ref={ref} />));
+"#,
+    r#"
+const Input = React.memo(_c2 = forwardRef(_c = (props, ref) => <input ref={ref} />));
+_c3 = Input;
+var _c, _c2, _c3;
+$RefreshReg$(_c, "Input$React.memo$forwardRef");
+$RefreshReg$(_c2, "Input$React.memo");
+$RefreshReg$(_c3, "Input");
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    hooks,
+    r#"
+const Counter = () => {
+    const [count, setCount] = useState(0);
+    return <button onClick={() => setCount(count + 1)}>{count}</button>;
+};
+function useTitle(title) {
+    useDocumentTitle(title);
+}
+"#,
+    r#"
+var _s = $RefreshSig$();
+const Counter = _s(() => {
+    _s();
+    const [count, setCount] = useState(0);
+    return <button onClick={() => setCount(count + 1)}>{count}</button>;
+}, "useState{[count, setCount]}");
+_c = Counter;
+var _s2 = $RefreshSig$();
+function useTitle(title) {
+    _s2();
+    useDocumentTitle(title);
+}
+_s2(useTitle, "useDocumentTitle{}", false, function () {
+    return [useDocumentTitle];
+});
+var _c;
+$RefreshReg$(_c, "Counter");
+"#
+);
@@ -0,0 +1,114 @@
+use regex::Regex;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+#[cfg(test)]
+mod tests;
+
+/// `babel-plugin-react-remove-properties`
+///
+/// Removes jsx attributes matching `regex` from both host and component
+/// elements. Attributes are matched by name, so spread attributes are left
+/// as-is.
+///
+/// If `regex` is `None`, attributes matching `^data-test` are removed.
+pub fn react_remove_properties(regex: Option<Regex>) -> impl Fold {
+    as_folder(RemoveProperties {
+        regex: regex.unwrap_or_else(|| Regex::new("^data-test").unwrap()),
+    })
+}
+
+/// Removes `Component.propTypes = { ... }` assignments and
+/// `static propTypes = { ... }` class properties.
+pub fn remove_prop_types() -> impl Fold {
+    as_folder(RemovePropTypes)
+}
+
+struct RemoveProperties {
+    regex: Regex,
+}
+
+impl VisitMut for RemoveProperties {
+    noop_visit_mut_type!();
+
+    fn visit_mut_jsx_opening_element(&mut self, n: &mut JSXOpeningElement) {
+        n.visit_mut_children_with(self);
+
+        let regex = &self.regex;
+        n.attrs.retain(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                ..
+            }) => !regex.is_match(&name.sym),
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::JSXNamespacedName(JSXNamespacedName { ns, name }),
+                ..
+            }) => !regex.is_match(&format!("{}:{}", ns.sym, name.sym)),
+            JSXAttrOrSpread::SpreadElement(..) => true,
+        });
+    }
+}
+
+struct RemovePropTypes;
+
+impl RemovePropTypes {
+    fn is_prop_types_assign(&self, s: &Stmt) -> bool {
+        match s {
+            Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+                Expr::Assign(AssignExpr {
+                    op: op!("="),
+                    left: PatOrExpr::Expr(left),
+                    ..
+                }) => match &**left {
+                    Expr::Member(MemberExpr {
+                        prop,
+                        computed: false,
+                        ..
+                    }) => match &**prop {
+                        Expr::Ident(prop) => &*prop.sym == "propTypes",
+                        _ => false,
+                    },
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl VisitMut for RemovePropTypes {
+    noop_visit_mut_type!();
+
+    fn visit_mut_class_members(&mut self, members: &mut Vec<ClassMember>) {
+        members.visit_mut_children_with(self);
+
+        members.retain(|member| match member {
+            ClassMember::ClassProp(ClassProp {
+                is_static: true,
+                computed: false,
+                key,
+                ..
+            }) => match &**key {
+                Expr::Ident(key) => &*key.sym != "propTypes",
+                _ => true,
+            },
+            _ => true,
+        });
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        items.retain(|item| match item {
+            ModuleItem::Stmt(s) => !self.is_prop_types_assign(s),
+            _ => true,
+        });
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        stmts.retain(|s| !self.is_prop_types_assign(s));
+    }
+}
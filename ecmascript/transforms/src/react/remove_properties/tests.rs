@@ -0,0 +1,82 @@
+use super::*;
+use swc_ecma_parser::{EsConfig, Syntax};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        jsx: true,
+        class_props: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| react_remove_properties(None),
+    data_testid,
+    r#"
+const App = () => <div data-testid="app" className="app">
+        <Button data-testid="button" onClick={onClick} {...props}>
+            <span data-test-id="label">Click</span>
+        </Button>
+    </div>;
+"#,
+    r#"
+const App = () => <div className="app">
+        <Button onClick={onClick} {...props}>
+            <span>Click</span>
+        </Button>
+    </div>;
+"#
+);
+
+test!(
+    syntax(),
+    |_| react_remove_properties(Some(Regex::new("^(data-foo|bar)$").unwrap())),
+    custom_regex,
+    r#"<div data-foo="a" data-foo-bar="b" bar baz />;"#,
+    r#"<div data-foo-bar="b" baz />;"#
+);
+
+test!(
+    syntax(),
+    |_| remove_prop_types(),
+    prop_types_assign,
+    r#"
+function Foo() {
+    return <div />;
+}
+Foo.propTypes = {
+    bar: PropTypes.string,
+};
+Foo.defaultProps = {
+    bar: "bar",
+};
+"#,
+    r#"
+function Foo() {
+    return <div />;
+}
+Foo.defaultProps = {
+    bar: "bar",
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| remove_prop_types(),
+    prop_types_class_prop,
+    r#"
+class Foo extends React.Component {
+    static propTypes = {
+        bar: PropTypes.string,
+    };
+    propTypes = 1;
+}
+"#,
+    r#"
+class Foo extends React.Component {
+    propTypes = 1;
+}
+"#
+);
@@ -1197,7 +1197,7 @@ identical!(
 
 test!(
     ts(),
-    |_| chain!(resolver(), class_properties()),
+    |_| chain!(resolver(), class_properties(Default::default())),
     issue_890_1,
     "const DURATION = 1000
 
@@ -1,4 +1,7 @@
-use crate::helpers::{inject_helpers, HELPERS};
+use crate::{
+    helpers::{inject_helpers, HELPERS},
+    normalize_assignment_targets,
+};
 use std::{
     fmt,
     fs::{create_dir_all, remove_dir_all, OpenOptions},
@@ -10,7 +13,7 @@ use std::{
 use swc_common::{
     comments::SingleThreadedComments, errors::Handler, sync::Lrc, FileName, SourceMap,
 };
-use swc_ecma_ast::{Pat, *};
+use swc_ecma_ast::*;
 use swc_ecma_codegen::Emitter;
 use swc_ecma_parser::{error::Error, lexer::Lexer, Parser, StringInput, Syntax};
 use swc_ecma_utils::DropSpan;
@@ -118,7 +121,7 @@ impl<'a> Tester<'a> {
             .fold_with(&mut as_folder(DropSpan {
                 preserve_ctxt: true,
             }))
-            .fold_with(&mut Normalizer);
+            .fold_with(&mut normalize_assignment_targets());
 
         Ok(module)
     }
@@ -136,6 +139,7 @@ impl<'a> Tester<'a> {
                     None,
                 )),
                 comments: None,
+                ident_hook: None,
             };
 
             // println!("Emitting: {:?}", module);
@@ -391,20 +395,6 @@ impl Write for Buf {
     }
 }
 
-struct Normalizer;
-impl Fold for Normalizer {
-    fn fold_pat_or_expr(&mut self, mut n: PatOrExpr) -> PatOrExpr {
-        if let PatOrExpr::Pat(pat) = n {
-            if let Pat::Expr(expr) = *pat {
-                return PatOrExpr::Expr(expr);
-            }
-            n = PatOrExpr::Pat(pat);
-        }
-
-        n
-    }
-}
-
 pub(crate) struct HygieneVisualizer;
 impl Fold for HygieneVisualizer {
     fn fold_ident(&mut self, ident: Ident) -> Ident {
@@ -0,0 +1,405 @@
+use crate::helpers::{self, HELPERS};
+use indexmap::IndexMap;
+use inflector::Inflector;
+use serde::{Deserialize, Serialize};
+use swc_atoms::js_word;
+use swc_common::{SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Globals replaced if [Config::corejs] is true, and their paths in the
+/// runtime package.
+static GLOBALS: &[(&str, &str)] = &[
+    ("Map", "core-js/map"),
+    ("Promise", "core-js/promise"),
+    ("Set", "core-js/set"),
+    ("Symbol", "core-js/symbol"),
+    ("WeakMap", "core-js/weak-map"),
+    ("WeakSet", "core-js/weak-set"),
+];
+
+/// Static methods replaced if [Config::corejs] is true, and their paths in
+/// the runtime package.
+static STATIC_METHODS: &[(&str, &str, &str)] = &[
+    ("Array", "from", "core-js/array/from"),
+    ("Array", "isArray", "core-js/array/is-array"),
+    ("Array", "of", "core-js/array/of"),
+    ("Object", "assign", "core-js/object/assign"),
+    ("Object", "entries", "core-js/object/entries"),
+    ("Object", "fromEntries", "core-js/object/from-entries"),
+    ("Object", "keys", "core-js/object/keys"),
+    ("Object", "values", "core-js/object/values"),
+];
+
+/// `@babel/plugin-transform-runtime`
+///
+/// Imports helpers, the regenerator runtime and optionally built-ins from a
+/// runtime package, instead of defining them in each file or relying on
+/// globals.
+///
+/// ```js
+/// import { classCallCheck as _classCallCheck } from '@swc/helpers';
+/// var _regeneratorRuntime = require('regenerator-runtime');
+/// const p = Promise.resolve();
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// import _classCallCheck from '@babel/runtime-corejs2/helpers/classCallCheck';
+/// import _regeneratorRuntime from '@babel/runtime-corejs2/regenerator';
+/// import _Promise from '@babel/runtime-corejs2/core-js/promise';
+/// const p = _Promise.resolve();
+/// ```
+///
+/// Each category is replaced only if it's enabled in [Config]. Both helpers
+/// imported from `@swc/helpers` and helpers injected inline are replaced,
+/// except for helpers which are not in the runtime package.
+///
+/// # When to run
+///
+/// This pass should be applied after `inject_helpers` and before the module
+/// transforms. It should also be applied after the resolver, as shadowed
+/// built-ins are not replaced.
+pub fn transform_runtime(c: Config) -> impl Fold {
+    let module_name = c.module_name.clone().unwrap_or_else(|| {
+        if c.corejs {
+            "@babel/runtime-corejs2".into()
+        } else {
+            "@babel/runtime".into()
+        }
+    });
+
+    as_folder(TransformRuntime {
+        c,
+        module_name,
+        helper_ctxt: None,
+        imports: Default::default(),
+    })
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// Package to import from. Defaults to `@babel/runtime-corejs2` if
+    /// [Config::corejs] is true, as `@babel/runtime` doesn't include core-js,
+    /// and to `@babel/runtime` otherwise.
+    #[serde(default)]
+    pub module_name: Option<String>,
+
+    /// Import helpers from `<module_name>/helpers`.
+    #[serde(default)]
+    pub helpers: bool,
+
+    /// Import the regenerator runtime from `<module_name>/regenerator`.
+    #[serde(default)]
+    pub regenerator: bool,
+
+    /// Replace built-ins like `Promise` with imports from
+    /// `<module_name>/core-js`.
+    #[serde(default)]
+    pub corejs: bool,
+}
+
+struct TransformRuntime {
+    c: Config,
+    module_name: String,
+    /// Syntax context of helpers injected inline.
+    helper_ctxt: Option<SyntaxContext>,
+    /// Import sources to the local bindings.
+    imports: IndexMap<String, Ident>,
+}
+
+impl TransformRuntime {
+    fn import(&mut self, path: &str, local: &str) -> Ident {
+        let src = format!("{}/{}", self.module_name, path);
+        self.imports
+            .entry(src)
+            .or_insert_with(|| private_ident!(local))
+            .clone()
+    }
+
+    /// Records the imports replacing `item` and returns true if `item` should
+    /// be removed.
+    fn replace_item(&mut self, item: &ModuleItem) -> bool {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import))
+                if self.c.helpers && &*import.src.value == "@swc/helpers" =>
+            {
+                if !import.specifiers.iter().all(|s| match s {
+                    ImportSpecifier::Named(..) => true,
+                    _ => false,
+                }) {
+                    return false;
+                }
+
+                for s in &import.specifiers {
+                    if let ImportSpecifier::Named(s) = s {
+                        let name = s.imported.as_ref().unwrap_or(&s.local);
+                        let src = format!("{}/helpers/{}", self.module_name, name.sym);
+                        self.imports.insert(src, s.local.clone());
+                    }
+                }
+                true
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ident, .. })))
+                if self.c.helpers && Some(ident.span.ctxt) == self.helper_ctxt =>
+            {
+                let name = match ident.sym.strip_prefix('_') {
+                    Some(name) if helpers::is_exported(&name.to_snake_case()) => name,
+                    _ => return false,
+                };
+
+                let src = format!("{}/helpers/{}", self.module_name, name);
+                self.imports.insert(src, ident.clone());
+                true
+            }
+
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl { decls, .. })))
+                if self.c.regenerator && decls.len() == 1 =>
+            {
+                let rt = match (&decls[0].name, decls[0].init.as_deref()) {
+                    (Pat::Ident(rt), Some(Expr::Call(call))) if is_regenerator_require(call) => rt,
+                    _ => return false,
+                };
+
+                let src = format!("{}/regenerator", self.module_name);
+                self.imports.insert(src, rt.clone());
+                true
+            }
+
+            _ => false,
+        }
+    }
+}
+
+/// Returns true if `call` is `require('regenerator-runtime')`.
+fn is_regenerator_require(call: &CallExpr) -> bool {
+    match &call.callee {
+        ExprOrSuper::Expr(callee) => match &**callee {
+            Expr::Ident(Ident {
+                sym: js_word!("require"),
+                ..
+            }) => {}
+            _ => return false,
+        },
+        _ => return false,
+    }
+
+    match &*call.args {
+        [ExprOrSpread { spread: None, expr }] => match &**expr {
+            Expr::Lit(Lit::Str(s)) => &*s.value == "regenerator-runtime",
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_global(i: &Ident, name: &str) -> bool {
+    &*i.sym == name && i.span.ctxt() == SyntaxContext::empty()
+}
+
+impl VisitMut for TransformRuntime {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) = e
+        {
+            if let (Expr::Ident(obj), Expr::Ident(prop)) = (&**obj, &**prop) {
+                let path = STATIC_METHODS
+                    .iter()
+                    .find(|(o, p, _)| is_global(obj, o) && &*prop.sym == *p);
+
+                if let Some((o, p, path)) = path {
+                    *e = Expr::Ident(self.import(path, &format!("_{}${}", o, p)));
+                    return;
+                }
+            }
+        }
+
+        e.visit_mut_children_with(self);
+
+        if let Expr::Ident(i) = e {
+            if let Some((name, path)) = GLOBALS.iter().find(|(name, _)| is_global(i, name)) {
+                *e = Expr::Ident(self.import(path, &format!("_{}", name)));
+            }
+        }
+    }
+
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        e.obj.visit_mut_with(self);
+        if e.computed {
+            e.prop.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        if HELPERS.is_set() {
+            self.helper_ctxt =
+                Some(HELPERS.with(|helpers| SyntaxContext::empty().apply_mark(helpers.mark())));
+        }
+        m.body.retain(|item| !self.replace_item(item));
+
+        if self.c.corejs {
+            m.visit_mut_children_with(self);
+        }
+
+        let imports = self.imports.drain(..).map(|(src, local)| {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: DUMMY_SP,
+                specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                    span: DUMMY_SP,
+                    local,
+                })],
+                src: Str {
+                    span: DUMMY_SP,
+                    value: src.into(),
+                    has_escape: false,
+                },
+                type_only: false,
+                asserts: None,
+                with: false,
+            }))
+        });
+        m.body.splice(0..0, imports);
+    }
+
+    fn visit_mut_prop(&mut self, p: &mut Prop) {
+        p.visit_mut_children_with(self);
+
+        if let Prop::Shorthand(i) = p {
+            let mut value = Expr::Ident(i.clone());
+            self.visit_mut_expr(&mut value);
+            if let Expr::Ident(v) = &value {
+                if v.sym == i.sym {
+                    return;
+                }
+            }
+
+            *p = Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(i.clone()),
+                value: Box::new(value),
+            });
+        }
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transform_runtime, Config};
+    use crate::{compat::es2015::classes, helpers::inject_helpers, resolver};
+    use swc_common::chain;
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(
+            resolver(),
+            classes(),
+            inject_helpers(),
+            transform_runtime(Config {
+                helpers: true,
+                ..Default::default()
+            })
+        ),
+        inline_helpers,
+        "class Foo {}",
+        "
+import _classCallCheck from '@babel/runtime/helpers/classCallCheck';
+let Foo = function Foo() {
+    'use strict';
+    _classCallCheck(this, Foo);
+};
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| transform_runtime(Config {
+            module_name: Some("my-runtime".into()),
+            helpers: true,
+            ..Default::default()
+        }),
+        helpers,
+        "
+import { classCallCheck as _classCallCheck, get as _get } from '@swc/helpers';
+var Foo = function Foo() {
+    _classCallCheck(this, Foo);
+};
+",
+        "
+import _classCallCheck from 'my-runtime/helpers/classCallCheck';
+import _get from 'my-runtime/helpers/get';
+var Foo = function Foo() {
+    _classCallCheck(this, Foo);
+};
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| transform_runtime(Config {
+            regenerator: true,
+            ..Default::default()
+        }),
+        regenerator,
+        "
+var regeneratorRuntime = require('regenerator-runtime');
+var _marked = regeneratorRuntime.mark(foo);
+",
+        "
+import regeneratorRuntime from '@babel/runtime/regenerator';
+var _marked = regeneratorRuntime.mark(foo);
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| chain!(
+            resolver(),
+            transform_runtime(Config {
+                corejs: true,
+                ..Default::default()
+            })
+        ),
+        corejs,
+        "
+const a = Promise.resolve(Object.assign({}, { Map }));
+function f(Set) {
+    return new Set(a.Promise);
+}
+",
+        "
+import _Promise from '@babel/runtime-corejs2/core-js/promise';
+import _Object$assign from '@babel/runtime-corejs2/core-js/object/assign';
+import _Map from '@babel/runtime-corejs2/core-js/map';
+const a = _Promise.resolve(_Object$assign({}, { Map: _Map }));
+function f(Set) {
+    return new Set(a.Promise);
+}
+"
+    );
+
+    test!(
+        ::swc_ecma_parser::Syntax::default(),
+        |_| transform_runtime(Default::default()),
+        disabled,
+        "
+import { classCallCheck as _classCallCheck } from '@swc/helpers';
+var regeneratorRuntime = require('regenerator-runtime');
+new Promise(f);
+",
+        "
+import { classCallCheck as _classCallCheck } from '@swc/helpers';
+var regeneratorRuntime = require('regenerator-runtime');
+new Promise(f);
+"
+    );
+}
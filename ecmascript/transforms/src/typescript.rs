@@ -1,3 +1,4 @@
-pub use self::strip::strip;
+pub use self::{const_enum::inline_const_enums, strip::strip};
 
+mod const_enum;
 pub mod strip;
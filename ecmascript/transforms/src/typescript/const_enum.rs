@@ -0,0 +1,239 @@
+use crate::util::HANDLER;
+use fxhash::FxHashMap;
+use std::collections::HashMap;
+use swc_atoms::{js_word, JsWord};
+use swc_common::{Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{ident::IdentLike, Id};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Inlines members of `const enum`s imported from other modules.
+///
+/// `resolve` is called with the source of an import and the exported name,
+/// and returns the values of the members if the binding is a const enum.
+///
+/// ```ts
+/// import { Direction } from './direction';
+/// move(Direction.Up);
+/// ```
+///
+/// becomes `move(0);` if `Direction.Up` is resolved to `0`. The import is
+/// removed by [strip](super::strip) if no use of `Direction` remains.
+///
+/// If `resolve` returns `None`, accesses are preserved, which is correct as
+/// long as the enum is emitted at runtime (`preserveConstEnums`). This is the
+/// behavior expected with `isolatedModules`. An access to a member which
+/// does not exist in a resolved enum is reported as an error.
+///
+/// # When to run
+///
+/// This pass should be applied after the resolver and before `strip`.
+pub fn inline_const_enums<F>(resolve: F) -> impl Fold
+where
+    F: Fn(&JsWord, &JsWord) -> Option<HashMap<JsWord, TsLit>>,
+{
+    as_folder(InlineConstEnums {
+        resolve,
+        imports: Default::default(),
+        resolved: Default::default(),
+    })
+}
+
+struct InlineConstEnums<F> {
+    resolve: F,
+    /// Local binding to `(src, exported name)`.
+    imports: FxHashMap<Id, (JsWord, JsWord)>,
+    resolved: FxHashMap<Id, Option<HashMap<JsWord, TsLit>>>,
+}
+
+impl<F> InlineConstEnums<F>
+where
+    F: Fn(&JsWord, &JsWord) -> Option<HashMap<JsWord, TsLit>>,
+{
+    fn members(&mut self, id: Id) -> Option<&HashMap<JsWord, TsLit>> {
+        let (src, name) = self.imports.get(&id)?;
+        let resolve = &self.resolve;
+        self.resolved
+            .entry(id)
+            .or_insert_with(|| resolve(src, name))
+            .as_ref()
+    }
+}
+
+impl<F> VisitMut for InlineConstEnums<F>
+where
+    F: Fn(&JsWord, &JsWord) -> Option<HashMap<JsWord, TsLit>>,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        for item in &m.body {
+            let import = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => import,
+                _ => continue,
+            };
+
+            for s in &import.specifiers {
+                let (local, name) = match s {
+                    ImportSpecifier::Named(s) => (
+                        &s.local,
+                        s.imported.as_ref().unwrap_or(&s.local).sym.clone(),
+                    ),
+                    ImportSpecifier::Default(s) => (&s.local, js_word!("default")),
+                    ImportSpecifier::Namespace(..) => continue,
+                };
+                self.imports
+                    .insert(local.to_id(), (import.src.value.clone(), name));
+            }
+        }
+
+        if self.imports.is_empty() {
+            return;
+        }
+
+        m.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let member = match e {
+            Expr::Member(member) => member,
+            _ => return,
+        };
+        let obj = match &member.obj {
+            ExprOrSuper::Expr(obj) => match &**obj {
+                Expr::Ident(obj) => obj,
+                _ => return,
+            },
+            _ => return,
+        };
+        let prop = match (&*member.prop, member.computed) {
+            (Expr::Ident(i), false) => &i.sym,
+            (Expr::Lit(Lit::Str(s)), true) => &s.value,
+            _ => return,
+        };
+
+        let span = member.span;
+        let obj_sym = obj.sym.clone();
+        let prop = prop.clone();
+        let value = match self.members(obj.to_id()) {
+            Some(members) => members.get(&prop).cloned(),
+            None => return,
+        };
+
+        *e = match value {
+            Some(TsLit::Number(n)) if n.value.is_sign_negative() => Expr::Unary(UnaryExpr {
+                span,
+                op: op!(unary, "-"),
+                arg: Box::new(Expr::Lit(Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: -n.value,
+                }))),
+            }),
+            Some(TsLit::Number(n)) => Expr::Lit(Lit::Num(Number { span, ..n })),
+            Some(TsLit::Str(s)) => Expr::Lit(Lit::Str(Str { span, ..s })),
+            Some(lit) => {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            lit.span(),
+                            "const enum members should be a number or a string",
+                        )
+                        .emit()
+                });
+                return;
+            }
+            None => {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            span,
+                            &format!("`{}` is not a member of the const enum `{}`", prop, obj_sym),
+                        )
+                        .emit()
+                });
+                return;
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inline_const_enums;
+    use crate::{resolver, typescript::strip};
+    use std::collections::HashMap;
+    use swc_atoms::JsWord;
+    use swc_common::{chain, DUMMY_SP};
+    use swc_ecma_ast::*;
+    use swc_ecma_parser::Syntax;
+
+    fn direction(src: &JsWord, name: &JsWord) -> Option<HashMap<JsWord, TsLit>> {
+        if &**src != "./direction" || &**name != "Direction" {
+            return None;
+        }
+
+        let num = |value| {
+            TsLit::Number(Number {
+                span: DUMMY_SP,
+                value,
+            })
+        };
+        let mut members = HashMap::default();
+        members.insert("Up".into(), num(0.0));
+        members.insert("Down".into(), num(-1.0));
+        members.insert(
+            "Name".into(),
+            TsLit::Str(Str {
+                span: DUMMY_SP,
+                value: "direction".into(),
+                has_escape: false,
+            }),
+        );
+        Some(members)
+    }
+
+    fn syntax() -> Syntax {
+        Syntax::Typescript(Default::default())
+    }
+
+    test!(
+        syntax(),
+        |_| chain!(resolver(), inline_const_enums(direction), strip()),
+        inline,
+        "
+import { Direction } from './direction';
+import { Other } from './other';
+
+move(Direction.Up, Direction['Down'], Direction.Name);
+move(Other.Up);
+",
+        "
+import { Other } from './other';
+
+move(0, -1, 'direction');
+move(Other.Up);
+"
+    );
+
+    test!(
+        syntax(),
+        |_| chain!(resolver(), inline_const_enums(direction), strip()),
+        shadowed,
+        "
+import { Direction as D } from './direction';
+
+function f(D) {
+    return D.Up;
+}
+f(D.Up);
+",
+        "
+function f(D) {
+    return D.Up;
+}
+f(0);
+"
+    );
+}
@@ -126,6 +126,7 @@ impl<'a> Tester<'a> {
                     None,
                 )),
                 comments: None,
+                ident_hook: None,
             };
 
             // println!("Emitting: {:?}", module);
@@ -1523,16 +1523,16 @@ function (Base) {
   _createClass(Obj, [{
     key: "update",
     value: function update() {
-      var _ref, _superRef;
+      var _prop, _superRef;
 
-      _set(_getPrototypeOf(Obj.prototype), _ref = proper.prop, (_superRef = +_get(_getPrototypeOf(Obj.prototype), _ref, this)) + 1, this, true), _superRef;
+      _set(_getPrototypeOf(Obj.prototype), _prop = proper.prop, (_superRef = +_get(_getPrototypeOf(Obj.prototype), _prop, this)) + 1, this, true), _superRef;
     }
   }, {
     key: "update2",
     value: function update2() {
-      var _ref, _superRef;
+      var _i, _superRef;
 
-      _set(_getPrototypeOf(Obj.prototype), _ref = i, (_superRef = +_get(_getPrototypeOf(Obj.prototype), _ref, this)) + 1, this, true), _superRef;
+      _set(_getPrototypeOf(Obj.prototype), _i = i, (_superRef = +_get(_getPrototypeOf(Obj.prototype), _i, this)) + 1, this, true), _superRef;
     }
   }]);
 
@@ -4257,16 +4257,16 @@ function (Base) {
   _createClass(Obj, [{
     key: "assign",
     value: function assign() {
-      var _ref;
+      var _prop;
 
-      _set(_getPrototypeOf(Obj.prototype), _ref = proper.prop, _get(_getPrototypeOf(Obj.prototype), _ref, this) + 1, this, true);
+      _set(_getPrototypeOf(Obj.prototype), _prop = proper.prop, _get(_getPrototypeOf(Obj.prototype), _prop, this) + 1, this, true);
     }
   }, {
     key: "assign2",
     value: function assign2() {
-      var _ref;
+      var _i;
 
-      _set(_getPrototypeOf(Obj.prototype), _ref = i, _get(_getPrototypeOf(Obj.prototype), _ref, this) + 1, this, true);
+      _set(_getPrototypeOf(Obj.prototype), _i = i, _get(_getPrototypeOf(Obj.prototype), _i, this) + 1, this, true);
     }
   }]);
 
@@ -6132,3 +6132,224 @@ const obj = new OtherClass();
 expect(obj.anotherMethod()).toBe(2);
 "#
 );
+
+test_exec!(
+    syntax(),
+    |_| spec_tr(),
+    inheritance_super_call_exec,
+    r#"
+const key = 'describe';
+
+class Animal {
+  constructor(name) {
+    this.name = name;
+  }
+
+  speak() {
+    return `${this.name} makes a sound`;
+  }
+
+  [key]() {
+    return `animal ${this.name}`;
+  }
+
+  static create(name) {
+    return new this(name);
+  }
+}
+
+class Dog extends Animal {
+  constructor(name) {
+    super(name);
+    this.kind = 'dog';
+  }
+
+  speak() {
+    return `${super.speak()}: woof`;
+  }
+
+  [key]() {
+    return `${super[key]()} (${this.kind})`;
+  }
+}
+
+const dog = Dog.create('Rex');
+
+expect(dog).toBeInstanceOf(Dog);
+expect(dog).toBeInstanceOf(Animal);
+expect(dog.name).toBe('Rex');
+expect(dog.speak()).toBe('Rex makes a sound: woof');
+expect(dog.describe()).toBe('animal Rex (dog)');
+expect(Object.keys(Dog.prototype)).toEqual([]);
+expect(() => Dog('Rex')).toThrow(TypeError);
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    computed_key_order,
+    r#"
+class Foo {
+  static [a()]() {}
+  [b()]() {}
+}
+"#,
+    r#"
+var Foo = function () {
+  "use strict";
+  function Foo() {
+    _classCallCheck(this, Foo);
+  }
+  var _key = a(), _key1 = b();
+  _createClass(Foo, [{
+    key: _key1,
+    value: function () {}
+  }], [{
+    key: _key,
+    value: function () {}
+  }]);
+  return Foo;
+}();
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| spec_tr(),
+    computed_key_order_exec,
+    r#"
+const log = [];
+const key = (k) => (log.push(k), k);
+
+class Foo {
+  [key('a')]() { return 'a'; }
+  static [key('b')]() { return 'b'; }
+  [key('c')]() { return 'c'; }
+  static d() {}
+  get [key('e')]() { return 'e'; }
+}
+
+expect(log).toEqual(['a', 'b', 'c', 'e']);
+expect(new Foo().a()).toBe('a');
+expect(Foo.b()).toBe('b');
+expect(new Foo().c()).toBe('c');
+expect(new Foo().e).toBe('e');
+"#
+);
+
+test!(
+    syntax(),
+    |_| spec_tr(),
+    anonymous_class_expression_shadowing,
+    r#"
+var _class = 1;
+var Foo = class {
+  get() {
+    return _class;
+  }
+};
+"#,
+    r#"
+var _class = 1;
+var Foo = function () {
+  "use strict";
+  function _class1() {
+    _classCallCheck(this, _class1);
+  }
+  _createClass(_class1, [{
+    key: "get",
+    value: function get() {
+      return _class;
+    }
+  }]);
+  return _class1;
+}();
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| spec_tr(),
+    named_class_expression_exec,
+    r#"
+class Base {
+  constructor(value) {
+    this.value = value;
+  }
+}
+
+const Outer = class Inner extends Base {
+  static create(value) {
+    return new Inner(value);
+  }
+
+  clone() {
+    return new Inner(this.value);
+  }
+
+  get self() {
+    return Inner;
+  }
+};
+
+const a = Outer.create(1);
+expect(a).toBeInstanceOf(Outer);
+expect(a).toBeInstanceOf(Base);
+expect(a.clone().value).toBe(1);
+expect(a.self).toBe(Outer);
+expect(Outer.name).toBe('Inner');
+expect(typeof Inner).toBe('undefined');
+
+const Simple = class Self {};
+expect(Simple.name).toBe('Self');
+expect(typeof Self).toBe('undefined');
+
+var _class = 'outer';
+const Anonymous = class {
+  get() {
+    return _class;
+  }
+};
+expect(new Anonymous().get()).toBe('outer');
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    super_compound_assignment_exec,
+    r#"
+class Base {}
+Base.prototype.x = 6;
+
+class Obj extends Base {
+  run() {
+    let calls = 0;
+    const key = () => (calls++, 'x');
+
+    expect(super[key()] += 3).toBe(9);
+    expect(super[key()] -= 3).toBe(3);
+    expect(super[key()] *= 3).toBe(18);
+    expect(super[key()] /= 3).toBe(2);
+    expect(super[key()] %= 4).toBe(2);
+    expect(super[key()] <<= 2).toBe(24);
+    expect(super[key()] >>= 1).toBe(3);
+    expect(super[key()] >>>= 1).toBe(3);
+    expect(super[key()] |= 1).toBe(7);
+    expect(super[key()] &= 3).toBe(2);
+    expect(super[key()] ^= 3).toBe(5);
+    expect(super[key()] **= 2).toBe(36);
+    expect(super[key()] &&= 3).toBe(3);
+    expect(super[key()] ||= 3).toBe(6);
+    expect(super[key()] ??= 3).toBe(6);
+    expect(super[key()]++).toBe(6);
+    expect(--super[key()]).toBe(5);
+
+    expect(calls).toBe(17);
+  }
+}
+
+new Obj().run();
+"#
+);
@@ -1491,3 +1491,35 @@ test!(
     "var ref;
 foo((ref = [1, 2], a = ref[0], b = ref[1], ref));"
 );
+
+test!(
+    syntax(),
+    |_| tr(),
+    assign_expr_key_value_default,
+    "({ a: b = 1 } = obj);",
+    "var ref, ref1;
+ref = obj, ref1 = ref.a, b = ref1 === void 0 ? 1 : ref1, ref;"
+);
+
+test_exec!(
+    syntax(),
+    |_| destructuring(Default::default()),
+    default_value_null_exec,
+    r#"
+const { a = 1 } = { a: null };
+expect(a).toBe(null);
+
+const [c = 1] = [null];
+expect(c).toBe(null);
+
+let b, d;
+({ a: b = 1 } = { a: null });
+expect(b).toBe(null);
+
+({ a: b = 1 } = {});
+expect(b).toBe(1);
+
+({ a: { d = 2 } = {} } = { a: { d: null } });
+expect(d).toBe(null);
+"#
+);
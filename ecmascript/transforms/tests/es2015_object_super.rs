@@ -0,0 +1,182 @@
+#![feature(test)]
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::compat::es2015::object_super;
+use swc_ecma_visit::Fold;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::default()
+}
+
+fn tr() -> impl Fold {
+    object_super()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    get_and_call,
+    "const obj = {
+    foo() {
+        return super.foo() + super.bar;
+    }
+};",
+    "let _obj;
+const obj = _obj = {
+    foo() {
+        return _get(_getPrototypeOf(_obj), "foo", this).call(this) + _get(_getPrototypeOf(_obj), \
+     'bar', this);
+    }
+};"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    assign,
+    "const obj = {
+    foo() {
+        super.bar = 1;
+        super[key()] += 2;
+    }
+};",
+    "let _obj;
+const obj = _obj = {
+    foo() {
+        let _ref;
+        _set(_getPrototypeOf(_obj), "bar", 1, this, true);
+        _set(_getPrototypeOf(_obj), _ref = key(), _get(_getPrototypeOf(_obj), _ref, this) + 2, \
+     this, true);
+    }
+};"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    arrow_body,
+    "const make = () => ({
+    foo() {
+        return super.foo;
+    }
+});",
+    "const make = () => {
+    let _obj;
+    return _obj = {
+        foo() {
+            return _get(_getPrototypeOf(_obj), "foo", this);
+        }
+    };
+};"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    without_super,
+    "const obj = {
+    foo() {
+        return 1;
+    }
+};",
+    "const obj = {
+    foo() {
+        return 1;
+    }
+};"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    super_to_string_exec,
+    r#"
+const obj = {
+  toString() {
+    return 'custom ' + super.toString();
+  },
+};
+
+expect(obj.toString()).toBe('custom [object Object]');
+expect(`${obj}`).toBe('custom [object Object]');
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    home_object_exec,
+    r#"
+const base = {
+  name() {
+    return 'base';
+  },
+  count: 0,
+};
+
+function make(label) {
+  return {
+    __proto__: base,
+    name() {
+      return label + ' ' + super.name();
+    },
+    inc() {
+      super.count += 1;
+      return this.count;
+    },
+  };
+}
+
+const a = make('a');
+const b = make('b');
+Object.setPrototypeOf(b, { name: () => 'other' });
+
+expect(a.name()).toBe('a base');
+expect(b.name()).toBe('b other');
+// `super.count` is read from `base`, and `this.count` is set on `a`.
+expect(a.inc()).toBe(1);
+expect(a.inc()).toBe(1);
+expect(a.count).toBe(1);
+expect(base.count).toBe(0);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    compound_assignment_exec,
+    r#"
+const base = { x: 6 };
+const obj = {
+  run() {
+    let calls = 0;
+    const key = () => (calls++, 'x');
+
+    expect(super[key()] += 3).toBe(9);
+    expect(super[key()] -= 3).toBe(3);
+    expect(super[key()] *= 3).toBe(18);
+    expect(super[key()] /= 3).toBe(2);
+    expect(super[key()] %= 4).toBe(2);
+    expect(super[key()] <<= 2).toBe(24);
+    expect(super[key()] >>= 1).toBe(3);
+    expect(super[key()] >>>= 1).toBe(3);
+    expect(super[key()] |= 1).toBe(7);
+    expect(super[key()] &= 3).toBe(2);
+    expect(super[key()] ^= 3).toBe(5);
+    expect(super[key()] **= 2).toBe(36);
+    expect(super[key()] &&= 3).toBe(3);
+    expect(super[key()] ||= 3).toBe(6);
+    expect(super[key()] ??= 3).toBe(6);
+    expect(super[key()]++).toBe(6);
+    expect(--super[key()]).toBe(5);
+
+    expect(calls).toBe(17);
+  }
+};
+Object.setPrototypeOf(obj, base);
+
+obj.run();
+"#
+);
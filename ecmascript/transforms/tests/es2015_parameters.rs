@@ -1694,3 +1694,44 @@ export default function reducer(state = initialState, action = {}) {
       } : param1;
   }"
 );
+
+test!(
+    syntax(),
+    |_| tr(),
+    default_unmapped_arguments,
+    "function foo(a, b = 1) {
+    a = 2;
+    return arguments[0];
+}",
+    "
+function foo(param, param1) {
+    var a = param, b = param1 === void 0 ? 1 : param1;
+    a = 2;
+    return arguments[0];
+}
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    default_unmapped_arguments_exec,
+    r#"function f(a, b = 1) {
+  a = 2;
+  return [arguments[0], arguments.length, b];
+}
+
+expect(f(5)).toEqual([5, 1, 1]);"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    default_reads_arguments,
+    r#"function f(a, b = arguments.length, c = a + b) {
+  return [a, b, c];
+}
+
+expect(f(1)).toEqual([1, 1, 2]);
+expect(f(1, 5)).toEqual([1, 5, 6]);"#
+);
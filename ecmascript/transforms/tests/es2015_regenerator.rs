@@ -1,9 +1,10 @@
 #![feature(test)]
 use swc_common::{chain, Mark};
-use swc_ecma_parser::Syntax;
+use swc_ecma_parser::{EsConfig, Syntax};
 use swc_ecma_transforms::{
     compat::{es2015, es2015::regenerator, es2016, es2017, es2017::async_to_generator},
     modules::common_js::common_js,
+    proposals::record_and_tuple,
     resolver,
 };
 use swc_ecma_visit::Fold;
@@ -1372,3 +1373,28 @@ test!(
     }
     "
 );
+
+test_exec!(
+    Syntax::Es(EsConfig {
+        record_and_tuple: true,
+        ..Default::default()
+    }),
+    |_| chain!(tr(Default::default()), record_and_tuple()),
+    record_and_tuple_yield,
+    "
+function* gen() {
+    const r = #{ a: yield 1, ...(yield 2) };
+    const t = #[yield 3, ...(yield 4)];
+    return [r, t];
+}
+
+const it = gen();
+expect(it.next().value).toBe(1);
+expect(it.next('a').value).toBe(2);
+expect(it.next({ b: 'b' }).value).toBe(3);
+expect(it.next('c').value).toBe(4);
+const [r, t] = it.next(['d']).value;
+expect(r).toEqual({ a: 'a', b: 'b' });
+expect(t).toEqual(['c', 'd']);
+"
+);
@@ -920,3 +920,115 @@ foo("foo", "bar");
 
 "#
 );
+
+test!(
+    syntax(),
+    |_| tr(),
+    array_spread_mixed,
+    "const c = [...a, x, ...b];",
+    "const c = _toConsumableArray(a).concat([x], _toConsumableArray(b));"
+);
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    array_spread_mixed_loose,
+    "const c = [...a, x, ...b];",
+    "const c = a.concat([x], b);"
+);
+
+test!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    array_spread_copy_loose,
+    "const c = [...a];",
+    "const c = a.slice();"
+);
+
+test_exec!(
+    syntax(),
+    |_| spread(Config {
+        loose: true,
+        ..Default::default()
+    }),
+    array_spread_loose_exec,
+    r#"
+const a = [1, 2];
+const b = [[3], 4];
+const c = [...a];
+
+expect(c).toEqual([1, 2]);
+expect(c).not.toBe(a);
+expect([...a, 0, ...b]).toEqual([1, 2, 0, [3], 4]);
+"#
+);
+
+fn array_fast_path() -> impl Fold {
+    spread(Config {
+        array_fast_path: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| array_fast_path(),
+    array_fast_path_copy,
+    "const c = [...a];",
+    "const c = Array.isArray(a) ? a.slice() : _toConsumableArray(a);"
+);
+
+test!(
+    syntax(),
+    |_| array_fast_path(),
+    array_fast_path_concat,
+    "const c = [x, ...a(), ...b];",
+    "var _arr;
+const c = [x].concat(Array.isArray(_arr = a()) ? _arr : _toConsumableArray(_arr), Array.isArray(b) \
+     ? b : _toConsumableArray(b));"
+);
+
+test!(
+    syntax(),
+    |_| array_fast_path(),
+    array_fast_path_call,
+    "foo(...args);",
+    "foo.apply(void 0, Array.isArray(args) ? args : _toConsumableArray(args));"
+);
+
+test_exec!(
+    syntax(),
+    |_| array_fast_path(),
+    array_fast_path_skips_iterator,
+    r#"
+const values = Array.prototype.values;
+let calls = 0;
+Array.prototype[Symbol.iterator] = function () {
+    calls++;
+    return values.call(this);
+};
+
+const a = [1, 2, 3];
+function sum(...args) {
+    return args.reduce((x, y) => x + y, 0);
+}
+
+let total = 0;
+for (let i = 0; i < 1000; i++) {
+    const b = [...a, i, ...a];
+    total += sum(...b);
+}
+
+Array.prototype[Symbol.iterator] = values;
+
+expect(total).toBe(1000 * 12 + 999 * 1000 / 2);
+expect(calls).toBe(0);
+expect([...new Set([1, 1, 2])]).toEqual([1, 2]);
+"#
+);
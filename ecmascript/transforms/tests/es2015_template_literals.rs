@@ -169,97 +169,18 @@ function a() {
   tag`\01`;
 }"#,
     r#"
-function _templateObject8() {
-  const data = _taggedTemplateLiteral([void 0], ["\\01"]);
-
-  _templateObject8 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject7() {
-  const data = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u{-0}", "right"]);
-
-  _templateObject7 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject6() {
-  const data = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u000g", "right"]);
-
-  _templateObject6 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject5() {
-  const data = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\xg", "right"]);
-
-  _templateObject5 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject4() {
-  const data = _taggedTemplateLiteral(["left", void 0], ["left", "\\xg"]);
-
-  _templateObject4 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject3() {
-  const data = _taggedTemplateLiteral([void 0, "right"], ["\\xg", "right"]);
-
-  _templateObject3 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject2() {
-  const data = _taggedTemplateLiteral([void 0], ["\\01"]);
-
-  _templateObject2 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject() {
-  const data = _taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"]);
-
-  _templateObject = function () {
-    return data;
-  };
-
-  return data;
-}
-
-tag(_templateObject());
-tag(_templateObject2());
-tag(_templateObject3(), 0);
-tag(_templateObject4(), 0);
-tag(_templateObject5(), 0, 1);
-tag(_templateObject6(), 0, 1);
-tag(_templateObject7(), 0, 1);
+var _templateObject, _templateObject2, _templateObject3, _templateObject4, _templateObject5, _templateObject6, _templateObject7, _templateObject8;
+tag(_templateObject || (_templateObject = _taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+tag(_templateObject2 || (_templateObject2 = _taggedTemplateLiteral([void 0], ["\\01"])));
+tag(_templateObject3 || (_templateObject3 = _taggedTemplateLiteral([void 0, "right"], ["\\xg", "right"])), 0);
+tag(_templateObject4 || (_templateObject4 = _taggedTemplateLiteral(["left", void 0], ["left", "\\xg"])), 0);
+tag(_templateObject5 || (_templateObject5 = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\xg", "right"])), 0, 1);
+tag(_templateObject6 || (_templateObject6 = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u000g", "right"])), 0, 1);
+tag(_templateObject7 || (_templateObject7 = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u{-0}", "right"])), 0, 1);
 
 function a() {
   var undefined = 4;
-  tag(_templateObject8());
+  tag(_templateObject8 || (_templateObject8 = _taggedTemplateLiteral([void 0], ["\\01"])));
 }"#
 );
 
@@ -427,99 +348,20 @@ function a() {
 
 "#,
     r#"
-function _templateObject8() {
-  const data = _taggedTemplateLiteral([void 0], ["\\01"]);
-
-  _templateObject8 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject7() {
-  const data = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u{-0}", "right"]);
-
-  _templateObject7 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject6() {
-  const data = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u000g", "right"]);
-
-  _templateObject6 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject5() {
-  const data = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\xg", "right"]);
-
-  _templateObject5 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject4() {
-  const data = _taggedTemplateLiteral(["left", void 0], ["left", "\\xg"]);
-
-  _templateObject4 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject3() {
-  const data = _taggedTemplateLiteral([void 0, "right"], ["\\xg", "right"]);
-
-  _templateObject3 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject2() {
-  const data = _taggedTemplateLiteral([void 0], ["\\01"]);
-
-  _templateObject2 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject() {
-  const data = _taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"]);
-
-  _templateObject = function () {
-    return data;
-  };
-
-  return data;
-}
-
+var _templateObject, _templateObject2, _templateObject3, _templateObject4, _templateObject5, _templateObject6, _templateObject7, _templateObject8;
 function _taggedTemplateLiteral(strings, raw) { if (!raw) { raw = strings.slice(0); } return Object.freeze(Object.defineProperties(strings, { raw: { value: Object.freeze(raw) } })); }
 
-tag(_templateObject());
-tag(_templateObject2());
-tag(_templateObject3(), 0);
-tag(_templateObject4(), 0);
-tag(_templateObject5(), 0, 1);
-tag(_templateObject6(), 0, 1);
-tag(_templateObject7(), 0, 1);
+tag(_templateObject || (_templateObject = _taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+tag(_templateObject2 || (_templateObject2 = _taggedTemplateLiteral([void 0], ["\\01"])));
+tag(_templateObject3 || (_templateObject3 = _taggedTemplateLiteral([void 0, "right"], ["\\xg", "right"])), 0);
+tag(_templateObject4 || (_templateObject4 = _taggedTemplateLiteral(["left", void 0], ["left", "\\xg"])), 0);
+tag(_templateObject5 || (_templateObject5 = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\xg", "right"])), 0, 1);
+tag(_templateObject6 || (_templateObject6 = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u000g", "right"])), 0, 1);
+tag(_templateObject7 || (_templateObject7 = _taggedTemplateLiteral(["left", void 0, "right"], ["left", "\\u{-0}", "right"])), 0, 1);
 
 function a() {
   var undefined = 4;
-  tag(_templateObject8());
+  tag(_templateObject8 || (_templateObject8 = _taggedTemplateLiteral([void 0], ["\\01"])));
 }
 
 "#
@@ -646,34 +488,15 @@ expect(bar()).not.toBe(foo());
 
 "#,
     r#"
-function _templateObject() {
-  const data = _taggedTemplateLiteral(["some template"]);
-
-  _templateObject = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject1() {
-  const data = _taggedTemplateLiteral(["some template"]);
-
-  _templateObject1 = function () {
-    return data;
-  };
-
-  return data;
-}
-
+var _templateObject, _templateObject1;
 var tag = v => v;
 
 function foo() {
-  return tag(_templateObject());
+  return tag(_templateObject || (_templateObject = _taggedTemplateLiteral(["some template"])));
 }
 
 function bar() {
-  return tag(_templateObject1());
+  return tag(_templateObject1 || (_templateObject1 = _taggedTemplateLiteral(["some template"])));
 }
 
 expect(foo()).toBe(foo());
@@ -697,40 +520,10 @@ var bar = bar`wow\naB${ 42 } ${_.baz()}`;
 
 "#,
     r#"
-function _templateObject() {
-  const data = _taggedTemplateLiteral(["wow\na", "b ", ""], ["wow\\na", "b ", ""]);
-
-  _templateObject = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject1() {
-  const data = _taggedTemplateLiteral(["wow\nab", " ", ""], ["wow\\nab", " ", ""]);
-
-  _templateObject1 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject2() {
-  const data = _taggedTemplateLiteral(["wow\naB", " ", ""], ["wow\\naB", " ", ""]);
-
-  _templateObject2 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-
-var foo = bar(_templateObject(), 42, _.foobar());
-var bar = bar(_templateObject1(), 42, _.foobar());
-var bar = bar(_templateObject2(), 42, _.baz());
+var _templateObject, _templateObject1, _templateObject2;
+var foo = bar(_templateObject || (_templateObject = _taggedTemplateLiteral(["wow\na", "b ", ""], ["wow\\na", "b ", ""])), 42, _.foobar());
+var bar = bar(_templateObject1 || (_templateObject1 = _taggedTemplateLiteral(["wow\nab", " ", ""], ["wow\\nab", " ", ""])), 42, _.foobar());
+var bar = bar(_templateObject2 || (_templateObject2 = _taggedTemplateLiteral(["wow\naB", " ", ""], ["wow\\naB", " ", ""])), 42, _.baz());
 
 "#
 );
@@ -746,28 +539,9 @@ var bar = tag`first${1}second`;
 
 "#,
     r#"
-function _templateObject() {
-  const data = _taggedTemplateLiteral(["wow"]);
-
-  _templateObject = function () {
-    return data;
-  };
-
-  return data;
-}
-
-function _templateObject1() {
-  const data = _taggedTemplateLiteral(["first", "second"]);
-
-  _templateObject1 = function () {
-    return data;
-  };
-
-  return data;
-}
-
-var foo = tag(_templateObject());
-var bar = tag(_templateObject1(), 1);
+var _templateObject, _templateObject1;
+var foo = tag(_templateObject || (_templateObject = _taggedTemplateLiteral(["wow"])));
+var bar = tag(_templateObject1 || (_templateObject1 = _taggedTemplateLiteral(["first", "second"])), 1);
 
 "#
 );
@@ -782,27 +556,14 @@ test!(
     console.log(i18n`Nobody will ever see this.`);
   }
 ",
-    "function _templateObject() {
-      const data = _taggedTemplateLiteral([
+    "var _templateObject, _templateObject1;
+    export function foo() {
+      console.log(i18n(_templateObject || (_templateObject = _taggedTemplateLiteral([
           \"Hello World\"
-      ]);
-      _templateObject = function() {
-          return data;
-      };
-      return data;
-  }
-  function _templateObject1() {
-      const data = _taggedTemplateLiteral([
+      ]))));
+      console.log(i18n(_templateObject1 || (_templateObject1 = _taggedTemplateLiteral([
           \"Nobody will ever see this.\"
-      ]);
-      _templateObject1 = function() {
-          return data;
-      };
-      return data;
-  }
-  export function foo() {
-      console.log(i18n(_templateObject()));
-      console.log(i18n(_templateObject1()));
+      ]))));
   }
   "
 );
@@ -907,3 +668,27 @@ test!(
     r#""The ".concat(argumentName, " has unexpected type of \"") + matchType + "\". Expected argument to be an object with the following " + "keys: \"".concat(reducerKeys.join('", "'), "\"")"#,
     ok_if_code_eq
 );
+
+test_exec!(
+    syntax(),
+    |_| tr(Default::default()),
+    tagged_template_identity_exec,
+    r#"
+const seen = [];
+const tag = (strings) => seen.push(strings);
+
+function f(x) {
+  return tag`a${x}b`;
+}
+f(1);
+f(2);
+for (let i = 0; i < 2; i++) tag`a${i}b`;
+
+expect(seen[1]).toBe(seen[0]);
+expect(seen[3]).toBe(seen[2]);
+expect(seen[2]).not.toBe(seen[0]);
+expect(Object.isFrozen(seen[0])).toBe(true);
+expect(Object.isFrozen(seen[0].raw)).toBe(true);
+expect(seen[0]).toEqual(["a", "b"]);
+"#
+);
@@ -2298,3 +2298,42 @@ test!(
     test();
     "
 );
+
+test!(
+    syntax(),
+    |_| async_to_generator(),
+    arrow_concise_body,
+    "
+const inc = async x => x + 1;
+const get = async () => await f();
+const obj = async () => ({ a: await f() });
+",
+    "
+const inc = _asyncToGenerator(function* (x) {
+    return x + 1;
+});
+const get = _asyncToGenerator(function* () {
+    return yield f();
+});
+const obj = _asyncToGenerator(function* () {
+    return { a: yield f() };
+});
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| async_to_generator(),
+    arrow_concise_body_exec,
+    "
+const inc = async x => x + 1;
+const double = async x => await inc(x) * 2;
+const pair = async x => ({ x, y: await inc(x) });
+
+return Promise.all([inc(1), double(1), pair(1)]).then(([a, b, c]) => {
+    expect(a).toBe(2);
+    expect(b).toBe(4);
+    expect(c).toEqual({ x: 1, y: 2 });
+});
+"
+);
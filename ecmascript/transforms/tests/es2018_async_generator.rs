@@ -0,0 +1,119 @@
+#![feature(test)]
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::compat::es2018::async_generator;
+use swc_ecma_visit::Fold;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::default()
+}
+
+fn tr() -> impl Fold {
+    async_generator()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    basic,
+    "async function* foo(a) {
+    yield await a;
+    yield* bar();
+}",
+    "function foo() {
+    return _wrapAsyncGenerator(function* (a) {
+        yield yield _awaitAsyncGenerator(a);
+        yield* _asyncGeneratorDelegate(_asyncIterator(bar()), _awaitAsyncGenerator);
+    }).apply(this, arguments);
+}"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    method,
+    "class Foo {
+    async *foo() {
+        yield this;
+    }
+}",
+    "class Foo {
+    foo() {
+        return _wrapAsyncGenerator(function* () {
+            yield this;
+        }).apply(this, arguments);
+    }
+}"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    async_fn_untouched,
+    "async function foo() {
+    await bar();
+}",
+    "async function foo() {
+    await bar();
+}"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    for_await_consumer,
+    "
+async function* gen() {
+    yield await Promise.resolve(1);
+    yield 2;
+    yield* (async function* () {
+        yield await 3;
+    })();
+}
+
+return (async () => {
+    const values = [];
+    for await (const v of gen()) {
+        values.push(v);
+    }
+    expect(values).toEqual([1, 2, 3]);
+})();
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    for_await_in_body,
+    "
+let closed = false;
+async function* inner() {
+    try {
+        yield 1;
+        yield 2;
+    } finally {
+        closed = true;
+    }
+}
+async function* outer() {
+    for await (const x of inner()) {
+        yield await (x * 10);
+        break;
+    }
+    yield 'done';
+}
+
+return (async () => {
+    const values = [];
+    const it = outer();
+    let result;
+    while (!(result = await it.next()).done) {
+        values.push(result.value);
+    }
+    expect(values).toEqual([10, 'done']);
+    expect(closed).toBe(true);
+})();
+"
+);
@@ -4,7 +4,7 @@ use swc_ecma_parser::Syntax;
 use swc_ecma_transforms::{
     compat::{
         es2015::{destructuring, spread},
-        es2018::object_rest_spread,
+        es2018::{object_rest_spread, object_rest_spread::Config, object_rest_spread_with_config},
     },
     modules::common_js::common_js,
     resolver,
@@ -126,6 +126,32 @@ var { a } = _ref, b = _objectWithoutProperties(_ref, ['a']);
 "#
 );
 
+test!(
+    syntax(),
+    |_| tr(),
+    rest_numeric_key,
+    r#"
+var { 1e3: a, [1e21]: b, ...c } = _ref;
+"#,
+    r#"
+var { 1e3: a, [1e21]: b } = _ref, c = _objectWithoutProperties(_ref, ['1000', '1e+21']);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    rest_numeric_key_exec,
+    r#"
+const obj = { 1e3: 'a', 1e21: 'b', 0.0000001: 'c', d: 'd' };
+const { 1e3: a, [1e21]: b, 1e-7: c, ...rest } = obj;
+expect(a).toBe('a');
+expect(b).toBe('b');
+expect(c).toBe('c');
+expect(rest).toEqual({ d: 'd' });
+"#
+);
+
 test_exec!(
     syntax(),
     |_| tr(),
@@ -2790,3 +2816,93 @@ const _ref2 = foo((_param)=>{
 
 "#
 );
+
+fn use_builtins() -> impl Fold {
+    object_rest_spread_with_config(Config { use_builtins: true })
+}
+
+test!(
+    syntax(),
+    |_| use_builtins(),
+    use_builtins_assign,
+    "
+const a = { ...b };
+const c = { ...b, d: 1, ...e };
+const f = { g, ...h };
+",
+    "
+const a = Object.assign({}, b);
+const c = Object.assign({}, b, {
+    d: 1
+}, e);
+const f = Object.assign({}, {
+    g
+}, h);
+"
+);
+
+test!(
+    syntax(),
+    |_| use_builtins(),
+    use_builtins_accessor,
+    "
+const a = { get b() { return 1; }, ...c };
+const d = { ...c, set e(v) {} };
+const f = { __proto__: g, ...c };
+",
+    "
+const a = _objectSpread({
+    get b() {
+        return 1;
+    }
+}, c);
+const d = _objectSpread({}, c, {
+    set e(v) {}
+});
+const f = _objectSpread({
+    __proto__: g
+}, c);
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| use_builtins(),
+    use_builtins_order_exec,
+    r#"
+const log = [];
+const a = {
+    get x() {
+        log.push('a.x');
+        return 1;
+    },
+    y: 1,
+};
+const b = { x: 2, z: 2 };
+
+const c = { x: 0, ...a, y: 3, ...b };
+expect(c).toEqual({ x: 2, y: 3, z: 2 });
+expect(log).toEqual(['a.x']);
+expect(Object.getOwnPropertyDescriptor(c, 'x').value).toBe(2);
+
+const d = { ...b, x: 4 };
+expect(d).toEqual({ x: 4, z: 2 });
+expect(d).not.toBe(b);
+expect({ ...null, ...undefined, ...'ab' }).toEqual({ 0: 'a', 1: 'b' });
+
+let setterCalled = false;
+const e = {
+    set x(v) {
+        setterCalled = true;
+    },
+    ...b,
+};
+expect(setterCalled).toBe(false);
+expect(Object.getOwnPropertyDescriptor(e, 'x').value).toBe(2);
+
+const f = { x: 1 };
+const g = { ...f };
+g.x = 2;
+expect(f.x).toBe(1);
+"#
+);
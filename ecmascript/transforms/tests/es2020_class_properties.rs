@@ -35,7 +35,7 @@ fn tr() -> impl Fold {
     chain!(
         resolver(),
         function_name(),
-        class_properties(),
+        class_properties(Default::default()),
         classes(),
         block_scoping(),
         reserved_words(false),
@@ -2798,13 +2798,13 @@ var Foo = function () {
   _createClass(Foo, [{
     key: 'test',
     value: function test() {
-      var old;
+      var _Foo, old, _Foo1;
 
-      _classStaticPrivateFieldSpecSet(Foo, Foo, _x, (old = +_classStaticPrivateFieldSpecGet(Foo, \
-     Foo, _x)) + 1), old;
+      _classStaticPrivateFieldSpecSet(_Foo = Foo, Foo, _x, (old = \
+     +_classStaticPrivateFieldSpecGet(_Foo, Foo, _x)) + 1), old;
 
-      _classStaticPrivateFieldSpecSet(Foo, Foo, _x, +_classStaticPrivateFieldSpecGet(Foo, Foo, _x) \
-     + 1);
+      _classStaticPrivateFieldSpecSet(_Foo1 = Foo, Foo, _x, \
+     +_classStaticPrivateFieldSpecGet(_Foo1, Foo, _x) + 1);
     }
   }]);
 
@@ -2819,7 +2819,7 @@ var _x = {
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties()),
+    |_| chain!(resolver(), class_properties(Default::default())),
     issue_308,
     "function bar(props) {}
 class Foo {
@@ -2847,7 +2847,7 @@ class Foo{
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), classes()),
+    |_| chain!(resolver(), class_properties(Default::default()), classes()),
     issue_342,
     "class Foo {
   constructor(bar) {
@@ -2872,7 +2872,11 @@ let Foo = function Foo(bar) {
 
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties(), block_scoping()),
+    |_| chain!(
+        resolver(),
+        class_properties(Default::default()),
+        block_scoping()
+    ),
     issue_443,
     "
 const MODE = 1;
@@ -2897,7 +2901,7 @@ _defineProperty(foo, 'MODE', MODE);"
 // public_regression_t7364
 test!(
     syntax(),
-    |_| chain!(class_properties(), async_to_generator()),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     public_regression_t7364,
     r#"
 class MyClass {
@@ -2971,7 +2975,7 @@ export { MyClass3 as default }
 // private_regression_t6719
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_regression_t6719,
     r#"
 function withContext(ComposedComponent) {
@@ -3057,7 +3061,7 @@ function withContext(ComposedComponent) {
 // private_reevaluated
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_reevaluated,
     r#"
 function classFactory() {
@@ -3121,7 +3125,7 @@ function classFactory() {
 // private_static
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static,
     r#"
 class Foo {
@@ -3167,7 +3171,11 @@ expect(Foo.test()).toBe("foo");
 // private_destructuring_object_pattern_1
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     private_destructuring_object_pattern_1,
     r#"
 class Foo {
@@ -3206,7 +3214,7 @@ var _client = new WeakMap();
 // private_static_inherited
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_inherited,
     r#"
 class Base {
@@ -3285,7 +3293,7 @@ class Sub2 extends Base {}
 // private_destructuring_object_pattern_1_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_object_pattern_1_exec,
     r#"
 class Foo {
@@ -3312,7 +3320,7 @@ expect(foo.z).toBe('bar');
 // private_static_undefined
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_undefined,
     r#"
 class Foo {
@@ -3351,7 +3359,11 @@ var _bar = {
 // private_destructuring_array_pattern
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern,
     r#"
 class Foo {
@@ -3385,7 +3397,7 @@ var _client = new WeakMap();
 // private_regression_t2983
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_regression_t2983,
     r#"
 call(class {
@@ -3422,7 +3434,11 @@ export { _class as default }
 // private_regression_t7364
 test!(
     syntax(),
-    |_| chain!(class_properties(), async_to_generator(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        async_to_generator(),
+        block_scoping()
+    ),
     private_regression_t7364,
     r#"
 class MyClass {
@@ -3508,7 +3524,11 @@ export { MyClass3 as default }
 // private_destructuring_array_pattern_1
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_1,
     r#"
 class Foo {
@@ -3550,7 +3570,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
         classes(),
     ),
     decorators_legacy_interop_strict,
@@ -3601,7 +3621,7 @@ let A = (_class = (_temp = function A() {
 // regression_8882_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     regression_8882_exec,
     r#"
 const classes = [];
@@ -3633,7 +3653,7 @@ for(let i=0; i<= 10; ++i) {
 //// regression_6154
 //test!(syntax(),|_| tr("{
 //  "presets": ["env"],
-//  "plugins": class_properties()
+//  "plugins": class_properties(Default::default())
 //}
 //"), regression_6154, r#"
 //class Test {
@@ -3739,7 +3759,7 @@ for(let i=0; i<= 10; ++i) {
 // private_static_export
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_export,
     r#"
 export class MyClass {
@@ -3770,7 +3790,7 @@ export { MyClass2 as default }
 // static_property_tdz_edgest_case
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes()),
+    |_| chain!(class_properties(Default::default()), classes()),
     static_property_tdz_edgest_case,
     r#"
 class A {
@@ -3797,7 +3817,7 @@ _defineProperty(A, _x, void 0);
 // regression_6153
 test!(
     syntax(),
-    |_| chain!(class_properties(), arrow()),
+    |_| chain!(class_properties(Default::default()), arrow()),
     regression_6153,
     r#"
 () => {
@@ -3898,7 +3918,7 @@ var qux = (function () {
 // regression_7371
 test!(
     syntax(),
-    |_| chain!(class_properties(), arrow()),
+    |_| chain!(class_properties(Default::default()), arrow()),
     regression_7371,
     r#"
 "use strict";
@@ -4108,7 +4128,11 @@ new ComputedField();
 // private_canonical
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     private_canonical,
     r#"
 class Point {
@@ -4196,7 +4220,7 @@ var _y = new WeakMap();
 // regression_8882
 test!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     regression_8882,
     r#"
 const classes = [];
@@ -4248,7 +4272,7 @@ for(let i = 0; i <= 10; ++i){
 // compile_to_class_constructor_collision_ignores_types
 test!(
     ts(),
-    |_| chain!(typescript::strip(), class_properties()),
+    |_| chain!(typescript::strip(), class_properties(Default::default())),
     compile_to_class_constructor_collision_ignores_types,
     r#"
 class C {
@@ -4274,7 +4298,11 @@ class C {
 // private_destructuring_array_pattern_3
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_3,
     r#"
 class Foo {
@@ -4307,7 +4335,7 @@ var _client = new WeakMap();
 // public_static_super_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     public_static_super_exec,
     r#"
 class A {
@@ -4332,7 +4360,11 @@ expect(getPropA()).toBe(1);
 // private_destructuring_array_pattern_2
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     private_destructuring_array_pattern_2,
     r#"
 class Foo {
@@ -4365,7 +4397,7 @@ var _client = new WeakMap();
 // private_non_block_arrow_func
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_non_block_arrow_func,
     r#"
 export default param =>
@@ -4405,7 +4437,7 @@ export default ((param)=>{
 // regression_8110
 test!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     regression_8110,
     r#"
 const field = Symbol('field');
@@ -4438,7 +4470,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
         classes()
     ),
     decorators_legacy_interop_local_define_property,
@@ -4495,7 +4527,7 @@ let A = (_class = (_temp = function A() {
 // public_computed_without_block_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     public_computed_without_block_exec,
     r#"
 const createClass = (k) => class { [k()] = 2 };
@@ -4510,7 +4542,7 @@ expect(instance.foo).toBe(2);
 test!(
     syntax(),
     |_| chain!(
-        class_properties(),
+        class_properties(Default::default()),
         exponentation(),
         classes(),
         block_scoping(),
@@ -4542,7 +4574,7 @@ var _bar = new WeakMap();
 // static_property_tdz_general
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes()),
+    |_| chain!(class_properties(Default::default()), classes()),
     static_property_tdz_general,
     r#"
 class C {
@@ -4567,7 +4599,7 @@ _defineProperty(C, _ref, 3);
 // public_native_classes
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     public_native_classes,
     r#"
 class Foo {
@@ -4619,7 +4651,7 @@ test!(
     // Seems useless, while being hard to implement.
     ignore,
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_infer_name,
     r#"
 var Foo = class {
@@ -4641,7 +4673,7 @@ var Foo = (_temp = _class = class Foo {}, _num = {
 // regression_7951
 test!(
     syntax(),
-    |_| chain!(resolver(), class_properties()),
+    |_| chain!(resolver(), class_properties(Default::default())),
     regression_7951,
     r#"
 export class Foo extends Bar {
@@ -4667,7 +4699,7 @@ _defineProperty(Foo, "foo", {});
 // private_native_classes
 test!(
     syntax(),
-    |_| chain!(class_properties(), block_scoping()),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_native_classes,
     r#"
 class Foo {
@@ -4715,7 +4747,11 @@ var _bar = new WeakMap();
 // public_computed_without_block
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     public_computed_without_block,
     r#"
 const createClass = (k) => class { [k()] = 2 };
@@ -4738,7 +4774,7 @@ var createClass = (k)=>{
 // private_destructuring_array_pattern_2_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_2_exec,
     r#"
 class Foo {
@@ -4763,7 +4799,11 @@ expect(foo.getClient()).toEqual(['bar', 'baz', 'quu']);
 // public_static_super
 test!(
     syntax(),
-    |_| chain!(class_properties(), classes(), block_scoping()),
+    |_| chain!(
+        class_properties(Default::default()),
+        classes(),
+        block_scoping()
+    ),
     public_static_super,
     r#"
 class A {
@@ -4811,7 +4851,7 @@ _defineProperty(B, "getPropA", () => _get(_getPrototypeOf(B), "prop", B));
 // private_destructuring_array_pattern_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_exec,
     r#"
 class Foo {
@@ -4835,7 +4875,7 @@ expect(foo.getClient()).toBe('bar');
 // private_destructuring_array_pattern_1_exec
 test_exec!(
     syntax(),
-    |_| class_properties(),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_1_exec,
     r#"
 class Foo {
@@ -4962,3 +5002,89 @@ class Foo {
 Foo.identifier = 5;
   "
 );
+
+test!(
+    syntax(),
+    |_| class_properties(class_properties::Config {
+        set_public_class_fields: true
+    }),
+    set_public_class_fields,
+    "
+class Foo {
+  bar = 1;
+  baz;
+  static qux = 2;
+}
+",
+    "
+class Foo {
+    constructor(){
+        this.bar = 1;
+        this.baz = void 0;
+    }
+}
+Foo.qux = 2;
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(
+        class_properties(class_properties::Config {
+            set_public_class_fields: true
+        }),
+        classes()
+    ),
+    set_public_class_fields_exec,
+    "
+class Base {
+  set foo(v) {
+    this.setterCalled = true;
+  }
+}
+
+class Foo extends Base {
+  foo = 1;
+}
+
+expect(new Foo().setterCalled).toBe(true);
+"
+);
+
+test_exec!(
+    syntax(),
+    |_| tr(),
+    private_compound_assignment_exec,
+    r#"
+class Foo {
+  #x = 6;
+
+  run() {
+    let calls = 0;
+    const get = () => (calls++, this);
+
+    expect(get().#x += 3).toBe(9);
+    expect(get().#x -= 3).toBe(6);
+    expect(get().#x *= 3).toBe(18);
+    expect(get().#x /= 3).toBe(6);
+    expect(get().#x %= 4).toBe(2);
+    expect(get().#x <<= 2).toBe(8);
+    expect(get().#x >>= 1).toBe(4);
+    expect(get().#x >>>= 1).toBe(2);
+    expect(get().#x |= 1).toBe(3);
+    expect(get().#x &= 2).toBe(2);
+    expect(get().#x ^= 7).toBe(5);
+    expect(get().#x **= 2).toBe(25);
+    expect(get().#x &&= 6).toBe(6);
+    expect(get().#x ||= 3).toBe(6);
+    expect(get().#x ??= 3).toBe(6);
+    expect(get().#x++).toBe(6);
+    expect(--get().#x).toBe(6);
+
+    expect(calls).toBe(17);
+  }
+}
+
+new Foo().run();
+"#
+);
@@ -1,7 +1,10 @@
 #![feature(test)]
 use swc_common::chain;
 use swc_ecma_parser::{Syntax, TsConfig};
-use swc_ecma_transforms::{compat::es2020::optional_chaining, typescript::strip};
+use swc_ecma_transforms::{
+    compat::es2020::{nullish_coalescing, optional_chaining},
+    typescript::strip,
+};
 use swc_ecma_visit::Fold;
 
 #[macro_use]
@@ -179,7 +182,7 @@ expect(test).toBe(true);
 
 test = delete obj?.b?.b;
 expect(obj.b).toBeUndefined();
-expect(test).toBeUndefined();
+expect(test).toBe(true);
 
 delete obj?.a;
 expect(obj.a).toBeUndefined();
@@ -385,10 +388,10 @@ const obj = {
     b: 0
   }
 };
-let test = obj === null || obj === void 0 ? void 0 : (ref = obj.a) === null || ref === void 0 ? void 0 : delete ref.b;
-test = obj === null || obj === void 0 ? void 0 : delete obj.a.b;
-test = obj === null || obj === void 0 ? void 0 : (ref1 = obj.b) === null || ref1 === void 0 ? void 0 : delete ref1.b;
-obj === null || obj === void 0 ? void 0 : delete obj.a;
+let test = obj === null || obj === void 0 ? true : (ref = obj.a) === null || ref === void 0 ? true : delete ref.b;
+test = obj === null || obj === void 0 ? true : delete obj.a.b;
+test = obj === null || obj === void 0 ? true : (ref1 = obj.b) === null || ref1 === void 0 ? true : delete ref1.b;
+obj === null || obj === void 0 ? true : delete obj.a;
 
 "#
 );
@@ -715,3 +718,88 @@ test!(
     const tmp = tt === null || tt === void 0 ? void 0 : tt.map((t) => t).join((v) => v);
     "
 );
+
+// `a?.b ?? c` must evaluate `a?.b` once, and use `c` if the chain
+// short-circuits.
+test_exec!(
+    syntax(),
+    |_| chain!(nullish_coalescing(Default::default()), tr(())),
+    nullish_coalescing_short_circuit,
+    r#"
+let a = null;
+expect(a?.b.c ?? "d").toBe("d");
+
+a = { b: { c: null } };
+expect(a?.b.c ?? "d").toBe("d");
+
+a = { b: null };
+expect(a?.b?.c ?? "d").toBe("d");
+expect(() => a?.b.c ?? "d").toThrow(TypeError);
+
+a = { b: { c: 0 } };
+expect(a?.b.c ?? "d").toBe(0);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(nullish_coalescing(Default::default()), tr(())),
+    nullish_coalescing_evaluates_chain_once,
+    r#"
+let count = 0;
+const a = {
+  get b() {
+    count++;
+    return { c: null };
+  },
+};
+const f = () => {
+  count++;
+  return a;
+};
+
+expect(f()?.b.c ?? "d").toBe("d");
+expect(count).toBe(2);
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| chain!(tr(()), nullish_coalescing(Default::default())),
+    optional_chaining_first,
+    r#"
+let a = null;
+expect(a?.b.c ?? "d").toBe("d");
+
+a = { b: { c: null } };
+expect(a?.b.c ?? "d").toBe("d");
+
+a = { b: { c: 0 } };
+expect(a?.b.c ?? "d").toBe(0);
+"#
+);
+
+// `delete` short-circuits to `true` without evaluating the rest of the chain.
+test_exec!(
+    syntax(),
+    |_| tr(()),
+    delete_short_circuit,
+    r#"
+let a = null;
+let evaluated = false;
+const key = () => {
+  evaluated = true;
+  return "c";
+};
+
+expect(delete a?.b[key()]).toBe(true);
+expect(evaluated).toBe(false);
+
+a = { b: { c: 1, d: 2 } };
+expect(delete a?.b[key()]).toBe(true);
+expect(evaluated).toBe(true);
+expect(a.b).toEqual({ d: 2 });
+expect(delete a?.b.d).toBe(true);
+expect(a.b).toEqual({});
+"#
+);
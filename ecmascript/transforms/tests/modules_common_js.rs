@@ -124,7 +124,7 @@ test!(
     |_| chain!(
         typescript::strip(),
         decorators(Default::default()),
-        class_properties(),
+        class_properties(Default::default()),
         simplifier(Default::default()),
         compat::es2018(),
         compat::es2017(),
@@ -1339,6 +1339,82 @@ function use() {
 "#
 );
 
+// lazy_dep_deferred_until_access
+test!(
+    syntax(),
+    |_| tr(Config {
+        lazy: Lazy::Bool(true),
+        ..Default::default()
+    }),
+    lazy_dep_deferred_until_access,
+    r#"
+import { foo } from "foo";
+
+export function bar() {
+  return foo;
+}
+"#,
+    r#"
+"use strict";
+
+Object.defineProperty(exports, "__esModule", {
+  value: true
+});
+exports.bar = bar;
+
+function _foo() {
+  const data = require("foo");
+
+  _foo = function () {
+    return data;
+  };
+
+  return data;
+}
+
+function bar() {
+  return _foo().foo;
+}
+"#
+);
+
+// lazy_whitelist_import_used_in_fn
+test!(
+    syntax(),
+    |_| tr(Config {
+        lazy: Lazy::List(vec!["white".into()]),
+        ..Default::default()
+    }),
+    lazy_whitelist_import_used_in_fn,
+    r#"
+import { foo } from "white";
+import { bar } from "black";
+
+function use() {
+  return foo + bar;
+}
+"#,
+    r#"
+"use strict";
+
+function _white() {
+  const data = require("white");
+
+  _white = function () {
+    return data;
+  };
+
+  return data;
+}
+
+var _black = require("black");
+
+function use() {
+  return _white().foo + _black.bar;
+}
+"#
+);
+
 // lazy_whitelist_reexport_default
 test!(
     syntax(),
@@ -4343,3 +4419,99 @@ export * from './pipes';
     });
     "#
 );
+
+test!(
+    Syntax::Es(EsConfig {
+        import_assertions: true,
+        ..Default::default()
+    }),
+    |_| tr(Default::default()),
+    import_assertions_removed,
+    "import json from './foo.json' assert { type: 'json' };
+json;",
+    "'use strict';
+var _fooJson = _interopRequireDefault(require('./foo.json'));
+_fooJson.default;"
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        batch_exports: true,
+        ..Default::default()
+    }),
+    batch_exports,
+    "
+export const a = 1;
+export const b = a + 1;
+",
+    "
+'use strict';
+const a = 1;
+const b = a + 1;
+module.exports = {
+    a: a,
+    b: b
+};
+Object.defineProperty(module.exports, '__esModule', {
+    value: true
+});
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        batch_exports: true,
+        ..Default::default()
+    }),
+    batch_exports_live_binding,
+    "
+export let count = 0;
+export function increment() {
+    count++;
+}
+",
+    "
+'use strict';
+Object.defineProperty(exports, '__esModule', {
+    value: true
+});
+exports.increment = increment;
+exports.count = void 0;
+let count = 0;
+exports.count = count;
+function increment() {
+    exports.count = count = +count + 1;
+}
+"
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    interop_default_shared,
+    r#"
+import foo from "foo";
+import foo2 from "foo";
+
+foo();
+foo.bar;
+
+function f() {
+  return [foo, foo2];
+}
+"#,
+    r#"
+"use strict";
+
+var _foo = _interopRequireDefault(require("foo"));
+
+_foo.default();
+_foo.default.bar;
+
+function f() {
+  return [_foo.default, _foo.default];
+}
+"#
+);
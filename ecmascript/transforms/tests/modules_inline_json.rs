@@ -0,0 +1,118 @@
+#![feature(test)]
+use swc_common::{chain, Mark};
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms::{
+    modules::{common_js::common_js, inline_json::inline_json},
+    resolver_with_mark,
+};
+use swc_ecma_visit::Fold;
+
+#[macro_use]
+mod common;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        import_assertions: true,
+        ..Default::default()
+    })
+}
+
+fn large_json() -> String {
+    format!("[{}]", vec!["1"; 1000].join(","))
+}
+
+fn load(src: &str) -> Option<String> {
+    match src {
+        "./data.json" => Some(r#"{ "b": [true, null, "c"], "a": 1 }"#.into()),
+        "./large.json" => Some(large_json()),
+        "./invalid.json" => Some("{ a: 1 }".into()),
+        "./proto.json" => Some(r#"{ "__proto__": { "a": 1 }, "b": { "c": [1] } }"#.into()),
+        _ => None,
+    }
+}
+
+fn tr() -> impl Fold {
+    let mark = Mark::fresh(Mark::root());
+
+    chain!(
+        resolver_with_mark(mark),
+        inline_json(load),
+        common_js(mark, Default::default())
+    )
+}
+
+test!(
+    syntax(),
+    |_| inline_json(load),
+    small,
+    "import data from './data.json' assert { type: 'json' };
+console.log(data.a);",
+    "const data = _deepFreeze({
+    'b': [true, null, 'c'],
+    'a': 1
+});
+console.log(data.a);"
+);
+
+test!(
+    syntax(),
+    |_| inline_json(load),
+    large,
+    "import data from './large.json' assert { type: 'json' };",
+    &format!("const data = _deepFreeze(JSON.parse('{}'));", large_json())
+);
+
+test!(
+    syntax(),
+    |_| inline_json(load),
+    proto,
+    "import data from './proto.json' assert { type: 'json' };",
+    "const data = _deepFreeze({
+    ['__proto__']: {
+        'a': 1
+    },
+    'b': {
+        'c': [1]
+    }
+});"
+);
+
+test_exec!(
+    syntax(),
+    |_| inline_json(load),
+    proto_exec,
+    "import data from './proto.json' assert { type: 'json' };
+expect(Object.getOwnPropertyDescriptor(data, '__proto__').value).toEqual({ a: 1 });
+expect(Object.getPrototypeOf(data)).toBe(Object.prototype);
+expect(Object.keys(data)).toEqual(['__proto__', 'b']);
+expect(Object.isFrozen(data)).toBe(true);
+expect(Object.isFrozen(data.b)).toBe(true);
+expect(Object.isFrozen(data.b.c)).toBe(true);"
+);
+
+test!(
+    syntax(),
+    |_| inline_json(load),
+    without_assertion,
+    "import data from './data.json';",
+    "import data from './data.json';"
+);
+
+test!(
+    syntax(),
+    |_| inline_json(load),
+    invalid_json,
+    "import data from './invalid.json' assert { type: 'json' };",
+    "import data from './invalid.json' assert { type: 'json' };"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    require_fallback,
+    "import data from './missing.json' assert { type: 'json' };
+console.log(data);",
+    "'use strict';
+var _missingJson = _interopRequireDefault(require('./missing.json'));
+console.log(_missingJson.default);"
+);
@@ -0,0 +1,115 @@
+#![feature(test)]
+use swc_common::{chain, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::{modules::wrap_in_function::wrap_in_function, resolver};
+use swc_ecma_visit::Fold;
+
+#[macro_use]
+mod common;
+
+fn ident(sym: &str) -> Expr {
+    Expr::Ident(Ident::new(sym.into(), DUMMY_SP))
+}
+
+fn tr() -> impl Fold {
+    chain!(
+        resolver(),
+        wrap_in_function(vec![
+            ("exports".into(), ident("exports")),
+            ("global".into(), ident("window")),
+        ])
+    )
+}
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    custom_global,
+    "
+var a = global.foo;
+exports.a = a;
+",
+    "
+(function(exports, global) {
+    var a = global.foo;
+    exports.a = a;
+})(exports, window);
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    hoisting,
+    "
+'use strict';
+foo();
+function foo() {
+    return bar;
+}
+var bar = 1;
+",
+    "
+(function(exports, global) {
+    'use strict';
+    foo();
+    function foo() {
+        return bar;
+    }
+    var bar = 1;
+})(exports, window);
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    this_expr,
+    "
+var self = this;
+function foo() {
+    return this;
+}
+",
+    "
+(function(exports, global) {
+    var self = this;
+    function foo() {
+        return this;
+    }
+}).call(this, exports, window);
+"
+);
+
+test!(
+    Syntax::default(),
+    |_| tr(),
+    module_decls,
+    "
+import foo from 'foo';
+foo(global);
+",
+    "
+import foo from 'foo';
+(function(exports, global) {
+    foo(global);
+})(exports, window);
+"
+);
+
+test_exec!(
+    Syntax::default(),
+    |_| chain!(
+        resolver(),
+        wrap_in_function(vec![("global".into(), ident("Math"))])
+    ),
+    exec,
+    "
+expect(global.max(1, 2)).toBe(2);
+expect(hoisted()).toBe(3);
+function hoisted() {
+    return global.max(1, 2) + 1;
+}
+"
+);
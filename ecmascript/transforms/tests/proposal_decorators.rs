@@ -30,7 +30,10 @@ fn syntax(decorators_before_export: bool) -> Syntax {
 }
 
 fn tr() -> impl Fold {
-    chain!(decorators(Default::default()), class_properties(),)
+    chain!(
+        decorators(Default::default()),
+        class_properties(Default::default()),
+    )
 }
 
 fn ts_transform() -> impl Fold {
@@ -40,13 +43,17 @@ fn ts_transform() -> impl Fold {
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     )
 }
 
 /// Folder for `transformation_*` tests
 fn transformation() -> impl Fold {
-    chain!(strip(), decorators(Default::default()), class_properties(),)
+    chain!(
+        strip(),
+        decorators(Default::default()),
+        class_properties(Default::default()),
+    )
 }
 
 // transformation_declaration
@@ -2053,7 +2060,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_constructors_return_new_constructor_exec,
     r#"
@@ -2214,7 +2221,7 @@ export { _class1 as default }
 //  "presets": ["env"],
 //  "plugins": [
 //    ["proposal-decorators", { "legacy": true }],
-//    [class_properties(), { "loose": true }]
+//    [class_properties(Default::default()), { "loose": true }]
 //  ]
 //}
 //"#),
@@ -2241,7 +2248,7 @@ export { _class1 as default }
 //  "presets": ["env"],
 //  "plugins": [
 //    ["proposal-decorators", { "legacy": true }],
-//    [class_properties(), { "loose": true }]
+//    [class_properties(Default::default()), { "loose": true }]
 //  ]
 //}
 //"#),
@@ -2376,7 +2383,7 @@ export { _class1 as default }
 //  "presets": ["env"],
 //  "plugins": [
 //    ["proposal-decorators", { "legacy": true }],
-//    [class_properties(), { "loose": true }]
+//    [class_properties(Default::default()), { "loose": true }]
 //  ]
 //}
 //"#),
@@ -2513,7 +2520,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_numeric_props_exec,
     r#"
@@ -2539,7 +2546,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_static_properties_mutate_descriptor_exec,
     r#"
@@ -2654,7 +2661,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_string_props_exec,
     r#"
@@ -2680,7 +2687,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_string_literal_properties_exec,
     r#"
@@ -2725,7 +2732,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_mutate_descriptor_exec,
     r#"
@@ -2858,7 +2865,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_numeric_props_exec,
     r#"
@@ -2915,7 +2922,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_return_descriptor_exec,
     r#"
@@ -3032,7 +3039,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_string_props_exec,
     r#"
@@ -3060,7 +3067,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_return_descriptor_exec,
     r#"
@@ -3173,7 +3180,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_string_props_exec,
     r#"
@@ -3199,7 +3206,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_regression_8041,
     r#"
@@ -3230,7 +3237,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_return_descriptor_exec,
     r#"
@@ -3365,7 +3372,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_ordering_reverse_order_exec,
     r#"
@@ -3409,7 +3416,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_numeric_props_exec,
     r#"
@@ -3436,7 +3443,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_static_properties_return_descriptor_exec,
     r#"
@@ -3556,7 +3563,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_export_default_exec,
     r#"
@@ -3586,7 +3593,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_ordering_reverse_order_exec,
     r#"
@@ -3633,7 +3640,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_mutate_descriptor_exec,
     r#"
@@ -3762,7 +3769,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_return_descriptor_exec,
     r#"
@@ -3894,7 +3901,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_return_descriptor_exec,
     r#"
@@ -4025,7 +4032,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_string_props_exec,
     r#"
@@ -4053,7 +4060,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_child_classes_properties_exec,
     r#"
@@ -4096,7 +4103,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_mutate_descriptor_exec,
     r#"
@@ -4362,7 +4369,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
         // classes(),
     ),
     issue_823_2,
@@ -4408,7 +4415,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
         classes(),
     ),
     issue_823_3,
@@ -4671,7 +4678,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(),
+        class_properties(Default::default()),
     ),
     issue_879_1,
     "export default class X {
@@ -0,0 +1,77 @@
+#![feature(test)]
+use common::Tester;
+use swc_ecma_utils::{directive_info, DirectiveInfo};
+
+#[macro_use]
+mod common;
+
+fn info(src: &str) -> DirectiveInfo {
+    let mut info = None;
+    Tester::run(|tester| {
+        let module = tester.parse_module("input.js", src)?;
+        info = Some(directive_info(&module));
+        Ok(())
+    });
+    info.unwrap()
+}
+
+#[test]
+fn use_client() {
+    let info = info(
+        "'use client';
+import { useState } from 'react';
+export default function Foo() {
+    return useState();
+}",
+    );
+
+    assert!(info.use_client);
+    assert!(!info.use_server);
+    assert!(info.server_fns.is_empty());
+}
+
+#[test]
+fn after_other_directives() {
+    assert!(info("'use strict'; \"use client\"; foo();").use_client);
+}
+
+#[test]
+fn not_a_directive() {
+    assert!(!info("foo(); 'use client';").use_client);
+    assert!(!info("'use\\x20client';").use_client);
+    assert!(!info("('use client');").use_client);
+}
+
+#[test]
+fn use_server() {
+    let info = info(
+        "'use server';
+export async function action() {}",
+    );
+
+    assert!(info.use_server);
+    assert!(info.server_fns.is_empty());
+}
+
+#[test]
+fn server_fns() {
+    let info = info(
+        "export default function Page() {
+    async function action() {
+        'use server';
+    }
+    const other = async () => {
+        'use server';
+        await action();
+    };
+    function notAction() {
+        foo();
+        'use server';
+    }
+}",
+    );
+
+    assert!(!info.use_client);
+    assert!(!info.use_server);
+    assert_eq!(info.server_fns.len(), 2);
+}
@@ -0,0 +1,79 @@
+#![feature(test)]
+use common::Tester;
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_ecma_transforms::resolver;
+use swc_ecma_utils::uses_globals;
+use swc_ecma_visit::FoldWith;
+
+#[macro_use]
+mod common;
+
+fn globals(src: &str, names: &[&str]) -> HashSet<JsWord> {
+    let mut found = None;
+    Tester::run(|tester| {
+        let module = tester
+            .parse_module("input.js", src)?
+            .fold_with(&mut resolver());
+        found = Some(uses_globals(&module, names));
+        Ok(())
+    });
+    found.unwrap()
+}
+
+fn set(names: &[&str]) -> HashSet<JsWord> {
+    names.iter().map(|&name| name.into()).collect()
+}
+
+#[test]
+fn promise() {
+    assert_eq!(
+        globals(
+            "new Promise(resolve); Symbol.iterator;",
+            &["Promise", "Symbol", "Map"]
+        ),
+        set(&["Promise", "Symbol"])
+    );
+}
+
+#[test]
+fn shadowed() {
+    assert_eq!(
+        globals(
+            "function foo() {
+    const Promise = bar();
+    return Promise.resolve();
+}",
+            &["Promise"]
+        ),
+        set(&[])
+    );
+}
+
+#[test]
+fn shadowed_in_other_scope() {
+    assert_eq!(
+        globals(
+            "function foo(Promise) { return Promise; }
+Promise.all([]);",
+            &["Promise"]
+        ),
+        set(&["Promise"])
+    );
+}
+
+#[test]
+fn property_keys() {
+    assert_eq!(
+        globals(
+            "obj.Promise; obj = { Symbol: 1, Map() {} }; class A { Set() {} }",
+            &["Promise", "Symbol", "Map", "Set"]
+        ),
+        set(&[])
+    );
+}
+
+#[test]
+fn shorthand() {
+    assert_eq!(globals("obj = { Map };", &["Map"]), set(&["Map"]));
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+use swc_atoms::JsWord;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitWith};
+
+/// Distinct atoms referenced by an ast node.
+///
+/// This is computed from the ast, not from the interner. Atoms are shared by
+/// the whole process, so atoms referenced only by other modules or dropped
+/// nodes are not included.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DistinctAtoms {
+    /// Number of distinct atoms.
+    pub count: usize,
+    /// Sum of the lengths of distinct atoms, in bytes.
+    pub bytes: usize,
+}
+
+/// Counts [DistinctAtoms] in identifiers, string literals, regular
+/// expressions and jsx texts of `node`.
+pub fn count_distinct_atoms<N>(node: &N) -> DistinctAtoms
+where
+    N: VisitWith<AtomCollector>,
+{
+    let mut v = AtomCollector::default();
+    node.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+    DistinctAtoms {
+        count: v.atoms.len(),
+        bytes: v.atoms.iter().map(|atom| atom.len()).sum(),
+    }
+}
+
+#[derive(Default)]
+pub struct AtomCollector {
+    atoms: HashSet<JsWord>,
+}
+
+impl AtomCollector {
+    fn add(&mut self, atom: &JsWord) {
+        if !self.atoms.contains(atom) {
+            self.atoms.insert(atom.clone());
+        }
+    }
+}
+
+impl Visit for AtomCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident, _: &dyn Node) {
+        self.add(&i.sym);
+    }
+
+    fn visit_jsx_text(&mut self, t: &JSXText, _: &dyn Node) {
+        self.add(&t.value);
+        self.add(&t.raw);
+    }
+
+    fn visit_regex(&mut self, r: &Regex, _: &dyn Node) {
+        self.add(&r.exp);
+        self.add(&r.flags);
+    }
+
+    fn visit_str(&mut self, s: &Str, _: &dyn Node) {
+        self.add(&s.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_distinct_atoms, DistinctAtoms};
+    use swc_common::FileName;
+    use swc_ecma_parser::{Parser, StringInput, Syntax};
+
+    #[test]
+    fn distinct_idents() {
+        testing::run_test(false, |cm, _| {
+            let fm = cm.new_source_file(
+                FileName::Anon,
+                "const foo = bar; foo(bar, baz); baz.foo = 'qux';".into(),
+            );
+            let module = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
+                .parse_module()
+                .map_err(|_| ())?;
+
+            assert_eq!(
+                count_distinct_atoms(&module),
+                DistinctAtoms {
+                    count: 4,
+                    bytes: 12,
+                }
+            );
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
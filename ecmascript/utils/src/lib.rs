@@ -1,4 +1,5 @@
 pub use self::{
+    atoms::{count_distinct_atoms, DistinctAtoms},
     factory::ExprFactory,
     ident::{id, Id},
     value::{
@@ -14,18 +15,20 @@ use crate::ident::IdentLike;
 use scoped_tls::scoped_thread_local;
 use std::{
     borrow::Cow,
+    collections::HashSet,
     f64::{INFINITY, NAN},
     num::FpCategory,
     ops::Add,
 };
 use swc_atoms::{js_word, JsWord};
-use swc_common::{errors::Handler, Mark, Span, Spanned, DUMMY_SP};
+use swc_common::{errors::Handler, Mark, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Node, Visit, VisitMut, VisitMutWith, VisitWith};
 use unicode_xid::UnicodeXID;
 
 #[macro_use]
 mod macros;
+pub mod atoms;
 pub mod constructor;
 mod factory;
 pub mod ident;
@@ -99,6 +102,170 @@ impl Visit for IdentFinder<'_> {
     }
 }
 
+/// Returns the names in `names` which are referenced as a global variable by
+/// `module`.
+///
+/// This can be used to decide which polyfills are required.
+///
+/// ```js
+/// new Promise(resolve);
+/// obj.Symbol;
+/// function foo(Map) {
+///     return Map;
+/// }
+/// ```
+///
+/// For the code above, `uses_globals(&module, &["Promise", "Symbol", "Map"])`
+/// returns `{"Promise"}`.
+///
+/// # When to call
+///
+/// This should be called after the resolver, as references to global
+/// variables are detected by their empty syntax context.
+pub fn uses_globals(module: &Module, names: &[&str]) -> HashSet<JsWord> {
+    let mut v = GlobalUsageFinder {
+        names,
+        found: Default::default(),
+    };
+    module.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+    v.found
+}
+
+struct GlobalUsageFinder<'a> {
+    names: &'a [&'a str],
+    found: HashSet<JsWord>,
+}
+
+impl GlobalUsageFinder<'_> {
+    fn check(&mut self, i: &Ident) {
+        if i.span.ctxt() == SyntaxContext::empty() && self.names.contains(&&*i.sym) {
+            self.found.insert(i.sym.clone());
+        }
+    }
+}
+
+impl Visit for GlobalUsageFinder<'_> {
+    noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr, _: &dyn Node) {
+        e.visit_children_with(self);
+
+        if let Expr::Ident(i) = e {
+            self.check(i);
+        }
+    }
+
+    fn visit_member_expr(&mut self, e: &MemberExpr, _: &dyn Node) {
+        e.obj.visit_with(e as _, self);
+        if e.computed {
+            e.prop.visit_with(e as _, self);
+        }
+    }
+
+    fn visit_prop(&mut self, p: &Prop, _: &dyn Node) {
+        p.visit_children_with(self);
+
+        if let Prop::Shorthand(i) = p {
+            self.check(i);
+        }
+    }
+}
+
+/// Directives used by React Server Components.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirectiveInfo {
+    /// The module starts with `"use client"`.
+    pub use_client: bool,
+    /// The module starts with `"use server"`, so all exported functions are
+    /// server functions.
+    pub use_server: bool,
+    /// Spans of functions whose body starts with `"use server"`.
+    pub server_fns: Vec<Span>,
+}
+
+/// Detects `"use client"` and `"use server"` directives in `module`.
+///
+/// ```js
+/// 'use client';
+///
+/// async function action() {
+///     'use server';
+/// }
+/// ```
+///
+/// For the code above, `use_client` is `true` and `server_fns` contains the
+/// span of `action`.
+///
+/// Only directives in a directive prologue are detected, so a string literal
+/// following another statement is not a directive.
+pub fn directive_info(module: &Module) -> DirectiveInfo {
+    let mut v = ServerFnFinder {
+        found: Default::default(),
+    };
+    module.visit_with(&Invalid { span: DUMMY_SP } as _, &mut v);
+
+    DirectiveInfo {
+        use_client: has_directive(&module.body, "use client"),
+        use_server: has_directive(&module.body, "use server"),
+        server_fns: v.found,
+    }
+}
+
+/// Returns the directives at the start of `stmts`.
+///
+/// Note that a directive with an escape sequence, like `'use\x20strict'`, has
+/// no special meaning.
+pub fn directives<T: StmtLike>(stmts: &[T]) -> impl Iterator<Item = &Str> {
+    stmts
+        .iter()
+        .map(|stmt| match stmt.as_stmt() {
+            Some(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                Expr::Lit(Lit::Str(s)) => Some(s),
+                _ => None,
+            },
+            _ => None,
+        })
+        .take_while(Option::is_some)
+        .filter_map(|s| s)
+}
+
+/// Returns `true` if `stmts` starts with the directive `value`.
+fn has_directive<T: StmtLike>(stmts: &[T], value: &str) -> bool {
+    directives(stmts).any(|s| !s.has_escape && &*s.value == value)
+}
+
+struct ServerFnFinder {
+    found: Vec<Span>,
+}
+
+impl ServerFnFinder {
+    fn check(&mut self, span: Span, body: Option<&BlockStmt>) {
+        if let Some(body) = body {
+            if has_directive(&body.stmts, "use server") {
+                self.found.push(span);
+            }
+        }
+    }
+}
+
+impl Visit for ServerFnFinder {
+    noop_visit_type!();
+
+    fn visit_function(&mut self, f: &Function, _: &dyn Node) {
+        f.visit_children_with(self);
+
+        self.check(f.span, f.body.as_ref());
+    }
+
+    fn visit_arrow_expr(&mut self, f: &ArrowExpr, _: &dyn Node) {
+        f.visit_children_with(self);
+
+        if let BlockStmtOrExpr::BlockStmt(body) = &f.body {
+            self.check(f.span, Some(body));
+        }
+    }
+}
+
 pub trait ModuleItemLike: StmtLike {
     fn try_into_module_decl(self) -> Result<ModuleDecl, Self> {
         Err(self)
@@ -880,6 +1047,11 @@ pub trait ExprExt {
 
             // TODO
             Expr::Class(..) => true,
+            // TODO
+            Expr::Record(..) => true,
+            Expr::Tuple(TupleLit { ref elems, .. }) => {
+                elems.iter().any(|e| e.expr.may_have_side_effects())
+            }
             Expr::Array(ArrayLit { ref elems, .. }) => elems
                 .iter()
                 .filter_map(|e| e.as_ref())
@@ -1347,6 +1519,75 @@ pub fn alias_if_required(expr: &Expr, default: &str) -> (Ident, bool) {
     (alias_ident_for(expr, default), true)
 }
 
+/// Memoizes the object and the computed property of `e`, which is the target
+/// of a compound assignment, so that they are evaluated only once even if `e`
+/// is both read and written.
+///
+/// Returns `(first, rest)`. `first` should be evaluated before `rest`, as it
+/// stores the object and the property into temporary variables, which are
+/// pushed to `vars` and read by `rest`.
+///
+/// e.g. `a[i++]` becomes `(_a = a)[_ref = i++]` and `_a[_ref]`, so
+/// `a[i++] **= 2` can be lowered to `(_a = a)[_ref = i++] = Math.pow(_a[_ref],
+/// 2)`.
+pub fn memoize_member(e: MemberExpr, vars: &mut Vec<VarDeclarator>) -> (MemberExpr, MemberExpr) {
+    let mut memoize = |e: Box<Expr>, default: &str| -> (Box<Expr>, Box<Expr>) {
+        let is_pure = match *e {
+            Expr::This(..) | Expr::Lit(Lit::Str(..)) | Expr::Lit(Lit::Num(..)) => true,
+            _ => false,
+        };
+        if is_pure {
+            return (e.clone(), e);
+        }
+
+        let alias = alias_ident_for(&e, default);
+        vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(alias.clone()),
+            init: None,
+            definite: false,
+        });
+
+        (
+            Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(alias.clone()))),
+                right: e,
+            })),
+            Box::new(Expr::Ident(alias)),
+        )
+    };
+
+    let (obj, obj_ref) = match e.obj {
+        ExprOrSuper::Expr(obj) => {
+            let (obj, obj_ref) = memoize(obj, "_obj");
+            (ExprOrSuper::Expr(obj), ExprOrSuper::Expr(obj_ref))
+        }
+        ExprOrSuper::Super(s) => (ExprOrSuper::Super(s.clone()), ExprOrSuper::Super(s)),
+    };
+    let (prop, prop_ref) = if e.computed {
+        memoize(e.prop, "_ref")
+    } else {
+        (e.prop.clone(), e.prop)
+    };
+
+    (
+        MemberExpr {
+            span: e.span,
+            obj,
+            prop,
+            computed: e.computed,
+        },
+        MemberExpr {
+            span: e.span,
+            obj: obj_ref,
+            prop: prop_ref,
+            computed: e.computed,
+        },
+    )
+}
+
 pub fn prop_name_to_expr(p: PropName) -> Expr {
     match p {
         PropName::Ident(i) => Expr::Ident(i),
@@ -1519,6 +1760,15 @@ impl IsDirective for Stmt {
     }
 }
 
+impl IsDirective for ModuleItem {
+    fn as_ref(&self) -> Option<&Stmt> {
+        match self {
+            ModuleItem::Stmt(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
 pub trait IdentExt {
     fn prefix(&self, prefix: &str) -> Ident;
 
@@ -1666,7 +1916,7 @@ where
             }
 
             // TODO
-            Expr::MetaProp(_) => v.push(Box::new(expr)),
+            Expr::MetaProp(_) | Expr::Record(_) => v.push(Box::new(expr)),
 
             Expr::Call(_) => v.push(Box::new(expr)),
             Expr::New(e) => {
@@ -1770,6 +2020,10 @@ where
                 });
             }
 
+            Expr::Tuple(TupleLit { elems, .. }) => {
+                elems.into_iter().for_each(|e| add_effects(v, e.expr));
+            }
+
             Expr::TaggedTpl { .. } => unimplemented!("add_effects for tagged template literal"),
             Expr::Tpl { .. } => unimplemented!("add_effects for template literal"),
             Expr::Class(ClassExpr { .. }) => unimplemented!("add_effects for class expression"),
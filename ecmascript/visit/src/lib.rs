@@ -577,6 +577,8 @@ define!({
         TsAs(TsAsExpr),
         PrivateName(PrivateName),
         OptChain(OptChainExpr),
+        Record(RecordLit),
+        Tuple(TupleLit),
         Invalid(Invalid),
     }
     pub struct ThisExpr {
@@ -590,6 +592,14 @@ define!({
         pub span: Span,
         pub props: Vec<PropOrSpread>,
     }
+    pub struct RecordLit {
+        pub span: Span,
+        pub props: Vec<PropOrSpread>,
+    }
+    pub struct TupleLit {
+        pub span: Span,
+        pub elems: Vec<ExprOrSpread>,
+    }
     pub enum PropOrSpread {
         Spread(SpreadElement),
         Prop(Box<Prop>),
@@ -927,6 +937,7 @@ define!({
         pub src: Str,
         pub type_only: bool,
         pub asserts: Option<ObjectLit>,
+        pub with: bool,
     }
     pub struct ExportAll {
         pub span: Span,
@@ -1008,6 +1019,7 @@ define!({
         InstanceOf,
         Exp,
         NullishCoalescing,
+        Pipeline,
     }
     pub enum AssignOp {
         Assign,
@@ -46,6 +46,7 @@ it("should work with amd and expternal helpers", () => {
     expect(out.map).toBeFalsy();
 
     expect(out.code).toContain(`define("a",`);
-    expect(out.code).toContain(`swcHelpers.classCallCheck(this, Foo);`);
-    expect(out.code).toContain(`swcHelpers.inherits(Bar, Foo);`);
+    expect(out.code).toContain(`"@swc/helpers"`);
+    expect(out.code).toContain(`.classCallCheck(this, Foo);`);
+    expect(out.code).toContain(`.inherits(Bar, Foo);`);
 });
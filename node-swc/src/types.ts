@@ -270,6 +270,39 @@ export interface JscConfig {
    * Defaults to `es3` (which enableds **all** pass).
    */
   target?: JscTarget;
+
+  /**
+   * Invariants which allow smaller output.
+   */
+  assumptions?: Assumptions;
+}
+
+export interface Assumptions {
+  /**
+   * Iterables passed to `for...of` and spread are arrays.
+   */
+  iterableIsArray?: boolean;
+  /**
+   * `document.all` is never used as an operand of `??`.
+   */
+  noDocumentAll?: boolean;
+  /**
+   * Public class fields can be initialized with assignments.
+   */
+  setPublicClassFields?: boolean;
+  /**
+   * Getters don't have side effects.
+   */
+  pureGetters?: boolean;
+  /**
+   * Arrays use the default iterator and have no holes.
+   */
+  arrayIteratorIsDefault?: boolean;
+
+  /**
+   * Every receiver of a method named `finally` is a promise.
+   */
+  finallyReceiverIsPromise?: boolean;
 }
 
 export type JscTarget =
@@ -279,7 +312,10 @@ export type JscTarget =
   | "es2016"
   | "es2017"
   | "es2018"
-  | "es2019";
+  | "es2019"
+  | "es2020"
+  | "es2021"
+  | "es2022";
 
 export type ParserConfig = TsParserConfig | EsParserConfig;
 export interface TsParserConfig {
@@ -296,6 +332,10 @@ export interface TsParserConfig {
    * Defaults to `false`
    */
   dynamicImport?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  importAssertions?: boolean;
 }
 
 export interface EsParserConfig {
@@ -358,6 +398,26 @@ export interface EsParserConfig {
    * Defaults to `false`
    */
   importMeta?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  topLevelAwait?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  importAssertions?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  pipeline?: boolean;
+  /**
+   * Required if `pipeline` is enabled.
+   */
+  pipelineProposal?: "minimal" | "fsharp";
+  /**
+   * Defaults to `false`
+   */
+  recordAndTuple?: boolean;
 }
 
 /**
@@ -385,6 +445,60 @@ export interface TransformConfig {
    * https://swc.rs/docs/configuring-swc.html#jsctransformdecoratormetadata
    */
   decoratorMetadata?: boolean;
+
+  /**
+   * Skip inherited properties in `for...in` loops by checking
+   * `Object.prototype.hasOwnProperty` first.
+   */
+  forInGuard?: boolean;
+
+  /**
+   * Sort the keys of object literals alphabetically. Objects with getters,
+   * setters, spreads, computed keys or values with side effects are not
+   * changed.
+   */
+  sortObjectKeys?: boolean;
+
+  /**
+   * Rewrite calls to built-in methods which don't exist in the target, like
+   * `[1, 2].at(-1)` or `Object.hasOwn(obj, key)`, into helpers.
+   */
+  polyfillBuiltins?: boolean;
+
+  /**
+   * Replace `Symbol.iterator` with a helper which falls back to the
+   * `"@@iterator"` key on engines without `Symbol`.
+   */
+  symbolIterator?: boolean;
+
+  /**
+   * Import helpers, the regenerator runtime and built-ins from a runtime
+   * package, like `@babel/plugin-transform-runtime`.
+   */
+  runtime?: TransformRuntimeConfig;
+}
+
+export interface TransformRuntimeConfig {
+  /**
+   * Package to import from. Defaults to `@babel/runtime-corejs2` if `corejs`
+   * is true, and to `@babel/runtime` otherwise.
+   */
+  moduleName?: string;
+
+  /**
+   * Import helpers from `<moduleName>/helpers`.
+   */
+  helpers?: boolean;
+
+  /**
+   * Import the regenerator runtime from `<moduleName>/regenerator`.
+   */
+  regenerator?: boolean;
+
+  /**
+   * Replace built-ins like `Promise` with imports from `<moduleName>/core-js`.
+   */
+  corejs?: boolean;
 }
 
 export interface ReactConfig {
@@ -442,6 +556,11 @@ export interface OptimizerConfig {
   globals?: GlobalPassOption;
   /// https://swc.rs/docs/configuring-swc.html#jsctransformoptimizerjsonify
   jsonify?: { minCost: number };
+  /**
+   * Annotates calls which initialize top-level variables with
+   * `#__PURE__` comments if the callee is in `allowlist`.
+   */
+  pureAnnotations?: { allowlist?: string[] };
 }
 
 /**
@@ -774,6 +893,8 @@ export type Expression =
   | TsAsExpression
   | PrivateName
   | OptionalChainingExpression
+  | RecordExpression
+  | TupleExpression
   | Invalid;
 
 interface ExpressionBase extends Node, HasSpan {}
@@ -802,6 +923,18 @@ export interface ObjectExpression extends ExpressionBase {
   properties: (Property | SpreadElement)[];
 }
 
+export interface RecordExpression extends ExpressionBase {
+  type: "RecordExpression";
+
+  properties: (Property | SpreadElement)[];
+}
+
+export interface TupleExpression extends ExpressionBase {
+  type: "TupleExpression";
+
+  elements: (Expression | SpreadElement)[];
+}
+
 export interface Argument {
   spread: Span;
   expression: Expression;
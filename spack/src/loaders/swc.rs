@@ -61,7 +61,7 @@ impl Load for SwcLoader {
                     let program =
                         program.fold_with(&mut inline_globals(env_map(), Default::default()));
                     let program = program.fold_with(&mut expr_simplifier());
-                    let program = program.fold_with(&mut dead_branch_remover());
+                    let program = program.fold_with(&mut dead_branch_remover(Default::default()));
 
                     program
                 })
@@ -83,6 +83,11 @@ impl Load for SwcLoader {
                                                 optimizer: None,
                                                 legacy_decorator: c.legacy_decorator,
                                                 decorator_metadata: c.decorator_metadata,
+                                                for_in_guard: c.for_in_guard,
+                                                sort_object_keys: c.sort_object_keys,
+                                                polyfill_builtins: c.polyfill_builtins,
+                                                symbol_iterator: c.symbol_iterator,
+                                                runtime: c.runtime.clone(),
                                                 hidden: Default::default(),
                                             })
                                         } else {
@@ -142,7 +147,7 @@ impl Load for SwcLoader {
                     let program =
                         program.fold_with(&mut inline_globals(env_map(), Default::default()));
                     let program = program.fold_with(&mut expr_simplifier());
-                    let program = program.fold_with(&mut dead_branch_remover());
+                    let program = program.fold_with(&mut dead_branch_remover(Default::default()));
 
                     let program = program.fold_with(&mut config.pass);
 
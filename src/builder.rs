@@ -1,12 +1,13 @@
-use crate::config::{GlobalPassOption, JscTarget, ModuleConfig};
+use crate::config::{Assumptions, GlobalPassOption, JscTarget, ModuleConfig};
 use either::Either;
 use std::{collections::HashMap, sync::Arc};
 use swc_atoms::JsWord;
 use swc_common::{chain, comments::Comments, errors::Handler, Mark, SourceMap};
 use swc_ecma_parser::Syntax;
 use swc_ecma_transforms::{
-    compat, const_modules, fixer, helpers, hygiene, modules, pass::Optional,
-    proposals::import_assertions, typescript,
+    compat, const_modules, fixer, helpers, hygiene, modules, optimization,
+    pass::{timed, Optional},
+    transform_runtime, typescript,
 };
 
 /// Builder is used to create a high performance `Compiler`.
@@ -18,9 +19,12 @@ pub struct PassBuilder<'a, 'b, P: swc_ecma_visit::Fold> {
     global_mark: Mark,
     target: JscTarget,
     loose: bool,
+    assumptions: Assumptions,
     hygiene: bool,
     fixer: bool,
     inject_helpers: bool,
+    symbol_iterator: bool,
+    runtime: Option<transform_runtime::Config>,
 }
 
 impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
@@ -38,10 +42,13 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             target: JscTarget::Es5,
             global_mark,
             loose,
+            assumptions: Default::default(),
             hygiene: true,
             env: None,
             fixer: true,
             inject_helpers: true,
+            symbol_iterator: false,
+            runtime: None,
         }
     }
 
@@ -56,11 +63,14 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             pass,
             target: self.target,
             loose: self.loose,
+            assumptions: self.assumptions,
             hygiene: self.hygiene,
             env: self.env,
             global_mark: self.global_mark,
             fixer: self.fixer,
             inject_helpers: self.inject_helpers,
+            symbol_iterator: self.symbol_iterator,
+            runtime: self.runtime,
         }
     }
 
@@ -97,11 +107,32 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
         self.then(pass)
     }
 
+    pub fn assumptions(mut self, assumptions: Assumptions) -> Self {
+        self.assumptions = assumptions;
+        self
+    }
+
     pub fn target(mut self, target: JscTarget) -> Self {
         self.target = target;
         self
     }
 
+    /// Replaces `Symbol.iterator` with a helper which works on engines without
+    /// `Symbol`.
+    ///
+    /// Note: this is disabled by default.
+    pub fn symbol_iterator(mut self, enable: bool) -> Self {
+        self.symbol_iterator = enable;
+        self
+    }
+
+    /// Imports helpers, the regenerator runtime and built-ins from a runtime
+    /// package like `@babel/runtime`.
+    pub fn transform_runtime(mut self, runtime: Option<transform_runtime::Config>) -> Self {
+        self.runtime = runtime;
+        self
+    }
+
     pub fn preset_env(mut self, env: Option<swc_ecma_preset_env::Config>) -> Self {
         self.env = env;
         self
@@ -135,19 +166,36 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             Some(ModuleConfig::Es6) | None => false,
         };
 
+        let iterable_is_array = self.loose || self.assumptions.iterable_is_array;
+
         // compat
         let compat_pass = if let Some(env) = self.env {
             Either::Left(chain!(
-                import_assertions(),
                 Optional::new(typescript::strip(), syntax.typescript()),
                 swc_ecma_preset_env::preset_env(self.global_mark, env)
             ))
         } else {
             Either::Right(chain!(
-                import_assertions(),
-                Optional::new(compat::es2020::es2020(), self.target < JscTarget::Es2020),
+                Optional::new(
+                    compat::es2020(compat::es2020::Config {
+                        nullish_coalescing: compat::es2020::nullish_coalescing::Config {
+                            no_document_all: self.assumptions.no_document_all
+                        },
+                        class_properties: compat::es2020::class_properties::Config {
+                            set_public_class_fields: self.assumptions.set_public_class_fields
+                        },
+                    }),
+                    self.target < JscTarget::Es2020
+                ),
                 Optional::new(typescript::strip(), syntax.typescript()),
-                Optional::new(compat::es2018(), self.target <= JscTarget::Es2018),
+                Optional::new(
+                    compat::es2018::es2018_with_config(compat::es2018::Config {
+                        promise_finally: compat::es2018::promise_finally::Config {
+                            assume_promise: self.assumptions.finally_receiver_is_promise
+                        },
+                    }),
+                    self.target <= JscTarget::Es2018
+                ),
                 Optional::new(compat::es2017(), self.target <= JscTarget::Es2017),
                 Optional::new(compat::es2016(), self.target <= JscTarget::Es2016),
                 Optional::new(
@@ -155,9 +203,12 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                         self.global_mark,
                         compat::es2015::Config {
                             for_of: compat::es2015::for_of::Config {
-                                assume_array: self.loose
+                                assume_array: iterable_is_array
+                            },
+                            spread: compat::es2015::spread::Config {
+                                loose: iterable_is_array,
+                                array_fast_path: self.assumptions.array_iterator_is_default,
                             },
-                            spread: compat::es2015::spread::Config { loose: self.loose },
                             destructuring: compat::es2015::destructuring::Config {
                                 loose: self.loose
                             },
@@ -174,17 +225,32 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
 
         chain!(
             self.pass,
-            compat_pass,
+            Optional::new(
+                optimization::cache_member_chains(),
+                self.assumptions.pure_getters
+            ),
+            timed("compat", compat_pass),
+            Optional::new(compat::symbol_iterator(), self.symbol_iterator),
             compat::reserved_words::reserved_words(),
             // module / helper
             Optional::new(
                 modules::import_analysis::import_analyzer(),
                 need_interop_analysis
             ),
-            Optional::new(helpers::inject_helpers(), self.inject_helpers),
-            ModuleConfig::build(self.cm.clone(), self.global_mark, module),
-            Optional::new(hygiene(), self.hygiene),
-            Optional::new(fixer(comments), self.fixer),
+            Optional::new(
+                timed("inject-helpers", helpers::inject_helpers()),
+                self.inject_helpers
+            ),
+            Optional::new(
+                transform_runtime::transform_runtime(self.runtime.clone().unwrap_or_default()),
+                self.runtime.is_some()
+            ),
+            timed(
+                "modules",
+                ModuleConfig::build(self.cm.clone(), self.global_mark, module)
+            ),
+            Optional::new(timed("hygiene", hygiene()), self.hygiene),
+            Optional::new(timed("fixer", fixer(comments)), self.fixer),
         )
     }
 }
@@ -77,4 +77,8 @@ where
     fn target(&self) -> JscTarget {
         self.target
     }
+
+    fn column(&self) -> Option<usize> {
+        self.inner.column()
+    }
 }
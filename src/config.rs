@@ -20,12 +20,15 @@ use swc_ecma_ext_transforms::jest;
 pub use swc_ecma_parser::JscTarget;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms::{
-    compat::es2020::typescript_class_properties,
-    const_modules, modules,
-    optimization::{inline_globals, json_parse, simplifier},
-    pass::{noop, Optional},
-    proposals::{decorators, export_default_from},
-    react, resolver_with_mark, typescript,
+    compat::{es2020::typescript_class_properties, polyfill_builtins},
+    const_modules, for_in_guard, modules,
+    optimization::{
+        annotate_pure_calls, inline_globals, json_parse, simplifier, simplify, sort_object_keys,
+        CommentRelocator,
+    },
+    pass::{noop, timed, Optional},
+    proposals::{decorators, export_default_from, pipeline_operator, record_and_tuple},
+    react, resolver_with_mark, transform_runtime, typescript,
 };
 use swc_ecma_visit::Fold;
 
@@ -192,6 +195,7 @@ impl Options {
             external_helpers,
             target,
             loose,
+            assumptions,
         } = config.jsc;
 
         let syntax = syntax.unwrap_or_default();
@@ -219,6 +223,16 @@ impl Options {
             }
         };
 
+        let pure_annotations_pass = match (
+            optimizer.as_ref().and_then(|v| v.pure_annotations.clone()),
+            comments,
+        ) {
+            (Some(cfg), Some(comments)) => {
+                Either::Left(annotate_pure_calls(comments, cfg.allowlist))
+            }
+            _ => Either::Right(noop()),
+        };
+
         let optimization = {
             let pass =
                 if let Some(opts) = optimizer.map(|o| o.globals.unwrap_or_else(Default::default)) {
@@ -237,7 +251,7 @@ impl Options {
         let pass = chain!(
             // handle jsx
             Optional::new(
-                react::react(cm.clone(), comments, transform.react),
+                timed("react", react::react(cm.clone(), comments, transform.react)),
                 syntax.jsx()
             ),
             // Decorators may use type information
@@ -249,21 +263,39 @@ impl Options {
                 syntax.decorators()
             ),
             Optional::new(typescript_class_properties(), syntax.typescript()),
-            Optional::new(typescript::strip(), syntax.typescript()),
-            resolver_with_mark(root_mark),
+            Optional::new(
+                timed("typescript", typescript::strip()),
+                syntax.typescript()
+            ),
+            timed("resolver", resolver_with_mark(root_mark)),
             const_modules,
             optimization,
+            Optional::new(for_in_guard(), transform.for_in_guard),
+            Optional::new(sort_object_keys(), transform.sort_object_keys),
+            Optional::new(polyfill_builtins(target), transform.polyfill_builtins),
             Optional::new(export_default_from(), syntax.export_default_from()),
-            Optional::new(simplifier(Default::default()), enable_optimizer),
-            json_parse_pass
+            Optional::new(pipeline_operator(), syntax.pipeline()),
+            Optional::new(record_and_tuple(), syntax.record_and_tuple()),
+            Optional::new(
+                simplifier(simplify::Config {
+                    comments: CommentRelocator::new(comments),
+                    ..Default::default()
+                }),
+                enable_optimizer
+            ),
+            json_parse_pass,
+            pure_annotations_pass
         );
 
         let pass = PassBuilder::new(&cm, &handler, loose, root_mark, pass)
+            .assumptions(assumptions)
             .target(target)
             .skip_helper_injection(self.skip_helper_injection)
             .hygiene(!self.disable_hygiene)
             .fixer(!self.disable_fixer)
             .preset_env(config.env)
+            .symbol_iterator(transform.symbol_iterator)
+            .transform_runtime(transform.runtime)
             .finalize(syntax, config.module, comments);
 
         let pass = chain!(pass, Optional::new(jest::jest(), transform.hidden.jest));
@@ -348,6 +380,7 @@ impl Default for Rc {
                     external_helpers: false,
                     target: Default::default(),
                     loose: false,
+                    assumptions: Default::default(),
                 },
                 module: None,
                 minify: None,
@@ -365,6 +398,7 @@ impl Default for Rc {
                     external_helpers: false,
                     target: Default::default(),
                     loose: false,
+                    assumptions: Default::default(),
                 },
                 module: None,
                 minify: None,
@@ -382,6 +416,7 @@ impl Default for Rc {
                     external_helpers: false,
                     target: Default::default(),
                     loose: false,
+                    assumptions: Default::default(),
                 },
                 module: None,
                 minify: None,
@@ -540,6 +575,60 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub loose: bool,
+
+    #[serde(default)]
+    pub assumptions: Assumptions,
+}
+
+/// Invariants guaranteed by the user, which allow lowering passes to emit
+/// smaller and faster code.
+///
+/// Each assumption can be enabled independently. Enabling `loose` implies
+/// `iterableIsArray`.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Assumptions {
+    /// Iterables passed to `for...of` and spread are arrays.
+    #[serde(default)]
+    pub iterable_is_array: bool,
+
+    /// `document.all` is never used as an operand of `??`.
+    #[serde(default)]
+    pub no_document_all: bool,
+
+    /// Public class fields can be initialized with assignments, as there's
+    /// no setter on the prototype chain.
+    #[serde(default)]
+    pub set_public_class_fields: bool,
+
+    /// Getters don't have side effects, so reading a property multiple times
+    /// is fine.
+    #[serde(default)]
+    pub pure_getters: bool,
+
+    /// Arrays are iterated by the default array iterator and have no holes,
+    /// so spreading an array can copy it without the iterator protocol.
+    #[serde(default)]
+    pub array_iterator_is_default: bool,
+
+    /// Every receiver of a method named `finally` is a promise.
+    #[serde(default)]
+    pub finally_receiver_is_promise: bool,
+}
+
+impl Assumptions {
+    /// Returns true if an assumption which configures the compat passes is
+    /// enabled.
+    ///
+    /// `env` builds the compat passes itself, so only `pureGetters` can be
+    /// used with it.
+    pub(crate) fn affects_compat(&self) -> bool {
+        self.iterable_is_array
+            || self.no_document_all
+            || self.set_public_class_fields
+            || self.array_iterator_is_default
+            || self.finally_receiver_is_promise
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -591,6 +680,29 @@ pub struct TransformConfig {
     #[serde(default)]
     pub decorator_metadata: bool,
 
+    /// Skip inherited properties in `for...in` loops.
+    #[serde(default)]
+    pub for_in_guard: bool,
+
+    /// Sort the keys of object literals alphabetically where it does not
+    /// change the behavior.
+    #[serde(default)]
+    pub sort_object_keys: bool,
+
+    /// Rewrite calls to built-in methods which are newer than the target.
+    #[serde(default)]
+    pub polyfill_builtins: bool,
+
+    /// Replace `Symbol.iterator` with a helper which falls back to
+    /// `"@@iterator"` if `Symbol` is not available.
+    #[serde(default)]
+    pub symbol_iterator: bool,
+
+    /// Import helpers, the regenerator runtime and built-ins from a runtime
+    /// package, like `@babel/plugin-transform-runtime`.
+    #[serde(default)]
+    pub runtime: Option<transform_runtime::Config>,
+
     #[serde(default)]
     pub hidden: HiddenTransformConfig,
 }
@@ -617,6 +729,20 @@ pub struct OptimizerConfig {
 
     #[serde(default)]
     pub jsonify: Option<JsonifyOption>,
+
+    /// Annotates calls which initialize top-level variables with
+    /// `/*#__PURE__*/`.
+    #[serde(default)]
+    pub pure_annotations: Option<PureAnnotationsOption>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PureAnnotationsOption {
+    /// Functions which don't have side effects, like `createContext` or
+    /// `React.createContext`.
+    #[serde(default)]
+    pub allowlist: Vec<String>,
 }
 
 #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
@@ -769,6 +895,21 @@ impl Merge for JscConfig {
         self.transform.merge(&from.transform);
         self.target.merge(&from.target);
         self.external_helpers.merge(&from.external_helpers);
+        self.assumptions.merge(&from.assumptions);
+    }
+}
+
+impl Merge for Assumptions {
+    fn merge(&mut self, from: &Self) {
+        self.iterable_is_array.merge(&from.iterable_is_array);
+        self.no_document_all.merge(&from.no_document_all);
+        self.set_public_class_fields
+            .merge(&from.set_public_class_fields);
+        self.pure_getters.merge(&from.pure_getters);
+        self.array_iterator_is_default
+            .merge(&from.array_iterator_is_default);
+        self.finally_receiver_is_promise
+            .merge(&from.finally_receiver_is_promise);
     }
 }
 
@@ -4,34 +4,42 @@ pub use sourcemap;
 
 pub use crate::builder::PassBuilder;
 use crate::config::{
-    BuiltConfig, Config, ConfigFile, InputSourceMap, JscTarget, Merge, Options, Rc, RootMode,
-    SourceMapsConfig,
+    BuiltConfig, Config, ConfigFile, InputSourceMap, JscConfig, JscTarget, Merge, ModuleConfig,
+    Options, Rc, RootMode, SourceMapsConfig,
 };
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
 use serde::Serialize;
 use serde_json::error::Category;
 use std::{
+    fmt::{self, Display, Formatter},
     fs::{read_to_string, File},
+    io::{self, Write},
+    mem::replace,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, Mutex, RwLock},
 };
 use swc_common::{
     chain,
     comments::{Comment, Comments},
-    errors::Handler,
+    errors::{
+        self, Diagnostic, DiagnosticBuilder, EmitterWriter, Handler, HandlerFlags, SourceMapperDyn,
+    },
     input::StringInput,
-    BytePos, FileName, Globals, SourceFile, SourceMap, Spanned, GLOBALS,
+    BytePos, FileName, FilePathMapping, Globals, SourceFile, SourceMap, Span, Spanned, DUMMY_SP,
+    GLOBALS,
 };
-use swc_ecma_ast::Program;
+use swc_ecma_ast::{Module, Program};
 use swc_ecma_codegen::{self, Emitter, Node};
 use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
 use swc_ecma_transforms::{
+    fixer,
     helpers::{self, Helpers},
+    hygiene,
     pass::noop,
     util,
 };
-use swc_ecma_visit::FoldWith;
+use swc_ecma_visit::{Fold, FoldWith};
 
 mod builder;
 mod codegen;
@@ -55,6 +63,14 @@ pub struct TransformOutput {
     pub map: Option<String>,
 }
 
+/// Replacement of a source range, returned by [Compiler::transform_range].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeEdit {
+    /// The range of the original source to replace.
+    pub span: Span,
+    pub code: String,
+}
+
 /// These are **low-level** apis.
 impl Compiler {
     pub fn globals(&self) -> &Globals {
@@ -187,6 +203,10 @@ impl Compiler {
         })
     }
 
+    /// Emits the code for `node`.
+    ///
+    /// If `minify` is true, comments stored in [Compiler::comments] are removed
+    /// except license comments (`/*! ... */`) and comments read by bundlers.
     pub fn print<T>(
         &self,
         node: &T,
@@ -199,14 +219,27 @@ impl Compiler {
         T: Node,
     {
         self.run(|| {
+            if minify {
+                let preserve_excl = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
+                    vc.retain(|c: &Comment| c.text.starts_with("!") || is_magic_comment(c));
+                    !vc.is_empty()
+                };
+                self.comments.leading.retain(preserve_excl);
+                self.comments.trailing.retain(preserve_excl);
+            }
+
             let mut src_map_buf = vec![];
 
             let src = {
                 let mut buf = vec![];
                 {
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify },
-                        comments: if minify { None } else { Some(&self.comments) },
+                        cfg: swc_ecma_codegen::Config {
+                            minify,
+                            ..Default::default()
+                        },
+                        comments: Some(&self.comments),
+                        ident_hook: None,
                         cm: self.cm.clone(),
                         wr: Box::new(self::codegen::WriterWapper {
                             target,
@@ -357,7 +390,14 @@ impl Compiler {
         name: &FileName,
     ) -> Result<BuiltConfig<impl 'a + swc_ecma_visit::Fold>, Error> {
         self.run(|| -> Result<_, Error> {
-            let config = self.read_config(opts, name)?;
+            let mut config = self.read_config(opts, name)?;
+            if let Some(ref c) = opts.config {
+                config.merge(c);
+            }
+            if config.env.is_some() && config.jsc.assumptions.affects_compat() {
+                bail!("`jsc.assumptions` can't be used with `env`, except `pureGetters`")
+            }
+
             let built = opts.build(
                 &self.cm,
                 &self.handler,
@@ -393,6 +433,104 @@ impl Compiler {
         })
     }
 
+    /// Applies `pass` only to the top-level items of `module` which overlap
+    /// `range`, and emits the new code for those items.
+    ///
+    /// This is intended for partial-document transforms like quick fixes of
+    /// editors. `module` should be processed by the resolver beforehand, as
+    /// hygiene and fixer are applied to the whole module so that identifiers
+    /// created by `pass` don't conflict with bindings outside of `range`.
+    ///
+    /// Helpers used by `pass` are injected before the transformed items, or
+    /// imported from `@swc/helpers` if `external_helpers` is true.
+    ///
+    /// If hygiene renames an identifier outside of `range`, the returned edit
+    /// covers the whole module. Returns `None` if no item overlaps `range`.
+    pub fn transform_range(
+        &self,
+        module: &Module,
+        range: Span,
+        external_helpers: bool,
+        mut pass: impl Fold,
+    ) -> Result<Option<RangeEdit>, Error> {
+        let overlaps = |span: Span| span.lo < range.hi && range.lo < span.hi;
+        let start = match module.body.iter().position(|item| overlaps(item.span())) {
+            Some(start) => start,
+            None => return Ok(None),
+        };
+        let end = module
+            .body
+            .iter()
+            .rposition(|item| overlaps(item.span()))
+            .unwrap()
+            + 1;
+        let span = module.body[start]
+            .span()
+            .with_hi(module.body[end - 1].span().hi);
+
+        let (transformed, len) = self.run_transform(external_helpers, || {
+            // Helpers are injected into the range, as function declarations and
+            // imports are hoisted.
+            let items = Module {
+                span,
+                body: module.body[start..end].to_vec(),
+                shebang: None,
+            }
+            .fold_with(&mut pass)
+            .fold_with(&mut helpers::inject_helpers())
+            .body;
+            let len = items.len();
+
+            let mut body = module.body[..start].to_vec();
+            body.extend(items);
+            body.extend_from_slice(&module.body[end..]);
+
+            let transformed = Module {
+                body,
+                ..module.clone()
+            }
+            .fold_with(&mut hygiene())
+            .fold_with(&mut fixer(Some(&self.comments)));
+
+            (transformed, len)
+        });
+
+        if transformed.body[..start] != module.body[..start]
+            || transformed.body[start + len..] != module.body[end..]
+        {
+            let code = self
+                .print(
+                    &transformed,
+                    JscTarget::default(),
+                    SourceMapsConfig::Bool(false),
+                    None,
+                    false,
+                )?
+                .code;
+
+            return Ok(Some(RangeEdit {
+                span: module.span,
+                code,
+            }));
+        }
+
+        let code = self
+            .print(
+                &Module {
+                    span,
+                    body: transformed.body[start..start + len].to_vec(),
+                    shebang: None,
+                },
+                JscTarget::default(),
+                SourceMapsConfig::Bool(false),
+                None,
+                false,
+            )?
+            .code;
+
+        Ok(Some(RangeEdit { span, code }))
+    }
+
     /// `custom_after_pass` is applied after swc transforms are applied.
     pub fn process_js_with_custom_pass<P>(
         &self,
@@ -460,14 +598,6 @@ impl Compiler {
         config: BuiltConfig<impl swc_ecma_visit::Fold>,
     ) -> Result<TransformOutput, Error> {
         self.run(|| {
-            if config.minify {
-                let preserve_excl = |_: &BytePos, vc: &mut Vec<Comment>| -> bool {
-                    vc.retain(|c: &Comment| c.text.starts_with("!"));
-                    !vc.is_empty()
-                };
-                self.comments.leading.retain(preserve_excl);
-                self.comments.trailing.retain(preserve_excl);
-            }
             let mut pass = config.pass;
             let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                 util::HANDLER.set(&self.handler, || {
@@ -487,6 +617,194 @@ impl Compiler {
     }
 }
 
+/// Options for [transform].
+#[derive(Debug, Clone, Default)]
+pub struct TransformOptions {
+    pub syntax: Syntax,
+    pub target: JscTarget,
+    /// Use `None` to emit import statements.
+    pub module: Option<ModuleConfig>,
+    pub minify: bool,
+    pub source_maps: bool,
+}
+
+impl TransformOptions {
+    fn to_options(&self) -> Options {
+        Options {
+            config: Some(Config {
+                jsc: JscConfig {
+                    syntax: Some(self.syntax),
+                    target: self.target,
+                    ..Default::default()
+                },
+                module: self.module.clone(),
+                minify: Some(self.minify),
+                ..Default::default()
+            }),
+            swcrc: false,
+            source_maps: Some(SourceMapsConfig::Bool(self.source_maps)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parses `src`, applies passes configured by `options` (including hygiene
+/// and fixer), and emits the code.
+///
+/// Diagnostics emitted while processing `src` are included in the returned
+/// error.
+pub fn transform(src: &str, options: TransformOptions) -> Result<TransformOutput, Error> {
+    transform_file(FileName::Anon, src, &options)
+}
+
+/// Applies [transform] to `files` in parallel, and returns the results in the
+/// order of `files`.
+///
+/// Each file is processed with its own [SourceMap] and [Handler], so the
+/// error of a file only contains diagnostics emitted for the file.
+#[cfg(feature = "concurrent")]
+pub fn transform_all(
+    files: Vec<(FileName, String)>,
+    options: &TransformOptions,
+) -> Vec<Result<TransformOutput, Error>> {
+    use rayon::prelude::*;
+
+    files
+        .into_par_iter()
+        .map(|(name, src)| transform_file(name, &src, options))
+        .collect()
+}
+
+fn transform_file(
+    name: FileName,
+    src: &str,
+    options: &TransformOptions,
+) -> Result<TransformOutput, Error> {
+    let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+    let errors = BufferedError::default();
+    let handler = {
+        let source_map: Arc<SourceMapperDyn> = cm.clone();
+        Handler::with_emitter_and_flags(
+            Box::new(EmitterWriter::new(
+                Box::new(errors.clone()),
+                Some(source_map),
+                false,
+                false,
+            )),
+            HandlerFlags {
+                can_emit_warnings: true,
+                ..Default::default()
+            },
+        )
+    };
+    let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+    let fm = cm.new_source_file(name, src.into());
+    let output = c
+        .process_js_file(fm, &options.to_options())
+        .with_context(|| errors.to_string())?;
+
+    if c.handler.has_errors() {
+        bail!("failed to transform:\n{}", errors)
+    }
+
+    Ok(output)
+}
+
+/// Returns true if `c` is a comment read by bundlers, like
+/// `/* webpackChunkName: "foo" */` in `import()` or `/*#__PURE__*/`, which is
+/// kept even if the output is minified.
+fn is_magic_comment(c: &Comment) -> bool {
+    let text = c.text.trim_start();
+    text.starts_with("webpack")
+        || text.starts_with("@vite-ignore")
+        || text.starts_with("#__PURE__")
+        || text.starts_with("@__PURE__")
+}
+
+/// Diagnostics emitted by the passes applied by [apply_passes].
+#[derive(Debug, Default)]
+pub struct PassResult {
+    /// Diagnostics in the order they were emitted.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl PassResult {
+    /// Returns true if any of the diagnostics is an error.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.is_error())
+    }
+}
+
+/// Applies `pass` to an already parsed `module` in place, and returns the
+/// diagnostics emitted by it.
+///
+/// The diagnostics are also emitted to `handler`. Unlike [Compiler::transform],
+/// hygiene and fixer are not applied unless they are a part of `pass`, and
+/// helpers are not injected unless `pass` contains `inject_helpers`.
+///
+/// This should be called in the [Globals] used to parse `module`, like
+/// [Compiler::run].
+pub fn apply_passes(module: &mut Module, mut pass: impl Fold, handler: &Handler) -> PassResult {
+    let collector = DiagnosticCollector::default();
+    let collecting_handler = Handler::with_emitter_and_flags(
+        Box::new(collector.clone()),
+        HandlerFlags {
+            can_emit_warnings: true,
+            ..Default::default()
+        },
+    );
+
+    let m = replace(
+        module,
+        Module {
+            span: DUMMY_SP,
+            body: vec![],
+            shebang: None,
+        },
+    );
+    *module = helpers::HELPERS.set(&Helpers::new(false), || {
+        util::HANDLER.set(&collecting_handler, || m.fold_with(&mut pass))
+    });
+
+    let diagnostics = collector.0.lock().unwrap().drain(..).collect::<Vec<_>>();
+    for d in &diagnostics {
+        DiagnosticBuilder::new_diagnostic(handler, d.clone()).emit();
+    }
+
+    PassResult { diagnostics }
+}
+
+/// Stores diagnostics emitted by a [Handler].
+#[derive(Clone, Default)]
+struct DiagnosticCollector(Arc<Mutex<Vec<Diagnostic>>>);
+
+impl errors::Emitter for DiagnosticCollector {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        self.0.lock().unwrap().push((**db).clone());
+    }
+}
+
+/// Buffers diagnostics emitted by a [Handler].
+#[derive(Clone, Default)]
+struct BufferedError(Arc<RwLock<Vec<u8>>>);
+
+impl Write for BufferedError {
+    fn write(&mut self, d: &[u8]) -> io::Result<usize> {
+        self.0.write().unwrap().write(d)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Display for BufferedError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&String::from_utf8_lossy(&self.0.read().unwrap()), f)
+    }
+}
+
 fn load_swcrc(path: &Path) -> Result<Rc, Error> {
     fn convert_json_err(e: serde_json::Error) -> Error {
         let line = e.line();
@@ -573,4 +891,18 @@ impl Comments for SwcComments {
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
         self.trailing.remove(&pos).map(|v| v.1)
     }
+
+    fn retain_leading(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+        self.leading.retain(|&pos, comments| {
+            comments.retain(|c| f(pos, c));
+            !comments.is_empty()
+        });
+    }
+
+    fn retain_trailing(&self, f: &mut dyn FnMut(BytePos, &Comment) -> bool) {
+        self.trailing.retain(|&pos, comments| {
+            comments.retain(|c| f(pos, c));
+            !comments.is_empty()
+        });
+    }
 }
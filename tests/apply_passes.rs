@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use swc::{apply_passes, config::JscTarget, Compiler};
+use swc_common::{errors::Level, FileName};
+use swc_ecma_ast::{EmptyStmt, Program, Stmt};
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::util::HANDLER;
+use swc_ecma_visit::{Fold, FoldWith};
+use testing::Tester;
+
+/// Removes `debugger` statements and warns about them.
+struct RemoveDebugger;
+
+impl Fold for RemoveDebugger {
+    fn fold_stmt(&mut self, s: Stmt) -> Stmt {
+        match s {
+            Stmt::Debugger(s) => {
+                HANDLER.with(|h| {
+                    h.struct_span_warn(s.span, "removed a debugger statement")
+                        .emit()
+                });
+                Stmt::Empty(EmptyStmt { span: s.span })
+            }
+            _ => s.fold_children_with(self),
+        }
+    }
+}
+
+#[test]
+fn collects_diagnostics() {
+    let src = "function f() {
+    debugger;
+    return 1;
+}
+";
+
+    let (module, result) = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Real("input.js".into()), src.into());
+            let start = fm.start_pos;
+
+            let program = c
+                .parse_js(fm, JscTarget::Es2020, Syntax::default(), true, true)
+                .unwrap();
+            let mut module = match program {
+                Program::Module(m) => m,
+                _ => unreachable!(),
+            };
+
+            let result = c.run(|| apply_passes(&mut module, RemoveDebugger, &c.handler));
+            assert!(!c.handler.has_errors());
+
+            let span = result.diagnostics[0].span.primary_span().unwrap();
+            assert_eq!((span.lo - start).0 as usize, src.find("debugger").unwrap());
+
+            Ok((module, result))
+        })
+        .unwrap();
+
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].level, Level::Warning);
+    assert_eq!(
+        result.diagnostics[0].message(),
+        "removed a debugger statement"
+    );
+    assert!(!result.has_errors());
+
+    let code = format!("{:?}", module);
+    assert!(!code.contains("Debugger"), "{}", code);
+}
@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use swc::{
+    config::{Assumptions, Config, JscConfig, JscTarget, Options},
+    Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_parser::{EsConfig, Syntax};
+use testing::{StdErr, Tester};
+
+fn compile(src: &str, assumptions: Assumptions) -> String {
+    try_compile(src, assumptions, None).unwrap()
+}
+
+fn try_compile(
+    src: &str,
+    assumptions: Assumptions,
+    env: Option<swc_ecma_preset_env::Config>,
+) -> Result<String, StdErr> {
+    Tester::new().print_errors(|cm, handler| {
+        let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+        let fm = cm.new_source_file(FileName::Real("input.js".into()), src.into());
+        let s = c.process_js_file(
+            fm,
+            &Options {
+                config: Some(Config {
+                    jsc: JscConfig {
+                        syntax: Some(Syntax::Es(EsConfig {
+                            nullish_coalescing: true,
+                            class_props: true,
+                            ..Default::default()
+                        })),
+                        target: JscTarget::Es5,
+                        assumptions,
+                        ..Default::default()
+                    },
+                    env,
+                    ..Default::default()
+                }),
+                swcrc: false,
+                is_module: true,
+                ..Default::default()
+            },
+        );
+
+        match s {
+            Ok(v) => {
+                if c.handler.has_errors() {
+                    Err(())
+                } else {
+                    Ok(v.code.into())
+                }
+            }
+            Err(..) => Err(()),
+        }
+    })
+}
+
+#[test]
+fn spread_iterable_is_array() {
+    let src = "const b = [...a];";
+
+    let output = compile(src, Default::default());
+    assert!(output.contains("_toConsumableArray(a)"), "{}", output);
+
+    let output = compile(
+        src,
+        Assumptions {
+            iterable_is_array: true,
+            ..Default::default()
+        },
+    );
+    assert!(!output.contains("_toConsumableArray"), "{}", output);
+    assert!(output.contains("a.slice()"), "{}", output);
+}
+
+#[test]
+fn array_iterator_is_default() {
+    let src = "const b = [...a, ...c()];";
+
+    let output = compile(
+        src,
+        Assumptions {
+            array_iterator_is_default: true,
+            ..Default::default()
+        },
+    );
+    assert!(
+        output.contains("Array.isArray(a) ? a : _toConsumableArray(a)"),
+        "{}",
+        output
+    );
+    assert!(output.contains("Array.isArray(_arr = c())"), "{}", output);
+}
+
+#[test]
+fn no_document_all() {
+    let src = "const b = a ?? 1;";
+
+    let output = compile(src, Default::default());
+    assert!(output.contains("void 0"), "{}", output);
+
+    let output = compile(
+        src,
+        Assumptions {
+            no_document_all: true,
+            ..Default::default()
+        },
+    );
+    assert!(output.contains("a != null ? a : 1"), "{}", output);
+}
+
+#[test]
+fn set_public_class_fields() {
+    let src = "class Foo { bar = 1; }";
+
+    let output = compile(src, Default::default());
+    assert!(output.contains("_defineProperty(this"), "{}", output);
+
+    let output = compile(
+        src,
+        Assumptions {
+            set_public_class_fields: true,
+            ..Default::default()
+        },
+    );
+    assert!(!output.contains("_defineProperty"), "{}", output);
+    assert!(output.contains("this.bar = 1"), "{}", output);
+}
+
+#[test]
+fn pure_getters() {
+    let src = "function foo(a) { return a.b.c.d + a.b.c.e; }";
+
+    let output = compile(src, Default::default());
+    assert!(output.contains("a.b.c.d + a.b.c.e"), "{}", output);
+
+    let output = compile(
+        src,
+        Assumptions {
+            pure_getters: true,
+            ..Default::default()
+        },
+    );
+    assert!(output.contains("var _c = a.b.c"), "{}", output);
+    assert!(output.contains("_c.d + _c.e"), "{}", output);
+}
+
+#[test]
+fn finally_receiver_is_promise() {
+    let src = "fetchData().finally(done);";
+
+    let output = compile(src, Default::default());
+    assert!(output.contains("fetchData().finally(done)"), "{}", output);
+
+    let output = compile(
+        src,
+        Assumptions {
+            finally_receiver_is_promise: true,
+            ..Default::default()
+        },
+    );
+    assert!(
+        output.contains("_promiseFinally(fetchData(), done)"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn env() {
+    let src = "const b = a ?? 1;";
+
+    let output = try_compile(
+        src,
+        Assumptions {
+            no_document_all: true,
+            ..Default::default()
+        },
+        Some(Default::default()),
+    );
+    assert!(output.is_err());
+
+    let output = try_compile(
+        src,
+        Assumptions {
+            pure_getters: true,
+            ..Default::default()
+        },
+        Some(Default::default()),
+    )
+    .unwrap();
+    assert!(output.contains("void 0"), "{}", output);
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use swc::{
+    config::{Config, JscConfig, JscTarget, ModuleConfig, Options},
+    Compiler,
+};
+use swc_common::FileName;
+use testing::Tester;
+
+fn compile(src: &str, module: Option<ModuleConfig>) -> String {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(FileName::Real("input.js".into()), src.into());
+            let s = c.process_js_file(
+                fm,
+                &Options {
+                    config: Some(Config {
+                        jsc: JscConfig {
+                            target: JscTarget::Es5,
+                            external_helpers: true,
+                            ..Default::default()
+                        },
+                        module,
+                        ..Default::default()
+                    }),
+                    swcrc: false,
+                    is_module: true,
+                    ..Default::default()
+                },
+            );
+
+            match s {
+                Ok(v) => {
+                    if c.handler.has_errors() {
+                        Err(())
+                    } else {
+                        Ok(v.code.into())
+                    }
+                }
+                Err(..) => Err(()),
+            }
+        })
+        .unwrap()
+}
+
+const SRC: &str = "class Foo {}
+class Bar extends Foo {}";
+
+#[test]
+fn es6_imports() {
+    let output = compile(SRC, Some(ModuleConfig::Es6));
+
+    assert!(
+        output.contains("classCallCheck as _classCallCheck"),
+        "{}",
+        output
+    );
+    assert!(output.contains("inherits as _inherits"), "{}", output);
+    assert!(output.contains(r#"from "@swc/helpers";"#), "{}", output);
+    assert!(output.contains("_classCallCheck(this, Foo);"), "{}", output);
+    assert!(!output.contains("function _"), "{}", output);
+}
+
+#[test]
+fn commonjs_requires() {
+    let output = compile(SRC, Some(ModuleConfig::CommonJs(Default::default())));
+
+    assert!(output.contains(r#"require("@swc/helpers")"#), "{}", output);
+    assert!(!output.contains("import "), "{}", output);
+    assert!(!output.contains("function _"), "{}", output);
+}
+
+#[test]
+fn inline_unpublished_helpers() {
+    let output = compile(
+        "class Foo {}
+console.log(globalThis);",
+        Some(ModuleConfig::Es6),
+    );
+
+    assert!(
+        output.contains("classCallCheck as _classCallCheck"),
+        "{}",
+        output
+    );
+    assert!(!output.contains("globalThis as _globalThis"), "{}", output);
+    assert!(output.contains("function _globalThis("), "{}", output);
+}
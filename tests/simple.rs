@@ -1,6 +1,9 @@
 use std::sync::Arc;
 use swc::{
-    config::{Config, JscConfig, Options},
+    config::{
+        Config, JscConfig, JscTarget, OptimizerConfig, Options, PureAnnotationsOption,
+        SourceMapsConfig, TransformConfig,
+    },
     Compiler,
 };
 use swc_common::FileName;
@@ -94,3 +97,108 @@ fn issue_834_3() {
         },
     );
 }
+
+#[test]
+fn print_minify_comments() {
+    let output = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+
+            let fm = cm.new_source_file(
+                FileName::Real("input.js".into()),
+                "/*! license */\n// comment\nimport(/* webpackChunkName: \"foo\" */ './foo');"
+                    .into(),
+            );
+            let program = c
+                .parse_js(
+                    fm,
+                    JscTarget::Es2020,
+                    Syntax::Es(EsConfig {
+                        dynamic_import: true,
+                        ..Default::default()
+                    }),
+                    true,
+                    true,
+                )
+                .map_err(|_| ())?;
+
+            c.print(
+                &program,
+                JscTarget::Es2020,
+                SourceMapsConfig::Bool(false),
+                None,
+                true,
+            )
+            .map_err(|_| ())
+        })
+        .unwrap();
+
+    assert!(output.code.contains("/*! license */"), "{}", output.code);
+    assert!(output.code.contains("webpackChunkName"), "{}", output.code);
+    assert!(!output.code.contains("// comment"), "{}", output.code);
+}
+
+#[test]
+fn pure_annotations() {
+    let code = compile(
+        "export const Context = createContext(null);\nexport const store = createStore(null);",
+        Options {
+            config: Some(Config {
+                jsc: JscConfig {
+                    transform: Some(TransformConfig {
+                        optimizer: Some(OptimizerConfig {
+                            pure_annotations: Some(PureAnnotationsOption {
+                                allowlist: vec!["createContext".into()],
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            swcrc: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(
+        code.contains("/*#__PURE__*/ createContext(null)"),
+        "{}",
+        code
+    );
+    assert!(!code.contains("/*#__PURE__*/ createStore"), "{}", code);
+}
+
+#[test]
+fn polyfill_builtins() {
+    let compile_with = |polyfill_builtins| {
+        compile(
+            "export const last = [1, 2].at(-1);",
+            Options {
+                config: Some(Config {
+                    jsc: JscConfig {
+                        target: JscTarget::Es2020,
+                        transform: Some(TransformConfig {
+                            polyfill_builtins,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+                swcrc: false,
+                ..Default::default()
+            },
+        )
+    };
+
+    let code = compile_with(false);
+    assert!(code.contains(".at(-1)"), "{}", code);
+    assert!(!code.contains("function _at("), "{}", code);
+
+    let code = compile_with(true);
+    assert!(!code.contains(".at(-1)"), "{}", code);
+    assert!(code.contains("function _at("), "{}", code);
+}
@@ -0,0 +1,145 @@
+#[cfg(feature = "concurrent")]
+use swc::transform_all;
+use swc::{
+    config::{JscTarget, ModuleConfig},
+    transform, TransformOptions,
+};
+use swc_common::FileName;
+use swc_ecma_parser::{EsConfig, Syntax, TsConfig};
+
+#[test]
+fn ts_jsx_to_es5() {
+    let output = transform(
+        "const name: string = 'swc';
+        const App = () => <div title={name}>Hello</div>;",
+        TransformOptions {
+            syntax: Syntax::Typescript(TsConfig {
+                tsx: true,
+                ..Default::default()
+            }),
+            target: JscTarget::Es5,
+            ..Default::default()
+        },
+    )
+    .expect("failed to transform");
+
+    assert!(
+        output.code.contains("React.createElement"),
+        "{}",
+        output.code
+    );
+    assert!(!output.code.contains(": string"), "{}", output.code);
+    assert!(!output.code.contains("=>"), "{}", output.code);
+    assert!(output.map.is_none());
+}
+
+#[test]
+fn source_map() {
+    let output = transform(
+        "export const foo = 1;",
+        TransformOptions {
+            source_maps: true,
+            ..Default::default()
+        },
+    )
+    .expect("failed to transform");
+
+    assert!(
+        output.code.contains("export var foo = 1"),
+        "{}",
+        output.code
+    );
+    assert!(output.map.is_some());
+}
+
+#[test]
+fn syntax_error() {
+    let err = transform("const = 1;", Default::default()).expect_err("should fail");
+
+    assert!(format!("{:?}", err).contains("error"), "{:?}", err);
+}
+
+#[test]
+#[cfg(feature = "concurrent")]
+fn transform_all_in_parallel() {
+    let files: Vec<_> = (0..32)
+        .map(|i| {
+            (
+                FileName::Real(format!("file{}.js", i).into()),
+                format!(
+                    "export const value{} = () => [{}, ...rest];\nclass A{} {{ foo() {{}} }}",
+                    i, i, i
+                ),
+            )
+        })
+        .collect();
+    let options = TransformOptions {
+        target: JscTarget::Es5,
+        ..Default::default()
+    };
+
+    let first = transform_all(files.clone(), &options);
+    let second = transform_all(files.clone(), &options);
+
+    assert_eq!(first.len(), files.len());
+    for (i, ((a, b), (_, src))) in first.iter().zip(&second).zip(&files).enumerate() {
+        let a = &a.as_ref().expect("failed to transform").code;
+        let b = &b.as_ref().expect("failed to transform").code;
+
+        assert_eq!(a, b);
+        assert_eq!(*a, transform(src, options.clone()).unwrap().code);
+        assert!(a.contains(&format!("value{}", i)), "{}", a);
+        assert!(!a.contains("=>"), "{}", a);
+    }
+}
+
+#[test]
+#[cfg(feature = "concurrent")]
+fn transform_all_isolates_errors() {
+    let files = vec![
+        (FileName::Real("good.js".into()), "const a = 1;".into()),
+        (FileName::Real("bad.js".into()), "const = 1;".into()),
+    ];
+
+    let results = transform_all(files, &Default::default());
+
+    assert!(results[0].is_ok(), "{:?}", results[0]);
+    let err = format!("{:?}", results[1].as_ref().unwrap_err());
+    assert!(err.contains("bad.js"), "{}", err);
+    assert!(!err.contains("good.js"), "{}", err);
+}
+
+#[test]
+fn dynamic_import_magic_comment() {
+    let src = "import(/* webpackChunkName: \"foo\" */ './foo');";
+    let syntax = Syntax::Es(EsConfig {
+        dynamic_import: true,
+        ..Default::default()
+    });
+
+    for (module, minify) in vec![
+        (None, false),
+        (None, true),
+        (Some(ModuleConfig::CommonJs(Default::default())), false),
+        (Some(ModuleConfig::CommonJs(Default::default())), true),
+    ] {
+        let output = transform(
+            src,
+            TransformOptions {
+                syntax,
+                module,
+                minify,
+                ..Default::default()
+            },
+        )
+        .expect("failed to transform");
+
+        let code = output.code.replace(' ', "");
+        assert!(
+            code.contains("(/*webpackChunkName:\"foo\"*/\"./foo\")")
+                || code.contains("(/*webpackChunkName:\"foo\"*/'./foo')"),
+            "{}",
+            output.code
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+use swc::{config::JscTarget, Compiler};
+use swc_common::{BytePos, FileName, Span, SyntaxContext};
+use swc_ecma_ast::Program;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms::{
+    compat::es2015::{arrow, classes},
+    resolver,
+};
+use swc_ecma_visit::{Fold, FoldWith};
+use testing::Tester;
+
+#[test]
+fn single_function() {
+    let src = "function a() {
+    return () => this;
+}
+function b() {
+    return () => this;
+}
+function c() {
+    return () => this;
+}
+";
+
+    let (span, code) = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Real("input.js".into()), src.into());
+            let start = fm.start_pos;
+
+            let program = c
+                .parse_js(fm, JscTarget::Es2020, Syntax::default(), true, true)
+                .unwrap();
+            let module = match c.run(|| program.fold_with(&mut resolver())) {
+                Program::Module(m) => m,
+                _ => unreachable!(),
+            };
+
+            let pos = src.find("function b").unwrap() as u32;
+            let range = Span::new(
+                start + BytePos(pos),
+                start + BytePos(pos + 1),
+                SyntaxContext::empty(),
+            );
+            let edit = c
+                .transform_range(&module, range, false, arrow())
+                .unwrap()
+                .unwrap();
+
+            let span = (edit.span.lo - start).0 as usize..(edit.span.hi - start).0 as usize;
+            Ok((span, edit.code))
+        })
+        .unwrap();
+
+    assert_eq!(
+        &src[span],
+        "function b() {
+    return () => this;
+}"
+    );
+    assert!(code.contains("var _this = this"), "{}", code);
+    assert!(!code.contains("=>"), "{}", code);
+    assert!(!code.contains("function a"), "{}", code);
+    assert!(!code.contains("function c"), "{}", code);
+}
+
+/// Transforms the top-level item starting with `item` and returns the source
+/// replaced by the edit and the new code.
+fn transform_item(
+    src: &str,
+    item: &str,
+    external_helpers: bool,
+    pass: impl Fold,
+) -> (String, String) {
+    let (span, code) = Tester::new()
+        .print_errors(|cm, handler| {
+            let c = Compiler::new(cm.clone(), Arc::new(handler));
+            let fm = cm.new_source_file(FileName::Real("input.js".into()), src.into());
+            let start = fm.start_pos;
+
+            let program = c
+                .parse_js(fm, JscTarget::Es2020, Syntax::default(), true, true)
+                .unwrap();
+            let module = match c.run(|| program.fold_with(&mut resolver())) {
+                Program::Module(m) => m,
+                _ => unreachable!(),
+            };
+
+            let pos = src.find(item).unwrap() as u32;
+            let range = Span::new(
+                start + BytePos(pos),
+                start + BytePos(pos + 1),
+                SyntaxContext::empty(),
+            );
+            let edit = c
+                .transform_range(&module, range, external_helpers, pass)
+                .unwrap()
+                .unwrap();
+
+            let span = (edit.span.lo - start).0 as usize..(edit.span.hi - start).0 as usize;
+            Ok((span, edit.code))
+        })
+        .unwrap();
+
+    (src[span].to_string(), code)
+}
+
+#[test]
+fn helpers() {
+    let src = "class A {}
+class B {}
+";
+
+    let (replaced, code) = transform_item(src, "class B", false, classes());
+
+    assert_eq!(replaced, "class B {}");
+    assert!(code.contains("function _classCallCheck("), "{}", code);
+    assert!(code.contains("_classCallCheck(this, B)"), "{}", code);
+    assert!(!code.contains("class A"), "{}", code);
+}
+
+#[test]
+fn external_helpers() {
+    let src = "class A {}
+class B {}
+";
+
+    let (replaced, code) = transform_item(src, "class B", true, classes());
+
+    assert_eq!(replaced, "class B {}");
+    assert!(code.contains("@swc/helpers"), "{}", code);
+    assert!(!code.contains("function _classCallCheck("), "{}", code);
+}
+
+#[test]
+fn conflict_outside_range() {
+    let src = "var _this = 1;
+function b() {
+    return () => this + _this;
+}
+";
+
+    let (replaced, code) = transform_item(src, "function b", false, arrow());
+
+    assert_eq!(
+        replaced,
+        "function b() {
+    return () => this + _this;
+}"
+    );
+    assert!(code.contains("var _this1 = this"), "{}", code);
+    assert!(code.contains("_this1 + _this"), "{}", code);
+    assert!(!code.contains("var _this = 1"), "{}", code);
+}